@@ -8,14 +8,15 @@ use super::{
 use crate::{
     args_parser::ArgsParser,
     bytesrepr::{self, deserialize, FromBytes, ToBytes},
-    execution::{Phase, PHASE_SERIALIZED_LENGTH},
+    execution::{CallStackElement, Phase, PHASE_SERIALIZED_LENGTH},
     ext_ffi,
     key::Key,
     unwrap_or_revert::UnwrapOrRevert,
     uref::URef,
     value::{
         account::{
-            BlockTime, PublicKey, BLOCKTIME_SERIALIZED_LENGTH, PUBLIC_KEY_SERIALIZED_LENGTH,
+            BlockTime, PublicKey, PurseId, BLOCKTIME_SERIALIZED_LENGTH,
+            PUBLIC_KEY_SERIALIZED_LENGTH,
         },
         Contract, Value,
     },
@@ -70,6 +71,74 @@ pub fn call_contract<A: ArgsParser, T: FromBytes>(
     deserialize(&res_bytes).unwrap_or_revert()
 }
 
+/// Call the entry point named `entry_point` of the given contract, passing the given
+/// (serialized) arguments. If the contract declares its entry points, the host checks the
+/// arguments against the parameters declared for the entry point before calling it, and the
+/// returned value against its declared return type.
+#[allow(clippy::ptr_arg)]
+pub fn call_entry_point<A: ArgsParser, T: FromBytes>(
+    c_ptr: ContractRef,
+    entry_point: &str,
+    args: &A,
+    extra_urefs: &Vec<Key>,
+) -> T {
+    let contract_key: Key = c_ptr.into();
+    let (key_ptr, key_size, _bytes1) = to_ptr(&contract_key);
+    let (name_ptr, name_size, _bytes2) = to_ptr(entry_point);
+    let (args_ptr, args_size, _bytes3) = ArgsParser::parse(args)
+        .map(|args| to_ptr(&args))
+        .unwrap_or_revert();
+    let (urefs_ptr, urefs_size, _bytes4) = to_ptr(extra_urefs);
+    let res_size = unsafe {
+        ext_ffi::call_entry_point(
+            key_ptr, key_size, name_ptr, name_size, args_ptr, args_size, urefs_ptr, urefs_size,
+        )
+    };
+    let res_ptr = alloc_bytes(res_size);
+    let res_bytes = unsafe {
+        ext_ffi::get_call_result(res_ptr);
+        Vec::from_raw_parts(res_ptr, res_size, res_size)
+    };
+    deserialize(&res_bytes).unwrap_or_revert()
+}
+
+/// Schedules a call of the entry point named `entry_point` of the given stored contract, passing
+/// the given arguments, to be run on behalf of the current account once the block time reaches
+/// `blocktime`.  The call is paid for at the minimum gas price from `gas_purse`, which the caller
+/// must be able to write to; its balance limits the gas available to the call.
+pub fn schedule_call<A: ArgsParser>(
+    c_ptr: ContractRef,
+    entry_point: &str,
+    args: &A,
+    blocktime: BlockTime,
+    gas_purse: PurseId,
+) -> Result<(), Error> {
+    let contract_key: Key = c_ptr.into();
+    let (key_ptr, key_size, _bytes1) = to_ptr(&contract_key);
+    let (name_ptr, name_size, _bytes2) = to_ptr(entry_point);
+    let (args_ptr, args_size, _bytes3) = ArgsParser::parse(args)
+        .map(|args| to_ptr(&args))
+        .unwrap_or_revert();
+    let schedule_bytes = {
+        let mut bytes = blocktime.to_bytes().unwrap_or_revert();
+        bytes.append(&mut gas_purse.to_bytes().unwrap_or_revert());
+        bytes
+    };
+    let result = unsafe {
+        ext_ffi::schedule_call(
+            key_ptr,
+            key_size,
+            name_ptr,
+            name_size,
+            args_ptr,
+            args_size,
+            schedule_bytes.as_ptr(),
+            schedule_bytes.len(),
+        )
+    };
+    result_from(result)
+}
+
 /// Takes the name of a function to store and a contract URef, and overwrites the value under
 /// that URef with a new Contract instance containing the original contract's named_keys, the
 /// current protocol version, and the newly created bytes of the stored function.
@@ -144,6 +213,19 @@ pub fn get_blocktime() -> BlockTime {
     deserialize(&bytes).unwrap_or_revert()
 }
 
+/// Returns the call stack of the current execution: the session code of the deploying account
+/// at the bottom, followed by each stored contract called since, the last one being the
+/// currently executing code.
+pub fn get_call_stack() -> Vec<CallStackElement> {
+    let res_size = unsafe { ext_ffi::get_call_stack() };
+    let res_ptr = alloc_bytes(res_size);
+    let res_bytes = unsafe {
+        ext_ffi::get_call_result(res_ptr);
+        Vec::from_raw_parts(res_ptr, res_size, res_size)
+    };
+    deserialize(&res_bytes).unwrap_or_revert()
+}
+
 pub fn get_phase() -> Phase {
     let dest_ptr = alloc_bytes(PHASE_SERIALIZED_LENGTH);
     unsafe { ext_ffi::get_phase(dest_ptr) };
@@ -191,6 +273,17 @@ pub fn put_key(name: &str, key: &Key) {
     unsafe { ext_ffi::put_key(name_ptr, name_size, key_ptr, key_size) };
 }
 
+/// Emits an event named `name` carrying the serialized `data`.
+///
+/// Events are returned alongside the results of the deploy, tagged with the key of the emitting
+/// contract and their position among all events emitted by the deploy.  They are discarded if
+/// the deploy fails.
+pub fn emit_event<T: ToBytes>(name: &str, data: &T) {
+    let (name_ptr, name_size, _bytes) = to_ptr(name);
+    let (data_ptr, data_size, _bytes2) = to_ptr(data);
+    unsafe { ext_ffi::emit_event(name_ptr, name_size, data_ptr, data_size) };
+}
+
 /// Removes Key persisted under [name] in the current context's map.
 pub fn remove_key(name: &str) {
     let (name_ptr, name_size, _bytes) = to_ptr(name);
@@ -12,7 +12,7 @@ use crate::{
     key::{Key, KEY_UREF_SERIALIZED_LENGTH},
     unwrap_or_revert::UnwrapOrRevert,
     uref::AccessRights,
-    value::{Contract, Value},
+    value::{abi::EntryPoint, Contract, Value},
 };
 
 pub(crate) fn read_untyped(key: &Key) -> Result<Option<Value>, bytesrepr::Error> {
@@ -92,6 +92,16 @@ fn write_untyped(key: &Key, value: &Value) {
     }
 }
 
+/// Delete the value under the key from the global state. The key must have
+/// WRITE access rights.
+pub fn delete<T>(turef: TURef<T>) {
+    let key: Key = turef.into();
+    let (key_ptr, key_size, _bytes) = to_ptr(&key);
+    unsafe {
+        ext_ffi::delete(key_ptr, key_size);
+    }
+}
+
 /// Writes the given value at the given key in the context-local partition of
 /// global state
 pub fn write_local<K, V>(key: K, value: V)
@@ -155,6 +165,40 @@ pub fn store_function_at_hash(name: &str, named_keys: BTreeMap<String, Key>) ->
     ContractRef::Hash(addr)
 }
 
+/// Stores the module of the currently running code at an immutable address generated by the
+/// host, as a contract with the given named entry points. Each entry point must be a function
+/// exported by the module; all other exports are removed from the stored module.
+pub fn store_contract(
+    entry_points: BTreeMap<String, EntryPoint>,
+    named_keys: BTreeMap<String, Key>,
+) -> ContractRef {
+    let (entry_points_ptr, entry_points_size, _bytes1) = to_ptr(&entry_points);
+    let (keys_ptr, keys_size, _bytes2) = to_ptr(&named_keys);
+    let mut addr = [0u8; 32];
+    unsafe {
+        ext_ffi::store_contract(
+            entry_points_ptr,
+            entry_points_size,
+            keys_ptr,
+            keys_size,
+            addr.as_mut_ptr(),
+        );
+    }
+    ContractRef::Hash(addr)
+}
+
+/// Stores the whole module of the currently running code at an immutable address generated by
+/// the host.  Every function exported by the module is an entry point of the stored contract,
+/// which can be invoked with [`call_entry_point`](crate::contract_api::runtime::call_entry_point).
+pub fn store_module(named_keys: BTreeMap<String, Key>) -> ContractRef {
+    let (keys_ptr, keys_size, _bytes) = to_ptr(&named_keys);
+    let mut addr = [0u8; 32];
+    unsafe {
+        ext_ffi::store_module(keys_ptr, keys_size, addr.as_mut_ptr());
+    }
+    ContractRef::Hash(addr)
+}
+
 /// Returns a new unforgable pointer, where value is initialized to `init`
 pub fn new_turef<T: Into<Value>>(init: T) -> TURef<T> {
     let key_ptr = alloc_bytes(KEY_UREF_SERIALIZED_LENGTH);
@@ -1,12 +1,16 @@
 // Can be removed once https://github.com/rust-lang/rustfmt/issues/3362 is resolved.
 #[rustfmt::skip]
 use alloc::vec;
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 use num_derive::{FromPrimitive, ToPrimitive};
 use num_traits::{FromPrimitive, ToPrimitive};
 
-use crate::bytesrepr::{Error, FromBytes, ToBytes};
+use crate::{
+    bytesrepr::{Error, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
+    key::Key,
+    value::account::{BlockTime, PublicKey, PurseId},
+};
 
 pub const PHASE_SERIALIZED_LENGTH: usize = 1;
 
@@ -34,3 +38,264 @@ impl FromBytes for Phase {
         Ok((phase, rest))
     }
 }
+
+const SESSION_TAG: u8 = 0;
+const STORED_CONTRACT_TAG: u8 = 1;
+
+/// A frame of the call stack of an execution, as returned by
+/// [`get_call_stack`](crate::contract_api::runtime::get_call_stack).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CallStackElement {
+    /// Session or payment code run on behalf of an account, at the bottom of the call stack.
+    Session { account: PublicKey, phase: Phase },
+    /// A stored contract living under a [`Key::Hash`] or [`Key::URef`], called by the frame
+    /// below it.
+    StoredContract { key: Key, phase: Phase },
+}
+
+impl CallStackElement {
+    pub fn phase(&self) -> Phase {
+        match self {
+            CallStackElement::Session { phase, .. }
+            | CallStackElement::StoredContract { phase, .. } => *phase,
+        }
+    }
+}
+
+impl ToBytes for CallStackElement {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = Vec::new();
+        match self {
+            CallStackElement::Session { account, phase } => {
+                result.push(SESSION_TAG);
+                result.append(&mut account.to_bytes()?);
+                result.append(&mut phase.to_bytes()?);
+            }
+            CallStackElement::StoredContract { key, phase } => {
+                result.push(STORED_CONTRACT_TAG);
+                result.append(&mut key.to_bytes()?);
+                result.append(&mut phase.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl FromBytes for CallStackElement {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (tag, rem): (u8, &[u8]) = FromBytes::from_bytes(bytes)?;
+        match tag {
+            SESSION_TAG => {
+                let (account, rem): (PublicKey, &[u8]) = FromBytes::from_bytes(rem)?;
+                let (phase, rem): (Phase, &[u8]) = FromBytes::from_bytes(rem)?;
+                Ok((CallStackElement::Session { account, phase }, rem))
+            }
+            STORED_CONTRACT_TAG => {
+                let (key, rem): (Key, &[u8]) = FromBytes::from_bytes(rem)?;
+                let (phase, rem): (Phase, &[u8]) = FromBytes::from_bytes(rem)?;
+                Ok((CallStackElement::StoredContract { key, phase }, rem))
+            }
+            _ => Err(Error::FormattingError),
+        }
+    }
+}
+
+impl ToBytes for Vec<CallStackElement> {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        if self.len() >= u32::max_value() as usize {
+            return Err(Error::OutOfMemoryError);
+        }
+        let mut result = Vec::with_capacity(U32_SERIALIZED_LENGTH);
+        result.append(&mut (self.len() as u32).to_bytes()?);
+        for element in self {
+            result.append(&mut element.to_bytes()?);
+        }
+        Ok(result)
+    }
+}
+
+impl FromBytes for Vec<CallStackElement> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (count, mut stream): (u32, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let mut result = Vec::new();
+        result.try_reserve_exact(count as usize)?;
+        for _ in 0..count {
+            let (element, rem): (CallStackElement, &[u8]) = FromBytes::from_bytes(stream)?;
+            result.push(element);
+            stream = rem;
+        }
+        Ok((result, stream))
+    }
+}
+
+/// A call of an entry point of a stored contract, scheduled with
+/// [`schedule_call`](crate::contract_api::runtime::schedule_call) to be run on behalf of `account`
+/// once the block time reaches `blocktime`.  The call is paid for from `gas_purse`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ScheduledCall {
+    contract: Key,
+    entry_point: String,
+    args: Vec<u8>,
+    blocktime: BlockTime,
+    gas_purse: PurseId,
+    account: PublicKey,
+}
+
+impl ScheduledCall {
+    pub fn new(
+        contract: Key,
+        entry_point: String,
+        args: Vec<u8>,
+        blocktime: BlockTime,
+        gas_purse: PurseId,
+        account: PublicKey,
+    ) -> Self {
+        ScheduledCall {
+            contract,
+            entry_point,
+            args,
+            blocktime,
+            gas_purse,
+            account,
+        }
+    }
+
+    /// The [`Key::Hash`] or [`Key::URef`] of the stored contract to call.
+    pub fn contract(&self) -> Key {
+        self.contract
+    }
+
+    pub fn entry_point(&self) -> &str {
+        &self.entry_point
+    }
+
+    /// The serialized arguments to call the entry point with.
+    pub fn args(&self) -> &[u8] {
+        &self.args
+    }
+
+    /// The block time from which the call is due.
+    pub fn blocktime(&self) -> BlockTime {
+        self.blocktime
+    }
+
+    pub fn gas_purse(&self) -> PurseId {
+        self.gas_purse
+    }
+
+    pub fn account(&self) -> PublicKey {
+        self.account
+    }
+}
+
+impl ToBytes for ScheduledCall {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.contract.to_bytes()?);
+        result.append(&mut self.entry_point.to_bytes()?);
+        result.append(&mut self.args.to_bytes()?);
+        result.append(&mut self.blocktime.to_bytes()?);
+        result.append(&mut self.gas_purse.to_bytes()?);
+        result.append(&mut self.account.to_bytes()?);
+        Ok(result)
+    }
+}
+
+impl FromBytes for ScheduledCall {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (contract, rem): (Key, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (entry_point, rem): (String, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (args, rem): (Vec<u8>, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (blocktime, rem): (BlockTime, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (gas_purse, rem): (PurseId, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (account, rem): (PublicKey, &[u8]) = FromBytes::from_bytes(rem)?;
+        let scheduled_call = ScheduledCall {
+            contract,
+            entry_point,
+            args,
+            blocktime,
+            gas_purse,
+            account,
+        };
+        Ok((scheduled_call, rem))
+    }
+}
+
+impl ToBytes for Vec<ScheduledCall> {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        if self.len() >= u32::max_value() as usize {
+            return Err(Error::OutOfMemoryError);
+        }
+        let mut result = Vec::with_capacity(U32_SERIALIZED_LENGTH);
+        result.append(&mut (self.len() as u32).to_bytes()?);
+        for scheduled_call in self {
+            result.append(&mut scheduled_call.to_bytes()?);
+        }
+        Ok(result)
+    }
+}
+
+impl FromBytes for Vec<ScheduledCall> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (count, mut stream): (u32, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let mut result = Vec::new();
+        result.try_reserve_exact(count as usize)?;
+        for _ in 0..count {
+            let (scheduled_call, rem): (ScheduledCall, &[u8]) = FromBytes::from_bytes(stream)?;
+            result.push(scheduled_call);
+            stream = rem;
+        }
+        Ok((result, stream))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec};
+
+    use super::{CallStackElement, Phase, ScheduledCall};
+    use crate::{
+        bytesrepr,
+        key::Key,
+        uref::{AccessRights, URef},
+        value::account::{BlockTime, PublicKey, PurseId},
+    };
+
+    #[test]
+    fn should_serialize_and_deserialize_call_stack() {
+        let call_stack = vec![
+            CallStackElement::Session {
+                account: PublicKey::new([1u8; 32]),
+                phase: Phase::Session,
+            },
+            CallStackElement::StoredContract {
+                key: Key::Hash([2u8; 32]),
+                phase: Phase::Session,
+            },
+        ];
+        bytesrepr::test_serialization_roundtrip(&call_stack);
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize_scheduled_calls() {
+        let scheduled_calls = vec![
+            ScheduledCall::new(
+                Key::Hash([1u8; 32]),
+                "vest".to_string(),
+                vec![1, 2, 3],
+                BlockTime::new(100),
+                PurseId::new(URef::new([2u8; 32], AccessRights::READ_ADD_WRITE)),
+                PublicKey::new([3u8; 32]),
+            ),
+            ScheduledCall::new(
+                Key::URef(URef::new([4u8; 32], AccessRights::READ)),
+                "call".to_string(),
+                vec![],
+                BlockTime::new(200),
+                PurseId::new(URef::new([5u8; 32], AccessRights::READ_ADD_WRITE)),
+                PublicKey::new([6u8; 32]),
+            ),
+        ];
+        bytesrepr::test_serialization_roundtrip(&scheduled_calls);
+    }
+}
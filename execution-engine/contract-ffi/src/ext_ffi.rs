@@ -103,4 +103,37 @@ extern "C" {
         dest_size: usize,
     ) -> i32;
     pub fn get_main_purse(dest_ptr: *mut u8);
+    pub fn emit_event(name_ptr: *const u8, name_size: usize, data_ptr: *const u8, data_size: usize);
+    pub fn store_contract(
+        entry_points_ptr: *const u8,
+        entry_points_size: usize,
+        named_keys_ptr: *const u8,
+        named_keys_size: usize,
+        hash_ptr: *const u8,
+    );
+    pub fn call_entry_point(
+        key_ptr: *const u8,
+        key_size: usize,
+        entry_point_name_ptr: *const u8,
+        entry_point_name_size: usize,
+        args_ptr: *const u8,
+        args_size: usize,
+        // extra urefs known by the caller to make available to the callee
+        extra_urefs_ptr: *const u8,
+        extra_urefs_size: usize,
+    ) -> usize; // the result can be read with `get_call_result`
+    pub fn store_module(named_keys_ptr: *const u8, named_keys_size: usize, hash_ptr: *const u8);
+    pub fn get_call_stack() -> usize; // the result can be read with `get_call_result`
+    pub fn delete(key_ptr: *const u8, key_size: usize);
+    pub fn schedule_call(
+        key_ptr: *const u8,
+        key_size: usize,
+        entry_point_name_ptr: *const u8,
+        entry_point_name_size: usize,
+        args_ptr: *const u8,
+        args_size: usize,
+        // serialized block time followed by the serialized gas purse
+        schedule_ptr: *const u8,
+        schedule_size: usize,
+    ) -> i32;
 }
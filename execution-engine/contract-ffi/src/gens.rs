@@ -1,4 +1,4 @@
-use alloc::{collections::BTreeMap, string::String, vec};
+use alloc::{boxed::Box, collections::BTreeMap, string::String, vec};
 
 use proptest::{
     array, bits,
@@ -13,6 +13,7 @@ use crate::{
     key::*,
     uref::{AccessRights, URef},
     value::{
+        abi::{AbiType, EntryPoint, EntryPointAccess, Parameter},
         account::{ActionThresholds, AssociatedKeys, PublicKey, PurseId, Weight, MAX_KEYS},
         *,
     },
@@ -109,11 +110,70 @@ prop_compose! {
     }
 }
 
+pub fn abi_type_arb() -> impl Strategy<Value = AbiType> {
+    let leaf = prop_oneof![
+        Just(AbiType::I32),
+        Just(AbiType::U8),
+        Just(AbiType::U32),
+        Just(AbiType::U64),
+        Just(AbiType::U128),
+        Just(AbiType::U256),
+        Just(AbiType::U512),
+        Just(AbiType::Unit),
+        Just(AbiType::String),
+        Just(AbiType::Key),
+        Just(AbiType::URef),
+        Just(AbiType::PublicKey),
+        Just(AbiType::Value),
+        Just(AbiType::Any),
+    ];
+    leaf.prop_recursive(3, 8, 2, |inner| {
+        prop_oneof![
+            inner.clone().prop_map(|item| AbiType::List(Box::new(item))),
+            inner
+                .clone()
+                .prop_map(|item| AbiType::Option(Box::new(item))),
+            (inner.clone(), inner)
+                .prop_map(|(key, value)| AbiType::Map(Box::new(key), Box::new(value))),
+        ]
+    })
+}
+
+pub fn entry_point_access_arb() -> impl Strategy<Value = EntryPointAccess> {
+    prop_oneof![
+        Just(EntryPointAccess::Public),
+        Just(EntryPointAccess::AccountOnly),
+        vec(key_arb(), 0..5).prop_map(EntryPointAccess::Callers),
+        vec(uref_arb(), 0..5).prop_map(EntryPointAccess::Groups),
+    ]
+}
+
+pub fn entry_point_arb() -> impl Strategy<Value = EntryPoint> {
+    (
+        vec(("\\PC*", abi_type_arb()), 0..5),
+        abi_type_arb(),
+        entry_point_access_arb(),
+    )
+        .prop_map(|(parameters, ret, access)| {
+            let parameters = parameters
+                .into_iter()
+                .map(|(name, abi_type)| Parameter::new(&name, abi_type))
+                .collect();
+            EntryPoint::new(parameters, ret).with_access(access)
+        })
+}
+
 pub fn contract_arb() -> impl Strategy<Value = Contract> {
     protocol_version_arb().prop_flat_map(move |protocol_version_arb| {
         named_keys_arb(20).prop_flat_map(move |urefs| {
-            vec(any::<u8>(), 1..1000)
-                .prop_map(move |body| Contract::new(body, urefs.clone(), protocol_version_arb))
+            (
+                vec(any::<u8>(), 1..1000),
+                btree_map("\\PC*", entry_point_arb(), 0..5),
+            )
+                .prop_map(move |(body, entry_points)| {
+                    Contract::new(body, urefs.clone(), protocol_version_arb)
+                        .with_entry_points(entry_points)
+                })
         })
     })
 }
@@ -41,6 +41,20 @@ pub enum Error {
     FailedTransferToRewardsPurse,
     FailedTransferToAccountPurse,
     SetRefundPurseCalledOutsidePayment,
+    SystemFunctionCalledOutsideSystemPhase,
+    RewardsPurseBalanceNotFound,
+    FailedTransferFromRewardsPurse,
+    CannotSlashAllValidators,
+    NotDelegated,
+    SelfDelegation,
+    ValidatorHasDelegations,
+    DelegationsKeyDeserializationFailed,
+    DelegationsDeserializationFailed,
+    ParamNotFound,
+    ParamKeyUnexpectedType,
+    ParamDeserializationFailed,
+    /// Returned when a parameter is out of its valid range.
+    InvalidParam,
 }
 
 pub type Result<T> = result::Result<T, Error>;
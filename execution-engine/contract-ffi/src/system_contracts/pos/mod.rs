@@ -1,3 +1,9 @@
 mod error;
+pub mod params;
+mod payout;
 
 pub use error::{Error, PurseLookupError, Result};
+pub use params::Params;
+pub use payout::{
+    unbond_payouts_from_value, unbond_payouts_to_value, UnbondPayout, UNBOND_PAYOUTS_KEY,
+};
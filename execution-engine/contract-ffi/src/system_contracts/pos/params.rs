@@ -0,0 +1,221 @@
+// Can be removed once https://github.com/rust-lang/rustfmt/issues/3362 is resolved.
+#[rustfmt::skip]
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{
+    bytesrepr::{Error, FromBytes, ToBytes},
+    system_contracts::pos,
+    value::{Value, U512},
+};
+
+/// The denominator of the parameters given in millionths.
+const MILLION: u64 = 1_000_000;
+
+/// Named keys of the PoS contract under which its parameters are stored.
+pub const BOND_DELAY_KEY: &str = "pos_bond_delay";
+pub const UNBOND_DELAY_KEY: &str = "pos_unbond_delay";
+pub const MAX_BOND_LEN_KEY: &str = "pos_max_bond_len";
+pub const MAX_UNBOND_LEN_KEY: &str = "pos_max_unbond_len";
+pub const MAX_SPREAD_KEY: &str = "pos_max_spread";
+pub const MAX_INCREASE_KEY: &str = "pos_max_increase";
+pub const MAX_DECREASE_KEY: &str = "pos_max_decrease";
+pub const MAX_REL_INCREASE_KEY: &str = "pos_max_rel_increase";
+pub const MAX_REL_DECREASE_KEY: &str = "pos_max_rel_decrease";
+pub const PROPOSER_REWARD_SHARE_KEY: &str = "pos_proposer_reward_share";
+pub const COMMISSION_RATE_KEY: &str = "pos_commission_rate";
+
+/// The parameters of the PoS contract, set at genesis and changeable by upgrades.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// The time from a bonding request until the bond becomes effective and part of the stake.
+    pub bond_delay: u64,
+    /// The time from an unbonding request until the stakes are paid out.
+    pub unbond_delay: u64,
+    /// The maximum number of pending bonding requests.
+    pub max_bond_len: u64,
+    /// The maximum number of pending unbonding requests.
+    pub max_unbond_len: u64,
+    /// The maximum difference between the largest and the smallest stakes.
+    pub max_spread: U512,
+    /// The maximum increase of stakes in a single bonding request.
+    pub max_increase: U512,
+    /// The maximum decrease of stakes in a single unbonding request.
+    pub max_decrease: U512,
+    /// The maximum increase of stakes in millionths of the total stakes in a single bonding
+    /// request.
+    pub max_rel_increase: u64,
+    /// The maximum decrease of stakes in millionths of the total stakes in a single unbonding
+    /// request.
+    pub max_rel_decrease: u64,
+    /// The share of the rewards for a block which goes to its proposer before the rest is split
+    /// among the validators, in millionths of the rewards.
+    pub proposer_reward_share: u64,
+    /// The share of the delegators' rewards which validators receive as commission, in
+    /// millionths of the rewards.
+    pub commission_rate: u64,
+}
+
+impl Params {
+    /// Checks that the parameters given in millionths of a whole are at most one whole: the
+    /// reward shares are subtracted from the rewards they are taken from, and no more than the
+    /// total stakes can be unbonded.
+    pub fn validate(&self) -> pos::Result<()> {
+        if self.max_rel_decrease > MILLION
+            || self.proposer_reward_share > MILLION
+            || self.commission_rate > MILLION
+        {
+            return Err(pos::Error::InvalidParam);
+        }
+        Ok(())
+    }
+
+    /// Returns the parameters as values, each paired with the name of the PoS named key under
+    /// which it is stored.
+    pub fn to_named_values(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            (BOND_DELAY_KEY, Value::UInt64(self.bond_delay)),
+            (UNBOND_DELAY_KEY, Value::UInt64(self.unbond_delay)),
+            (MAX_BOND_LEN_KEY, Value::UInt64(self.max_bond_len)),
+            (MAX_UNBOND_LEN_KEY, Value::UInt64(self.max_unbond_len)),
+            (MAX_SPREAD_KEY, Value::UInt512(self.max_spread)),
+            (MAX_INCREASE_KEY, Value::UInt512(self.max_increase)),
+            (MAX_DECREASE_KEY, Value::UInt512(self.max_decrease)),
+            (MAX_REL_INCREASE_KEY, Value::UInt64(self.max_rel_increase)),
+            (MAX_REL_DECREASE_KEY, Value::UInt64(self.max_rel_decrease)),
+            (
+                PROPOSER_REWARD_SHARE_KEY,
+                Value::UInt64(self.proposer_reward_share),
+            ),
+            (COMMISSION_RATE_KEY, Value::UInt64(self.commission_rate)),
+        ]
+    }
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            // One hour and one day in milliseconds of block time.
+            bond_delay: 60 * 60 * 1000,
+            unbond_delay: 24 * 60 * 60 * 1000,
+            max_bond_len: 100,
+            max_unbond_len: 1000,
+            // TODO: Should this be a percentage instead?
+            // TODO: Pick a reasonable value.
+            max_spread: U512::MAX,
+            max_increase: U512::MAX,
+            max_decrease: U512::MAX,
+            max_rel_increase: 1_000_000_000,
+            max_rel_decrease: 900_000,
+            proposer_reward_share: 100_000,
+            commission_rate: 100_000,
+        }
+    }
+}
+
+impl ToBytes for Params {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.bond_delay.to_bytes()?);
+        result.append(&mut self.unbond_delay.to_bytes()?);
+        result.append(&mut self.max_bond_len.to_bytes()?);
+        result.append(&mut self.max_unbond_len.to_bytes()?);
+        result.append(&mut self.max_spread.to_bytes()?);
+        result.append(&mut self.max_increase.to_bytes()?);
+        result.append(&mut self.max_decrease.to_bytes()?);
+        result.append(&mut self.max_rel_increase.to_bytes()?);
+        result.append(&mut self.max_rel_decrease.to_bytes()?);
+        result.append(&mut self.proposer_reward_share.to_bytes()?);
+        result.append(&mut self.commission_rate.to_bytes()?);
+        Ok(result)
+    }
+}
+
+impl FromBytes for Params {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (bond_delay, rem): (u64, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (unbond_delay, rem): (u64, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (max_bond_len, rem): (u64, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (max_unbond_len, rem): (u64, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (max_spread, rem): (U512, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (max_increase, rem): (U512, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (max_decrease, rem): (U512, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (max_rel_increase, rem): (u64, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (max_rel_decrease, rem): (u64, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (proposer_reward_share, rem): (u64, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (commission_rate, rem): (u64, &[u8]) = FromBytes::from_bytes(rem)?;
+        let params = Params {
+            bond_delay,
+            unbond_delay,
+            max_bond_len,
+            max_unbond_len,
+            max_spread,
+            max_increase,
+            max_decrease,
+            max_rel_increase,
+            max_rel_decrease,
+            proposer_reward_share,
+            commission_rate,
+        };
+        Ok((params, rem))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Params;
+    use crate::{bytesrepr, system_contracts::pos, value::U512};
+
+    #[test]
+    fn should_serialize_and_deserialize_params() {
+        bytesrepr::test_serialization_roundtrip(&Params::default());
+        bytesrepr::test_serialization_roundtrip(&Params {
+            bond_delay: 1,
+            unbond_delay: 2,
+            max_bond_len: 3,
+            max_unbond_len: 4,
+            max_spread: U512::from(5),
+            max_increase: U512::from(6),
+            max_decrease: U512::from(7),
+            max_rel_increase: 8,
+            max_rel_decrease: 9,
+            proposer_reward_share: 10,
+            commission_rate: 11,
+        });
+    }
+
+    #[test]
+    fn should_validate_params() {
+        assert_eq!(Params::default().validate(), Ok(()));
+        let params = Params {
+            max_rel_decrease: 1_000_000,
+            proposer_reward_share: 1_000_000,
+            commission_rate: 1_000_000,
+            ..Default::default()
+        };
+        assert_eq!(params.validate(), Ok(()));
+
+        let invalid_params = [
+            Params {
+                max_rel_decrease: 1_000_001,
+                ..Default::default()
+            },
+            Params {
+                proposer_reward_share: 1_000_001,
+                ..Default::default()
+            },
+            Params {
+                commission_rate: 1_000_001,
+                ..Default::default()
+            },
+        ];
+        for params in &invalid_params {
+            assert_eq!(params.validate(), Err(pos::Error::InvalidParam));
+        }
+    }
+
+    #[test]
+    fn should_name_all_params() {
+        assert_eq!(Params::default().to_named_values().len(), 11);
+    }
+}
@@ -0,0 +1,106 @@
+use alloc::vec::Vec;
+
+use crate::{
+    bytesrepr::{Error, FromBytes, ToBytes},
+    value::{account::PublicKey, Value, U512},
+};
+
+/// The local key of the PoS contract under which `step` records the unbond payouts it made, so
+/// that they can be reported when the effects of the block are committed. It is only written by a
+/// `step` which pays something out.
+pub const UNBOND_PAYOUTS_KEY: u8 = 3;
+
+/// A payout of unbonded motes from the PoS bonding purse to the main purse of `account`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UnbondPayout {
+    pub account: PublicKey,
+    pub amount: U512,
+}
+
+impl UnbondPayout {
+    pub fn new(account: PublicKey, amount: U512) -> Self {
+        UnbondPayout { account, amount }
+    }
+}
+
+impl ToBytes for UnbondPayout {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.account.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        Ok(result)
+    }
+}
+
+impl FromBytes for UnbondPayout {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (account, rem): (PublicKey, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (amount, rem): (U512, &[u8]) = FromBytes::from_bytes(rem)?;
+        Ok((UnbondPayout::new(account, amount), rem))
+    }
+}
+
+/// Serializes `payouts` into the value stored under [`UNBOND_PAYOUTS_KEY`].
+pub fn unbond_payouts_to_value(payouts: &[UnbondPayout]) -> Result<Value, Error> {
+    let mut bytes = (payouts.len() as u64).to_bytes()?;
+    for payout in payouts {
+        bytes.append(&mut payout.to_bytes()?);
+    }
+    Ok(Value::ByteArray(bytes))
+}
+
+/// Parses the payouts from a value stored under [`UNBOND_PAYOUTS_KEY`].
+pub fn unbond_payouts_from_value(value: &Value) -> Result<Vec<UnbondPayout>, Error> {
+    let bytes = match value {
+        Value::ByteArray(bytes) => bytes,
+        _ => return Err(Error::FormattingError),
+    };
+    let (len, mut rem): (u64, &[u8]) = FromBytes::from_bytes(bytes)?;
+    let mut payouts = Vec::new();
+    for _ in 0..len {
+        let (payout, rest) = UnbondPayout::from_bytes(rem)?;
+        payouts.push(payout);
+        rem = rest;
+    }
+    if !rem.is_empty() {
+        return Err(Error::LeftOverBytes);
+    }
+    Ok(payouts)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::{unbond_payouts_from_value, unbond_payouts_to_value, UnbondPayout};
+    use crate::{
+        bytesrepr::{self, Error},
+        value::{account::PublicKey, Value, U512},
+    };
+
+    #[test]
+    fn should_serialize_and_deserialize_unbond_payout() {
+        bytesrepr::test_serialization_roundtrip(&UnbondPayout::new(
+            PublicKey::new([1; 32]),
+            U512::from(1_000),
+        ));
+    }
+
+    #[test]
+    fn should_convert_unbond_payouts_to_and_from_value() {
+        let payouts = vec![
+            UnbondPayout::new(PublicKey::new([1; 32]), U512::from(100)),
+            UnbondPayout::new(PublicKey::new([2; 32]), U512::from(200)),
+        ];
+        let value = unbond_payouts_to_value(&payouts).expect("should serialize");
+        assert_eq!(unbond_payouts_from_value(&value), Ok(payouts));
+
+        let value = unbond_payouts_to_value(&[]).expect("should serialize");
+        assert_eq!(unbond_payouts_from_value(&value), Ok(vec![]));
+
+        assert_eq!(
+            unbond_payouts_from_value(&Value::UInt64(1)),
+            Err(Error::FormattingError)
+        );
+    }
+}
@@ -0,0 +1,476 @@
+//! Types describing the named entry points of a stored contract, their parameters, return
+//! types and who may call them.
+//!
+//! Arguments are passed to contracts as the serialized bytes of each argument (see
+//! [`ArgsParser`](crate::args_parser::ArgsParser)), so checking an argument against an
+//! [`AbiType`] means checking that its bytes are exactly one serialized value of that type.
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use crate::{
+    bytesrepr::{Error, FromBytes, ToBytes, U32_SERIALIZED_LENGTH, U8_SERIALIZED_LENGTH},
+    key::Key,
+    uref::URef,
+    value::{account::PublicKey, Value, U128, U256, U512},
+};
+
+const I32_TAG: u8 = 0;
+const U8_TAG: u8 = 1;
+const U32_TAG: u8 = 2;
+const U64_TAG: u8 = 3;
+const U128_TAG: u8 = 4;
+const U256_TAG: u8 = 5;
+const U512_TAG: u8 = 6;
+const UNIT_TAG: u8 = 7;
+const STRING_TAG: u8 = 8;
+const KEY_TAG: u8 = 9;
+const UREF_TAG: u8 = 10;
+const PUBLIC_KEY_TAG: u8 = 11;
+const VALUE_TAG: u8 = 12;
+const LIST_TAG: u8 = 13;
+const OPTION_TAG: u8 = 14;
+const MAP_TAG: u8 = 15;
+const ANY_TAG: u8 = 16;
+
+const PUBLIC_TAG: u8 = 0;
+const ACCOUNT_ONLY_TAG: u8 = 1;
+const CALLERS_TAG: u8 = 2;
+const GROUPS_TAG: u8 = 3;
+
+/// The type of an entry point parameter or return value.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum AbiType {
+    I32,
+    U8,
+    U32,
+    U64,
+    U128,
+    U256,
+    U512,
+    Unit,
+    String,
+    Key,
+    URef,
+    PublicKey,
+    Value,
+    /// A `Vec` of the given type, e.g. `List(U8)` for `Vec<u8>`.
+    List(Box<AbiType>),
+    Option(Box<AbiType>),
+    /// A `BTreeMap` with the given key and value types.
+    Map(Box<AbiType>, Box<AbiType>),
+    /// Any bytes; not checked.
+    Any,
+}
+
+impl AbiType {
+    /// Returns `true` if `bytes` are exactly one serialized value of this type.
+    pub fn is_valid(&self, bytes: &[u8]) -> bool {
+        match self.skip(bytes) {
+            Ok(rem) => rem.is_empty(),
+            Err(_) => false,
+        }
+    }
+
+    /// Parses a serialized value of this type off the front of `bytes`, returning the remainder.
+    fn skip<'a>(&self, bytes: &'a [u8]) -> Result<&'a [u8], Error> {
+        fn rem<T: FromBytes>(bytes: &[u8]) -> Result<&[u8], Error> {
+            T::from_bytes(bytes).map(|(_, rem)| rem)
+        }
+
+        match self {
+            AbiType::I32 => rem::<i32>(bytes),
+            AbiType::U8 => rem::<u8>(bytes),
+            AbiType::U32 => rem::<u32>(bytes),
+            AbiType::U64 => rem::<u64>(bytes),
+            AbiType::U128 => rem::<U128>(bytes),
+            AbiType::U256 => rem::<U256>(bytes),
+            AbiType::U512 => rem::<U512>(bytes),
+            AbiType::Unit => Ok(bytes),
+            AbiType::String => rem::<String>(bytes),
+            AbiType::Key => rem::<Key>(bytes),
+            AbiType::URef => rem::<URef>(bytes),
+            AbiType::PublicKey => rem::<PublicKey>(bytes),
+            AbiType::Value => rem::<Value>(bytes),
+            AbiType::List(item_type) => {
+                let (count, mut stream): (u32, &[u8]) = FromBytes::from_bytes(bytes)?;
+                for _ in 0..count {
+                    stream = item_type.skip(stream)?;
+                }
+                Ok(stream)
+            }
+            AbiType::Option(inner_type) => {
+                let (tag, rem): (u8, &[u8]) = FromBytes::from_bytes(bytes)?;
+                match tag {
+                    0 => Ok(rem),
+                    1 => inner_type.skip(rem),
+                    _ => Err(Error::FormattingError),
+                }
+            }
+            AbiType::Map(key_type, value_type) => {
+                let (count, mut stream): (u32, &[u8]) = FromBytes::from_bytes(bytes)?;
+                for _ in 0..count {
+                    stream = key_type.skip(stream)?;
+                    stream = value_type.skip(stream)?;
+                }
+                Ok(stream)
+            }
+            AbiType::Any => Ok(&bytes[bytes.len()..]),
+        }
+    }
+}
+
+impl ToBytes for AbiType {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = Vec::with_capacity(U8_SERIALIZED_LENGTH);
+        match self {
+            AbiType::I32 => result.push(I32_TAG),
+            AbiType::U8 => result.push(U8_TAG),
+            AbiType::U32 => result.push(U32_TAG),
+            AbiType::U64 => result.push(U64_TAG),
+            AbiType::U128 => result.push(U128_TAG),
+            AbiType::U256 => result.push(U256_TAG),
+            AbiType::U512 => result.push(U512_TAG),
+            AbiType::Unit => result.push(UNIT_TAG),
+            AbiType::String => result.push(STRING_TAG),
+            AbiType::Key => result.push(KEY_TAG),
+            AbiType::URef => result.push(UREF_TAG),
+            AbiType::PublicKey => result.push(PUBLIC_KEY_TAG),
+            AbiType::Value => result.push(VALUE_TAG),
+            AbiType::List(item_type) => {
+                result.push(LIST_TAG);
+                result.append(&mut item_type.to_bytes()?);
+            }
+            AbiType::Option(inner_type) => {
+                result.push(OPTION_TAG);
+                result.append(&mut inner_type.to_bytes()?);
+            }
+            AbiType::Map(key_type, value_type) => {
+                result.push(MAP_TAG);
+                result.append(&mut key_type.to_bytes()?);
+                result.append(&mut value_type.to_bytes()?);
+            }
+            AbiType::Any => result.push(ANY_TAG),
+        }
+        Ok(result)
+    }
+}
+
+impl FromBytes for AbiType {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (tag, rem): (u8, &[u8]) = FromBytes::from_bytes(bytes)?;
+        match tag {
+            I32_TAG => Ok((AbiType::I32, rem)),
+            U8_TAG => Ok((AbiType::U8, rem)),
+            U32_TAG => Ok((AbiType::U32, rem)),
+            U64_TAG => Ok((AbiType::U64, rem)),
+            U128_TAG => Ok((AbiType::U128, rem)),
+            U256_TAG => Ok((AbiType::U256, rem)),
+            U512_TAG => Ok((AbiType::U512, rem)),
+            UNIT_TAG => Ok((AbiType::Unit, rem)),
+            STRING_TAG => Ok((AbiType::String, rem)),
+            KEY_TAG => Ok((AbiType::Key, rem)),
+            UREF_TAG => Ok((AbiType::URef, rem)),
+            PUBLIC_KEY_TAG => Ok((AbiType::PublicKey, rem)),
+            VALUE_TAG => Ok((AbiType::Value, rem)),
+            LIST_TAG => {
+                let (item_type, rem): (AbiType, &[u8]) = FromBytes::from_bytes(rem)?;
+                Ok((AbiType::List(Box::new(item_type)), rem))
+            }
+            OPTION_TAG => {
+                let (inner_type, rem): (AbiType, &[u8]) = FromBytes::from_bytes(rem)?;
+                Ok((AbiType::Option(Box::new(inner_type)), rem))
+            }
+            MAP_TAG => {
+                let (key_type, rem): (AbiType, &[u8]) = FromBytes::from_bytes(rem)?;
+                let (value_type, rem): (AbiType, &[u8]) = FromBytes::from_bytes(rem)?;
+                Ok((AbiType::Map(Box::new(key_type), Box::new(value_type)), rem))
+            }
+            ANY_TAG => Ok((AbiType::Any, rem)),
+            _ => Err(Error::FormattingError),
+        }
+    }
+}
+
+/// A named parameter of an entry point.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Parameter {
+    name: String,
+    abi_type: AbiType,
+}
+
+impl Parameter {
+    pub fn new(name: &str, abi_type: AbiType) -> Self {
+        Parameter {
+            name: name.into(),
+            abi_type,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn abi_type(&self) -> &AbiType {
+        &self.abi_type
+    }
+}
+
+impl ToBytes for Parameter {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = self.name.to_bytes()?;
+        result.append(&mut self.abi_type.to_bytes()?);
+        Ok(result)
+    }
+}
+
+impl FromBytes for Parameter {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (name, rem): (String, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (abi_type, rem): (AbiType, &[u8]) = FromBytes::from_bytes(rem)?;
+        Ok((Parameter { name, abi_type }, rem))
+    }
+}
+
+/// The reason arguments were rejected by [`EntryPoint::check_args`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum ArgumentError {
+    /// The number of arguments doesn't match the number of parameters.
+    Count { expected: usize, actual: usize },
+    /// The argument for the named parameter is not a value of the parameter's type.
+    Type { parameter: String },
+}
+
+/// Who may call an entry point.  The engine checks it against the immediate caller, i.e. the
+/// contract or account at the top of the call stack, before executing the entry point.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum EntryPointAccess {
+    /// Anyone may call the entry point.
+    Public,
+    /// Only session code of an account may call the entry point, not other contracts.
+    AccountOnly,
+    /// Only the listed accounts and contracts may call the entry point.
+    Callers(Vec<Key>),
+    /// Only callers holding one of the listed [`URef`]s, with at least its access rights, may
+    /// call the entry point.
+    Groups(Vec<URef>),
+}
+
+impl Default for EntryPointAccess {
+    fn default() -> Self {
+        EntryPointAccess::Public
+    }
+}
+
+impl ToBytes for EntryPointAccess {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = Vec::with_capacity(U8_SERIALIZED_LENGTH);
+        match self {
+            EntryPointAccess::Public => result.push(PUBLIC_TAG),
+            EntryPointAccess::AccountOnly => result.push(ACCOUNT_ONLY_TAG),
+            EntryPointAccess::Callers(callers) => {
+                result.push(CALLERS_TAG);
+                result.append(&mut callers.to_bytes()?);
+            }
+            EntryPointAccess::Groups(groups) => {
+                result.push(GROUPS_TAG);
+                result.append(&mut groups.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl FromBytes for EntryPointAccess {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (tag, rem): (u8, &[u8]) = FromBytes::from_bytes(bytes)?;
+        match tag {
+            PUBLIC_TAG => Ok((EntryPointAccess::Public, rem)),
+            ACCOUNT_ONLY_TAG => Ok((EntryPointAccess::AccountOnly, rem)),
+            CALLERS_TAG => {
+                let (callers, rem): (Vec<Key>, &[u8]) = FromBytes::from_bytes(rem)?;
+                Ok((EntryPointAccess::Callers(callers), rem))
+            }
+            GROUPS_TAG => {
+                let (groups, rem): (Vec<URef>, &[u8]) = FromBytes::from_bytes(rem)?;
+                Ok((EntryPointAccess::Groups(groups), rem))
+            }
+            _ => Err(Error::FormattingError),
+        }
+    }
+}
+
+/// An exported function of a stored contract which can be called by name, along with the types
+/// of its parameters and return value, and who may call it.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct EntryPoint {
+    parameters: Vec<Parameter>,
+    ret: AbiType,
+    access: EntryPointAccess,
+}
+
+impl EntryPoint {
+    /// Creates a public entry point.
+    pub fn new(parameters: Vec<Parameter>, ret: AbiType) -> Self {
+        EntryPoint {
+            parameters,
+            ret,
+            access: EntryPointAccess::Public,
+        }
+    }
+
+    /// Restricts who may call the entry point.
+    pub fn with_access(mut self, access: EntryPointAccess) -> Self {
+        self.access = access;
+        self
+    }
+
+    pub fn parameters(&self) -> &[Parameter] {
+        &self.parameters
+    }
+
+    pub fn ret(&self) -> &AbiType {
+        &self.ret
+    }
+
+    pub fn access(&self) -> &EntryPointAccess {
+        &self.access
+    }
+
+    /// Checks that `args` has one argument per parameter, each a value of the parameter's type.
+    pub fn check_args(&self, args: &[Vec<u8>]) -> Result<(), ArgumentError> {
+        if args.len() != self.parameters.len() {
+            return Err(ArgumentError::Count {
+                expected: self.parameters.len(),
+                actual: args.len(),
+            });
+        }
+        match self
+            .parameters
+            .iter()
+            .zip(args)
+            .find(|(parameter, arg)| !parameter.abi_type.is_valid(arg))
+        {
+            Some((parameter, _)) => Err(ArgumentError::Type {
+                parameter: parameter.name.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl ToBytes for EntryPoint {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        if self.parameters.len() >= u32::max_value() as usize {
+            return Err(Error::OutOfMemoryError);
+        }
+        let mut result = Vec::with_capacity(U32_SERIALIZED_LENGTH);
+        result.append(&mut (self.parameters.len() as u32).to_bytes()?);
+        for parameter in &self.parameters {
+            result.append(&mut parameter.to_bytes()?);
+        }
+        result.append(&mut self.ret.to_bytes()?);
+        result.append(&mut self.access.to_bytes()?);
+        Ok(result)
+    }
+}
+
+impl FromBytes for EntryPoint {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (count, mut stream): (u32, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let mut parameters = Vec::new();
+        for _ in 0..count {
+            let (parameter, rem): (Parameter, &[u8]) = FromBytes::from_bytes(stream)?;
+            parameters.push(parameter);
+            stream = rem;
+        }
+        let (ret, rem): (AbiType, &[u8]) = FromBytes::from_bytes(stream)?;
+        let (access, rem): (EntryPointAccess, &[u8]) = FromBytes::from_bytes(rem)?;
+        Ok((
+            EntryPoint {
+                parameters,
+                ret,
+                access,
+            },
+            rem,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, collections::BTreeMap, string::String, vec, vec::Vec};
+
+    use super::{AbiType, ArgumentError, EntryPoint, EntryPointAccess, Parameter};
+    use crate::{
+        bytesrepr::{self, ToBytes},
+        key::Key,
+        uref::{AccessRights, URef},
+        value::U512,
+    };
+
+    fn transfer() -> EntryPoint {
+        EntryPoint::new(
+            vec![
+                Parameter::new("recipient", AbiType::Key),
+                Parameter::new("amount", AbiType::U512),
+                Parameter::new(
+                    "memo",
+                    AbiType::Option(Box::new(AbiType::List(Box::new(AbiType::U8)))),
+                ),
+            ],
+            AbiType::Map(Box::new(AbiType::String), Box::new(AbiType::U512)),
+        )
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize_entry_point() {
+        bytesrepr::test_serialization_roundtrip(&transfer());
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize_entry_point_access() {
+        let group = URef::new([2u8; 32], AccessRights::READ);
+        let entry_point = transfer().with_access(EntryPointAccess::Groups(vec![group]));
+        bytesrepr::test_serialization_roundtrip(&entry_point);
+
+        let entry_point =
+            transfer().with_access(EntryPointAccess::Callers(vec![Key::Account([3u8; 32])]));
+        bytesrepr::test_serialization_roundtrip(&entry_point);
+    }
+
+    #[test]
+    fn should_accept_arguments_of_parameter_types() {
+        let args = vec![
+            Key::Hash([1u8; 32]).to_bytes().unwrap(),
+            U512::from(7).to_bytes().unwrap(),
+            Some(vec![1u8, 2, 3]).to_bytes().unwrap(),
+        ];
+        assert_eq!(transfer().check_args(&args), Ok(()));
+
+        let mut ret: BTreeMap<String, U512> = BTreeMap::new();
+        ret.insert("balance".into(), U512::from(7));
+        assert!(transfer().ret().is_valid(&ret.to_bytes().unwrap()));
+    }
+
+    #[test]
+    fn should_reject_arguments_not_matching_parameters() {
+        let key = Key::Hash([1u8; 32]).to_bytes().unwrap();
+        let amount = U512::from(7).to_bytes().unwrap();
+        let memo: Option<Vec<u8>> = None;
+        let memo = memo.to_bytes().unwrap();
+
+        assert_eq!(
+            transfer().check_args(&[key.clone(), amount.clone()]),
+            Err(ArgumentError::Count {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            transfer().check_args(&[amount, key, memo]),
+            Err(ArgumentError::Type {
+                parameter: "recipient".into()
+            })
+        );
+    }
+}
@@ -346,6 +346,33 @@ impl FromBytes for PublicKey {
     }
 }
 
+impl ToBytes for Vec<PublicKey> {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let size = self.len() as u32;
+        let mut result: Vec<u8> =
+            Vec::with_capacity(U32_SERIALIZED_LENGTH + self.len() * PUBLIC_KEY_SERIALIZED_LENGTH);
+        result.extend(size.to_bytes()?);
+        for public_key in self {
+            result.extend(public_key.to_bytes()?);
+        }
+        Ok(result)
+    }
+}
+
+impl FromBytes for Vec<PublicKey> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (size, mut stream): (u32, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let mut result = Vec::new();
+        result.try_reserve_exact(size as usize)?;
+        for _ in 0..size {
+            let (public_key, rem): (PublicKey, &[u8]) = FromBytes::from_bytes(stream)?;
+            result.push(public_key);
+            stream = rem;
+        }
+        Ok((result, stream))
+    }
+}
+
 /// Represents an error that happens when trying to add a new associated key
 /// on an account.
 ///
@@ -3,7 +3,7 @@ use alloc::{collections::BTreeMap, string::String, vec::Vec};
 use crate::{
     bytesrepr::{Error, FromBytes, ToBytes, U32_SERIALIZED_LENGTH, U64_SERIALIZED_LENGTH},
     key::{Key, KEY_UREF_SERIALIZED_LENGTH},
-    value::ProtocolVersion,
+    value::{abi::EntryPoint, ProtocolVersion},
 };
 
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -11,6 +11,7 @@ pub struct Contract {
     bytes: Vec<u8>,
     named_keys: BTreeMap<String, Key>,
     protocol_version: ProtocolVersion,
+    entry_points: BTreeMap<String, EntryPoint>,
 }
 
 impl Contract {
@@ -23,9 +24,17 @@ impl Contract {
             bytes,
             named_keys,
             protocol_version,
+            entry_points: BTreeMap::new(),
         }
     }
 
+    /// Declares the named entry points of the contract, each of which is a function exported by
+    /// its module.
+    pub fn with_entry_points(mut self, entry_points: BTreeMap<String, EntryPoint>) -> Self {
+        self.entry_points = entry_points;
+        self
+    }
+
     pub fn named_keys_append(&mut self, keys: &mut BTreeMap<String, Key>) {
         self.named_keys.append(keys);
     }
@@ -53,6 +62,14 @@ impl Contract {
     pub fn take_named_keys(self) -> BTreeMap<String, Key> {
         self.named_keys
     }
+
+    pub fn entry_points(&self) -> &BTreeMap<String, EntryPoint> {
+        &self.entry_points
+    }
+
+    pub fn entry_point(&self, name: &str) -> Option<&EntryPoint> {
+        self.entry_points.get(name)
+    }
 }
 
 impl ToBytes for Contract {
@@ -68,12 +85,14 @@ impl ToBytes for Contract {
                     self.bytes.len() +                                   //size for elements of bytes
                     U32_SERIALIZED_LENGTH +                              //size for length of named_keys
                     KEY_UREF_SERIALIZED_LENGTH * self.named_keys.len() + //size for named_keys elements
-                    U64_SERIALIZED_LENGTH; //size for protocol_version
+                    U64_SERIALIZED_LENGTH +                              //size for protocol_version
+                    U32_SERIALIZED_LENGTH; //size for length of entry_points
 
         let mut result = Vec::with_capacity(size);
         result.append(&mut self.bytes.to_bytes()?);
         result.append(&mut self.named_keys.to_bytes()?);
         result.append(&mut self.protocol_version.to_bytes()?);
+        result.append(&mut self.entry_points.to_bytes()?);
         Ok(result)
     }
 }
@@ -83,13 +102,16 @@ impl FromBytes for Contract {
         let (bytes, rem1): (Vec<u8>, &[u8]) = FromBytes::from_bytes(bytes)?;
         let (named_keys, rem2): (BTreeMap<String, Key>, &[u8]) = FromBytes::from_bytes(rem1)?;
         let (protocol_version, rem3): (ProtocolVersion, &[u8]) = FromBytes::from_bytes(rem2)?;
+        let (entry_points, rem4): (BTreeMap<String, EntryPoint>, &[u8]) =
+            FromBytes::from_bytes(rem3)?;
         Ok((
             Contract {
                 bytes,
                 named_keys,
                 protocol_version,
+                entry_points,
             },
-            rem3,
+            rem4,
         ))
     }
 }
@@ -1,3 +1,4 @@
+pub mod abi;
 pub mod account;
 pub mod contract;
 pub mod protocol_version;
@@ -8,7 +8,7 @@ use contract_ffi::{
 
 use erc20_logic::{ERC20Trait, ERC20TransferError, ERC20TransferFromError};
 
-use crate::{api::Api, error::Error};
+use crate::{api::Api, error::Error, events};
 
 pub const INIT_FLAG_KEY: [u8; 32] = [1u8; 32];
 pub const TOTAL_SUPPLY_KEY: [u8; 32] = [255u8; 32];
@@ -60,8 +60,9 @@ fn entry_point() {
     let mut token = ERC20Token;
     match Api::from_args() {
         Api::Transfer(recipient, amount) => {
-            match token.transfer(&runtime::get_caller(), &recipient, amount) {
-                Ok(()) => {}
+            let sender = runtime::get_caller();
+            match token.transfer(&sender, &recipient, amount) {
+                Ok(()) => events::emit_transfer(sender, recipient, amount),
                 Err(ERC20TransferError::NotEnoughBalance) => {
                     runtime::revert(Error::TransferFailureNotEnoughBalance)
                 }
@@ -69,7 +70,7 @@ fn entry_point() {
         }
         Api::TransferFrom(owner, recipient, amount) => {
             match token.transfer_from(&runtime::get_caller(), &owner, &recipient, amount) {
-                Ok(()) => {}
+                Ok(()) => events::emit_transfer(owner, recipient, amount),
                 Err(ERC20TransferFromError::TransferError(
                     ERC20TransferError::NotEnoughBalance,
                 )) => runtime::revert(Error::TransferFromFailureNotEnoughBalance),
@@ -78,7 +79,11 @@ fn entry_point() {
                 }
             };
         }
-        Api::Approve(spender, amount) => token.approve(&runtime::get_caller(), &spender, amount),
+        Api::Approve(spender, amount) => {
+            let owner = runtime::get_caller();
+            token.approve(&owner, &spender, amount);
+            events::emit_approval(owner, spender, amount);
+        }
         Api::BalanceOf(address) => runtime::ret(token.balance_of(&address), Vec::new()),
         Api::TotalSupply => runtime::ret(token.total_supply(), Vec::new()),
         Api::Allowance(owner, spender) => {
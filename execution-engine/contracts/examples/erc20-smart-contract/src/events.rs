@@ -0,0 +1,62 @@
+use alloc::vec::Vec;
+
+use contract_ffi::{
+    bytesrepr::{self, ToBytes},
+    contract_api::{account::PublicKey, runtime},
+    value::U512,
+};
+
+pub const TRANSFER_EVENT: &str = "transfer";
+pub const APPROVAL_EVENT: &str = "approval";
+
+/// Emitted whenever tokens move from `sender` to `recipient`.
+pub struct Transfer {
+    pub sender: PublicKey,
+    pub recipient: PublicKey,
+    pub amount: U512,
+}
+
+impl ToBytes for Transfer {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.sender.to_bytes()?);
+        result.append(&mut self.recipient.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        Ok(result)
+    }
+}
+
+/// Emitted whenever `owner` sets the allowance of `spender`.
+pub struct Approval {
+    pub owner: PublicKey,
+    pub spender: PublicKey,
+    pub amount: U512,
+}
+
+impl ToBytes for Approval {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.owner.to_bytes()?);
+        result.append(&mut self.spender.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        Ok(result)
+    }
+}
+
+pub fn emit_transfer(sender: PublicKey, recipient: PublicKey, amount: U512) {
+    let event = Transfer {
+        sender,
+        recipient,
+        amount,
+    };
+    runtime::emit_event(TRANSFER_EVENT, &event);
+}
+
+pub fn emit_approval(owner: PublicKey, spender: PublicKey, amount: U512) {
+    let event = Approval {
+        owner,
+        spender,
+        amount,
+    };
+    runtime::emit_event(APPROVAL_EVENT, &event);
+}
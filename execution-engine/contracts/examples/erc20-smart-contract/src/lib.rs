@@ -6,6 +6,7 @@ mod api;
 mod deployer;
 mod erc20;
 mod error;
+mod events;
 mod proxy;
 
 #[no_mangle]
@@ -8,7 +8,7 @@ use core::fmt::Write;
 use contract_ffi::{
     contract_api::{runtime, storage, ContractRef, Error, TURef},
     key::Key,
-    system_contracts::mint,
+    system_contracts::{mint, pos},
     unwrap_or_revert::UnwrapOrRevert,
     uref::{AccessRights, URef},
     value::{
@@ -27,6 +27,7 @@ const POS_FUNCTION_NAME: &str = "pos_ext";
 enum Args {
     MintURef = 0,
     GenesisValidators = 1,
+    Params = 2,
 }
 
 #[no_mangle]
@@ -46,6 +47,11 @@ pub extern "C" fn call() {
             .unwrap_or_revert_with(Error::MissingArgument)
             .unwrap_or_revert_with(Error::InvalidArgument);
 
+    let params: pos::Params = runtime::get_arg(Args::Params as u32)
+        .unwrap_or_revert_with(Error::MissingArgument)
+        .unwrap_or_revert_with(Error::InvalidArgument);
+    params.validate().unwrap_or_revert();
+
     // Add genesis validators to PoS contract object.
     // For now, we are storing validators in `named_keys` map of the PoS contract
     // in the form: key: "v_{validator_pk}_{validator_stake}", value: doesn't
@@ -83,6 +89,11 @@ pub extern "C" fn call() {
         named_keys.insert(String::from(*name), Key::URef(*uref));
     });
 
+    // Store each parameter under its own uref, so upgrades can change them individually.
+    for (name, value) in params.to_named_values() {
+        named_keys.insert(String::from(name), storage::new_turef(value).into());
+    }
+
     let uref = storage::store_function(POS_FUNCTION_NAME, named_keys)
         .into_turef()
         .unwrap_or_revert_with(Error::UnexpectedContractRefVariant)
@@ -0,0 +1,210 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+};
+use core::fmt::Write;
+
+use base16;
+
+use contract_ffi::{
+    contract_api::runtime,
+    key::Key,
+    system_contracts::pos::{Error, Result},
+    value::{account::PublicKey, U512},
+};
+
+pub trait DelegationsProvider {
+    fn read() -> Result<Delegations>;
+    fn write(delegations: &Delegations);
+}
+
+/// A `DelegationsProvider` that reads and writes the delegations to/from the
+/// contract's known urefs.
+pub struct ContractDelegations;
+
+impl DelegationsProvider for ContractDelegations {
+    /// Reads the current delegations from the contract's known urefs.
+    fn read() -> Result<Delegations> {
+        let mut delegations = Delegations::default();
+        for (name, _) in runtime::list_named_keys() {
+            let mut split_name = name.split('_');
+            if Some("d") != split_name.next() {
+                continue;
+            }
+            let validator = split_name
+                .next()
+                .ok_or(Error::DelegationsKeyDeserializationFailed)
+                .and_then(decode_public_key)?;
+            let delegator = split_name
+                .next()
+                .ok_or(Error::DelegationsKeyDeserializationFailed)
+                .and_then(decode_public_key)?;
+            let amount = split_name
+                .next()
+                .and_then(|b| U512::from_dec_str(b).ok())
+                .ok_or(Error::DelegationsDeserializationFailed)?;
+            delegations.delegate(&delegator, &validator, amount);
+        }
+        Ok(delegations)
+    }
+
+    /// Writes the current delegations to the contract's known urefs.
+    fn write(delegations: &Delegations) {
+        // Encode the delegations as a set of uref names.
+        let mut new_urefs: BTreeSet<String> = BTreeSet::new();
+        for (validator, delegators) in &delegations.0 {
+            for (delegator, amount) in delegators {
+                let mut uref = String::from("d_");
+                write_public_key(&mut uref, validator);
+                uref.push('_');
+                write_public_key(&mut uref, delegator);
+                uref.write_fmt(format_args!("_{}", amount))
+                    .expect("Writing to a string cannot fail");
+                new_urefs.insert(uref);
+            }
+        }
+        // Remove and add urefs to update the contract's known urefs accordingly.
+        for (name, _) in runtime::list_named_keys() {
+            if name.starts_with("d_") && !new_urefs.remove(&name) {
+                runtime::remove_key(&name);
+            }
+        }
+        for name in new_urefs {
+            runtime::put_key(&name, &Key::Hash([0; 32]));
+        }
+    }
+}
+
+fn decode_public_key(hex_key: &str) -> Result<PublicKey> {
+    if hex_key.len() != 64 {
+        return Err(Error::DelegationsKeyDeserializationFailed);
+    }
+    let mut key_bytes = [0u8; 32];
+    let _bytes_written = base16::decode_slice(hex_key, &mut key_bytes)
+        .map_err(|_| Error::DelegationsKeyDeserializationFailed)?;
+    debug_assert!(_bytes_written == key_bytes.len());
+    Ok(PublicKey::new(key_bytes))
+}
+
+fn write_public_key(target: &mut String, public_key: &PublicKey) {
+    for byte in &public_key.value()[..32] {
+        write!(target, "{:02x}", byte).expect("Writing to a string cannot fail");
+    }
+}
+
+/// The delegations map, assigning to each validator the amount of motes each
+/// of its delegators has bonded to it.
+///
+/// The delegated amounts are also part of the validators' stakes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Delegations(pub BTreeMap<PublicKey, BTreeMap<PublicKey, U512>>);
+
+impl Delegations {
+    /// Adds `amount` to the motes `delegator` has delegated to `validator`.
+    pub fn delegate(&mut self, delegator: &PublicKey, validator: &PublicKey, amount: U512) {
+        self.0
+            .entry(*validator)
+            .or_insert_with(BTreeMap::new)
+            .entry(*delegator)
+            .and_modify(|x| *x += amount)
+            .or_insert(amount);
+    }
+
+    /// If `maybe_amount` is `None`, removes the whole delegation, otherwise
+    /// subtracts the given amount from it.
+    ///
+    /// Returns the amount that was subtracted, or an error if the delegation
+    /// doesn't exist or is lower than the specified amount.
+    pub fn undelegate(
+        &mut self,
+        delegator: &PublicKey,
+        validator: &PublicKey,
+        maybe_amount: Option<U512>,
+    ) -> Result<U512> {
+        let delegators = self.0.get_mut(validator).ok_or(Error::NotDelegated)?;
+        let delegation = delegators.get_mut(delegator).ok_or(Error::NotDelegated)?;
+        let amount = maybe_amount.unwrap_or(*delegation);
+        if amount > *delegation {
+            return Err(Error::UnbondTooLarge);
+        }
+        *delegation -= amount;
+        if delegation.is_zero() {
+            delegators.remove(delegator);
+            if delegators.is_empty() {
+                self.0.remove(validator);
+            }
+        }
+        Ok(amount)
+    }
+
+    /// Removes all delegations to `validator`.
+    pub fn remove_validator(&mut self, validator: &PublicKey) {
+        self.0.remove(validator);
+    }
+
+    /// Returns the total amount delegated to `validator`.
+    pub fn delegated_to(&self, validator: &PublicKey) -> U512 {
+        self.0
+            .get(validator)
+            .map(|delegators| {
+                delegators
+                    .values()
+                    .fold(U512::zero(), |sum, amount| sum.saturating_add(*amount))
+            })
+            .unwrap_or_else(U512::zero)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use contract_ffi::{
+        system_contracts::pos::Error,
+        value::{account::PublicKey, U512},
+    };
+
+    use crate::delegations::Delegations;
+
+    const KEY1: [u8; 32] = [1; 32];
+    const KEY2: [u8; 32] = [2; 32];
+    const KEY3: [u8; 32] = [3; 32];
+
+    #[test]
+    fn test_delegate() {
+        let validator = PublicKey::new(KEY1);
+        let mut delegations = Delegations::default();
+        delegations.delegate(&PublicKey::new(KEY2), &validator, U512::from(5));
+        delegations.delegate(&PublicKey::new(KEY3), &validator, U512::from(7));
+        delegations.delegate(&PublicKey::new(KEY2), &validator, U512::from(4));
+        assert_eq!(U512::from(16), delegations.delegated_to(&validator));
+        assert_eq!(
+            U512::zero(),
+            delegations.delegated_to(&PublicKey::new(KEY2))
+        );
+    }
+
+    #[test]
+    fn test_undelegate() {
+        let validator = PublicKey::new(KEY1);
+        let delegator = PublicKey::new(KEY2);
+        let mut delegations = Delegations::default();
+        delegations.delegate(&delegator, &validator, U512::from(10));
+        assert_eq!(
+            Err(Error::UnbondTooLarge),
+            delegations.undelegate(&delegator, &validator, Some(U512::from(11)))
+        );
+        assert_eq!(
+            Ok(U512::from(4)),
+            delegations.undelegate(&delegator, &validator, Some(U512::from(4)))
+        );
+        assert_eq!(U512::from(6), delegations.delegated_to(&validator));
+        assert_eq!(
+            Ok(U512::from(6)),
+            delegations.undelegate(&delegator, &validator, None)
+        );
+        assert_eq!(Delegations::default(), delegations);
+        assert_eq!(
+            Err(Error::NotDelegated),
+            delegations.undelegate(&delegator, &validator, None)
+        );
+    }
+}
@@ -2,29 +2,36 @@
 
 extern crate alloc;
 
+mod delegations;
 mod queue;
+mod rewards;
 mod stakes;
 
 // Can be removed once https://github.com/rust-lang/rustfmt/issues/3362 is resolved.
 #[rustfmt::skip]
 use alloc::vec;
 use alloc::{string::String, vec::Vec};
+use core::convert::TryFrom;
 
 use contract_ffi::{
-    contract_api::{runtime, system},
+    contract_api::{runtime, storage, system, TURef},
     execution::Phase,
     key::Key,
-    system_contracts::pos::{Error, PurseLookupError, Result},
+    system_contracts::pos::{
+        self, params, Error, Params, PurseLookupError, Result, UnbondPayout, UNBOND_PAYOUTS_KEY,
+    },
     unwrap_or_revert::UnwrapOrRevert,
     uref::{AccessRights, URef},
     value::{
         account::{BlockTime, PublicKey, PurseId},
-        U512,
+        Value, U512,
     },
 };
 
 use crate::{
+    delegations::{ContractDelegations, DelegationsProvider},
     queue::{QueueEntry, QueueLocal, QueueProvider},
+    rewards::Rewards,
     stakes::{ContractStakes, StakesProvider},
 };
 
@@ -47,39 +54,16 @@ const REWARDS_PURSE_KEY: &str = "pos_rewards_purse";
 /// uref this name corresponds to is set by the user.
 const REFUND_PURSE_KEY: &str = "pos_refund_purse";
 
-/// The time from a bonding request until the bond becomes effective and part of
-/// the stake.
-const BOND_DELAY: u64 = 0;
-/// The time from an unbonding request until the stakes are paid out.
-const UNBOND_DELAY: u64 = 0;
-/// The maximum number of pending bonding requests.
-const MAX_BOND_LEN: usize = 100;
-/// The maximum number of pending unbonding requests.
-const MAX_UNBOND_LEN: usize = 1000;
-/// The maximum difference between the largest and the smallest stakes.
-// TODO: Should this be a percentage instead?
-// TODO: Pick a reasonable value.
-const MAX_SPREAD: U512 = U512::MAX;
-/// The maximum increase of stakes in a single bonding request.
-const MAX_INCREASE: U512 = U512::MAX;
-/// The maximum decrease of stakes in a single unbonding request.
-const MAX_DECREASE: U512 = U512::MAX;
-/// The maximum increase of stakes in millionths of the total stakes in a single
-/// bonding request.
-const MAX_REL_INCREASE: u64 = 1_000_000_000;
-/// The maximum decrease of stakes in millionths of the total stakes in a single
-/// unbonding request.
-const MAX_REL_DECREASE: u64 = 900_000;
-
 /// Enqueues the deploy's creator for becoming a validator. The bond `amount` is
 /// paid from the purse `source`.
 fn bond<Q: QueueProvider, S: StakesProvider>(
     amount: U512,
     validator: PublicKey,
     timestamp: BlockTime,
+    params: &Params,
 ) -> Result<()> {
     let mut queue = Q::read_bonding();
-    if queue.0.len() >= MAX_BOND_LEN {
+    if queue.0.len() >= params.max_bond_len as usize {
         return Err(Error::TooManyEventsInQueue);
     }
 
@@ -88,45 +72,129 @@ fn bond<Q: QueueProvider, S: StakesProvider>(
     for entry in &queue.0 {
         stakes.bond(&entry.validator, entry.amount);
     }
-    stakes.validate_bonding(&validator, amount)?;
+    stakes.validate_bonding(&validator, amount, params)?;
 
     queue.push(validator, amount, timestamp)?;
     Q::write_bonding(&queue);
     Ok(())
 }
 
+/// Enqueues the deploy's creator for delegating `amount` to `validator`. The
+/// delegation becomes part of the validator's stake after the bonding delay.
+fn delegate<Q: QueueProvider, S: StakesProvider>(
+    amount: U512,
+    delegator: PublicKey,
+    validator: PublicKey,
+    timestamp: BlockTime,
+    params: &Params,
+) -> Result<()> {
+    if delegator == validator {
+        return Err(Error::SelfDelegation);
+    }
+    let mut queue = Q::read_bonding();
+    if queue.0.len() >= params.max_bond_len as usize {
+        return Err(Error::TooManyEventsInQueue);
+    }
+
+    let mut stakes = S::read()?;
+    // Simulate applying all earlier bonds. The modified stakes are not written.
+    for entry in &queue.0 {
+        stakes.bond(&entry.validator, entry.amount);
+    }
+    if !stakes.0.contains_key(&validator) {
+        return Err(Error::NotBonded);
+    }
+    stakes.validate_bonding(&validator, amount, params)?;
+
+    queue.push_delegation(delegator, validator, amount, timestamp)?;
+    Q::write_bonding(&queue);
+    Ok(())
+}
+
 /// Enqueues the deploy's creator for unbonding. Their vote weight as a
 /// validator is decreased immediately, but the funds will only be released
 /// after a delay. If `maybe_amount` is `None`, all funds are enqueued for
 /// withdrawal, terminating the validator status.
-fn unbond<Q: QueueProvider, S: StakesProvider>(
+///
+/// Only the validator's own stake can be unbonded, and a validator with
+/// delegations has to keep part of it: unbonding exactly its own stake, like
+/// unbonding everything, fails with `ValidatorHasDelegations` until the
+/// delegators have undelegated.
+fn unbond<Q: QueueProvider, S: StakesProvider, D: DelegationsProvider>(
     maybe_amount: Option<U512>,
     validator: PublicKey,
     timestamp: BlockTime,
+    params: &Params,
 ) -> Result<()> {
     let mut queue = Q::read_unbonding();
-    if queue.0.len() >= MAX_UNBOND_LEN {
+    if queue.0.len() >= params.max_unbond_len as usize {
         return Err(Error::TooManyEventsInQueue);
     }
 
     let mut stakes = S::read()?;
-    let payout = stakes.unbond(&validator, maybe_amount)?;
+    let delegated = D::read()?.delegated_to(&validator);
+    if !delegated.is_zero() {
+        let own_stake = *stakes.0.get(&validator).ok_or(Error::NotBonded)? - delegated;
+        match maybe_amount {
+            Some(amount) if amount < own_stake => {}
+            Some(amount) if amount > own_stake => return Err(Error::UnbondTooLarge),
+            // A validator without any stake of its own would still be bonded by its delegators.
+            Some(_) | None => return Err(Error::ValidatorHasDelegations),
+        }
+    }
+    let payout = stakes.unbond(&validator, maybe_amount, params)?;
     S::write(&stakes);
-    // TODO: Make sure the destination is valid and the amount can be paid. The
-    // actual payment will be made later, after the unbonding delay.
-    // contract_api::transfer_dry_run(POS_PURSE, dest, amount)?;
+    // The payout is transferred to the validator's main purse by `step`, which the system calls
+    // in every block, once the unbonding delay has passed.
     queue.push(validator, payout, timestamp)?;
     Q::write_unbonding(&queue);
     Ok(())
 }
 
-/// Removes all due requests from the queues and applies them.
-fn step<Q: QueueProvider, S: StakesProvider>(timestamp: BlockTime) -> Result<Vec<QueueEntry>> {
+/// Enqueues the deploy's creator for undelegating from `validator`. The
+/// validator's vote weight is decreased immediately, but the funds will only
+/// be released after a delay. If `maybe_amount` is `None`, the whole delegation
+/// is enqueued for withdrawal.
+///
+/// Only the delegator's own delegation and the length of the unbonding queue
+/// limit undelegating: the validator's stake may end up further below the
+/// others' than `max_spread` allows.
+fn undelegate<Q: QueueProvider, S: StakesProvider, D: DelegationsProvider>(
+    maybe_amount: Option<U512>,
+    delegator: PublicKey,
+    validator: PublicKey,
+    timestamp: BlockTime,
+    params: &Params,
+) -> Result<()> {
+    let mut queue = Q::read_unbonding();
+    if queue.0.len() >= params.max_unbond_len as usize {
+        return Err(Error::TooManyEventsInQueue);
+    }
+
+    let mut delegations = D::read()?;
+    let amount = delegations.undelegate(&delegator, &validator, maybe_amount)?;
+    let mut stakes = S::read()?;
+    stakes.undelegate(&validator, amount)?;
+    queue.push_delegation(delegator, validator, amount, timestamp)?;
+    D::write(&delegations);
+    S::write(&stakes);
+    Q::write_unbonding(&queue);
+    Ok(())
+}
+
+/// Removes all due requests from the queues and applies them. Returns the
+/// unbonding requests and the delegations to validators which are no longer
+/// bonded, which still have to be paid out.
+fn step<Q: QueueProvider, S: StakesProvider, D: DelegationsProvider>(
+    timestamp: BlockTime,
+    params: &Params,
+) -> Result<Vec<QueueEntry>> {
     let mut bonding_queue = Q::read_bonding();
     let mut unbonding_queue = Q::read_unbonding();
 
-    let bonds = bonding_queue.pop_due(timestamp.saturating_sub(BlockTime::new(BOND_DELAY)));
-    let unbonds = unbonding_queue.pop_due(timestamp.saturating_sub(BlockTime::new(UNBOND_DELAY)));
+    let bonds = bonding_queue.pop_due(timestamp.saturating_sub(BlockTime::new(params.bond_delay)));
+    let mut unbonds =
+        unbonding_queue.pop_due(timestamp.saturating_sub(BlockTime::new(params.unbond_delay)));
 
     if !unbonds.is_empty() {
         Q::write_unbonding(&unbonding_queue);
@@ -135,15 +203,140 @@ fn step<Q: QueueProvider, S: StakesProvider>(timestamp: BlockTime) -> Result<Vec
     if !bonds.is_empty() {
         Q::write_bonding(&bonding_queue);
         let mut stakes = S::read()?;
+        let mut delegations = D::read()?;
         for entry in bonds {
-            stakes.bond(&entry.validator, entry.amount);
+            match entry.delegator {
+                None => stakes.bond(&entry.validator, entry.amount),
+                Some(_) if !stakes.0.contains_key(&entry.validator) => unbonds.push(entry),
+                Some(delegator) => {
+                    stakes.bond(&entry.validator, entry.amount);
+                    delegations.delegate(&delegator, &entry.validator, entry.amount);
+                }
+            }
         }
         S::write(&stakes);
+        D::write(&delegations);
     }
 
     Ok(unbonds)
 }
 
+/// Splits `amount` of rewards among the bonded validators and their delegators, and adds each
+/// validator's stake increase to its stakes. Returns the rewards, which still have to be
+/// transferred.
+fn distribute_rewards<S: StakesProvider, D: DelegationsProvider>(
+    amount: U512,
+    proposer: &PublicKey,
+    finality_signers: &[PublicKey],
+    params: &Params,
+) -> Result<Rewards> {
+    let mut stakes = S::read()?;
+    let mut delegations = D::read()?;
+    let rewards = Rewards::new(
+        &stakes,
+        &delegations,
+        amount,
+        proposer,
+        finality_signers,
+        params,
+    );
+    if !rewards.stake_increases.is_empty() {
+        for (validator, amount) in &rewards.stake_increases {
+            stakes.bond(validator, *amount);
+        }
+        S::write(&stakes);
+    }
+    if !rewards.delegation_increases.0.is_empty() {
+        for (validator, delegators) in &rewards.delegation_increases.0 {
+            for (delegator, amount) in delegators {
+                delegations.delegate(delegator, validator, *amount);
+            }
+        }
+        D::write(&delegations);
+    }
+    Ok(rewards)
+}
+
+/// Removes the stakes, including the delegations, and the pending requests of the given
+/// validators, e.g. because they equivocated. Returns the total amount confiscated, which still
+/// has to be transferred.
+fn slash<Q: QueueProvider, S: StakesProvider, D: DelegationsProvider>(
+    validators: &[PublicKey],
+) -> Result<U512> {
+    let mut stakes = S::read()?;
+    let mut amount = validators
+        .iter()
+        .fold(U512::zero(), |sum, validator| sum + stakes.slash(validator));
+    if stakes.0.is_empty() {
+        return Err(Error::CannotSlashAllValidators);
+    }
+
+    let mut delegations = D::read()?;
+    for validator in validators {
+        delegations.remove_validator(validator);
+    }
+
+    // Pending bonds are confiscated too, so they can't bond the slashed validators again.
+    let mut bonding_queue = Q::read_bonding();
+    let bonds = bonding_queue.remove_validators(validators);
+    if !bonds.is_empty() {
+        amount = bonds.iter().fold(amount, |sum, entry| sum + entry.amount);
+        Q::write_bonding(&bonding_queue);
+    }
+
+    let mut unbonding_queue = Q::read_unbonding();
+    let unbonds = unbonding_queue.remove_validators(validators);
+    if !unbonds.is_empty() {
+        amount = unbonds.iter().fold(amount, |sum, entry| sum + entry.amount);
+        Q::write_unbonding(&unbonding_queue);
+    }
+
+    if !amount.is_zero() {
+        S::write(&stakes);
+        D::write(&delegations);
+    }
+    Ok(amount)
+}
+
+/// Reads the parameter stored under the named key `name`.
+fn read_param<T: TryFrom<Value> + Into<Value>>(name: &str) -> Result<T> {
+    let uref = match runtime::get_key(name).ok_or(Error::ParamNotFound)? {
+        Key::URef(uref) => uref,
+        _ => return Err(Error::ParamKeyUnexpectedType),
+    };
+    let turef = TURef::from_uref(uref).map_err(|_| Error::ParamKeyUnexpectedType)?;
+    storage::read(turef)
+        .map_err(|_| Error::ParamDeserializationFailed)?
+        .ok_or(Error::ParamNotFound)
+}
+
+/// Reads the parameters of the PoS contract from its named keys.
+fn read_params() -> Result<Params> {
+    Ok(Params {
+        bond_delay: read_param(params::BOND_DELAY_KEY)?,
+        unbond_delay: read_param(params::UNBOND_DELAY_KEY)?,
+        max_bond_len: read_param(params::MAX_BOND_LEN_KEY)?,
+        max_unbond_len: read_param(params::MAX_UNBOND_LEN_KEY)?,
+        max_spread: read_param(params::MAX_SPREAD_KEY)?,
+        max_increase: read_param(params::MAX_INCREASE_KEY)?,
+        max_decrease: read_param(params::MAX_DECREASE_KEY)?,
+        max_rel_increase: read_param(params::MAX_REL_INCREASE_KEY)?,
+        max_rel_decrease: read_param(params::MAX_REL_DECREASE_KEY)?,
+        proposer_reward_share: read_param(params::PROPOSER_REWARD_SHARE_KEY)?,
+        commission_rate: read_param(params::COMMISSION_RATE_KEY)?,
+    })
+}
+
+/// Reverts unless called by the system account in the system phase.
+fn check_system_call() {
+    if runtime::get_caller().value() != SYSTEM_ACCOUNT {
+        runtime::revert(Error::SystemFunctionCalledByUserAccount);
+    }
+    if runtime::get_phase() != Phase::System {
+        runtime::revert(Error::SystemFunctionCalledOutsideSystemPhase);
+    }
+}
+
 /// Attempts to look up a purse from the named_keys
 fn get_purse_id(name: &str) -> core::result::Result<PurseId, PurseLookupError> {
     runtime::get_key(name)
@@ -264,18 +457,9 @@ pub fn delegate() {
             // own purse.
             system::transfer_from_purse_to_purse(source, pos_purse, amount)
                 .unwrap_or_revert_with(Error::BondTransferFailed);
-            bond::<QueueLocal, ContractStakes>(amount, validator, timestamp).unwrap_or_revert();
-
-            // TODO: Remove this and set nonzero delays once the system calls `step` in each
-            // block.
-            let unbonds = step::<QueueLocal, ContractStakes>(timestamp).unwrap_or_revert();
-            for entry in unbonds {
-                let _ = system::transfer_from_purse_to_account(
-                    pos_purse,
-                    entry.validator,
-                    entry.amount,
-                );
-            }
+            let params = read_params().unwrap_or_revert();
+            bond::<QueueLocal, ContractStakes>(amount, validator, timestamp, &params)
+                .unwrap_or_revert();
         }
         // Type of this method: `fn unbond(amount: Option<U512>)`
         "unbond" => {
@@ -283,34 +467,143 @@ pub fn delegate() {
             let maybe_amount = runtime::get_arg(1)
                 .unwrap_or_revert_with(Error::MissingArgument)
                 .unwrap_or_revert_with(Error::InvalidArgument);
-            unbond::<QueueLocal, ContractStakes>(maybe_amount, validator, timestamp)
-                .unwrap_or_revert();
-
-            // TODO: Remove this and set nonzero delays once the system calls `step` in each
-            // block.
-            let unbonds = step::<QueueLocal, ContractStakes>(timestamp).unwrap_or_revert();
-            for entry in unbonds {
-                system::transfer_from_purse_to_account(pos_purse, entry.validator, entry.amount)
-                    .unwrap_or_revert_with(Error::UnbondTransferFailed);
+            let params = read_params().unwrap_or_revert();
+            unbond::<QueueLocal, ContractStakes, ContractDelegations>(
+                maybe_amount,
+                validator,
+                timestamp,
+                &params,
+            )
+            .unwrap_or_revert();
+        }
+        // Type of this method: `fn delegate(amount: U512, purse: URef, validator: PublicKey)`
+        "delegate" => {
+            let delegator = runtime::get_caller();
+            let amount: U512 = runtime::get_arg(1)
+                .unwrap_or_revert_with(Error::MissingArgument)
+                .unwrap_or_revert_with(Error::InvalidArgument);
+            if amount.is_zero() {
+                runtime::revert(Error::BondTooSmall);
             }
+            let source_uref: URef = runtime::get_arg(2)
+                .unwrap_or_revert_with(Error::MissingArgument)
+                .unwrap_or_revert_with(Error::InvalidArgument);
+            let validator: PublicKey = runtime::get_arg(3)
+                .unwrap_or_revert_with(Error::MissingArgument)
+                .unwrap_or_revert_with(Error::InvalidArgument);
+            let source = PurseId::new(source_uref);
+            system::transfer_from_purse_to_purse(source, pos_purse, amount)
+                .unwrap_or_revert_with(Error::BondTransferFailed);
+            let params = read_params().unwrap_or_revert();
+            delegate::<QueueLocal, ContractStakes>(
+                amount, delegator, validator, timestamp, &params,
+            )
+            .unwrap_or_revert();
+        }
+        // Type of this method: `fn undelegate(amount: Option<U512>, validator: PublicKey)`
+        "undelegate" => {
+            let delegator = runtime::get_caller();
+            let maybe_amount = runtime::get_arg(1)
+                .unwrap_or_revert_with(Error::MissingArgument)
+                .unwrap_or_revert_with(Error::InvalidArgument);
+            let validator: PublicKey = runtime::get_arg(2)
+                .unwrap_or_revert_with(Error::MissingArgument)
+                .unwrap_or_revert_with(Error::InvalidArgument);
+            let params = read_params().unwrap_or_revert();
+            undelegate::<QueueLocal, ContractStakes, ContractDelegations>(
+                maybe_amount,
+                delegator,
+                validator,
+                timestamp,
+                &params,
+            )
+            .unwrap_or_revert();
         }
         // Type of this method: `fn step()`
         "step" => {
-            // This is called by the system in every block.
-            let unbonds = step::<QueueLocal, ContractStakes>(timestamp).unwrap_or_revert();
+            // This is called by the system once in every block, through the engine's `step`.
+            check_system_call();
+            let params = read_params().unwrap_or_revert();
+            let unbonds =
+                step::<QueueLocal, ContractStakes, ContractDelegations>(timestamp, &params)
+                    .unwrap_or_revert();
 
             // Mateusz: Moved outside of `step` function so that it [step] can be unit
             // tested.
+            let mut payouts = Vec::new();
+            let mut failed = Vec::new();
             for entry in unbonds {
-                // TODO: We currently ignore `TransferResult::TransferError`s here, since we
-                // can't recover from them and we shouldn't retry indefinitely.
-                // That would mean the contract just keeps the money forever,
-                // though.
-                let _ = system::transfer_from_purse_to_account(
-                    pos_purse,
-                    entry.validator,
-                    entry.amount,
-                );
+                let account = entry.account();
+                if system::transfer_from_purse_to_account(pos_purse, account, entry.amount).is_ok()
+                {
+                    payouts.push(UnbondPayout::new(account, entry.amount));
+                } else {
+                    failed.push(entry);
+                }
+            }
+            // Payouts that could not be transferred stay due, and are retried by the next step.
+            if !failed.is_empty() {
+                let mut queue = QueueLocal::read_unbonding();
+                queue.requeue(failed);
+                QueueLocal::write_unbonding(&queue);
+            }
+            // The engine reports the payouts recorded in the effects of a block when committing
+            // them.
+            if !payouts.is_empty() {
+                let value = pos::unbond_payouts_to_value(&payouts).unwrap_or_revert();
+                storage::write_local(UNBOND_PAYOUTS_KEY, value);
+            }
+        }
+        // Type of this method: `fn distribute_rewards(proposer: PublicKey, finality_signers:
+        // Vec<PublicKey>)`
+        "distribute_rewards" => {
+            // This is called by the system in every block.
+            check_system_call();
+            let proposer: PublicKey = runtime::get_arg(1)
+                .unwrap_or_revert_with(Error::MissingArgument)
+                .unwrap_or_revert_with(Error::InvalidArgument);
+            let finality_signers: Vec<PublicKey> = runtime::get_arg(2)
+                .unwrap_or_revert_with(Error::MissingArgument)
+                .unwrap_or_revert_with(Error::InvalidArgument);
+
+            let rewards_purse = get_rewards_purse().unwrap_or_revert();
+            let amount = system::get_balance(rewards_purse)
+                .unwrap_or_revert_with(Error::RewardsPurseBalanceNotFound);
+            let params = read_params().unwrap_or_revert();
+            let rewards = distribute_rewards::<ContractStakes, ContractDelegations>(
+                amount,
+                &proposer,
+                &finality_signers,
+                &params,
+            )
+            .unwrap_or_revert();
+
+            let stake_increase = rewards.total_stake_increase();
+            if !stake_increase.is_zero() {
+                system::transfer_from_purse_to_purse(rewards_purse, pos_purse, stake_increase)
+                    .unwrap_or_revert_with(Error::FailedTransferFromRewardsPurse);
+            }
+            for (validator, amount) in rewards.payouts {
+                system::transfer_from_purse_to_account(rewards_purse, validator, amount)
+                    .unwrap_or_revert_with(Error::FailedTransferFromRewardsPurse);
+            }
+        }
+        // Type of this method: `fn slash(validators: Vec<PublicKey>)`
+        "slash" => {
+            // This is called by the system with the equivocations detected in a block.
+            check_system_call();
+            let validators: Vec<PublicKey> = runtime::get_arg(1)
+                .unwrap_or_revert_with(Error::MissingArgument)
+                .unwrap_or_revert_with(Error::InvalidArgument);
+
+            // The confiscated motes are moved to the rewards purse, so they are distributed among
+            // the remaining validators with the next rewards.
+            let amount = slash::<QueueLocal, ContractStakes, ContractDelegations>(&validators)
+                .unwrap_or_revert();
+            if !amount.is_zero() {
+                let rewards_purse = get_rewards_purse().unwrap_or_revert();
+                system::transfer_from_purse_to_purse(pos_purse, rewards_purse, amount)
+                    .unwrap_or_revert_with(Error::FailedTransferToRewardsPurse);
             }
         }
         "get_payment_purse" => {
@@ -364,7 +657,7 @@ mod tests {
     use std::{cell::RefCell, iter};
 
     use contract_ffi::{
-        system_contracts::pos::Result,
+        system_contracts::pos::{Error, Params, Result},
         value::{
             account::{BlockTime, PublicKey},
             U512,
@@ -372,14 +665,18 @@ mod tests {
     };
 
     use crate::{
-        bond,
+        bond, delegate,
+        delegations::{Delegations, DelegationsProvider},
+        distribute_rewards,
         queue::{Queue, QueueProvider},
+        slash,
         stakes::{Stakes, StakesProvider},
-        step, unbond, BOND_DELAY, UNBOND_DELAY,
+        step, unbond, undelegate,
     };
 
     const KEY1: [u8; 32] = [1; 32];
     const KEY2: [u8; 32] = [2; 32];
+    const KEY3: [u8; 32] = [3; 32];
 
     thread_local! {
         static BONDING: RefCell<Queue> = RefCell::new(Queue(Default::default()));
@@ -387,6 +684,7 @@ mod tests {
         static STAKES: RefCell<Stakes> = RefCell::new(
             Stakes(iter::once((PublicKey::new(KEY1), U512::from(1_000))).collect())
         );
+        static DELEGATIONS: RefCell<Delegations> = RefCell::new(Delegations::default());
     }
 
     struct TestQueues;
@@ -421,6 +719,18 @@ mod tests {
         }
     }
 
+    struct TestDelegations;
+
+    impl DelegationsProvider for TestDelegations {
+        fn read() -> Result<Delegations> {
+            DELEGATIONS.with(|d| Ok(d.borrow().clone()))
+        }
+
+        fn write(delegations: &Delegations) {
+            DELEGATIONS.with(|d| d.replace(delegations.clone()));
+        }
+    }
+
     fn assert_stakes(stakes: &[([u8; 32], usize)]) {
         let expected = Stakes(
             stakes
@@ -433,26 +743,266 @@ mod tests {
 
     #[test]
     fn test_bond_step_unbond() {
-        bond::<TestQueues, TestStakes>(U512::from(500), PublicKey::new(KEY2), BlockTime::new(1))
-            .expect("bond validator 2");
+        let params = Params::default();
+        bond::<TestQueues, TestStakes>(
+            U512::from(500),
+            PublicKey::new(KEY2),
+            BlockTime::new(1),
+            &params,
+        )
+        .expect("bond validator 2");
 
         // Bonding becomes effective only after the delay.
         assert_stakes(&[(KEY1, 1_000)]);
-        step::<TestQueues, TestStakes>(BlockTime::new(BOND_DELAY)).expect("step 1");
+        step::<TestQueues, TestStakes, TestDelegations>(BlockTime::new(params.bond_delay), &params)
+            .expect("step 1");
         assert_stakes(&[(KEY1, 1_000)]);
-        step::<TestQueues, TestStakes>(BlockTime::new(1 + BOND_DELAY)).expect("step 2");
+        step::<TestQueues, TestStakes, TestDelegations>(
+            BlockTime::new(1 + params.bond_delay),
+            &params,
+        )
+        .expect("step 2");
         assert_stakes(&[(KEY1, 1_000), (KEY2, 500)]);
 
-        unbond::<TestQueues, TestStakes>(
+        unbond::<TestQueues, TestStakes, TestDelegations>(
             Some(U512::from(500)),
             PublicKey::new(KEY1),
             BlockTime::new(2),
+            &params,
         )
         .expect("partly unbond validator 1");
 
         // Unbonding becomes effective immediately.
         assert_stakes(&[(KEY1, 500), (KEY2, 500)]);
-        step::<TestQueues, TestStakes>(BlockTime::new(2 + UNBOND_DELAY)).expect("step 3");
+        step::<TestQueues, TestStakes, TestDelegations>(
+            BlockTime::new(2 + params.unbond_delay),
+            &params,
+        )
+        .expect("step 3");
         assert_stakes(&[(KEY1, 500), (KEY2, 500)]);
     }
+
+    #[test]
+    fn test_distribute_rewards() {
+        let params = Params::default();
+        let rewards = distribute_rewards::<TestStakes, TestDelegations>(
+            U512::from(1_000),
+            &PublicKey::new(KEY1),
+            &[PublicKey::new(KEY1)],
+            &params,
+        )
+        .expect("distribute rewards");
+
+        // The only validator gets all the rewards, and its stake grows by the part not paid out.
+        let payout = rewards
+            .payouts
+            .get(&PublicKey::new(KEY1))
+            .cloned()
+            .unwrap_or_else(U512::zero);
+        let stake_increase = rewards.total_stake_increase();
+        assert_eq!(payout + stake_increase, U512::from(1_000));
+        assert_stakes(&[(KEY1, 1_000 + stake_increase.as_usize())]);
+    }
+
+    #[test]
+    fn test_slash() {
+        let params = Params::default();
+        bond::<TestQueues, TestStakes>(
+            U512::from(500),
+            PublicKey::new(KEY2),
+            BlockTime::new(1),
+            &params,
+        )
+        .expect("bond validator 2");
+        step::<TestQueues, TestStakes, TestDelegations>(
+            BlockTime::new(1 + params.bond_delay),
+            &params,
+        )
+        .expect("step");
+        unbond::<TestQueues, TestStakes, TestDelegations>(
+            Some(U512::from(200)),
+            PublicKey::new(KEY2),
+            BlockTime::new(2),
+            &params,
+        )
+        .expect("partly unbond validator 2");
+        assert_stakes(&[(KEY1, 1_000), (KEY2, 300)]);
+
+        // Both the remaining stake and the pending unbonding request are confiscated.
+        assert_eq!(
+            Ok(U512::from(500)),
+            slash::<TestQueues, TestStakes, TestDelegations>(&[PublicKey::new(KEY2)])
+        );
+        assert_stakes(&[(KEY1, 1_000)]);
+        assert!(TestQueues::read_unbonding().0.is_empty());
+
+        assert_eq!(
+            Err(Error::CannotSlashAllValidators),
+            slash::<TestQueues, TestStakes, TestDelegations>(&[PublicKey::new(KEY1)])
+        );
+        assert_stakes(&[(KEY1, 1_000)]);
+    }
+
+    #[test]
+    fn test_delegate_step_undelegate() {
+        let params = Params::default();
+        let validator = PublicKey::new(KEY1);
+        let delegator = PublicKey::new(KEY3);
+        assert_eq!(
+            Err(Error::NotBonded),
+            delegate::<TestQueues, TestStakes>(
+                U512::from(500),
+                delegator,
+                PublicKey::new(KEY2),
+                BlockTime::new(1),
+                &params
+            )
+        );
+        delegate::<TestQueues, TestStakes>(
+            U512::from(500),
+            delegator,
+            validator,
+            BlockTime::new(1),
+            &params,
+        )
+        .expect("delegate to validator 1");
+
+        // Delegating becomes effective only after the delay, and adds to the validator's stakes.
+        assert_stakes(&[(KEY1, 1_000)]);
+        step::<TestQueues, TestStakes, TestDelegations>(
+            BlockTime::new(1 + params.bond_delay),
+            &params,
+        )
+        .expect("step 1");
+        assert_stakes(&[(KEY1, 1_500)]);
+        assert_eq!(
+            U512::from(500),
+            TestDelegations::read().unwrap().delegated_to(&validator)
+        );
+
+        // The validator can't unbond the delegated stake, nor all of its own stake while it has
+        // delegators.
+        assert_eq!(
+            Err(Error::UnbondTooLarge),
+            unbond::<TestQueues, TestStakes, TestDelegations>(
+                Some(U512::from(1_001)),
+                validator,
+                BlockTime::new(2),
+                &params,
+            )
+        );
+        assert_eq!(
+            Err(Error::ValidatorHasDelegations),
+            unbond::<TestQueues, TestStakes, TestDelegations>(
+                Some(U512::from(1_000)),
+                validator,
+                BlockTime::new(2),
+                &params,
+            )
+        );
+        assert_eq!(
+            Err(Error::ValidatorHasDelegations),
+            unbond::<TestQueues, TestStakes, TestDelegations>(
+                None,
+                validator,
+                BlockTime::new(2),
+                &params
+            )
+        );
+
+        undelegate::<TestQueues, TestStakes, TestDelegations>(
+            Some(U512::from(200)),
+            delegator,
+            validator,
+            BlockTime::new(2),
+            &params,
+        )
+        .expect("partly undelegate from validator 1");
+
+        // Undelegating becomes effective immediately, and the motes are paid out to the delegator.
+        assert_stakes(&[(KEY1, 1_300)]);
+        let unbonds = step::<TestQueues, TestStakes, TestDelegations>(
+            BlockTime::new(2 + params.unbond_delay),
+            &params,
+        )
+        .expect("step 2");
+        assert_eq!(1, unbonds.len());
+        assert_eq!(delegator, unbonds[0].account());
+        assert_eq!(U512::from(200), unbonds[0].amount);
+        assert_eq!(
+            U512::from(300),
+            TestDelegations::read().unwrap().delegated_to(&validator)
+        );
+
+        // The validator can unbond less than its own stake while it has delegators.
+        unbond::<TestQueues, TestStakes, TestDelegations>(
+            Some(U512::from(999)),
+            validator,
+            BlockTime::new(3),
+            &params,
+        )
+        .expect("partly unbond validator 1");
+        assert_stakes(&[(KEY1, 301)]);
+    }
+
+    #[test]
+    fn test_undelegate_from_validator_at_spread_limit() {
+        let params = Params {
+            max_spread: U512::from(500),
+            ..Params::default()
+        };
+        let validator = PublicKey::new(KEY1);
+        let delegator = PublicKey::new(KEY3);
+        delegate::<TestQueues, TestStakes>(
+            U512::from(100),
+            delegator,
+            validator,
+            BlockTime::new(1),
+            &params,
+        )
+        .expect("delegate to validator 1");
+        bond::<TestQueues, TestStakes>(
+            U512::from(1_600),
+            PublicKey::new(KEY2),
+            BlockTime::new(1),
+            &params,
+        )
+        .expect("bond validator 2");
+        step::<TestQueues, TestStakes, TestDelegations>(
+            BlockTime::new(1 + params.bond_delay),
+            &params,
+        )
+        .expect("step 1");
+        assert_stakes(&[(KEY1, 1_100), (KEY2, 1_600)]);
+
+        // Validator 1 is at the spread limit, so it can't unbond anything.
+        assert_eq!(
+            Err(Error::SpreadTooHigh),
+            unbond::<TestQueues, TestStakes, TestDelegations>(
+                Some(U512::from(1)),
+                validator,
+                BlockTime::new(2),
+                &params,
+            )
+        );
+
+        // The delegator can still leave validator 1, whose stake drops below the spread limit.
+        undelegate::<TestQueues, TestStakes, TestDelegations>(
+            None,
+            delegator,
+            validator,
+            BlockTime::new(2),
+            &params,
+        )
+        .expect("undelegate from validator 1");
+        assert_stakes(&[(KEY1, 1_000), (KEY2, 1_600)]);
+        let unbonds = step::<TestQueues, TestStakes, TestDelegations>(
+            BlockTime::new(2 + params.unbond_delay),
+            &params,
+        )
+        .expect("step 2");
+        assert_eq!(1, unbonds.len());
+        assert_eq!(delegator, unbonds[0].account());
+        assert_eq!(U512::from(100), unbonds[0].amount);
+    }
 }
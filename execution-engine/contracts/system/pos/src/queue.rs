@@ -11,14 +11,22 @@ use contract_ffi::{
     },
 };
 
+// Local key `3` is `pos::UNBOND_PAYOUTS_KEY`.
 const BONDING_KEY: u8 = 1;
 const UNBONDING_KEY: u8 = 2;
 
+/// Precedes the length of queues whose entries include the delegator, which is never the length
+/// of a queue stored before delegations were introduced.
+const DELEGATIONS_QUEUE_TAG: u64 = u64::MAX;
+
 /// A pending entry in the bonding or unbonding queue.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct QueueEntry {
-    /// The validator who is bonding or unbonding.
+    /// The validator who is bonding or unbonding, or whose delegation changes.
     pub validator: PublicKey,
+    /// The delegator who is delegating to or undelegating from the validator,
+    /// if this is not the validator's own request.
+    pub delegator: Option<PublicKey>,
     /// The amount by which to change the stakes.
     pub amount: U512,
     /// The timestamp when the request was made.
@@ -30,22 +38,52 @@ impl QueueEntry {
     fn new(validator: PublicKey, amount: U512, timestamp: BlockTime) -> QueueEntry {
         QueueEntry {
             validator,
+            delegator: None,
             amount,
             timestamp,
         }
     }
+
+    /// Creates a new `QueueEntry` for a delegator with the current block's
+    /// timestamp.
+    fn new_delegation(
+        delegator: PublicKey,
+        validator: PublicKey,
+        amount: U512,
+        timestamp: BlockTime,
+    ) -> QueueEntry {
+        QueueEntry {
+            validator,
+            delegator: Some(delegator),
+            amount,
+            timestamp,
+        }
+    }
+
+    /// Returns the account which made the request, and to which unbonded motes
+    /// are paid out.
+    pub fn account(&self) -> PublicKey {
+        self.delegator.unwrap_or(self.validator)
+    }
 }
 
-impl FromBytes for QueueEntry {
-    fn from_bytes(bytes: &[u8]) -> result::Result<(Self, &[u8]), bytesrepr::Error> {
+impl QueueEntry {
+    /// Deserializes an entry stored before delegations were introduced, which has no delegator.
+    fn from_bytes_without_delegator(
+        bytes: &[u8],
+    ) -> result::Result<(Self, &[u8]), bytesrepr::Error> {
         let (validator, bytes) = PublicKey::from_bytes(bytes)?;
         let (amount, bytes) = U512::from_bytes(bytes)?;
         let (timestamp, bytes) = BlockTime::from_bytes(bytes)?;
-        let entry = QueueEntry {
-            validator,
-            amount,
-            timestamp,
-        };
+        Ok((QueueEntry::new(validator, amount, timestamp), bytes))
+    }
+}
+
+impl FromBytes for QueueEntry {
+    fn from_bytes(bytes: &[u8]) -> result::Result<(Self, &[u8]), bytesrepr::Error> {
+        let (mut entry, bytes) = QueueEntry::from_bytes_without_delegator(bytes)?;
+        let (delegator, bytes) = Option::<PublicKey>::from_bytes(bytes)?;
+        entry.delegator = delegator;
         Ok((entry, bytes))
     }
 }
@@ -55,6 +93,7 @@ impl ToBytes for QueueEntry {
         Ok((self.validator.to_bytes()?.into_iter())
             .chain(self.amount.to_bytes()?)
             .chain(self.timestamp.to_bytes()?)
+            .chain(self.delegator.to_bytes()?)
             .collect())
     }
 }
@@ -113,15 +152,37 @@ impl Queue {
     ///
     /// Returns an error if the validator already has a request in the queue.
     pub fn push(&mut self, validator: PublicKey, amount: U512, timestamp: BlockTime) -> Result<()> {
-        if self.0.iter().any(|entry| entry.validator == validator) {
+        self.push_entry(QueueEntry::new(validator, amount, timestamp))
+    }
+
+    /// Pushes a new entry for a delegator to the end of the queue.
+    ///
+    /// Returns an error if the delegator already has a request for the same
+    /// validator in the queue.
+    pub fn push_delegation(
+        &mut self,
+        delegator: PublicKey,
+        validator: PublicKey,
+        amount: U512,
+        timestamp: BlockTime,
+    ) -> Result<()> {
+        self.push_entry(QueueEntry::new_delegation(
+            delegator, validator, amount, timestamp,
+        ))
+    }
+
+    fn push_entry(&mut self, new_entry: QueueEntry) -> Result<()> {
+        if self.0.iter().any(|entry| {
+            entry.validator == new_entry.validator && entry.delegator == new_entry.delegator
+        }) {
             return Err(Error::MultipleRequests);
         }
         if let Some(entry) = self.0.last() {
-            if entry.timestamp > timestamp {
+            if entry.timestamp > new_entry.timestamp {
                 return Err(Error::TimeWentBackwards);
             }
         }
-        self.0.push(QueueEntry::new(validator, amount, timestamp));
+        self.0.push(new_entry);
         Ok(())
     }
 
@@ -134,6 +195,23 @@ impl Queue {
         self.0 = rest;
         older_than
     }
+
+    /// Puts entries which were removed by `pop_due` back at the front of the queue, so that
+    /// they are returned again by the next `pop_due`.
+    pub fn requeue(&mut self, mut entries: Vec<QueueEntry>) {
+        entries.append(&mut self.0);
+        self.0 = entries;
+    }
+
+    /// Removes and returns all queue entries of the specified validators.
+    pub fn remove_validators(&mut self, validators: &[PublicKey]) -> Vec<QueueEntry> {
+        let (removed, rest) = self
+            .0
+            .iter()
+            .partition(|entry| validators.contains(&entry.validator));
+        self.0 = rest;
+        removed
+    }
 }
 
 impl TryFrom<Value> for Queue {
@@ -161,10 +239,20 @@ impl Into<Value> for &Queue {
 
 impl FromBytes for Queue {
     fn from_bytes(bytes: &[u8]) -> result::Result<(Self, &[u8]), bytesrepr::Error> {
-        let (len, mut bytes) = u64::from_bytes(bytes)?;
+        let (tag_or_len, bytes) = u64::from_bytes(bytes)?;
+        let with_delegators = tag_or_len == DELEGATIONS_QUEUE_TAG;
+        let (len, mut bytes) = if with_delegators {
+            u64::from_bytes(bytes)?
+        } else {
+            (tag_or_len, bytes)
+        };
         let mut queue = Vec::new();
         for _ in 0..len {
-            let (entry, rest) = QueueEntry::from_bytes(bytes)?;
+            let (entry, rest) = if with_delegators {
+                QueueEntry::from_bytes(bytes)?
+            } else {
+                QueueEntry::from_bytes_without_delegator(bytes)?
+            };
             bytes = rest;
             queue.push(entry);
         }
@@ -174,7 +262,8 @@ impl FromBytes for Queue {
 
 impl ToBytes for Queue {
     fn to_bytes(&self) -> result::Result<Vec<u8>, bytesrepr::Error> {
-        let mut bytes = (self.0.len() as u64).to_bytes()?; // TODO: Allocate correct capacity.
+        let mut bytes = DELEGATIONS_QUEUE_TAG.to_bytes()?; // TODO: Allocate correct capacity.
+        bytes.extend((self.0.len() as u64).to_bytes()?);
         for entry in &self.0 {
             bytes.extend(entry.to_bytes()?);
         }
@@ -185,6 +274,7 @@ impl ToBytes for Queue {
 #[cfg(test)]
 mod tests {
     use contract_ffi::{
+        bytesrepr::{self, ToBytes},
         system_contracts::pos::Error,
         value::{
             account::{BlockTime, PublicKey},
@@ -237,4 +327,115 @@ mod tests {
             queue.pop_due(BlockTime::new(105))
         );
     }
+
+    #[test]
+    fn test_requeue() {
+        let val1 = PublicKey::new(KEY1);
+        let val2 = PublicKey::new(KEY2);
+        let val3 = PublicKey::new(KEY3);
+        let mut queue: Queue = Default::default();
+        assert_eq!(Ok(()), queue.push(val1, U512::from(5), BlockTime::new(100)));
+        assert_eq!(Ok(()), queue.push(val2, U512::from(6), BlockTime::new(101)));
+        let due = queue.pop_due(BlockTime::new(101));
+        assert_eq!(Ok(()), queue.push(val3, U512::from(7), BlockTime::new(102)));
+        queue.requeue(due);
+        assert_eq!(
+            Err(Error::MultipleRequests),
+            queue.push(val1, U512::from(5), BlockTime::new(103))
+        );
+        assert_eq!(
+            vec![
+                QueueEntry::new(val1, U512::from(5), BlockTime::new(100)),
+                QueueEntry::new(val2, U512::from(6), BlockTime::new(101)),
+            ],
+            queue.pop_due(BlockTime::new(101))
+        );
+        assert_eq!(
+            vec![QueueEntry::new(val3, U512::from(7), BlockTime::new(102))],
+            queue.pop_due(BlockTime::new(105))
+        );
+    }
+
+    #[test]
+    fn test_remove_validators() {
+        let val1 = PublicKey::new(KEY1);
+        let val2 = PublicKey::new(KEY2);
+        let val3 = PublicKey::new(KEY3);
+        let mut queue: Queue = Default::default();
+        assert_eq!(Ok(()), queue.push(val1, U512::from(5), BlockTime::new(100)));
+        assert_eq!(Ok(()), queue.push(val2, U512::from(6), BlockTime::new(101)));
+        assert_eq!(Ok(()), queue.push(val3, U512::from(7), BlockTime::new(102)));
+        assert_eq!(
+            vec![
+                QueueEntry::new(val1, U512::from(5), BlockTime::new(100)),
+                QueueEntry::new(val3, U512::from(7), BlockTime::new(102)),
+            ],
+            queue.remove_validators(&[val1, val3])
+        );
+        assert_eq!(
+            vec![QueueEntry::new(val2, U512::from(6), BlockTime::new(101))],
+            queue.pop_due(BlockTime::new(105))
+        );
+    }
+
+    #[test]
+    fn test_push_delegation() {
+        let val1 = PublicKey::new(KEY1);
+        let val2 = PublicKey::new(KEY2);
+        let del = PublicKey::new(KEY3);
+        let mut queue: Queue = Default::default();
+        assert_eq!(Ok(()), queue.push(val1, U512::from(5), BlockTime::new(100)));
+        assert_eq!(
+            Ok(()),
+            queue.push_delegation(del, val1, U512::from(5), BlockTime::new(101))
+        );
+        assert_eq!(
+            Ok(()),
+            queue.push_delegation(del, val2, U512::from(5), BlockTime::new(101))
+        );
+        assert_eq!(
+            Err(Error::MultipleRequests),
+            queue.push_delegation(del, val1, U512::from(5), BlockTime::new(102))
+        );
+        assert_eq!(val1, queue.0[0].account());
+        assert_eq!(del, queue.0[1].account());
+    }
+
+    #[test]
+    fn test_serialization_roundtrip() {
+        let mut queue: Queue = Default::default();
+        assert_eq!(
+            Ok(()),
+            queue.push(PublicKey::new(KEY1), U512::from(5), BlockTime::new(100))
+        );
+        assert_eq!(
+            Ok(()),
+            queue.push_delegation(
+                PublicKey::new(KEY3),
+                PublicKey::new(KEY2),
+                U512::from(6),
+                BlockTime::new(101)
+            )
+        );
+        let bytes = queue.to_bytes().expect("should serialize");
+        let deserialized: Queue = bytesrepr::deserialize(&bytes).expect("should deserialize");
+        assert_eq!(queue.0, deserialized.0);
+    }
+
+    #[test]
+    fn test_deserialize_queue_without_delegators() {
+        let entries = vec![
+            QueueEntry::new(PublicKey::new(KEY1), U512::from(5), BlockTime::new(100)),
+            QueueEntry::new(PublicKey::new(KEY2), U512::from(6), BlockTime::new(101)),
+        ];
+        // The layout before delegations were introduced
+        let mut bytes = (entries.len() as u64).to_bytes().unwrap();
+        for entry in &entries {
+            bytes.extend(entry.validator.to_bytes().unwrap());
+            bytes.extend(entry.amount.to_bytes().unwrap());
+            bytes.extend(entry.timestamp.to_bytes().unwrap());
+        }
+        let deserialized: Queue = bytesrepr::deserialize(&bytes).expect("should deserialize");
+        assert_eq!(entries, deserialized.0);
+    }
 }
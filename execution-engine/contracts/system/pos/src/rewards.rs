@@ -0,0 +1,257 @@
+use alloc::collections::BTreeMap;
+
+use contract_ffi::{
+    system_contracts::pos::Params,
+    value::{account::PublicKey, U512},
+};
+
+use super::{delegations::Delegations, stakes::Stakes};
+
+/// The rewards for a block. Each validator's reward is shared with its delegators by delegation
+/// weight, and the validator's commission on the delegators' rewards is paid out to its account.
+/// Everything else is added to the stakes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Rewards {
+    pub payouts: BTreeMap<PublicKey, U512>,
+    /// The increase of each validator's stake, including the increases of its delegations.
+    pub stake_increases: BTreeMap<PublicKey, U512>,
+    pub delegation_increases: Delegations,
+}
+
+impl Rewards {
+    /// Splits `amount` among the bonded validators which signed the block, or among all bonded
+    /// validators if `finality_signers` is empty, pro rata to their stakes.  If the `proposer`
+    /// is bonded, it gets the proposer reward share of the amount up front.
+    ///
+    /// Motes which can't be split evenly are not distributed.
+    pub fn new(
+        stakes: &Stakes,
+        delegations: &Delegations,
+        amount: U512,
+        proposer: &PublicKey,
+        finality_signers: &[PublicKey],
+        params: &Params,
+    ) -> Rewards {
+        let eligible_stakes: BTreeMap<&PublicKey, U512> = stakes
+            .0
+            .iter()
+            .filter(|(validator, _)| {
+                finality_signers.is_empty() || finality_signers.contains(validator)
+            })
+            .map(|(validator, stake)| (validator, *stake))
+            .collect();
+        let total_stake = eligible_stakes
+            .values()
+            .fold(U512::zero(), |sum, stake| sum.saturating_add(*stake));
+        if total_stake.is_zero() {
+            return Rewards::default();
+        }
+
+        let mut rewards: BTreeMap<PublicKey, U512> = BTreeMap::new();
+        let proposer_reward = if stakes.0.contains_key(proposer) {
+            amount * params.proposer_reward_share / 1_000_000
+        } else {
+            U512::zero()
+        };
+        if !proposer_reward.is_zero() {
+            rewards.insert(*proposer, proposer_reward);
+        }
+        let remainder = amount - proposer_reward;
+        for (validator, stake) in eligible_stakes {
+            *rewards.entry(*validator).or_insert_with(U512::zero) +=
+                remainder * stake / total_stake;
+        }
+
+        let mut result = Rewards::default();
+        for (validator, reward) in rewards {
+            let mut commission = U512::zero();
+            if let (Some(stake), Some(delegators)) =
+                (stakes.0.get(&validator), delegations.0.get(&validator))
+            {
+                for (delegator, delegated) in delegators {
+                    let delegator_reward = reward * *delegated / *stake;
+                    let delegator_commission =
+                        delegator_reward * params.commission_rate / 1_000_000;
+                    commission += delegator_commission;
+                    let delegation_increase = delegator_reward - delegator_commission;
+                    if !delegation_increase.is_zero() {
+                        result.delegation_increases.delegate(
+                            delegator,
+                            &validator,
+                            delegation_increase,
+                        );
+                    }
+                }
+            }
+            if !commission.is_zero() {
+                result.payouts.insert(validator, commission);
+            }
+            let stake_increase = reward - commission;
+            if !stake_increase.is_zero() {
+                result.stake_increases.insert(validator, stake_increase);
+            }
+        }
+        result
+    }
+
+    /// Returns the total amount by which the stakes are increased.
+    pub fn total_stake_increase(&self) -> U512 {
+        self.stake_increases
+            .values()
+            .fold(U512::zero(), |sum, amount| sum + *amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use contract_ffi::{
+        system_contracts::pos::Params,
+        value::{account::PublicKey, U512},
+    };
+
+    use crate::{delegations::Delegations, rewards::Rewards, stakes::Stakes};
+
+    const KEY1: [u8; 32] = [1; 32];
+    const KEY2: [u8; 32] = [2; 32];
+    const KEY3: [u8; 32] = [3; 32];
+
+    fn new_stakes(stakes: &[([u8; 32], u64)]) -> Stakes {
+        Stakes(
+            stakes
+                .iter()
+                .map(|&(key, amount)| (PublicKey::new(key), U512::from(amount)))
+                .collect(),
+        )
+    }
+
+    /// Returns the total reward of each validator, whether paid out or added to its stake.
+    fn total_rewards(rewards: &Rewards) -> Stakes {
+        let mut totals = Stakes(rewards.payouts.clone());
+        for (validator, amount) in &rewards.stake_increases {
+            totals.bond(validator, *amount);
+        }
+        totals
+    }
+
+    #[test]
+    fn test_rewards_pro_rata() {
+        let stakes = new_stakes(&[(KEY1, 100), (KEY2, 300)]);
+        let rewards = Rewards::new(
+            &stakes,
+            &Delegations::default(),
+            U512::from(1_000_000),
+            &PublicKey::new(KEY3),
+            &[],
+            &Params::default(),
+        );
+        assert_eq!(
+            new_stakes(&[(KEY1, 250_000), (KEY2, 750_000)]),
+            total_rewards(&rewards)
+        );
+    }
+
+    #[test]
+    fn test_rewards_proposer_share() {
+        let stakes = new_stakes(&[(KEY1, 100), (KEY2, 100)]);
+        let amount = 1_000_000;
+        let rewards = Rewards::new(
+            &stakes,
+            &Delegations::default(),
+            U512::from(amount),
+            &PublicKey::new(KEY1),
+            &[],
+            &Params::default(),
+        );
+        let proposer_reward = amount * Params::default().proposer_reward_share / 1_000_000;
+        let shared_reward = (amount - proposer_reward) / 2;
+        assert_eq!(
+            new_stakes(&[
+                (KEY1, proposer_reward + shared_reward),
+                (KEY2, shared_reward)
+            ]),
+            total_rewards(&rewards)
+        );
+    }
+
+    #[test]
+    fn test_rewards_only_for_signers() {
+        let stakes = new_stakes(&[(KEY1, 100), (KEY2, 100), (KEY3, 200)]);
+        let rewards = Rewards::new(
+            &stakes,
+            &Delegations::default(),
+            U512::from(1_000),
+            &PublicKey::new([4; 32]),
+            &[PublicKey::new(KEY2), PublicKey::new(KEY3)],
+            &Params::default(),
+        );
+        assert_eq!(
+            new_stakes(&[(KEY2, 333), (KEY3, 666)]),
+            total_rewards(&rewards)
+        );
+    }
+
+    #[test]
+    fn test_rewards_commission() {
+        let stakes = new_stakes(&[(KEY1, 100)]);
+        let amount = 1_000_000;
+        let rewards = Rewards::new(
+            &stakes,
+            &Delegations::default(),
+            U512::from(amount),
+            &PublicKey::new(KEY1),
+            &[],
+            &Params::default(),
+        );
+        // Without delegators, the validator's whole reward is added to its stake.
+        assert!(rewards.payouts.is_empty());
+        assert_eq!(rewards.total_stake_increase(), U512::from(amount));
+    }
+
+    #[test]
+    fn test_rewards_delegators() {
+        let stakes = new_stakes(&[(KEY1, 400)]);
+        let mut delegations = Delegations::default();
+        delegations.delegate(
+            &PublicKey::new(KEY2),
+            &PublicKey::new(KEY1),
+            U512::from(100),
+        );
+        delegations.delegate(
+            &PublicKey::new(KEY3),
+            &PublicKey::new(KEY1),
+            U512::from(200),
+        );
+        let amount = 1_000_000;
+        let rewards = Rewards::new(
+            &stakes,
+            &delegations,
+            U512::from(amount),
+            &PublicKey::new(KEY1),
+            &[],
+            &Params::default(),
+        );
+
+        let delegator_2_reward = amount / 4;
+        let delegator_3_reward = amount / 2;
+        let commission_2 = delegator_2_reward * Params::default().commission_rate / 1_000_000;
+        let commission_3 = delegator_3_reward * Params::default().commission_rate / 1_000_000;
+        let mut expected_delegation_increases = Delegations::default();
+        expected_delegation_increases.delegate(
+            &PublicKey::new(KEY2),
+            &PublicKey::new(KEY1),
+            U512::from(delegator_2_reward - commission_2),
+        );
+        expected_delegation_increases.delegate(
+            &PublicKey::new(KEY3),
+            &PublicKey::new(KEY1),
+            U512::from(delegator_3_reward - commission_3),
+        );
+        assert_eq!(expected_delegation_increases, rewards.delegation_increases);
+        assert_eq!(
+            rewards.payouts.get(&PublicKey::new(KEY1)).cloned(),
+            Some(U512::from(commission_2 + commission_3))
+                .filter(|commission| !commission.is_zero())
+        );
+        assert_eq!(new_stakes(&[(KEY1, amount)]), total_rewards(&rewards));
+    }
+}
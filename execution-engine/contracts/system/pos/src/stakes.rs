@@ -9,12 +9,10 @@ use base16;
 use contract_ffi::{
     contract_api::runtime,
     key::Key,
-    system_contracts::pos::{Error, Result},
+    system_contracts::pos::{Error, Params, Result},
     value::{account::PublicKey, U512},
 };
 
-use super::{MAX_DECREASE, MAX_INCREASE, MAX_REL_DECREASE, MAX_REL_INCREASE, MAX_SPREAD};
-
 pub trait StakesProvider {
     fn read() -> Result<Stakes>;
     fn write(stakes: &Stakes);
@@ -87,7 +85,7 @@ impl StakesProvider for ContractStakes {
 }
 
 /// The stakes map, assigning the staked amount of motes to each bonded
-/// validator.
+/// validator. This includes the motes delegated to the validator.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Stakes(pub BTreeMap<PublicKey, U512>);
 
@@ -101,12 +99,19 @@ impl Stakes {
     /// * unbonding the specified amount is not allowed,
     /// * tries to unbond last validator,
     /// * validator was not bonded.
-    pub fn unbond(&mut self, validator: &PublicKey, maybe_amount: Option<U512>) -> Result<U512> {
+    pub fn unbond(
+        &mut self,
+        validator: &PublicKey,
+        maybe_amount: Option<U512>,
+        params: &Params,
+    ) -> Result<U512> {
         let min = self
             .max_without(validator)
             .unwrap_or_else(U512::zero)
-            .saturating_sub(MAX_SPREAD);
-        let max_decrease = MAX_DECREASE.min(self.sum() * MAX_REL_DECREASE / 1_000_000);
+            .saturating_sub(params.max_spread);
+        let max_decrease = params
+            .max_decrease
+            .min(self.sum() * params.max_rel_decrease / 1_000_000);
 
         if let Some(amount) = maybe_amount {
             // The minimum stake value to not violate the maximum spread.
@@ -141,6 +146,23 @@ impl Stakes {
         Ok(stake)
     }
 
+    /// Subtracts `amount` that a delegator withdraws from the validator's stakes.
+    ///
+    /// The limits that apply to unbonding (the maximum spread and decrease, and keeping the last
+    /// validator bonded) restrict the validators' own stake, and don't keep a delegator from
+    /// leaving. The validator's own stake always remains, so it stays bonded.
+    ///
+    /// Returns an error if the validator was not bonded, or its stakes are not higher than
+    /// `amount`.
+    pub fn undelegate(&mut self, validator: &PublicKey, amount: U512) -> Result<()> {
+        let stake = self.0.get_mut(validator).ok_or(Error::NotBonded)?;
+        if *stake <= amount {
+            return Err(Error::UnbondTooLarge);
+        }
+        *stake -= amount;
+        Ok(())
+    }
+
     /// Adds `amount` to the validator's stakes.
     pub fn bond(&mut self, validator: &PublicKey, amount: U512) {
         self.0
@@ -149,21 +171,35 @@ impl Stakes {
             .or_insert(amount);
     }
 
+    /// Removes all the validator's stakes, without any of the limits that apply to unbonding.
+    ///
+    /// Returns the amount that was removed, which is zero if the validator was not bonded.
+    pub fn slash(&mut self, validator: &PublicKey) -> U512 {
+        self.0.remove(validator).unwrap_or_else(U512::zero)
+    }
+
     /// Returns an error if bonding the specified amount is not allowed.
-    pub fn validate_bonding(&self, validator: &PublicKey, amount: U512) -> Result<()> {
+    pub fn validate_bonding(
+        &self,
+        validator: &PublicKey,
+        amount: U512,
+        params: &Params,
+    ) -> Result<()> {
         let max = self
             .min_without(validator)
             .unwrap_or(U512::MAX)
-            .saturating_add(MAX_SPREAD);
+            .saturating_add(params.max_spread);
         let min = self
             .max_without(validator)
             .unwrap_or_else(U512::zero)
-            .saturating_sub(MAX_SPREAD);
+            .saturating_sub(params.max_spread);
         let stake = self.0.get(validator).map(|s| *s + amount).unwrap_or(amount);
         if stake > max || stake < min {
             return Err(Error::SpreadTooHigh);
         }
-        let max_increase = MAX_INCREASE.min(self.sum() * MAX_REL_INCREASE / 1_000_000);
+        let max_increase = params
+            .max_increase
+            .min(self.sum() * params.max_rel_increase / 1_000_000);
         if (stake.is_zero() && amount > min.saturating_add(max_increase))
             || (!stake.is_zero() && amount > max_increase)
         {
@@ -203,7 +239,7 @@ impl Stakes {
 #[cfg(test)]
 mod tests {
     use contract_ffi::{
-        system_contracts::pos::Error,
+        system_contracts::pos::{Error, Params},
         value::{account::PublicKey, U512},
     };
 
@@ -226,7 +262,7 @@ mod tests {
         let mut stakes = new_stakes(&[(KEY2, 100)]);
         assert_eq!(
             Ok(()),
-            stakes.validate_bonding(&PublicKey::new(KEY1), U512::from(5))
+            stakes.validate_bonding(&PublicKey::new(KEY1), U512::from(5), &Params::default())
         );
         stakes.bond(&PublicKey::new(KEY1), U512::from(5));
         assert_eq!(new_stakes(&[(KEY1, 5), (KEY2, 100)]), stakes);
@@ -237,7 +273,7 @@ mod tests {
         let mut stakes = new_stakes(&[(KEY1, 50), (KEY2, 100)]);
         assert_eq!(
             Ok(()),
-            stakes.validate_bonding(&PublicKey::new(KEY1), U512::from(4))
+            stakes.validate_bonding(&PublicKey::new(KEY1), U512::from(4), &Params::default())
         );
         stakes.bond(&PublicKey::new(KEY1), U512::from(4));
         assert_eq!(new_stakes(&[(KEY1, 54), (KEY2, 100)]), stakes);
@@ -251,7 +287,8 @@ mod tests {
             Err(Error::BondTooLarge),
             stakes.validate_bonding(
                 &PublicKey::new(KEY1),
-                U512::from(crate::MAX_REL_INCREASE * total / 1_000_000 + 1)
+                U512::from(Params::default().max_rel_increase * total / 1_000_000 + 1),
+                &Params::default()
             ),
             "Successfully bonded more than the maximum amount."
         );
@@ -259,7 +296,8 @@ mod tests {
             Ok(()),
             stakes.validate_bonding(
                 &PublicKey::new(KEY1),
-                U512::from(crate::MAX_REL_INCREASE * total / 1_000_000)
+                U512::from(Params::default().max_rel_increase * total / 1_000_000),
+                &Params::default()
             ),
             "Failed to bond the maximum amount."
         );
@@ -270,7 +308,7 @@ mod tests {
         let mut stakes = new_stakes(&[(KEY1, 5), (KEY2, 100)]);
         assert_eq!(
             Ok(U512::from(5)),
-            stakes.unbond(&PublicKey::new(KEY1), None)
+            stakes.unbond(&PublicKey::new(KEY1), None, &Params::default())
         );
         assert_eq!(new_stakes(&[(KEY2, 100)]), stakes);
     }
@@ -280,7 +318,7 @@ mod tests {
         let mut stakes = new_stakes(&[(KEY1, 5)]);
         assert_eq!(
             Err(Error::CannotUnbondLastValidator),
-            stakes.unbond(&PublicKey::new(KEY1), None)
+            stakes.unbond(&PublicKey::new(KEY1), None, &Params::default())
         );
     }
 
@@ -289,11 +327,51 @@ mod tests {
         let mut stakes = new_stakes(&[(KEY1, 50)]);
         assert_eq!(
             Ok(U512::from(4)),
-            stakes.unbond(&PublicKey::new(KEY1), Some(U512::from(4)))
+            stakes.unbond(
+                &PublicKey::new(KEY1),
+                Some(U512::from(4)),
+                &Params::default()
+            )
         );
         assert_eq!(new_stakes(&[(KEY1, 46)]), stakes);
     }
 
+    #[test]
+    fn test_undelegate_ignores_unbonding_limits() {
+        let params = Params {
+            max_spread: U512::from(10),
+            max_decrease: U512::from(1),
+            ..Params::default()
+        };
+        let mut stakes = new_stakes(&[(KEY1, 110), (KEY2, 100)]);
+        assert_eq!(
+            Err(Error::SpreadTooHigh),
+            stakes.unbond(&PublicKey::new(KEY2), Some(U512::from(50)), &params)
+        );
+        assert_eq!(
+            Ok(()),
+            stakes.undelegate(&PublicKey::new(KEY2), U512::from(50))
+        );
+        assert_eq!(new_stakes(&[(KEY1, 110), (KEY2, 50)]), stakes);
+        assert_eq!(
+            Err(Error::UnbondTooLarge),
+            stakes.undelegate(&PublicKey::new(KEY2), U512::from(50))
+        );
+        assert_eq!(
+            Err(Error::NotBonded),
+            stakes.undelegate(&PublicKey::new([3; 32]), U512::from(1))
+        );
+    }
+
+    #[test]
+    fn test_slash() {
+        let mut stakes = new_stakes(&[(KEY1, 5), (KEY2, 100)]);
+        assert_eq!(U512::from(100), stakes.slash(&PublicKey::new(KEY2)));
+        assert_eq!(new_stakes(&[(KEY1, 5)]), stakes);
+        assert_eq!(U512::zero(), stakes.slash(&PublicKey::new(KEY2)));
+        assert_eq!(new_stakes(&[(KEY1, 5)]), stakes);
+    }
+
     #[test]
     fn test_unbond_too_much_rel() {
         let mut stakes = new_stakes(&[(KEY1, 999), (KEY2, 1)]);
@@ -302,15 +380,23 @@ mod tests {
             Err(Error::UnbondTooLarge),
             stakes.unbond(
                 &PublicKey::new(KEY1),
-                Some(U512::from(crate::MAX_REL_DECREASE * total / 1_000_000 + 1))
+                Some(U512::from(
+                    Params::default().max_rel_decrease * total / 1_000_000 + 1
+                )),
+                &Params::default()
             ),
             "Successfully unbonded more than the maximum amount."
         );
         assert_eq!(
-            Ok(U512::from(crate::MAX_REL_DECREASE * total / 1_000_000)),
+            Ok(U512::from(
+                Params::default().max_rel_decrease * total / 1_000_000
+            )),
             stakes.unbond(
                 &PublicKey::new(KEY1),
-                Some(U512::from(crate::MAX_REL_DECREASE * total / 1_000_000))
+                Some(U512::from(
+                    Params::default().max_rel_decrease * total / 1_000_000
+                )),
+                &Params::default()
             ),
             "Failed to unbond the maximum amount."
         );
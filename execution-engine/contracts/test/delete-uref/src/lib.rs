@@ -0,0 +1,47 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract_ffi::{
+    contract_api::{runtime, storage, Error as ApiError, TURef},
+    unwrap_or_revert::UnwrapOrRevert,
+    uref::AccessRights,
+    value::U512,
+};
+
+const SCRATCH_NAME: &str = "scratch";
+
+const MODE_DELETE: &str = "delete";
+const MODE_READ_ONLY: &str = "read_only";
+
+#[repr(u16)]
+enum Error {
+    ValueNotDeleted = 0,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mode: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let scratch = storage::new_turef(U512::from(1));
+    runtime::put_key(SCRATCH_NAME, &scratch.into());
+
+    match mode.as_str() {
+        MODE_DELETE => {
+            storage::delete(scratch);
+            let maybe_value: Option<U512> = storage::read(scratch).unwrap_or_revert();
+            if maybe_value.is_some() {
+                runtime::revert(ApiError::User(Error::ValueNotDeleted as u16));
+            }
+        }
+        MODE_READ_ONLY => {
+            let read_only: TURef<U512> = TURef::new(scratch.addr(), AccessRights::READ);
+            storage::delete(read_only);
+        }
+        _ => runtime::revert(ApiError::InvalidArgument),
+    }
+}
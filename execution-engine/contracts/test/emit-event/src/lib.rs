@@ -0,0 +1,26 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract_ffi::{
+    contract_api::{runtime, Error},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+
+const MODE_REVERT: &str = "revert";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mode: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(Error::MissingArgument)
+        .unwrap_or_revert_with(Error::InvalidArgument);
+
+    runtime::emit_event("first", &1u32);
+    runtime::emit_event("second", &String::from("two"));
+
+    if mode == MODE_REVERT {
+        runtime::revert(Error::User(1));
+    }
+}
@@ -0,0 +1,116 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+
+use contract_ffi::{
+    contract_api::{runtime, storage, ContractRef, Error},
+    key::Key,
+    unwrap_or_revert::UnwrapOrRevert,
+    uref::URef,
+    value::{
+        abi::{AbiType, EntryPoint, EntryPointAccess, Parameter},
+        U512,
+    },
+};
+
+const ENTRY_POINT_PUBLIC: &str = "public";
+const ENTRY_POINT_ACCOUNT_ONLY: &str = "account_only";
+const ENTRY_POINT_CALLERS: &str = "callers";
+const ENTRY_POINT_GROUPS: &str = "groups";
+const ENTRY_POINT_PROXY: &str = "proxy";
+
+const MODE_DIRECT: &str = "direct";
+const MODE_PROXY: &str = "proxy";
+
+#[no_mangle]
+pub extern "C" fn public() {}
+
+#[no_mangle]
+pub extern "C" fn account_only() {}
+
+#[no_mangle]
+pub extern "C" fn callers() {}
+
+#[no_mangle]
+pub extern "C" fn groups() {}
+
+/// Calls the entry point named by the second argument of the contract given as the first one,
+/// so that the callee is called by this contract rather than by an account.
+#[no_mangle]
+pub extern "C" fn proxy() {
+    let contract_key: Key = runtime::get_arg(0)
+        .unwrap_or_revert_with(Error::MissingArgument)
+        .unwrap_or_revert_with(Error::InvalidArgument);
+    let entry_point: String = runtime::get_arg(1)
+        .unwrap_or_revert_with(Error::MissingArgument)
+        .unwrap_or_revert_with(Error::InvalidArgument);
+    let contract_ref: ContractRef = contract_key
+        .to_c_ptr()
+        .unwrap_or_revert_with(Error::UnexpectedKeyVariant);
+    runtime::call_entry_point::<_, ()>(contract_ref, &entry_point, &(), &Vec::new());
+}
+
+fn entry_points(group: URef) -> BTreeMap<String, EntryPoint> {
+    let caller = Key::Account(runtime::get_caller().value());
+    let restricted = |access| EntryPoint::new(Vec::new(), AbiType::Unit).with_access(access);
+
+    let mut entry_points = BTreeMap::new();
+    entry_points.insert(
+        String::from(ENTRY_POINT_PUBLIC),
+        restricted(EntryPointAccess::Public),
+    );
+    entry_points.insert(
+        String::from(ENTRY_POINT_ACCOUNT_ONLY),
+        restricted(EntryPointAccess::AccountOnly),
+    );
+    entry_points.insert(
+        String::from(ENTRY_POINT_CALLERS),
+        restricted(EntryPointAccess::Callers(vec![caller])),
+    );
+    entry_points.insert(
+        String::from(ENTRY_POINT_GROUPS),
+        restricted(EntryPointAccess::Groups(vec![group])),
+    );
+    entry_points.insert(
+        String::from(ENTRY_POINT_PROXY),
+        EntryPoint::new(
+            vec![
+                Parameter::new("contract", AbiType::Key),
+                Parameter::new("entry_point", AbiType::String),
+            ],
+            AbiType::Unit,
+        ),
+    );
+    entry_points
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mode: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(Error::MissingArgument)
+        .unwrap_or_revert_with(Error::InvalidArgument);
+    let entry_point: String = runtime::get_arg(1)
+        .unwrap_or_revert_with(Error::MissingArgument)
+        .unwrap_or_revert_with(Error::InvalidArgument);
+
+    let group: URef = storage::new_turef(U512::zero()).into();
+    let contract_ref = storage::store_contract(entry_points(group), BTreeMap::new());
+
+    match mode.as_str() {
+        MODE_DIRECT => {
+            runtime::call_entry_point::<_, ()>(contract_ref, &entry_point, &(), &Vec::new())
+        }
+        MODE_PROXY => {
+            let contract_key: Key = contract_ref.clone().into();
+            runtime::call_entry_point::<_, ()>(
+                contract_ref,
+                ENTRY_POINT_PROXY,
+                &(contract_key, entry_point),
+                &Vec::new(),
+            )
+        }
+        _ => runtime::revert(Error::InvalidArgument),
+    }
+}
@@ -0,0 +1,54 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+
+use contract_ffi::{
+    contract_api::{runtime, storage, Error},
+    execution::{CallStackElement, Phase},
+    key::Key,
+    value::abi::{AbiType, EntryPoint},
+};
+
+const ENTRY_POINT_CALL_STACK: &str = "call_stack";
+
+#[repr(u16)]
+enum CustomError {
+    UnexpectedSessionCallStack = 1,
+    UnexpectedContractCallStack = 2,
+}
+
+#[no_mangle]
+pub extern "C" fn call_stack() {
+    runtime::ret(runtime::get_call_stack(), Vec::new())
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let session = CallStackElement::Session {
+        account: runtime::get_caller(),
+        phase: Phase::Session,
+    };
+    if runtime::get_call_stack() != vec![session] {
+        runtime::revert(Error::User(CustomError::UnexpectedSessionCallStack as u16));
+    }
+
+    let mut entry_points = BTreeMap::new();
+    entry_points.insert(
+        String::from(ENTRY_POINT_CALL_STACK),
+        EntryPoint::new(Vec::new(), AbiType::Any),
+    );
+    let contract_ref = storage::store_contract(entry_points, BTreeMap::new());
+    let contract_key: Key = contract_ref.clone().into();
+
+    let call_stack: Vec<CallStackElement> =
+        runtime::call_entry_point(contract_ref, ENTRY_POINT_CALL_STACK, &(), &Vec::new());
+    let contract = CallStackElement::StoredContract {
+        key: contract_key,
+        phase: Phase::Session,
+    };
+    if call_stack != vec![session, contract] {
+        runtime::revert(Error::User(CustomError::UnexpectedContractCallStack as u16));
+    }
+}
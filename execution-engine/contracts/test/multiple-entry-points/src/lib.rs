@@ -0,0 +1,34 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+
+use contract_ffi::{
+    contract_api::{runtime, storage},
+    key::Key,
+    value::U512,
+};
+
+const CONTRACT_NAME: &str = "multiple_entry_points";
+const FIRST_NAME: &str = "first";
+const SECOND_NAME: &str = "second";
+
+#[no_mangle]
+pub extern "C" fn first() {
+    let uref = storage::new_turef(U512::from(1));
+    runtime::put_key(FIRST_NAME, &uref.into());
+}
+
+#[no_mangle]
+pub extern "C" fn second() {
+    let uref = storage::new_turef(U512::from(2));
+    runtime::put_key(SECOND_NAME, &uref.into());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_ref = storage::store_module(BTreeMap::new());
+    let contract_key: Key = contract_ref.into();
+    runtime::put_key(CONTRACT_NAME, &contract_key);
+}
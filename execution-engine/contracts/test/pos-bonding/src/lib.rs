@@ -39,13 +39,33 @@ fn unbond(pos: &ContractRef, amount: Option<U512>) {
     runtime::call_contract::<_, ()>(pos.clone(), &(POS_UNBOND, amount), &Vec::<Key>::new());
 }
 
+fn delegate(pos: &ContractRef, amount: &U512, source: PurseId, validator: PublicKey) {
+    runtime::call_contract::<_, ()>(
+        pos.clone(),
+        &(POS_DELEGATE, *amount, source, validator),
+        &vec![purse_to_key(source)],
+    );
+}
+
+fn undelegate(pos: &ContractRef, amount: Option<U512>, validator: PublicKey) {
+    runtime::call_contract::<_, ()>(
+        pos.clone(),
+        &(POS_UNDELEGATE, amount, validator),
+        &Vec::<Key>::new(),
+    );
+}
+
 const POS_BOND: &str = "bond";
 const POS_UNBOND: &str = "unbond";
+const POS_DELEGATE: &str = "delegate";
+const POS_UNDELEGATE: &str = "undelegate";
 
 const TEST_BOND: &str = "bond";
 const TEST_BOND_FROM_MAIN_PURSE: &str = "bond-from-main-purse";
 const TEST_SEED_NEW_ACCOUNT: &str = "seed_new_account";
 const TEST_UNBOND: &str = "unbond";
+const TEST_DELEGATE: &str = "delegate";
+const TEST_UNDELEGATE: &str = "undelegate";
 
 #[no_mangle]
 pub extern "C" fn call() {
@@ -86,6 +106,27 @@ pub extern "C" fn call() {
             .unwrap_or_revert_with(ApiError::MissingArgument)
             .unwrap_or_revert_with(ApiError::InvalidArgument);
         unbond(&pos_pointer, maybe_amount);
+    } else if command == TEST_DELEGATE {
+        let amount = runtime::get_arg(1)
+            .unwrap_or_revert_with(ApiError::MissingArgument)
+            .unwrap_or_revert_with(ApiError::InvalidArgument);
+        let validator: PublicKey = runtime::get_arg(2)
+            .unwrap_or_revert_with(ApiError::MissingArgument)
+            .unwrap_or_revert_with(ApiError::InvalidArgument);
+        let p1 = system::create_purse();
+
+        system::transfer_from_purse_to_purse(account::get_main_purse(), p1, amount)
+            .unwrap_or_revert();
+
+        delegate(&pos_pointer, &amount, p1, validator);
+    } else if command == TEST_UNDELEGATE {
+        let maybe_amount: Option<U512> = runtime::get_arg(1)
+            .unwrap_or_revert_with(ApiError::MissingArgument)
+            .unwrap_or_revert_with(ApiError::InvalidArgument);
+        let validator: PublicKey = runtime::get_arg(2)
+            .unwrap_or_revert_with(ApiError::MissingArgument)
+            .unwrap_or_revert_with(ApiError::InvalidArgument);
+        undelegate(&pos_pointer, maybe_amount, validator);
     } else {
         runtime::revert(ApiError::User(Error::UnknownCommand as u16));
     }
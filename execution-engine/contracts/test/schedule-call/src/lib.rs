@@ -0,0 +1,56 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use contract_ffi::{
+    contract_api::{account, runtime, storage, system, Error},
+    unwrap_or_revert::UnwrapOrRevert,
+    value::{
+        abi::{AbiType, EntryPoint},
+        account::BlockTime,
+        U512,
+    },
+};
+
+const ENTRY_POINT_MARK: &str = "mark";
+const GAS_PURSE_NAME: &str = "gas_purse";
+const MARKER_NAME: &str = "scheduled_call_marker";
+
+#[no_mangle]
+pub extern "C" fn mark() {
+    let blocktime: u64 = runtime::get_blocktime().into();
+    runtime::put_key(MARKER_NAME, &storage::new_turef(blocktime).into());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let blocktime: u64 = runtime::get_arg(0)
+        .unwrap_or_revert_with(Error::MissingArgument)
+        .unwrap_or_revert_with(Error::InvalidArgument);
+    let gas_amount: U512 = runtime::get_arg(1)
+        .unwrap_or_revert_with(Error::MissingArgument)
+        .unwrap_or_revert_with(Error::InvalidArgument);
+
+    let mut entry_points = BTreeMap::new();
+    entry_points.insert(
+        String::from(ENTRY_POINT_MARK),
+        EntryPoint::new(Vec::new(), AbiType::Unit),
+    );
+    let contract_ref = storage::store_contract(entry_points, BTreeMap::new());
+
+    let gas_purse = system::create_purse();
+    system::transfer_from_purse_to_purse(account::get_main_purse(), gas_purse, gas_amount)
+        .unwrap_or_revert();
+    runtime::put_key(GAS_PURSE_NAME, &gas_purse.value().into());
+
+    runtime::schedule_call(
+        contract_ref,
+        ENTRY_POINT_MARK,
+        &(),
+        BlockTime::new(blocktime),
+        gas_purse,
+    )
+    .unwrap_or_revert();
+}
@@ -0,0 +1,105 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{boxed::Box, collections::BTreeMap, string::String, vec, vec::Vec};
+
+use contract_ffi::{
+    contract_api::{runtime, storage, Error},
+    unwrap_or_revert::UnwrapOrRevert,
+    value::{
+        abi::{AbiType, EntryPoint, Parameter},
+        U512,
+    },
+};
+
+const ENTRY_POINT_ADD: &str = "add";
+const ENTRY_POINT_BAD_RETURN: &str = "bad_return";
+const ENTRY_POINT_MISSING: &str = "subtract";
+
+const MODE_VALID: &str = "valid";
+const MODE_WRONG_TYPE: &str = "wrong_type";
+const MODE_WRONG_COUNT: &str = "wrong_count";
+const MODE_BAD_RETURN: &str = "bad_return";
+const MODE_MISSING: &str = "missing";
+
+#[no_mangle]
+pub extern "C" fn add() {
+    let a: U512 = runtime::get_arg(0)
+        .unwrap_or_revert_with(Error::MissingArgument)
+        .unwrap_or_revert_with(Error::InvalidArgument);
+    let b: U512 = runtime::get_arg(1)
+        .unwrap_or_revert_with(Error::MissingArgument)
+        .unwrap_or_revert_with(Error::InvalidArgument);
+    runtime::ret(a + b, Vec::new())
+}
+
+#[no_mangle]
+pub extern "C" fn bad_return() {
+    runtime::ret(String::from("not a number"), Vec::new())
+}
+
+fn entry_points() -> BTreeMap<String, EntryPoint> {
+    let mut entry_points = BTreeMap::new();
+    entry_points.insert(
+        String::from(ENTRY_POINT_ADD),
+        EntryPoint::new(
+            vec![
+                Parameter::new("a", AbiType::U512),
+                Parameter::new("b", AbiType::U512),
+            ],
+            AbiType::U512,
+        ),
+    );
+    entry_points.insert(
+        String::from(ENTRY_POINT_BAD_RETURN),
+        EntryPoint::new(Vec::new(), AbiType::Option(Box::new(AbiType::U512))),
+    );
+    entry_points
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mode: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(Error::MissingArgument)
+        .unwrap_or_revert_with(Error::InvalidArgument);
+
+    let contract_ref = storage::store_contract(entry_points(), BTreeMap::new());
+
+    match mode.as_str() {
+        MODE_VALID => {
+            let sum: U512 = runtime::call_entry_point(
+                contract_ref,
+                ENTRY_POINT_ADD,
+                &(U512::from(2), U512::from(3)),
+                &Vec::new(),
+            );
+            assert_eq!(sum, U512::from(5));
+        }
+        MODE_WRONG_TYPE => {
+            let _sum: U512 = runtime::call_entry_point(
+                contract_ref,
+                ENTRY_POINT_ADD,
+                &(2u64, 3u64),
+                &Vec::new(),
+            );
+        }
+        MODE_WRONG_COUNT => {
+            let _sum: U512 = runtime::call_entry_point(
+                contract_ref,
+                ENTRY_POINT_ADD,
+                &(U512::from(2),),
+                &Vec::new(),
+            );
+        }
+        MODE_BAD_RETURN => {
+            let _result: Option<U512> =
+                runtime::call_entry_point(contract_ref, ENTRY_POINT_BAD_RETURN, &(), &Vec::new());
+        }
+        MODE_MISSING => {
+            let _result: U512 =
+                runtime::call_entry_point(contract_ref, ENTRY_POINT_MISSING, &(), &Vec::new());
+        }
+        _ => runtime::revert(Error::InvalidArgument),
+    }
+}
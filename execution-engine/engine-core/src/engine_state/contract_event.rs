@@ -0,0 +1,24 @@
+use contract_ffi::key::Key;
+
+/// An event emitted by a contract through the `emit_event` host function.
+///
+/// `ordinal` is the position of the event among all events emitted during a
+/// single deploy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractEvent {
+    pub emitter: Key,
+    pub ordinal: u32,
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+impl ContractEvent {
+    pub fn new(emitter: Key, ordinal: u32, name: String, data: Vec<u8>) -> Self {
+        ContractEvent {
+            emitter,
+            ordinal,
+            name,
+            data,
+        }
+    }
+}
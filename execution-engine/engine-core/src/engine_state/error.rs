@@ -29,6 +29,11 @@ pub enum Error {
     AuthorizationError,
     #[fail(display = "Insufficient payment")]
     InsufficientPaymentError,
+    #[fail(
+        display = "Gas price {} is below the minimum gas price {}",
+        gas_price, min_gas_price
+    )]
+    GasPriceTooLow { gas_price: u64, min_gas_price: u64 },
     #[fail(display = "Deploy error")]
     DeployError,
     #[fail(display = "Payment finalization error")]
@@ -0,0 +1,23 @@
+use engine_shared::motes::Motes;
+
+use super::{error::Error, execution_result::ExecutionResult};
+
+/// The outcome of a dry run of a deploy's session code.
+#[derive(Debug)]
+pub struct EstimateResult {
+    /// The result of executing the session code, including the gas it consumed and its effects.
+    /// The effects are only informational and are never committed.
+    pub execution_result: ExecutionResult,
+    /// The payment, in motes, which covers the gas consumed by the session code at the deploy's
+    /// gas price.  It does not include the cost of executing payment code.
+    pub payment: Motes,
+}
+
+impl EstimateResult {
+    pub fn precondition_failure(error: Error) -> EstimateResult {
+        EstimateResult {
+            execution_result: ExecutionResult::precondition_failure(error),
+            payment: Motes::default(),
+        }
+    }
+}
@@ -1,3 +1,7 @@
+/// Name of the function invoked when a deploy doesn't name an entry point.
+pub const DEFAULT_ENTRY_POINT_NAME: &str = "call";
+
+#[derive(Clone)]
 pub enum ExecutableDeployItem {
     ModuleBytes {
         module_bytes: Vec<u8>,
@@ -5,14 +9,17 @@ pub enum ExecutableDeployItem {
     },
     StoredContractByHash {
         hash: Vec<u8>,
+        entry_point_name: String,
         args: Vec<u8>,
     },
     StoredContractByName {
         name: String,
+        entry_point_name: String,
         args: Vec<u8>,
     },
     StoredContractByURef {
         uref: Vec<u8>,
+        entry_point_name: String,
         args: Vec<u8>,
     },
 }
@@ -26,4 +33,27 @@ impl ExecutableDeployItem {
             ExecutableDeployItem::StoredContractByURef { args, .. } => args,
         }
     }
+
+    /// Returns the name of the entry point to invoke, which is
+    /// [`DEFAULT_ENTRY_POINT_NAME`] unless the deploy names another one.
+    pub fn entry_point_name(&self) -> &str {
+        match self {
+            ExecutableDeployItem::ModuleBytes { .. } => DEFAULT_ENTRY_POINT_NAME,
+            ExecutableDeployItem::StoredContractByHash {
+                entry_point_name, ..
+            }
+            | ExecutableDeployItem::StoredContractByName {
+                entry_point_name, ..
+            }
+            | ExecutableDeployItem::StoredContractByURef {
+                entry_point_name, ..
+            } => {
+                if entry_point_name.is_empty() {
+                    DEFAULT_ENTRY_POINT_NAME
+                } else {
+                    entry_point_name
+                }
+            }
+        }
+    }
 }
@@ -5,7 +5,10 @@ use engine_shared::{
 };
 use engine_storage::global_state::StateReader;
 
-use super::{error, execution_effect::ExecutionEffect, op::Op, CONV_RATE};
+use super::{
+    contract_event::ContractEvent, error, execution_effect::ExecutionEffect, gas_limit, op::Op,
+};
+use crate::execution::ExecutionTrace;
 
 #[derive(Debug)]
 pub enum ExecutionResult {
@@ -14,9 +17,15 @@ pub enum ExecutionResult {
         error: error::Error,
         effect: ExecutionEffect,
         cost: Gas,
+        trace: Option<ExecutionTrace>,
     },
     /// Execution was finished successfully
-    Success { effect: ExecutionEffect, cost: Gas },
+    Success {
+        effect: ExecutionEffect,
+        events: Vec<ContractEvent>,
+        cost: Gas,
+        trace: Option<ExecutionTrace>,
+    },
 }
 
 impl ExecutionResult {
@@ -28,6 +37,7 @@ impl ExecutionResult {
             error,
             effect: Default::default(),
             cost: Gas::default(),
+            trace: None,
         }
     }
 
@@ -59,25 +69,115 @@ impl ExecutionResult {
         }
     }
 
+    /// Returns the events emitted during execution.  A failed execution has
+    /// its events discarded, so this is empty for [ExecutionResult::Failure].
+    pub fn events(&self) -> &[ContractEvent] {
+        match self {
+            ExecutionResult::Failure { .. } => &[],
+            ExecutionResult::Success { events, .. } => events,
+        }
+    }
+
+    /// Returns the trace recorded during execution, if it was executed in trace mode.
+    pub fn trace(&self) -> Option<&ExecutionTrace> {
+        match self {
+            ExecutionResult::Failure { trace, .. } => trace.as_ref(),
+            ExecutionResult::Success { trace, .. } => trace.as_ref(),
+        }
+    }
+
     pub fn with_cost(self, cost: Gas) -> Self {
         match self {
-            ExecutionResult::Failure { error, effect, .. } => ExecutionResult::Failure {
+            ExecutionResult::Failure {
+                error,
+                effect,
+                trace,
+                ..
+            } => ExecutionResult::Failure {
                 error,
                 effect,
                 cost,
+                trace,
+            },
+            ExecutionResult::Success {
+                effect,
+                events,
+                trace,
+                ..
+            } => ExecutionResult::Success {
+                effect,
+                events,
+                cost,
+                trace,
             },
-            ExecutionResult::Success { effect, .. } => ExecutionResult::Success { effect, cost },
         }
     }
 
     pub fn with_effect(self, effect: ExecutionEffect) -> Self {
         match self {
-            ExecutionResult::Failure { error, cost, .. } => ExecutionResult::Failure {
+            ExecutionResult::Failure {
+                error, cost, trace, ..
+            } => ExecutionResult::Failure {
                 error,
                 effect,
                 cost,
+                trace,
+            },
+            ExecutionResult::Success {
+                events,
+                cost,
+                trace,
+                ..
+            } => ExecutionResult::Success {
+                effect,
+                events,
+                cost,
+                trace,
+            },
+        }
+    }
+
+    pub fn with_events(self, events: Vec<ContractEvent>) -> Self {
+        match self {
+            failure @ ExecutionResult::Failure { .. } => failure,
+            ExecutionResult::Success {
+                effect,
+                cost,
+                trace,
+                ..
+            } => ExecutionResult::Success {
+                effect,
+                events,
+                cost,
+                trace,
+            },
+        }
+    }
+
+    pub fn with_trace(self, trace: Option<ExecutionTrace>) -> Self {
+        match self {
+            ExecutionResult::Failure {
+                error,
+                effect,
+                cost,
+                ..
+            } => ExecutionResult::Failure {
+                error,
+                effect,
+                cost,
+                trace,
+            },
+            ExecutionResult::Success {
+                effect,
+                events,
+                cost,
+                ..
+            } => ExecutionResult::Success {
+                effect,
+                events,
+                cost,
+                trace,
             },
-            ExecutionResult::Success { cost, .. } => ExecutionResult::Success { effect, cost },
         }
     }
 }
@@ -148,6 +248,7 @@ impl ExecutionResultBuilder {
     pub fn check_forced_transfer(
         &mut self,
         max_payment_cost: Motes,
+        gas_price: u64,
         account_main_purse_balance: Motes,
         payment_purse_balance: Motes,
         account_main_purse: Key,
@@ -161,9 +262,9 @@ impl ExecutionResultBuilder {
         let payment_result_is_failure = payment_result.is_failure();
 
         // payment_code_spec_3_b_ii: if (balance of PoS pay purse) < (gas spent during
-        // payment code execution) * conv_rate, no session
+        // payment code execution) * gas_price, no session
         let insufficient_balance_to_continue =
-            payment_purse_balance < Motes::from_gas(payment_result_cost, CONV_RATE)?;
+            payment_purse_balance < Motes::from_gas(payment_result_cost, gas_price)?;
 
         // payment_code_spec_4: insufficient payment
         if !(insufficient_balance_to_continue || payment_result_is_failure) {
@@ -192,12 +293,13 @@ impl ExecutionResultBuilder {
 
         let error = error::Error::InsufficientPaymentError;
         let effect = ExecutionEffect::new(ops, transforms);
-        let cost = Gas::from_motes(max_payment_cost, CONV_RATE).unwrap_or_default();
+        let cost = gas_limit(max_payment_cost, gas_price);
 
         Some(ExecutionResult::Failure {
             error,
             effect,
             cost,
+            trace: payment_result.trace().cloned(),
         })
     }
 
@@ -209,18 +311,24 @@ impl ExecutionResultBuilder {
         let cost = self.total_cost();
         let mut ops = AdditiveMap::new();
         let mut transforms = AdditiveMap::new();
+        let mut events = Vec::new();
+        let mut trace = None;
 
         let mut ret: ExecutionResult = ExecutionResult::Success {
             effect: Default::default(),
+            events: Vec::new(),
             cost,
+            trace: None,
         };
 
         match self.payment_execution_result {
             Some(result) => {
+                Self::add_trace(&mut trace, result.trace());
                 if result.is_failure() {
                     return Ok(result);
                 } else {
                     Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    Self::add_events(&mut events, result.events());
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingPaymentExecutionResult),
@@ -230,10 +338,12 @@ impl ExecutionResultBuilder {
         // exec error
         match self.session_execution_result {
             Some(result) => {
+                Self::add_trace(&mut trace, result.trace());
                 if result.is_failure() {
                     ret = result.with_cost(cost);
                 } else {
                     Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    Self::add_events(&mut events, result.events());
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingSessionExecutionResult),
@@ -241,13 +351,16 @@ impl ExecutionResultBuilder {
 
         match self.finalize_execution_result {
             Some(result) => {
+                Self::add_trace(&mut trace, result.trace());
                 if result.is_failure() {
                     // payment_code_spec_5_a: FinalizationError should only ever be raised here
                     return Ok(ExecutionResult::precondition_failure(
                         error::Error::FinalizationError,
-                    ));
+                    )
+                    .with_trace(trace));
                 } else {
                     Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    Self::add_events(&mut events, result.events());
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingFinalizeExecutionResult),
@@ -256,7 +369,10 @@ impl ExecutionResultBuilder {
         // Remove redundant writes to allow more opportunity to commute
         let reduced_effect = Self::reduce_identity_writes(ops, transforms, reader, correlation_id);
 
-        Ok(ret.with_effect(reduced_effect))
+        Ok(ret
+            .with_effect(reduced_effect)
+            .with_events(events)
+            .with_trace(trace))
     }
 
     fn add_effects(
@@ -272,6 +388,25 @@ impl ExecutionResultBuilder {
         }
     }
 
+    /// Appends `new_events`, renumbering their ordinals so they remain sequential across the
+    /// payment, session and finalize phases of a deploy.
+    fn add_events(events: &mut Vec<ContractEvent>, new_events: &[ContractEvent]) {
+        for event in new_events {
+            let mut event = event.clone();
+            event.ordinal = events.len() as u32;
+            events.push(event);
+        }
+    }
+
+    /// Appends the trace of a phase, if any, to the trace of the deploy.
+    fn add_trace(trace: &mut Option<ExecutionTrace>, phase_trace: Option<&ExecutionTrace>) {
+        if let Some(phase_trace) = phase_trace {
+            trace
+                .get_or_insert_with(ExecutionTrace::new)
+                .extend(phase_trace.clone());
+        }
+    }
+
     /// In the case we are writing the same value as was there originally,
     /// it is equivalent to having a `Transform::Identity` and `Op::Read`.
     /// This function makes that reduction before returning the `ExecutionEffect`.
@@ -8,11 +8,15 @@ use rand::{
 
 use contract_ffi::{
     key::Key,
+    system_contracts::pos,
     value::{account::PublicKey, ProtocolVersion, U512},
 };
 use engine_shared::{motes::Motes, newtypes::Blake2bHash, transform::TypeMismatch};
 use engine_storage::global_state::CommitResult;
-use engine_wasm_prep::wasm_costs::WasmCosts;
+use engine_wasm_prep::{
+    host_function_costs::{HostFunctionCost, HostFunctionCosts},
+    wasm_costs::WasmCosts,
+};
 
 use crate::engine_state::execution_effect::ExecutionEffect;
 
@@ -117,6 +121,10 @@ pub struct GenesisConfig {
     proof_of_stake_installer_bytes: Vec<u8>,
     accounts: Vec<GenesisAccount>,
     wasm_costs: WasmCosts,
+    host_function_costs: HostFunctionCosts,
+    min_gas_price: u64,
+    storage_cost_per_byte: u64,
+    proof_of_stake_params: pos::Params,
 }
 
 impl GenesisConfig {
@@ -128,6 +136,10 @@ impl GenesisConfig {
         proof_of_stake_installer_bytes: Vec<u8>,
         accounts: Vec<GenesisAccount>,
         wasm_costs: WasmCosts,
+        host_function_costs: HostFunctionCosts,
+        min_gas_price: u64,
+        storage_cost_per_byte: u64,
+        proof_of_stake_params: pos::Params,
     ) -> Self {
         GenesisConfig {
             name,
@@ -137,6 +149,10 @@ impl GenesisConfig {
             proof_of_stake_installer_bytes,
             accounts,
             wasm_costs,
+            host_function_costs,
+            min_gas_price,
+            storage_cost_per_byte,
+            proof_of_stake_params,
         }
     }
 
@@ -164,6 +180,22 @@ impl GenesisConfig {
         self.wasm_costs
     }
 
+    pub fn host_function_costs(&self) -> HostFunctionCosts {
+        self.host_function_costs
+    }
+
+    pub fn min_gas_price(&self) -> u64 {
+        self.min_gas_price
+    }
+
+    pub fn storage_cost_per_byte(&self) -> u64 {
+        self.storage_cost_per_byte
+    }
+
+    pub fn proof_of_stake_params(&self) -> pos::Params {
+        self.proof_of_stake_params
+    }
+
     pub fn get_bonded_validators(&self) -> impl Iterator<Item = (PublicKey, Motes)> + '_ {
         let zero = Motes::zero();
         self.accounts.iter().filter_map(move |genesis_account| {
@@ -218,6 +250,55 @@ impl Distribution<GenesisConfig> for Standard {
             opcodes_div: rng.gen(),
         };
 
+        let host_function_costs = {
+            let mut cost = || HostFunctionCost::new(rng.gen(), rng.gen());
+            HostFunctionCosts {
+                read_value: cost(),
+                write: cost(),
+                add: cost(),
+                new_uref: cost(),
+                get_arg: cost(),
+                ret: cost(),
+                call_contract: cost(),
+                get_key: cost(),
+                has_key: cost(),
+                put_key: cost(),
+                remove_key: cost(),
+                list_named_keys: cost(),
+                store_function: cost(),
+                upgrade_contract_at_uref: cost(),
+                manage_keys: cost(),
+                create_purse: cost(),
+                transfer: cost(),
+                get_balance: cost(),
+                host_buffer: cost(),
+                context: cost(),
+                emit_event: cost(),
+                get_call_stack: cost(),
+                delete: cost(),
+                schedule_call: cost(),
+                call_entry_point: cost(),
+            }
+        };
+
+        let min_gas_price = rng.gen();
+
+        let storage_cost_per_byte = rng.gen();
+
+        let proof_of_stake_params = pos::Params {
+            bond_delay: rng.gen(),
+            unbond_delay: rng.gen(),
+            max_bond_len: rng.gen(),
+            max_unbond_len: rng.gen(),
+            max_spread: U512::from(rng.gen::<u64>()),
+            max_increase: U512::from(rng.gen::<u64>()),
+            max_decrease: U512::from(rng.gen::<u64>()),
+            max_rel_increase: rng.gen(),
+            max_rel_decrease: rng.gen(),
+            proposer_reward_share: rng.gen(),
+            commission_rate: rng.gen(),
+        };
+
         GenesisConfig {
             name,
             timestamp,
@@ -226,6 +307,10 @@ impl Distribution<GenesisConfig> for Standard {
             proof_of_stake_installer_bytes,
             accounts,
             wasm_costs,
+            host_function_costs,
+            min_gas_price,
+            storage_cost_per_byte,
+            proof_of_stake_params,
         }
     }
 }
@@ -0,0 +1,143 @@
+use std::cmp;
+
+use contract_ffi::{key::Key, value::Value};
+use engine_shared::newtypes::Blake2bHash;
+
+/// The maximum number of entries listed by a single request.  Longer listings are split into
+/// pages, each resumed from the last key of the previous one.
+pub const MAX_LIST_KEYS_LIMIT: usize = 1000;
+
+/// The kinds of [`Key`] which can be enumerated.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum KeyKind {
+    Account,
+    Hash,
+    URef,
+    Local,
+}
+
+impl KeyKind {
+    /// Returns the tag byte with which keys of this kind begin when serialized.
+    pub fn tag(self) -> u8 {
+        match self {
+            KeyKind::Account => 0,
+            KeyKind::Hash => 1,
+            KeyKind::URef => 2,
+            KeyKind::Local => 3,
+        }
+    }
+}
+
+pub enum ListKeysResult {
+    RootNotFound,
+    Success(Vec<(Key, Value)>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListKeysRequest {
+    state_hash: Blake2bHash,
+    key_kind: Option<KeyKind>,
+    prefix: Vec<u8>,
+    cursor: Option<Key>,
+    limit: usize,
+}
+
+impl ListKeysRequest {
+    /// Creates a request for the keys at `state_hash`.
+    ///
+    /// If `key_kind` is given, only keys of that kind whose serialized bytes following the kind's
+    /// tag begin with `prefix` are listed.  Otherwise, `prefix` is matched against the whole
+    /// serialized key.  If `cursor` is given, only keys which sort after it are listed, so the
+    /// last key of a previous result can be used to resume an enumeration.  At most `limit`
+    /// entries are listed, and never more than [`MAX_LIST_KEYS_LIMIT`].
+    pub fn new(
+        state_hash: Blake2bHash,
+        key_kind: Option<KeyKind>,
+        prefix: Vec<u8>,
+        cursor: Option<Key>,
+        limit: Option<usize>,
+    ) -> Self {
+        ListKeysRequest {
+            state_hash,
+            key_kind,
+            prefix,
+            cursor,
+            limit: cmp::min(limit.unwrap_or(MAX_LIST_KEYS_LIMIT), MAX_LIST_KEYS_LIMIT),
+        }
+    }
+
+    pub fn state_hash(&self) -> Blake2bHash {
+        self.state_hash
+    }
+
+    pub fn key_kind(&self) -> Option<KeyKind> {
+        self.key_kind
+    }
+
+    pub fn prefix(&self) -> &[u8] {
+        &self.prefix
+    }
+
+    pub fn cursor(&self) -> Option<Key> {
+        self.cursor
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns the prefix to be matched against serialized keys.
+    pub fn serialized_prefix(&self) -> Vec<u8> {
+        match self.key_kind {
+            Some(key_kind) => {
+                let mut ret = Vec::with_capacity(1 + self.prefix.len());
+                ret.push(key_kind.tag());
+                ret.extend_from_slice(&self.prefix);
+                ret
+            }
+            None => self.prefix.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use contract_ffi::{
+        bytesrepr::ToBytes,
+        key::Key,
+        uref::{AccessRights, URef},
+    };
+
+    use engine_shared::newtypes::Blake2bHash;
+
+    use super::{KeyKind, ListKeysRequest, MAX_LIST_KEYS_LIMIT};
+
+    #[test]
+    fn key_kind_tag_should_match_serialized_key() {
+        let keys = [
+            (KeyKind::Account, Key::Account([1u8; 32])),
+            (KeyKind::Hash, Key::Hash([2u8; 32])),
+            (
+                KeyKind::URef,
+                Key::URef(URef::new([3u8; 32], AccessRights::READ)),
+            ),
+            (KeyKind::Local, Key::local([4u8; 32], &[5u8; 32])),
+        ];
+        for (key_kind, key) in keys.iter() {
+            let key_bytes = key.to_bytes().unwrap();
+            assert_eq!(key_kind.tag(), key_bytes[0]);
+        }
+    }
+
+    #[test]
+    fn limit_should_not_exceed_maximum() {
+        let request =
+            |limit| ListKeysRequest::new(Blake2bHash::new(&[]), None, Vec::new(), None, limit);
+        assert_eq!(request(Some(2)).limit(), 2);
+        assert_eq!(request(None).limit(), MAX_LIST_KEYS_LIMIT);
+        assert_eq!(
+            request(Some(MAX_LIST_KEYS_LIMIT + 1)).limit(),
+            MAX_LIST_KEYS_LIMIT
+        );
+    }
+}
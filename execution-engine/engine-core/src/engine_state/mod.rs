@@ -1,11 +1,15 @@
+pub mod contract_event;
 pub mod deploy_item;
 pub mod engine_config;
 mod error;
+pub mod estimate;
 pub mod executable_deploy_item;
 pub mod execution_effect;
 pub mod execution_result;
 pub mod genesis;
+pub mod list_keys;
 pub mod op;
+pub mod parallel;
 pub mod query;
 pub mod system_contract_cache;
 pub mod upgrade;
@@ -13,19 +17,23 @@ pub mod utils;
 
 use std::{
     cell::RefCell,
+    cmp,
     collections::{BTreeMap, BTreeSet, HashMap},
+    io::{Read, Write},
+    iter,
     rc::Rc,
 };
 
 use num_traits::Zero;
 use parity_wasm::elements::Module;
+use rayon::prelude::*;
 
 use contract_ffi::{
     args_parser::ArgsParser,
-    bytesrepr::ToBytes,
-    execution::Phase,
+    bytesrepr::{self, ToBytes},
+    execution::{Phase, ScheduledCall},
     key::{Key, KEY_HASH_LENGTH},
-    system_contracts::mint,
+    system_contracts::{mint, pos::UnbondPayout},
     uref::{AccessRights, URef, UREF_ADDR_LENGTH},
     value::{
         account::{BlockTime, PublicKey, PurseId},
@@ -42,14 +50,22 @@ use engine_shared::{
 use engine_storage::{
     global_state::{CommitResult, StateProvider, StateReader},
     protocol_data::ProtocolData,
+    snapshot::{self, SnapshotSummary},
+    trie::merkle_proof::TrieMerkleProof,
+    trie_store::{DiffResult, PruneResult},
+};
+use engine_wasm_prep::{
+    host_function_costs::HostFunctionCosts, wasm_costs::WasmCosts, Preprocessor,
 };
-use engine_wasm_prep::{wasm_costs::WasmCosts, Preprocessor};
 
 use self::{
     deploy_item::DeployItem,
-    executable_deploy_item::ExecutableDeployItem,
+    estimate::EstimateResult,
+    executable_deploy_item::{ExecutableDeployItem, DEFAULT_ENTRY_POINT_NAME},
+    execution_effect::ExecutionEffect,
     execution_result::ExecutionResult,
     genesis::{GenesisAccount, GenesisConfig, GenesisResult, POS_PAYMENT_PURSE, POS_REWARDS_PURSE},
+    parallel::ParallelExecutionResult,
     system_contract_cache::SystemContractCache,
 };
 pub use self::{
@@ -59,19 +75,30 @@ pub use self::{
 use crate::{
     engine_state::{
         error::Error::MissingSystemContractError,
+        list_keys::{ListKeysRequest, ListKeysResult},
         query::{QueryRequest, QueryResult},
         upgrade::{UpgradeConfig, UpgradeResult},
     },
     execution::{self, AddressGenerator, Executor, MINT_NAME, POS_NAME},
-    tracking_copy::{TrackingCopy, TrackingCopyExt},
+    tracking_copy::{AddResult, TrackingCopy, TrackingCopyExt},
     KnownKeys,
 };
 
-// TODO?: MAX_PAYMENT && CONV_RATE values are currently arbitrary w/ real values
-// TBD gas * CONV_RATE = motes
+// TODO?: MAX_PAYMENT value is currently arbitrary w/ real value TBD
+// gas * gas_price = motes, where gas_price is provided by each deploy
 pub const MAX_PAYMENT: u64 = 10_000_000;
+/// Conventional gas price (in motes per unit of gas).
 pub const CONV_RATE: u64 = 10;
 
+/// Returns the gas limit that `motes` buy at `gas_price`.
+///
+/// Gas is free at a gas price of 0, which a chain allows by setting its minimum gas price to 0.
+/// The limit is then bounded by `motes` as if each unit of gas cost one mote, while the gas spent
+/// still costs `gas * 0 = 0` motes.
+pub fn gas_limit(motes: Motes, gas_price: u64) -> Gas {
+    Gas::from_motes(motes, cmp::max(gas_price, 1)).unwrap_or_default()
+}
+
 pub const SYSTEM_ACCOUNT_ADDR: [u8; 32] = [0u8; 32];
 
 const GENESIS_INITIAL_BLOCKTIME: u64 = 0;
@@ -112,6 +139,16 @@ where
         }
     }
 
+    pub fn host_function_costs(
+        &self,
+        protocol_version: ProtocolVersion,
+    ) -> Result<Option<HostFunctionCosts>, Error> {
+        match self.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => Ok(Some(*protocol_data.host_function_costs())),
+            None => Ok(None),
+        }
+    }
+
     pub fn get_protocol_data(
         &self,
         protocol_version: ProtocolVersion,
@@ -129,7 +166,7 @@ where
         genesis_config: GenesisConfig,
     ) -> Result<GenesisResult, Error> {
         // Preliminaries
-        let executor = Executor;
+        let executor = Executor::default();
         let blocktime = BlockTime::new(GENESIS_INITIAL_BLOCKTIME);
         let gas_limit = Gas::new(std::u64::MAX.into());
         let phase = Phase::System;
@@ -237,7 +274,11 @@ where
                     .get_bonded_validators()
                     .map(|(k, v)| (k, v.value()))
                     .collect();
-                let args = (mint_reference, bonded_validators);
+                let args = (
+                    mint_reference,
+                    bonded_validators,
+                    genesis_config.proof_of_stake_params(),
+                );
                 ArgsParser::parse(&args)
                     .and_then(|args| args.to_bytes())
                     .expect("args should parse")
@@ -252,6 +293,9 @@ where
             // Constructs a partial protocol data with already known urefs to pass the validation
             // step
             let partial_protocol_data = ProtocolData::new(
+                Default::default(),
+                Default::default(),
+                Default::default(),
                 Default::default(),
                 mint_reference,
                 // This is used as unknown key
@@ -279,7 +323,14 @@ where
         };
 
         // Spec #2: Associate given CostTable with given ProtocolVersion.
-        let protocol_data = ProtocolData::new(wasm_costs, mint_reference, proof_of_stake_reference);
+        let protocol_data = ProtocolData::new(
+            wasm_costs,
+            genesis_config.host_function_costs(),
+            genesis_config.min_gas_price(),
+            genesis_config.storage_cost_per_byte(),
+            mint_reference,
+            proof_of_stake_reference,
+        );
 
         self.state
             .put_protocol_data(protocol_version, &protocol_data)
@@ -467,9 +518,30 @@ where
             None => *current_protocol_data.wasm_costs(),
         };
 
-        // 3.1.2.2 persist wasm CostTable
+        // resolve host function costs for new protocol version
+        let new_host_function_costs = match upgrade_config.host_function_costs() {
+            Some(new_host_function_costs) => new_host_function_costs,
+            None => *current_protocol_data.host_function_costs(),
+        };
+
+        // resolve minimum gas price for new protocol version
+        let new_min_gas_price = match upgrade_config.min_gas_price() {
+            Some(new_min_gas_price) => new_min_gas_price,
+            None => current_protocol_data.min_gas_price(),
+        };
+
+        // resolve storage cost per byte for new protocol version
+        let new_storage_cost_per_byte = match upgrade_config.storage_cost_per_byte() {
+            Some(new_storage_cost_per_byte) => new_storage_cost_per_byte,
+            None => current_protocol_data.storage_cost_per_byte(),
+        };
+
+        // 3.1.2.2 persist CostTable
         let new_protocol_data = ProtocolData::new(
             new_wasm_costs,
+            new_host_function_costs,
+            new_min_gas_price,
+            new_storage_cost_per_byte,
             current_protocol_data.mint(),
             current_protocol_data.proof_of_stake(),
         );
@@ -544,7 +616,7 @@ where
                 let state = Rc::clone(&tracking_copy);
                 let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
-                Executor.better_exec(
+                Executor::default().better_exec(
                     upgrade_installer_module,
                     &args,
                     &mut keys,
@@ -565,6 +637,54 @@ where
             }
         }
 
+        // overwrite the PoS parameters if new ones are provided, and store those which a PoS
+        // contract installed before they were introduced is missing
+        let new_proof_of_stake_params = upgrade_config.proof_of_stake_params();
+        if let Some(proof_of_stake_params) = new_proof_of_stake_params {
+            if proof_of_stake_params.validate().is_err() {
+                return Err(Error::InvalidUpgradeConfig);
+            }
+        }
+        let proof_of_stake_key = Key::URef(current_protocol_data.proof_of_stake());
+        let mut proof_of_stake_contract = tracking_copy
+            .borrow_mut()
+            .get_contract(correlation_id, proof_of_stake_key)?;
+        let mut address_generator = {
+            // seeded w/ protocol version like the upgrade installer's deploy hash
+            let bytes = new_protocol_version.value().to_bytes()?;
+            AddressGenerator::new(Blake2bHash::new(&bytes).into(), Phase::System)
+        };
+        let mut added_params = false;
+        for (name, value) in new_proof_of_stake_params
+            .unwrap_or_default()
+            .to_named_values()
+        {
+            match proof_of_stake_contract.named_keys().get(name) {
+                Some(Key::URef(uref)) => {
+                    if new_proof_of_stake_params.is_some() {
+                        tracking_copy.borrow_mut().write(Key::URef(*uref), value)
+                    }
+                }
+                Some(_) => return Err(Error::InvalidUpgradeConfig),
+                None => {
+                    let uref = URef::new(
+                        address_generator.create_address(),
+                        AccessRights::READ_ADD_WRITE,
+                    );
+                    tracking_copy.borrow_mut().write(Key::URef(uref), value);
+                    proof_of_stake_contract
+                        .named_keys_mut()
+                        .insert(String::from(name), Key::URef(uref));
+                    added_params = true;
+                }
+            }
+        }
+        if added_params {
+            tracking_copy
+                .borrow_mut()
+                .write(proof_of_stake_key, Value::Contract(proof_of_stake_contract));
+        }
+
         let effects = tracking_copy.borrow().effect();
 
         // commit
@@ -609,15 +729,130 @@ where
             .into())
     }
 
-    pub fn get_module(
+    /// Runs a query, and returns proofs of the presence or absence of every key read by it.
+    ///
+    /// The proofs are ordered by key, and can be checked against the query's state hash with
+    /// [`engine_storage::trie::merkle_proof::verify_proof`].
+    pub fn run_query_with_proofs(
+        &self,
+        correlation_id: CorrelationId,
+        query_request: QueryRequest,
+    ) -> Result<(QueryResult, Vec<TrieMerkleProof<Key, Value>>), Error> {
+        let state_hash = query_request.state_hash();
+
+        let mut tracking_copy = match self.tracking_copy(state_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok((QueryResult::RootNotFound, Vec::new())),
+        };
+
+        let query_result = tracking_copy
+            .query(correlation_id, query_request.key(), query_request.path())
+            .map_err(|err| Error::ExecError(err.into()))?
+            .into();
+
+        let mut keys: Vec<Key> = tracking_copy.effect().ops.keys().cloned().collect();
+        keys.sort();
+
+        let mut proofs = Vec::with_capacity(keys.len());
+        for key in keys {
+            match self
+                .state
+                .read_with_proof(correlation_id, state_hash, &key)
+                .map_err(|err| Error::ExecError(err.into()))?
+            {
+                Some(proof) => proofs.push(proof),
+                None => return Ok((QueryResult::RootNotFound, Vec::new())),
+            }
+        }
+
+        Ok((query_result, proofs))
+    }
+
+    pub fn run_list_keys(
+        &self,
+        correlation_id: CorrelationId,
+        list_keys_request: ListKeysRequest,
+    ) -> Result<ListKeysResult, Error> {
+        let prefix = list_keys_request.serialized_prefix();
+        let start_after = match list_keys_request.cursor() {
+            Some(cursor) => Some(cursor.to_bytes()?),
+            None => None,
+        };
+
+        let maybe_pairs = self
+            .state
+            .read_with_prefix(
+                correlation_id,
+                list_keys_request.state_hash(),
+                &prefix,
+                start_after.as_ref().map(Vec::as_slice),
+                Some(list_keys_request.limit()),
+            )
+            .map_err(|error| Error::ExecError(error.into()))?;
+
+        match maybe_pairs {
+            Some(pairs) => Ok(ListKeysResult::Success(pairs)),
+            None => Ok(ListKeysResult::RootNotFound),
+        }
+    }
+
+    /// Returns the keys whose values differ between the global state at `pre_state_hash` and at
+    /// `post_state_hash`.
+    pub fn run_diff(
         &self,
-        tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
+        correlation_id: CorrelationId,
+        pre_state_hash: Blake2bHash,
+        post_state_hash: Blake2bHash,
+    ) -> Result<DiffResult<Key, Value>, Error> {
+        self.state
+            .diff(correlation_id, pre_state_hash, post_state_hash)
+            .map_err(|error| Error::ExecError(error.into()))
+    }
+
+    /// Deletes all global state which is not reachable from any of `live_state_hashes`.
+    pub fn run_prune(
+        &self,
+        correlation_id: CorrelationId,
+        live_state_hashes: &[Blake2bHash],
+    ) -> Result<PruneResult, Error> {
+        self.state
+            .prune(correlation_id, live_state_hashes)
+            .map_err(|error| Error::ExecError(error.into()))
+    }
+
+    /// Writes a snapshot of the global state at `state_hash`, and of the protocol data for
+    /// `protocol_version`, to `writer`.
+    pub fn run_export_snapshot(
+        &self,
+        state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        writer: &mut dyn Write,
+    ) -> Result<SnapshotSummary, snapshot::Error> {
+        self.state
+            .export_snapshot(state_hash, protocol_version, writer)
+    }
+
+    /// Reads a snapshot from `reader` into global state.
+    pub fn run_import_snapshot(
+        &self,
+        reader: &mut dyn Read,
+    ) -> Result<SnapshotSummary, snapshot::Error> {
+        self.state.import_snapshot(reader)
+    }
+
+    pub fn get_module<R>(
+        &self,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
         deploy_item: &ExecutableDeployItem,
         account: &Account,
         correlation_id: CorrelationId,
         preprocessor: &Preprocessor,
         protocol_version: &ProtocolVersion,
-    ) -> Result<Module, error::Error> {
+    ) -> Result<Module, error::Error>
+    where
+        R: StateReader<Key, Value>,
+        R::Error: Into<execution::Error>,
+    {
         let stored_contract_key = match deploy_item {
             ExecutableDeployItem::ModuleBytes { module_bytes, .. } => {
                 let module = preprocessor.preprocess(&module_bytes)?;
@@ -704,8 +939,51 @@ where
             return Err(error::Error::ExecError(exec_error));
         }
 
+        // If the contract declares the entry point, the account is checked against its access
+        // policy and the arguments against its parameters.  A contract declaring entry points
+        // can't be invoked through other exports.
+        let entry_point_name = deploy_item.entry_point_name();
+        if let Some(entry_point) = contract.entry_point(entry_point_name) {
+            let known_urefs =
+                execution::extract_access_rights_from_keys(account.named_keys().values().cloned());
+            execution::check_entry_point_access(
+                entry_point_name,
+                entry_point.access(),
+                &[Key::Account(account.pub_key())],
+                |group| match (known_urefs.get(&group.addr()), group.access_rights()) {
+                    (Some(known_rights), Some(rights)) => known_rights
+                        .iter()
+                        .any(|known_right| *known_right & rights == rights),
+                    (Some(_), None) => true,
+                    (None, _) => false,
+                },
+            )?;
+
+            let args_bytes = deploy_item.args();
+            let args: Vec<Vec<u8>> = if args_bytes.is_empty() {
+                Vec::new()
+            } else {
+                bytesrepr::deserialize(args_bytes)?
+            };
+            if let Err(error) = entry_point.check_args(&args) {
+                return Err(error::Error::ExecError(
+                    execution::Error::InvalidArguments {
+                        entry_point: entry_point_name.to_string(),
+                        error,
+                    },
+                ));
+            }
+        } else if entry_point_name != DEFAULT_ENTRY_POINT_NAME
+            && !contract.entry_points().is_empty()
+        {
+            return Err(error::Error::ExecError(execution::Error::FunctionNotFound(
+                entry_point_name.to_string(),
+            )));
+        }
+
         let (ret, _, _) = contract.destructure();
-        let module = engine_wasm_prep::deserialize(&ret)?;
+        let mut module = engine_wasm_prep::deserialize(&ret)?;
+        execution::select_entry_point(&mut module, entry_point_name)?;
         Ok(module)
     }
 
@@ -720,14 +998,6 @@ where
         blocktime: BlockTime,
         deploy_item: DeployItem,
     ) -> Result<ExecutionResult, RootNotFound> {
-        // spec: https://casperlabs.atlassian.net/wiki/spaces/EN/pages/123404576/Payment+code+execution+specification
-
-        let session = deploy_item.session();
-        let payment = deploy_item.payment();
-        let address = Key::Account(deploy_item.address().value());
-        let authorization_keys = deploy_item.authorization_keys();
-        let deploy_hash = deploy_item.deploy_hash();
-
         // Create tracking copy (which functions as a deploy context)
         // validation_spec_2: prestate_hash check
         let tracking_copy = match self.tracking_copy(prestate_hash) {
@@ -736,15 +1006,168 @@ where
             Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
         };
 
+        Ok(self.deploy_with_tracking_copy(
+            correlation_id,
+            executor,
+            preprocessor,
+            protocol_version,
+            blocktime,
+            deploy_item,
+            tracking_copy,
+        ))
+    }
+
+    /// Executes the session code of a deploy against `prestate_hash` with an effectively
+    /// unlimited gas budget, without running payment code or finalizing payment.
+    ///
+    /// If `skip_authorization` is set, the authorization keys of the deploy are not checked
+    /// against the account's associated keys and deployment threshold.  The effects of the
+    /// session code are returned for inspection only and must never be committed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn estimate(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        preprocessor: &Preprocessor,
+        protocol_version: ProtocolVersion,
+        prestate_hash: Blake2bHash,
+        blocktime: BlockTime,
+        deploy_item: DeployItem,
+        skip_authorization: bool,
+    ) -> Result<EstimateResult, RootNotFound> {
+        let tracking_copy = match self.tracking_copy(prestate_hash) {
+            Err(error) => return Ok(EstimateResult::precondition_failure(error)),
+            Ok(None) => return Err(RootNotFound(prestate_hash)),
+            Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
+        };
+
+        let session = deploy_item.session();
+        let address = Key::Account(deploy_item.address().value());
+        let authorization_keys = deploy_item.authorization_keys();
+        let deploy_hash = deploy_item.deploy_hash();
+        let gas_price = deploy_item.gas_price();
+
+        let account: Account = match tracking_copy
+            .borrow_mut()
+            .get_account(correlation_id, deploy_item.address().value())
+        {
+            Ok(account) => account,
+            Err(_) => {
+                return Ok(EstimateResult::precondition_failure(
+                    Error::AuthorizationError,
+                ))
+            }
+        };
+
+        if !skip_authorization {
+            if !account.can_authorize(authorization_keys) {
+                return Ok(EstimateResult::precondition_failure(
+                    Error::AuthorizationError,
+                ));
+            }
+            if !account.can_deploy_with(authorization_keys) {
+                return Ok(EstimateResult::precondition_failure(
+                    execution::Error::DeploymentAuthorizationFailure.into(),
+                ));
+            }
+        }
+
+        let session_module = match self.get_module(
+            Rc::clone(&tracking_copy),
+            &session,
+            &account,
+            correlation_id,
+            preprocessor,
+            &protocol_version,
+        ) {
+            Ok(module) => module,
+            Err(error) => return Ok(EstimateResult::precondition_failure(error)),
+        };
+
+        let protocol_data = match self.state.get_protocol_data(protocol_version) {
+            Ok(Some(protocol_data)) => protocol_data,
+            Ok(None) => {
+                return Ok(EstimateResult::precondition_failure(
+                    Error::InvalidProtocolVersion(protocol_version),
+                ))
+            }
+            Err(error) => {
+                return Ok(EstimateResult::precondition_failure(Error::ExecError(
+                    error.into(),
+                )))
+            }
+        };
+
+        let min_gas_price = protocol_data.min_gas_price();
+        if gas_price < min_gas_price {
+            return Ok(EstimateResult::precondition_failure(
+                Error::GasPriceTooLow {
+                    gas_price,
+                    min_gas_price,
+                },
+            ));
+        }
+
+        let gas_limit = Gas::new(U512::from(std::u64::MAX));
+        let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
+
+        let execution_result = executor.exec(
+            session_module,
+            session.args(),
+            address,
+            &account,
+            authorization_keys.clone(),
+            blocktime,
+            deploy_hash,
+            gas_limit,
+            protocol_version,
+            correlation_id,
+            tracking_copy,
+            Phase::Session,
+            protocol_data,
+            system_contract_cache,
+        );
+
+        let payment = Motes::from_gas(execution_result.cost(), gas_price).expect("motes overflow");
+
+        Ok(EstimateResult {
+            execution_result,
+            payment,
+        })
+    }
+
+    /// Executes a deploy using the given tracking copy as its prestate.
+    ///
+    /// The effects of the deploy are returned as part of the [`ExecutionResult`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn deploy_with_tracking_copy<R>(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        preprocessor: &Preprocessor,
+        protocol_version: ProtocolVersion,
+        blocktime: BlockTime,
+        deploy_item: DeployItem,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+    ) -> ExecutionResult
+    where
+        R: StateReader<Key, Value>,
+        R::Error: Into<execution::Error>,
+    {
+        // spec: https://casperlabs.atlassian.net/wiki/spaces/EN/pages/123404576/Payment+code+execution+specification
+
+        let session = deploy_item.session();
+        let payment = deploy_item.payment();
+        let address = Key::Account(deploy_item.address().value());
+        let authorization_keys = deploy_item.authorization_keys();
+        let deploy_hash = deploy_item.deploy_hash();
+        let gas_price = deploy_item.gas_price();
+
         // Get addr bytes from `address` (which is actually a Key)
         // validation_spec_3: account validity
         let account_addr = match address.as_account() {
             Some(account_addr) => account_addr,
-            None => {
-                return Ok(ExecutionResult::precondition_failure(
-                    error::Error::AuthorizationError,
-                ))
-            }
+            None => return ExecutionResult::precondition_failure(error::Error::AuthorizationError),
         };
 
         // Get account from tracking copy
@@ -755,27 +1178,25 @@ where
         {
             Ok(account) => account,
             Err(_) => {
-                return Ok(ExecutionResult::precondition_failure(
-                    error::Error::AuthorizationError,
-                ));
+                return ExecutionResult::precondition_failure(error::Error::AuthorizationError);
             }
         };
 
         // Authorize using provided authorization keys
         // validation_spec_3: account validity
         if !account.can_authorize(authorization_keys) {
-            return Ok(ExecutionResult::precondition_failure(
+            return ExecutionResult::precondition_failure(
                 crate::engine_state::error::Error::AuthorizationError,
-            ));
+            );
         }
 
         // Check total key weight against deploy threshold
         // validation_spec_4: deploy validity
         if !account.can_deploy_with(authorization_keys) {
-            return Ok(ExecutionResult::precondition_failure(
+            return ExecutionResult::precondition_failure(
                 // TODO?:this doesn't happen in execution any longer, should error variant be moved
                 execution::Error::DeploymentAuthorizationFailure.into(),
-            ));
+            );
         }
 
         // Create session code `A` from provided session bytes
@@ -790,7 +1211,7 @@ where
         ) {
             Ok(module) => module,
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(error));
+                return ExecutionResult::precondition_failure(error);
             }
         };
 
@@ -799,15 +1220,23 @@ where
             Ok(Some(protocol_data)) => protocol_data,
             Ok(None) => {
                 let error = Error::InvalidProtocolVersion(protocol_version);
-                return Ok(ExecutionResult::precondition_failure(error));
+                return ExecutionResult::precondition_failure(error);
             }
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(Error::ExecError(
-                    error.into(),
-                )));
+                return ExecutionResult::precondition_failure(Error::ExecError(error.into()));
             }
         };
 
+        // Enforce the chain's minimum gas price
+        // validation_spec_6: gas price
+        let min_gas_price = protocol_data.min_gas_price();
+        if gas_price < min_gas_price {
+            return ExecutionResult::precondition_failure(Error::GasPriceTooLow {
+                gas_price,
+                min_gas_price,
+            });
+        }
+
         let max_payment_cost: Motes = Motes::new(U512::from(MAX_PAYMENT));
 
         // Get mint system contract details
@@ -823,13 +1252,13 @@ where
                 .get_contract(correlation_id, Key::URef(mint_reference))
             {
                 Ok(contract) => contract,
-                Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+                Err(error) => return ExecutionResult::precondition_failure(error.into()),
             };
 
             if !self.system_contract_cache.has(&mint_reference) {
                 let module = match engine_wasm_prep::deserialize(mint_contract.bytes()) {
                     Ok(module) => module,
-                    Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+                    Err(error) => return ExecutionResult::precondition_failure(error.into()),
                 };
                 self.system_contract_cache.insert(mint_reference, module);
             }
@@ -850,7 +1279,7 @@ where
         {
             Ok(contract) => contract,
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(error.into()));
+                return ExecutionResult::precondition_failure(error.into());
             }
         };
 
@@ -863,7 +1292,7 @@ where
                 match proof_of_stake_contract.named_keys().get(POS_REWARDS_PURSE) {
                     Some(key) => *key,
                     None => {
-                        return Ok(ExecutionResult::precondition_failure(Error::DeployError));
+                        return ExecutionResult::precondition_failure(Error::DeployError);
                     }
                 };
 
@@ -874,7 +1303,7 @@ where
             ) {
                 Ok(key) => key,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return ExecutionResult::precondition_failure(error.into());
                 }
             }
         };
@@ -890,7 +1319,7 @@ where
             ) {
                 Ok(key) => key,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return ExecutionResult::precondition_failure(error.into());
                 }
             }
         };
@@ -902,15 +1331,13 @@ where
             .get_purse_balance(correlation_id, account_main_purse_balance_key)
         {
             Ok(balance) => balance,
-            Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+            Err(error) => return ExecutionResult::precondition_failure(error.into()),
         };
 
         // Enforce minimum main purse balance validation
         // validation_spec_5: account main purse minimum balance
         if account_main_purse_balance < max_payment_cost {
-            return Ok(ExecutionResult::precondition_failure(
-                Error::InsufficientPaymentError,
-            ));
+            return ExecutionResult::precondition_failure(Error::InsufficientPaymentError);
         }
 
         // Finalization is executed by system account (currently genesis account)
@@ -929,8 +1356,8 @@ where
         // Execute provided payment code
         let payment_result = {
             // payment_code_spec_1: init pay environment w/ gas limit == (max_payment_cost /
-            // conv_rate)
-            let pay_gas_limit = Gas::from_motes(max_payment_cost, CONV_RATE).unwrap_or_default();
+            // gas_price)
+            let pay_gas_limit = gas_limit(max_payment_cost, gas_price);
 
             // Create payment code module from bytes
             // validation_spec_1: valid wasm bytes
@@ -944,7 +1371,7 @@ where
             ) {
                 Ok(module) => module,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error));
+                    return ExecutionResult::precondition_failure(error);
                 }
             };
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
@@ -978,7 +1405,7 @@ where
             let payment_purse: Key =
                 match proof_of_stake_contract.named_keys().get(POS_PAYMENT_PURSE) {
                     Some(key) => *key,
-                    None => return Ok(ExecutionResult::precondition_failure(Error::DeployError)),
+                    None => return ExecutionResult::precondition_failure(Error::DeployError),
                 };
 
             let purse_balance_key = match tracking_copy.borrow_mut().get_purse_balance_key(
@@ -988,7 +1415,7 @@ where
             ) {
                 Ok(key) => key,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return ExecutionResult::precondition_failure(error.into());
                 }
             };
 
@@ -998,7 +1425,7 @@ where
             {
                 Ok(balance) => balance,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return ExecutionResult::precondition_failure(error.into());
                 }
             }
         };
@@ -1007,13 +1434,14 @@ where
             .set_payment_execution_result(payment_result)
             .check_forced_transfer(
                 max_payment_cost,
+                gas_price,
                 account_main_purse_balance,
                 payment_purse_balance,
                 account_main_purse_balance_key,
                 rewards_purse_balance_key,
             )
         {
-            return Ok(failure);
+            return failure;
         }
 
         let post_payment_tc = tracking_copy.borrow();
@@ -1022,12 +1450,11 @@ where
         // session_code_spec_2: execute session code
         let session_result = {
             // payment_code_spec_3_b_i: if (balance of PoS pay purse) >= (gas spent during
-            // payment code execution) * conv_rate, yes session
-            // session_code_spec_1: gas limit = ((balance of PoS payment purse) / conv_rate)
+            // payment code execution) * gas_price, yes session
+            // session_code_spec_1: gas limit = ((balance of PoS payment purse) / gas_price)
             // - (gas spent during payment execution)
-            let session_gas_limit: Gas = Gas::from_motes(payment_purse_balance, CONV_RATE)
-                .unwrap_or_default()
-                - payment_result_cost;
+            let session_gas_limit: Gas =
+                gas_limit(payment_purse_balance, gas_price) - payment_result_cost;
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
             executor.exec(
@@ -1074,7 +1501,7 @@ where
                             match engine_wasm_prep::deserialize(&proof_of_stake_contract.bytes()) {
                                 Ok(module) => module,
                                 Err(error) => {
-                                    return Ok(ExecutionResult::precondition_failure(error.into()))
+                                    return ExecutionResult::precondition_failure(error.into())
                                 }
                             };
                         self.system_contract_cache
@@ -1084,9 +1511,15 @@ where
                 };
 
             let proof_of_stake_args = {
-                //((gas spent during payment code execution) + (gas spent during session code execution)) * conv_rate
-                let finalize_cost_motes: Motes = Motes::from_gas(execution_result_builder.total_cost(), CONV_RATE).expect("motes overflow");
-                let args = ("finalize_payment", finalize_cost_motes.value(), account_addr);
+                //((gas spent during payment code execution) + (gas spent during session code execution)) * gas_price
+                let finalize_cost_motes: Motes =
+                    Motes::from_gas(execution_result_builder.total_cost(), gas_price)
+                        .expect("motes overflow");
+                let args = (
+                    "finalize_payment",
+                    finalize_cost_motes.value(),
+                    account_addr,
+                );
                 ArgsParser::parse(&args)
                     .and_then(|args| args.to_bytes())
                     .expect("args should parse")
@@ -1099,7 +1532,7 @@ where
                 .get_contract(correlation_id, Key::URef(proof_of_stake_reference))
             {
                 Ok(info) => info,
-                Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+                Err(error) => return ExecutionResult::precondition_failure(error.into()),
             };
 
             let mut proof_of_stake_keys = proof_of_stake_contract.named_keys().to_owned();
@@ -1137,7 +1570,610 @@ where
         // NOTE: payment_code_spec_5_a is enforced in execution_result_builder.build()
         // payment_code_spec_6: return properly combined set of transforms and
         // appropriate error
-        Ok(ret)
+        ret
+    }
+
+    /// Executes deploys one after another, each one against the state produced by the deploys
+    /// preceding it.
+    ///
+    /// Returns the result of each deploy along with the combined effect of all of them, which
+    /// can be committed on top of `prestate_hash` in one go, or `None` if `prestate_hash` is not
+    /// found.
+    #[allow(clippy::too_many_arguments)]
+    pub fn deploy_sequentially(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        preprocessor: &Preprocessor,
+        protocol_version: ProtocolVersion,
+        prestate_hash: Blake2bHash,
+        blocktime: BlockTime,
+        deploy_items: Vec<DeployItem>,
+    ) -> Result<Option<(Vec<ExecutionResult>, ExecutionEffect)>, Error> {
+        let mut tracking_copy = match self.tracking_copy(prestate_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(None),
+        };
+
+        let mut execution_results = Vec::with_capacity(deploy_items.len());
+
+        for deploy_item in deploy_items {
+            let execution_result = self.deploy_with_tracking_copy(
+                correlation_id,
+                executor,
+                preprocessor,
+                protocol_version,
+                blocktime,
+                deploy_item,
+                Rc::new(RefCell::new(tracking_copy.fork())),
+            );
+
+            let execution_result =
+                Self::apply_execution_result(correlation_id, &mut tracking_copy, execution_result);
+
+            execution_results.push(execution_result);
+        }
+
+        Ok(Some((execution_results, tracking_copy.effect())))
+    }
+
+    /// Runs the PoS `step` method in the system phase, which is meant to be done once per block,
+    /// before its deploys are executed.
+    ///
+    /// This makes the bonding and unbonding requests which are due at `blocktime` effective, and
+    /// pays out the matured unbonds to the main purses of their accounts.
+    pub fn step(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        protocol_version: ProtocolVersion,
+        prestate_hash: Blake2bHash,
+        blocktime: BlockTime,
+    ) -> Result<ExecutionResult, RootNotFound> {
+        let tracking_copy = match self.tracking_copy(prestate_hash) {
+            Err(error) => return Ok(ExecutionResult::precondition_failure(error)),
+            Ok(None) => return Err(RootNotFound(prestate_hash)),
+            Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
+        };
+
+        Ok(self.exec_proof_of_stake_system_call(
+            correlation_id,
+            executor,
+            protocol_version,
+            blocktime,
+            ("step",),
+            tracking_copy,
+        ))
+    }
+
+    /// Distributes the rewards accumulated in the PoS rewards purse among the bonded validators,
+    /// by calling the PoS `distribute_rewards` method in the system phase.
+    ///
+    /// The block `proposer` gets a share of the rewards up front; the rest is split pro rata to
+    /// the stakes of the `finality_signers`, or of all bonded validators if none are given.
+    #[allow(clippy::too_many_arguments)]
+    pub fn distribute_rewards(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        protocol_version: ProtocolVersion,
+        prestate_hash: Blake2bHash,
+        blocktime: BlockTime,
+        proposer: PublicKey,
+        finality_signers: Vec<PublicKey>,
+    ) -> Result<ExecutionResult, RootNotFound> {
+        let tracking_copy = match self.tracking_copy(prestate_hash) {
+            Err(error) => return Ok(ExecutionResult::precondition_failure(error)),
+            Ok(None) => return Err(RootNotFound(prestate_hash)),
+            Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
+        };
+
+        Ok(self.exec_proof_of_stake_system_call(
+            correlation_id,
+            executor,
+            protocol_version,
+            blocktime,
+            ("distribute_rewards", proposer, finality_signers),
+            tracking_copy,
+        ))
+    }
+
+    /// Slashes the given validators, e.g. for equivocating in the block being executed, by
+    /// calling the PoS `slash` method in the system phase.
+    ///
+    /// Their stakes and pending unbonding requests are confiscated and moved to the PoS rewards
+    /// purse.
+    pub fn slash(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        protocol_version: ProtocolVersion,
+        prestate_hash: Blake2bHash,
+        blocktime: BlockTime,
+        validators: Vec<PublicKey>,
+    ) -> Result<ExecutionResult, RootNotFound> {
+        let tracking_copy = match self.tracking_copy(prestate_hash) {
+            Err(error) => return Ok(ExecutionResult::precondition_failure(error)),
+            Ok(None) => return Err(RootNotFound(prestate_hash)),
+            Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
+        };
+
+        Ok(self.exec_proof_of_stake_system_call(
+            correlation_id,
+            executor,
+            protocol_version,
+            blocktime,
+            ("slash", validators),
+            tracking_copy,
+        ))
+    }
+
+    /// Calls the PoS contract with `args` on behalf of the system account in the system phase,
+    /// against `tracking_copy`.
+    fn exec_proof_of_stake_system_call<R, A>(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        protocol_version: ProtocolVersion,
+        blocktime: BlockTime,
+        args: A,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+    ) -> ExecutionResult
+    where
+        R: StateReader<Key, Value>,
+        R::Error: Into<execution::Error>,
+        A: ArgsParser,
+    {
+        let protocol_data = match self.get_protocol_data(protocol_version) {
+            Ok(Some(protocol_data)) => protocol_data,
+            Ok(None) => {
+                let error = Error::InvalidProtocolVersion(protocol_version);
+                return ExecutionResult::precondition_failure(error);
+            }
+            Err(error) => return ExecutionResult::precondition_failure(error),
+        };
+
+        let proof_of_stake_reference = protocol_data.proof_of_stake();
+        let proof_of_stake_contract = match tracking_copy
+            .borrow_mut()
+            .get_contract(correlation_id, Key::URef(proof_of_stake_reference))
+        {
+            Ok(contract) => contract,
+            Err(error) => return ExecutionResult::precondition_failure(error.into()),
+        };
+
+        let proof_of_stake_module = match self.system_contract_cache.get(&proof_of_stake_reference)
+        {
+            Some(module) => module,
+            None => {
+                let module = match engine_wasm_prep::deserialize(&proof_of_stake_contract.bytes()) {
+                    Ok(module) => module,
+                    Err(error) => return ExecutionResult::precondition_failure(error.into()),
+                };
+                self.system_contract_cache
+                    .insert(proof_of_stake_reference, module.clone());
+                module
+            }
+        };
+
+        let args_bytes = match ArgsParser::parse(&args).and_then(|args| args.to_bytes()) {
+            Ok(args_bytes) => args_bytes,
+            Err(error) => return ExecutionResult::precondition_failure(error.into()),
+        };
+
+        // There is no deploy, so the hash seeding the addresses of new URefs is derived from the
+        // call and the block it is made in
+        let deploy_hash: [u8; 32] = {
+            let mut bytes = args_bytes.clone();
+            bytes.extend_from_slice(&Into::<u64>::into(blocktime).to_le_bytes());
+            Blake2bHash::new(&bytes).into()
+        };
+
+        let system_account = Account::new(
+            SYSTEM_ACCOUNT_ADDR,
+            Default::default(),
+            PurseId::new(URef::new(Default::default(), AccessRights::READ_ADD_WRITE)),
+            Default::default(),
+            Default::default(),
+        );
+        let mut proof_of_stake_keys = proof_of_stake_contract.named_keys().to_owned();
+        let base_key = Key::from(proof_of_stake_reference);
+        let gas_limit = Gas::new(U512::from(std::u64::MAX));
+        let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
+
+        executor.exec_direct(
+            proof_of_stake_module,
+            &args_bytes,
+            &mut proof_of_stake_keys,
+            base_key,
+            &system_account,
+            iter::once(PublicKey::new(SYSTEM_ACCOUNT_ADDR)).collect(),
+            blocktime,
+            deploy_hash,
+            gas_limit,
+            protocol_version,
+            correlation_id,
+            tracking_copy,
+            Phase::System,
+            protocol_data,
+            system_contract_cache,
+        )
+    }
+
+    /// Runs the calls queued via `schedule_call` which are due at `blocktime`, in the order they
+    /// became due, each one against the state produced by the calls preceding it.
+    ///
+    /// Due calls are removed from the queue whether or not they succeed.  Each call is executed in
+    /// the system phase on behalf of the account which scheduled it, with as much gas as its gas
+    /// purse can buy at the minimum gas price, and the gas spent is paid from that purse to the
+    /// PoS rewards purse.  Returns the result of each call along with the combined effect of all
+    /// of them, or `None` if `prestate_hash` is not found.
+    pub fn run_scheduled_calls(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        preprocessor: &Preprocessor,
+        protocol_version: ProtocolVersion,
+        prestate_hash: Blake2bHash,
+        blocktime: BlockTime,
+    ) -> Result<Option<(Vec<ExecutionResult>, ExecutionEffect)>, Error> {
+        let protocol_data = match self.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => protocol_data,
+            None => return Err(Error::InvalidProtocolVersion(protocol_version)),
+        };
+
+        let mut tracking_copy = match self.tracking_copy(prestate_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(None),
+        };
+
+        let (due_calls, pending_calls): (Vec<ScheduledCall>, Vec<ScheduledCall>) = tracking_copy
+            .get_scheduled_calls(correlation_id)?
+            .into_iter()
+            .partition(|scheduled_call| scheduled_call.blocktime() <= blocktime);
+        if due_calls.is_empty() {
+            return Ok(Some((Vec::new(), tracking_copy.effect())));
+        }
+        tracking_copy.put_scheduled_calls(pending_calls)?;
+
+        let mut execution_results = Vec::with_capacity(due_calls.len());
+
+        for (index, scheduled_call) in due_calls.into_iter().enumerate() {
+            let execution_result = self
+                .run_scheduled_call(
+                    correlation_id,
+                    executor,
+                    preprocessor,
+                    protocol_version,
+                    protocol_data,
+                    blocktime,
+                    &scheduled_call,
+                    index,
+                    &tracking_copy,
+                )
+                .unwrap_or_else(ExecutionResult::precondition_failure);
+
+            let execution_result =
+                Self::apply_execution_result(correlation_id, &mut tracking_copy, execution_result);
+
+            execution_results.push(execution_result);
+        }
+
+        Ok(Some((execution_results, tracking_copy.effect())))
+    }
+
+    /// Executes a single due `scheduled_call` against `tracking_copy` and charges its gas purse.
+    ///
+    /// Returns an error if the call can't be executed at all, in which case nothing is charged.
+    #[allow(clippy::too_many_arguments)]
+    fn run_scheduled_call(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        preprocessor: &Preprocessor,
+        protocol_version: ProtocolVersion,
+        protocol_data: ProtocolData,
+        blocktime: BlockTime,
+        scheduled_call: &ScheduledCall,
+        index: usize,
+        tracking_copy: &TrackingCopy<S::Reader>,
+    ) -> Result<ExecutionResult, Error> {
+        let call_tracking_copy = Rc::new(RefCell::new(tracking_copy.fork()));
+
+        let account_addr = scheduled_call.account().value();
+        let account = call_tracking_copy
+            .borrow_mut()
+            .get_account(correlation_id, account_addr)?;
+
+        let entry_point_name = scheduled_call.entry_point().to_string();
+        let args = scheduled_call.args().to_vec();
+        let deploy_item = match scheduled_call.contract() {
+            Key::Hash(hash) => ExecutableDeployItem::StoredContractByHash {
+                hash: hash.to_vec(),
+                entry_point_name,
+                args,
+            },
+            Key::URef(uref) => ExecutableDeployItem::StoredContractByURef {
+                uref: uref.addr().to_vec(),
+                entry_point_name,
+                args,
+            },
+            other => {
+                return Err(Error::ExecError(execution::Error::TypeMismatch(
+                    engine_shared::transform::TypeMismatch::new(
+                        "Key::Hash or Key::URef".to_string(),
+                        other.type_string(),
+                    ),
+                )))
+            }
+        };
+
+        let module = self.get_module(
+            Rc::clone(&call_tracking_copy),
+            &deploy_item,
+            &account,
+            correlation_id,
+            preprocessor,
+            &protocol_version,
+        )?;
+
+        let mint_reference = protocol_data.mint();
+
+        let gas_purse_balance_key = call_tracking_copy.borrow_mut().get_purse_balance_key(
+            correlation_id,
+            mint_reference,
+            Key::URef(scheduled_call.gas_purse().value()),
+        )?;
+
+        let rewards_purse_balance_key = {
+            let proof_of_stake_contract = call_tracking_copy
+                .borrow_mut()
+                .get_contract(correlation_id, Key::from(protocol_data.proof_of_stake()))?;
+            let rewards_purse_key =
+                match proof_of_stake_contract.named_keys().get(POS_REWARDS_PURSE) {
+                    Some(key) => *key,
+                    None => return Err(Error::DeployError),
+                };
+            call_tracking_copy.borrow_mut().get_purse_balance_key(
+                correlation_id,
+                mint_reference,
+                rewards_purse_key,
+            )?
+        };
+
+        let gas_price = protocol_data.min_gas_price();
+        let gas_limit = {
+            let gas_purse_balance = call_tracking_copy
+                .borrow_mut()
+                .get_purse_balance(correlation_id, gas_purse_balance_key)?;
+            gas_limit(gas_purse_balance, gas_price)
+        };
+
+        let deploy_hash: [u8; 32] = {
+            let mut bytes = scheduled_call.to_bytes()?;
+            bytes.append(&mut (index as u64).to_bytes()?);
+            Blake2bHash::new(&bytes).into()
+        };
+
+        let execution_result = executor.exec(
+            module,
+            deploy_item.args(),
+            Key::Account(account_addr),
+            &account,
+            iter::once(scheduled_call.account()).collect(),
+            blocktime,
+            deploy_hash,
+            gas_limit,
+            protocol_version,
+            correlation_id,
+            Rc::clone(&call_tracking_copy),
+            Phase::System,
+            protocol_data,
+            SystemContractCache::clone(&self.system_contract_cache),
+        );
+
+        // The effect of a failed call is discarded, but its gas is paid for all the same.
+        let mut post_call_tracking_copy = if execution_result.is_success() {
+            call_tracking_copy.replace(tracking_copy.fork())
+        } else {
+            tracking_copy.fork()
+        };
+
+        let gas_purse_balance =
+            post_call_tracking_copy.get_purse_balance(correlation_id, gas_purse_balance_key)?;
+        let cost = match Motes::from_gas(execution_result.cost(), gas_price) {
+            Some(cost) => cmp::min(cost, gas_purse_balance),
+            None => gas_purse_balance,
+        };
+        post_call_tracking_copy.write(
+            gas_purse_balance_key,
+            Value::UInt512((gas_purse_balance - cost).value()),
+        );
+        match post_call_tracking_copy
+            .add(
+                correlation_id,
+                rewards_purse_balance_key,
+                Value::UInt512(cost.value()),
+            )
+            .map_err(|error| Error::ExecError(error.into()))?
+        {
+            AddResult::Success => {}
+            AddResult::KeyNotFound(key) => {
+                return Err(Error::ExecError(execution::Error::KeyNotFound(key)))
+            }
+            AddResult::TypeMismatch(type_mismatch) => {
+                return Err(Error::ExecError(execution::Error::TypeMismatch(
+                    type_mismatch,
+                )))
+            }
+        }
+
+        Ok(execution_result.with_effect(post_call_tracking_copy.effect()))
+    }
+
+    /// Executes deploys in parallel, producing the same results as
+    /// [`EngineState::deploy_sequentially`].
+    ///
+    /// All the deploys are first executed against `prestate_hash` on the thread pool. Their
+    /// results are then checked in order, and a deploy whose ops conflict with those of the
+    /// deploys preceding it is executed again against the state produced by those deploys.
+    /// Returns `None` if `prestate_hash` is not found.
+    #[allow(clippy::too_many_arguments)]
+    pub fn deploy_in_parallel(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        preprocessor: &Preprocessor,
+        protocol_version: ProtocolVersion,
+        prestate_hash: Blake2bHash,
+        blocktime: BlockTime,
+        deploy_items: Vec<DeployItem>,
+    ) -> Result<Option<ParallelExecutionResult>, Error>
+    where
+        S: Sync,
+    {
+        let mut tracking_copy = match self.tracking_copy(prestate_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(None),
+        };
+
+        let parallel_results: Vec<Result<ExecutionResult, RootNotFound>> = deploy_items
+            .par_iter()
+            .map(|deploy_item| {
+                self.deploy(
+                    correlation_id,
+                    executor,
+                    preprocessor,
+                    protocol_version,
+                    prestate_hash,
+                    blocktime,
+                    deploy_item.clone(),
+                )
+            })
+            .collect();
+
+        let mut execution_results = Vec::with_capacity(deploy_items.len());
+        let mut commutes = Vec::with_capacity(deploy_items.len());
+        let mut preceding_ops = AdditiveMap::new();
+
+        // Every deploy pays into the PoS payment purse, which `finalize_payment` empties again
+        // before unsetting the refund purse of the PoS contract, so every deploy reads and writes
+        // the balance of the payment purse and the PoS contract.  As long as the preceding deploys
+        // left these as they were in the prestate, that doesn't make a deploy conflict with them.
+        let payment_keys = self.get_payment_keys(correlation_id, protocol_version, &tracking_copy);
+
+        for (deploy_item, parallel_result) in deploy_items.into_iter().zip(parallel_results) {
+            let parallel_result = match parallel_result {
+                Ok(execution_result) => execution_result,
+                Err(_) => return Ok(None),
+            };
+
+            let unchanged_keys: Vec<Key> = payment_keys
+                .iter()
+                .cloned()
+                .filter(|key| Self::is_unchanged(correlation_id, &mut tracking_copy, key))
+                .collect();
+
+            let execution_result =
+                if parallel::commutes(&parallel_result, &preceding_ops, &unchanged_keys) {
+                    commutes.push(true);
+                    parallel_result
+                } else {
+                    commutes.push(false);
+                    self.deploy_with_tracking_copy(
+                        correlation_id,
+                        executor,
+                        preprocessor,
+                        protocol_version,
+                        blocktime,
+                        deploy_item,
+                        Rc::new(RefCell::new(tracking_copy.fork())),
+                    )
+                };
+
+            let execution_result =
+                Self::apply_execution_result(correlation_id, &mut tracking_copy, execution_result);
+
+            for (key, op) in execution_result.effect().ops.iter() {
+                preceding_ops.insert_add(*key, *op);
+            }
+
+            execution_results.push(execution_result);
+        }
+
+        Ok(Some(ParallelExecutionResult {
+            execution_results,
+            effect: tracking_copy.effect(),
+            commutes,
+        }))
+    }
+
+    /// Returns the keys of the PoS contract and of the balance of its payment purse, which the
+    /// payment of every deploy modifies, or none if they can't be found.
+    fn get_payment_keys(
+        &self,
+        correlation_id: CorrelationId,
+        protocol_version: ProtocolVersion,
+        tracking_copy: &TrackingCopy<S::Reader>,
+    ) -> Vec<Key> {
+        let protocol_data = match self.state.get_protocol_data(protocol_version) {
+            Ok(Some(protocol_data)) => protocol_data,
+            _ => return Vec::new(),
+        };
+        let proof_of_stake_key = Key::URef(protocol_data.proof_of_stake());
+        // Looked up on a fork, so that the lookup isn't part of the effect of the block.
+        let mut tracking_copy = tracking_copy.fork();
+        let payment_purse_balance_key = tracking_copy
+            .get_contract(correlation_id, proof_of_stake_key)
+            .ok()
+            .and_then(|contract| contract.named_keys().get(POS_PAYMENT_PURSE).cloned())
+            .and_then(|payment_purse| {
+                tracking_copy
+                    .get_purse_balance_key(correlation_id, protocol_data.mint(), payment_purse)
+                    .ok()
+            });
+        match payment_purse_balance_key {
+            Some(balance_key) => vec![proof_of_stake_key.normalize(), balance_key.normalize()],
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the value under `key` in `tracking_copy` is the same as in the state it
+    /// reads against.  Returns `false` if either can't be read.
+    fn is_unchanged(
+        correlation_id: CorrelationId,
+        tracking_copy: &mut TrackingCopy<S::Reader>,
+        key: &Key,
+    ) -> bool {
+        let prestate_value = match tracking_copy.reader().read(correlation_id, key) {
+            Ok(value) => value,
+            Err(_) => return false,
+        };
+        match tracking_copy.get(correlation_id, key) {
+            Ok(value) => value == prestate_value,
+            Err(_) => false,
+        }
+    }
+
+    /// Applies the effect of `execution_result` to `tracking_copy`, so that deploys executed
+    /// against it afterwards observe the effect.
+    fn apply_execution_result(
+        correlation_id: CorrelationId,
+        tracking_copy: &mut TrackingCopy<S::Reader>,
+        execution_result: ExecutionResult,
+    ) -> ExecutionResult {
+        // The effect was produced against the current state, so it should always apply. If it
+        // does not, the deploy is treated as failing its preconditions and its effect is dropped.
+        match tracking_copy.apply_effect(correlation_id, execution_result.effect()) {
+            Ok(AddResult::Success) => execution_result,
+            Ok(AddResult::KeyNotFound(key)) => ExecutionResult::precondition_failure(
+                Error::ExecError(execution::Error::KeyNotFound(key)),
+            ),
+            Ok(AddResult::TypeMismatch(type_mismatch)) => ExecutionResult::precondition_failure(
+                Error::ExecError(execution::Error::TypeMismatch(type_mismatch)),
+            ),
+            Err(error) => ExecutionResult::precondition_failure(Error::ExecError(error.into())),
+        }
     }
 
     pub fn apply_effect(
@@ -1150,6 +2186,7 @@ where
     where
         Error: From<S::Error>,
     {
+        let unbond_payouts = self.get_unbond_payouts(protocol_version, &effects)?;
         match self.state.commit(correlation_id, pre_state_hash, effects)? {
             CommitResult::Success { state_root, .. } => {
                 let bonded_validators =
@@ -1157,12 +2194,31 @@ where
                 Ok(CommitResult::Success {
                     state_root,
                     bonded_validators,
+                    unbond_payouts,
                 })
             }
             commit_result => Ok(commit_result),
         }
     }
 
+    /// Returns the payouts of matured unbonding requests which the PoS `step` made in `effects`.
+    fn get_unbond_payouts(
+        &self,
+        protocol_version: ProtocolVersion,
+        effects: &AdditiveMap<Key, Transform>,
+    ) -> Result<Vec<UnbondPayout>, Error>
+    where
+        Error: From<S::Error>,
+    {
+        match self.state.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => Ok(utils::pos_unbond_payouts(
+                protocol_data.proof_of_stake(),
+                effects,
+            )),
+            None => Err(Error::InvalidProtocolVersion(protocol_version)),
+        }
+    }
+
     /// Calculates bonded validators at `root_hash` state.
     ///
     /// Should only be called with a valid root hash after a successful call to
@@ -12,6 +12,19 @@ pub enum Op {
     NoOp,
 }
 
+impl Op {
+    /// Returns `true` if performing `self` and `other` on the same key gives the same outcome
+    /// regardless of the order in which they are performed.
+    pub fn commutes_with(self, other: Op) -> bool {
+        match (self, other) {
+            (_, Op::NoOp) | (Op::NoOp, _) => true,
+            (Op::Read, Op::Read) => true,
+            (Op::Add, Op::Add) => true,
+            _ => false,
+        }
+    }
+}
+
 impl Add for Op {
     type Output = Op;
 
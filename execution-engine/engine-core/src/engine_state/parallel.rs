@@ -0,0 +1,138 @@
+use contract_ffi::key::Key;
+use engine_shared::additive_map::AdditiveMap;
+
+use super::{execution_effect::ExecutionEffect, execution_result::ExecutionResult, op::Op};
+
+/// The outcome of executing a batch of deploys in parallel.
+#[derive(Debug)]
+pub struct ParallelExecutionResult {
+    /// The result of each deploy, identical to that of executing the deploys sequentially.
+    pub execution_results: Vec<ExecutionResult>,
+    /// The combined effect of all the deploys.
+    pub effect: ExecutionEffect,
+    /// For each deploy, whether it commutes with all the deploys preceding it.  Deploys which do
+    /// not commute had to be re-executed against the state produced by the preceding deploys.
+    pub commutes: Vec<bool>,
+}
+
+/// Returns `true` if `execution_result`, produced by executing a deploy against the prestate,
+/// is also what executing the deploy after deploys with the combined `preceding_ops` produces.
+///
+/// Conflicting ops on any of `unchanged_keys` are disregarded: the preceding deploys left the
+/// values under these keys as they found them in the prestate, so the deploy observes the same
+/// values whichever way it is executed.
+pub fn commutes(
+    execution_result: &ExecutionResult,
+    preceding_ops: &AdditiveMap<Key, Op>,
+    unchanged_keys: &[Key],
+) -> bool {
+    if execution_result.is_failure() {
+        // The effect of a failed deploy doesn't include everything the deploy read, so the
+        // failure is only known to be unaffected if the preceding deploys didn't modify anything.
+        return preceding_ops.iter().all(|(key, preceding_op)| {
+            unchanged_keys.contains(key) || preceding_op.commutes_with(Op::Read)
+        });
+    }
+
+    execution_result
+        .effect()
+        .ops
+        .iter()
+        .filter(|(key, _)| !unchanged_keys.contains(key))
+        .all(|(key, op)| match preceding_ops.get(key) {
+            Some(preceding_op) => op.commutes_with(*preceding_op),
+            None => true,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use contract_ffi::key::Key;
+    use engine_shared::{additive_map::AdditiveMap, gas::Gas, transform::Transform};
+
+    use super::commutes;
+    use crate::engine_state::{
+        error::Error, execution_effect::ExecutionEffect, execution_result::ExecutionResult, op::Op,
+    };
+
+    const KEY_1: Key = Key::Hash([1u8; 32]);
+    const KEY_2: Key = Key::Hash([2u8; 32]);
+
+    fn ops(entries: &[(Key, Op)]) -> AdditiveMap<Key, Op> {
+        let mut ops = AdditiveMap::new();
+        for (key, op) in entries {
+            ops.insert_add(*key, *op);
+        }
+        ops
+    }
+
+    fn success(entries: &[(Key, Op)]) -> ExecutionResult {
+        let mut transforms = AdditiveMap::new();
+        for (key, _) in entries {
+            transforms.insert(*key, Transform::Identity);
+        }
+        ExecutionResult::Success {
+            effect: ExecutionEffect::new(ops(entries), transforms),
+            events: Vec::new(),
+            cost: Gas::default(),
+            trace: None,
+        }
+    }
+
+    #[test]
+    fn should_commute_with_disjoint_ops() {
+        let result = success(&[(KEY_1, Op::Write)]);
+        assert!(commutes(&result, &ops(&[(KEY_2, Op::Write)]), &[]));
+        assert!(commutes(&result, &AdditiveMap::new(), &[]));
+    }
+
+    #[test]
+    fn should_commute_with_reads_of_read_keys() {
+        let result = success(&[(KEY_1, Op::Read)]);
+        assert!(commutes(&result, &ops(&[(KEY_1, Op::Read)]), &[]));
+    }
+
+    #[test]
+    fn should_commute_with_adds_to_added_keys() {
+        let result = success(&[(KEY_1, Op::Add)]);
+        assert!(commutes(&result, &ops(&[(KEY_1, Op::Add)]), &[]));
+    }
+
+    #[test]
+    fn should_not_commute_with_conflicting_ops() {
+        let conflicting = [
+            (Op::Read, Op::Write),
+            (Op::Read, Op::Add),
+            (Op::Write, Op::Read),
+            (Op::Write, Op::Write),
+            (Op::Write, Op::Add),
+            (Op::Add, Op::Read),
+            (Op::Add, Op::Write),
+        ];
+        for (op, preceding_op) in conflicting.iter() {
+            let result = success(&[(KEY_1, *op)]);
+            assert!(!commutes(&result, &ops(&[(KEY_1, *preceding_op)]), &[]));
+        }
+    }
+
+    #[test]
+    fn should_commute_failure_only_with_unmodified_state() {
+        let result = ExecutionResult::precondition_failure(Error::DeployError);
+        assert!(commutes(&result, &AdditiveMap::new(), &[]));
+        assert!(commutes(&result, &ops(&[(KEY_1, Op::Read)]), &[]));
+        assert!(!commutes(&result, &ops(&[(KEY_2, Op::Write)]), &[]));
+        assert!(!commutes(&result, &ops(&[(KEY_2, Op::Add)]), &[]));
+    }
+
+    #[test]
+    fn should_disregard_conflicts_on_unchanged_keys() {
+        let result = success(&[(KEY_1, Op::Write), (KEY_2, Op::Read)]);
+        let preceding_ops = ops(&[(KEY_1, Op::Write), (KEY_2, Op::Write)]);
+        assert!(!commutes(&result, &preceding_ops, &[KEY_1]));
+        assert!(commutes(&result, &preceding_ops, &[KEY_1, KEY_2]));
+
+        let result = ExecutionResult::precondition_failure(Error::DeployError);
+        assert!(!commutes(&result, &preceding_ops, &[KEY_1]));
+        assert!(commutes(&result, &preceding_ops, &[KEY_1, KEY_2]));
+    }
+}
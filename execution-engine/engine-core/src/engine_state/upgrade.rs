@@ -1,9 +1,9 @@
 use std::fmt;
 
-use contract_ffi::{key::Key, value::ProtocolVersion};
+use contract_ffi::{key::Key, system_contracts::pos, value::ProtocolVersion};
 use engine_shared::{newtypes::Blake2bHash, transform::TypeMismatch};
 use engine_storage::global_state::CommitResult;
-use engine_wasm_prep::wasm_costs::WasmCosts;
+use engine_wasm_prep::{host_function_costs::HostFunctionCosts, wasm_costs::WasmCosts};
 
 use crate::engine_state::execution_effect::ExecutionEffect;
 
@@ -57,6 +57,10 @@ pub struct UpgradeConfig {
     upgrade_installer_args: Option<Vec<u8>>,
     upgrade_installer_bytes: Option<Vec<u8>>,
     wasm_costs: Option<WasmCosts>,
+    host_function_costs: Option<HostFunctionCosts>,
+    min_gas_price: Option<u64>,
+    storage_cost_per_byte: Option<u64>,
+    proof_of_stake_params: Option<pos::Params>,
     activation_point: Option<ActivationPoint>,
 }
 
@@ -68,6 +72,10 @@ impl UpgradeConfig {
         upgrade_installer_args: Option<Vec<u8>>,
         upgrade_installer_bytes: Option<Vec<u8>>,
         wasm_costs: Option<WasmCosts>,
+        host_function_costs: Option<HostFunctionCosts>,
+        min_gas_price: Option<u64>,
+        storage_cost_per_byte: Option<u64>,
+        proof_of_stake_params: Option<pos::Params>,
         activation_point: Option<ActivationPoint>,
     ) -> Self {
         UpgradeConfig {
@@ -77,6 +85,10 @@ impl UpgradeConfig {
             upgrade_installer_args,
             upgrade_installer_bytes,
             wasm_costs,
+            host_function_costs,
+            min_gas_price,
+            storage_cost_per_byte,
+            proof_of_stake_params,
             activation_point,
         }
     }
@@ -107,6 +119,22 @@ impl UpgradeConfig {
         self.wasm_costs
     }
 
+    pub fn host_function_costs(&self) -> Option<HostFunctionCosts> {
+        self.host_function_costs
+    }
+
+    pub fn min_gas_price(&self) -> Option<u64> {
+        self.min_gas_price
+    }
+
+    pub fn storage_cost_per_byte(&self) -> Option<u64> {
+        self.storage_cost_per_byte
+    }
+
+    pub fn proof_of_stake_params(&self) -> Option<pos::Params> {
+        self.proof_of_stake_params
+    }
+
     pub fn activation_point(&self) -> Option<u64> {
         self.activation_point
     }
@@ -1,8 +1,20 @@
-use contract_ffi::value::{account::PublicKey, U512};
+use contract_ffi::{
+    bytesrepr::ToBytes,
+    key::{Key, LOCAL_SEED_LENGTH},
+    system_contracts::pos::{self, UnbondPayout, UNBOND_PAYOUTS_KEY},
+    uref::URef,
+    value::{account::PublicKey, U512},
+};
+use engine_shared::{additive_map::AdditiveMap, newtypes::Blake2bHash, transform::Transform};
+
+const STORAGE_USAGE_PREFIX: &[u8] = b"storage_usage";
+const STORAGE_CREATOR_PREFIX: &[u8] = b"storage_creator";
+const SCHEDULED_CALLS_PREFIX: &[u8] = b"scheduled_calls";
 
 /// In PoS, the validators are stored under named keys with names formatted as
-/// "v_<hex-formatted-PublicKey>_<bond-amount>".  This function attempts to parse such a string back
-/// into the `PublicKey` and bond amount.
+/// "v_<hex-formatted-PublicKey>_<bond-amount>", where the bond amount includes the motes delegated
+/// to the validator.  This function attempts to parse such a string back into the `PublicKey` and
+/// bond amount.
 pub fn pos_validator_key_name_to_tuple(pos_key_name: &str) -> Option<(PublicKey, U512)> {
     let mut split_bond = pos_key_name.split('_'); // expected format is "v_{public_key}_{bond}".
     if Some("v") != split_bond.next() {
@@ -27,13 +39,65 @@ pub fn pos_validator_key_name_to_tuple(pos_key_name: &str) -> Option<(PublicKey,
     }
 }
 
+/// Returns the payouts of matured unbonding requests which the PoS `step` recorded in `effects`,
+/// in the order they were made.  Returns none if `effects` don't include a `step` which paid
+/// something out.
+pub fn pos_unbond_payouts(
+    proof_of_stake: URef,
+    effects: &AdditiveMap<Key, Transform>,
+) -> Vec<UnbondPayout> {
+    let key_bytes = UNBOND_PAYOUTS_KEY
+        .to_bytes()
+        .expect("should serialize local key");
+    let key = Key::local(proof_of_stake.addr(), &key_bytes);
+    match effects.get(&key) {
+        Some(Transform::Write(value)) => pos::unbond_payouts_from_value(value).unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
+/// Returns the key under which the number of bytes of global state attributed to `owner` (an
+/// account or a contract) is stored, as a `Value::UInt64`.
+pub fn storage_usage_key(owner: &Key) -> Key {
+    let mut bytes = STORAGE_USAGE_PREFIX.to_vec();
+    bytes.extend(owner.to_bytes().expect("should serialize key"));
+    let seed: [u8; LOCAL_SEED_LENGTH] = Blake2bHash::new(&bytes).into();
+    Key::local(seed, &[])
+}
+
+/// Returns the key under which the account or contract which created `key` is stored, as a
+/// `Value::Key`.  It is only recorded for `URef` and `Local` keys, as the owner of any other key
+/// follows from the key itself.
+pub fn storage_creator_key(key: &Key) -> Key {
+    let mut bytes = STORAGE_CREATOR_PREFIX.to_vec();
+    bytes.extend(key.to_bytes().expect("should serialize key"));
+    let seed: [u8; LOCAL_SEED_LENGTH] = Blake2bHash::new(&bytes).into();
+    Key::local(seed, &[])
+}
+
+/// Returns the key under which the queue of calls scheduled via `schedule_call` is stored, as a
+/// `Value::ByteArray` holding the serialized `Vec<ScheduledCall>` ordered by block time.
+pub fn scheduled_calls_key() -> Key {
+    let seed: [u8; LOCAL_SEED_LENGTH] = Blake2bHash::new(SCHEDULED_CALLS_PREFIX).into();
+    Key::local(seed, &[])
+}
+
 #[cfg(test)]
 mod tests {
     use hex_fmt::HexFmt;
 
-    use contract_ffi::value::{account::PublicKey, U512};
+    use contract_ffi::{
+        bytesrepr::ToBytes,
+        key::Key,
+        system_contracts::pos::{self, UnbondPayout, UNBOND_PAYOUTS_KEY},
+        uref::{AccessRights, URef},
+        value::{account::PublicKey, Value, U512},
+    };
+    use engine_shared::{additive_map::AdditiveMap, transform::Transform};
 
-    use super::pos_validator_key_name_to_tuple;
+    use super::{
+        pos_unbond_payouts, pos_validator_key_name_to_tuple, storage_creator_key, storage_usage_key,
+    };
 
     #[test]
     fn should_parse_string_to_validator_tuple() {
@@ -83,4 +147,52 @@ mod tests {
         let no_stake = format!("v_{}", HexFmt(&public_key.value()));
         assert!(pos_validator_key_name_to_tuple(&no_stake).is_none());
     }
+
+    #[test]
+    fn should_collect_unbond_payouts_from_effects() {
+        let proof_of_stake = URef::new([7u8; 32], AccessRights::READ_ADD_WRITE);
+        let payouts = vec![
+            UnbondPayout::new(PublicKey::new([1u8; 32]), U512::from(100)),
+            UnbondPayout::new(PublicKey::new([2u8; 32]), U512::from(200)),
+        ];
+        let payouts_key = Key::local(
+            proof_of_stake.addr(),
+            &UNBOND_PAYOUTS_KEY.to_bytes().unwrap(),
+        );
+
+        let mut effects = AdditiveMap::new();
+        assert!(pos_unbond_payouts(proof_of_stake, &effects).is_empty());
+
+        effects.insert(Key::Hash([0u8; 32]), Transform::Write(Value::UInt64(1)));
+        assert!(pos_unbond_payouts(proof_of_stake, &effects).is_empty());
+
+        let value = pos::unbond_payouts_to_value(&payouts).unwrap();
+        effects.insert(payouts_key, Transform::Write(value));
+        assert_eq!(pos_unbond_payouts(proof_of_stake, &effects), payouts);
+
+        // The same local key of another contract doesn't hold payouts
+        let other_contract = URef::new([8u8; 32], AccessRights::READ_ADD_WRITE);
+        assert!(pos_unbond_payouts(other_contract, &effects).is_empty());
+    }
+
+    #[test]
+    fn should_derive_distinct_storage_keys_per_owner() {
+        let owners = [
+            Key::Account([1u8; 32]),
+            Key::Account([2u8; 32]),
+            Key::Hash([1u8; 32]),
+            Key::URef(URef::new([1u8; 32], AccessRights::READ_ADD_WRITE)).normalize(),
+        ];
+        let mut derived = Vec::new();
+        for owner in owners.iter() {
+            for key in [storage_usage_key(owner), storage_creator_key(owner)].iter() {
+                assert!(!owners.contains(key));
+                assert!(!derived.contains(key));
+                derived.push(*key);
+            }
+            // the keys are derived deterministically
+            assert_eq!(storage_usage_key(owner), derived[derived.len() - 2]);
+            assert_eq!(storage_creator_key(owner), derived[derived.len() - 1]);
+        }
+    }
 }
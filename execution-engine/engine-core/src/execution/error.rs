@@ -8,7 +8,10 @@ use contract_ffi::{
     key::Key,
     system_contracts,
     uref::{AccessRights, URef},
-    value::account::{AddKeyFailure, RemoveKeyFailure, SetThresholdFailure, UpdateKeyFailure},
+    value::{
+        abi::ArgumentError,
+        account::{AddKeyFailure, RemoveKeyFailure, SetThresholdFailure, UpdateKeyFailure},
+    },
 };
 use engine_shared::transform::TypeMismatch;
 
@@ -48,6 +51,20 @@ pub enum Error {
         expected: u32,
         actual: u32,
     },
+    /// The arguments of a call to a contract entry point don't match its parameters.
+    InvalidArguments {
+        entry_point: String,
+        error: ArgumentError,
+    },
+    /// The value returned from a contract entry point is not of its declared return type.
+    InvalidReturnValue {
+        entry_point: String,
+    },
+    /// The caller is not allowed to call a contract entry point by its access policy.
+    EntryPointAccessDenied {
+        entry_point: String,
+        caller: Key,
+    },
 }
 
 impl fmt::Display for Error {
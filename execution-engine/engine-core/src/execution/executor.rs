@@ -18,9 +18,12 @@ use contract_ffi::{
 use engine_shared::{gas::Gas, newtypes::CorrelationId};
 use engine_storage::{global_state::StateReader, protocol_data::ProtocolData};
 
-use super::{extract_access_rights_from_keys, instance_and_memory, Error, Runtime};
+use super::{extract_access_rights_from_keys, instance_and_memory, Error, ExecutionTrace, Runtime};
 use crate::{
-    engine_state::{execution_result::ExecutionResult, system_contract_cache::SystemContractCache},
+    engine_state::{
+        execution_effect::ExecutionEffect, execution_result::ExecutionResult,
+        system_contract_cache::SystemContractCache,
+    },
     execution::{address_generator::AddressGenerator, FN_STORE_ID_INITIAL},
     runtime_context::{self, RuntimeContext},
     tracking_copy::TrackingCopy,
@@ -45,6 +48,7 @@ macro_rules! on_fail_charge {
                     error: exec_err.into(),
                     effect: Default::default(),
                     cost: $cost,
+                    trace: None,
                 };
             }
         }
@@ -58,16 +62,49 @@ macro_rules! on_fail_charge {
                     error: exec_err.into(),
                     effect: $effect,
                     cost: $cost,
+                    trace: None,
                 };
             }
         }
     };
 }
 
-pub struct Executor;
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Executor {
+    trace: bool,
+}
 
 #[allow(clippy::too_many_arguments)]
 impl Executor {
+    /// Creates an executor which records an [`ExecutionTrace`] of each execution if `trace` is
+    /// set.
+    pub fn new(trace: bool) -> Self {
+        Executor { trace }
+    }
+
+    /// Starts a trace of an execution of `base_key`, if tracing is enabled.
+    fn start_trace(&self, base_key: Key) -> Option<Rc<RefCell<ExecutionTrace>>> {
+        if !self.trace {
+            return None;
+        }
+        let mut trace = ExecutionTrace::new();
+        trace.enter(base_key);
+        Some(Rc::new(RefCell::new(trace)))
+    }
+
+    /// Ends the trace started by [`Executor::start_trace`], recording the trap which aborted
+    /// the execution, if any.
+    fn finish_trace(
+        trace: Option<Rc<RefCell<ExecutionTrace>>>,
+        error: Option<&wasmi::Error>,
+    ) -> Option<ExecutionTrace> {
+        trace.map(|trace| {
+            let mut trace = trace.borrow().clone();
+            trace.exit(error);
+            trace
+        })
+    }
+
     pub fn exec<R: StateReader<Key, Value>>(
         &self,
         parity_module: Module,
@@ -121,6 +158,9 @@ impl Executor {
             )
         };
 
+        // Global state written by the deploy itself is attributed to the account executing it.
+        tc.borrow_mut().set_storage_owner(Some(base_key));
+
         let context = RuntimeContext::new(
             tc,
             &mut named_keys,
@@ -135,23 +175,53 @@ impl Executor {
             gas_counter,
             FN_STORE_ID_INITIAL,
             Rc::new(RefCell::new(address_generator)),
+            Default::default(),
             protocol_version,
             correlation_id,
             phase,
             protocol_data,
         );
 
+        let trace = self.start_trace(base_key);
         let mut runtime = Runtime::new(system_contract_cache, memory, parity_module, context);
-        on_fail_charge!(
-            instance.invoke_export("call", &[], &mut runtime),
-            runtime.context().gas_counter(),
-            effects_snapshot
-        );
-
-        ExecutionResult::Success {
-            effect: runtime.context().effect(),
-            cost: runtime.context().gas_counter(),
+        if let Some(trace) = trace.as_ref() {
+            runtime.set_trace(Rc::clone(trace));
         }
+
+        let result = instance.invoke_export("call", &[], &mut runtime);
+        runtime
+            .context()
+            .state()
+            .borrow_mut()
+            .set_storage_owner(None);
+        let trace = Self::finish_trace(trace, result.as_ref().err());
+
+        let execution_result = match result {
+            Ok(_) => match runtime.charge_storage_usage() {
+                Ok(()) => ExecutionResult::Success {
+                    effect: runtime.context().effect(),
+                    events: runtime.context().events().borrow().clone(),
+                    cost: runtime.context().gas_counter(),
+                    trace: None,
+                },
+                Err(error) => ExecutionResult::Failure {
+                    error: error.into(),
+                    effect: effects_snapshot,
+                    cost: runtime.context().gas_counter(),
+                    trace: None,
+                },
+            },
+            Err(error) => {
+                let exec_err: Error = error.into();
+                ExecutionResult::Failure {
+                    error: exec_err.into(),
+                    effect: effects_snapshot,
+                    cost: runtime.context().gas_counter(),
+                    trace: None,
+                }
+            }
+        };
+        execution_result.with_trace(trace)
     }
 
     pub fn exec_direct<R: StateReader<Key, Value>>(
@@ -219,6 +289,7 @@ impl Executor {
             gas_counter,
             FN_STORE_ID_INITIAL,
             address_generator,
+            Default::default(),
             protocol_version,
             correlation_id,
             phase,
@@ -228,50 +299,74 @@ impl Executor {
         let (instance, memory) =
             on_fail_charge!(instance_and_memory(parity_module.clone(), protocol_version));
 
+        let trace = self.start_trace(base_key);
         let mut runtime = Runtime::new(system_contract_cache, memory, parity_module, context);
+        if let Some(trace) = trace.as_ref() {
+            runtime.set_trace(Rc::clone(trace));
+        }
+
+        let result = instance.invoke_export("call", &[], &mut runtime);
+        let trace = Self::finish_trace(trace, result.as_ref().err());
 
-        match instance.invoke_export("call", &[], &mut runtime) {
+        let execution_result = match result {
             Ok(_) => ExecutionResult::Success {
                 effect: runtime.context().effect(),
+                events: runtime.context().events().borrow().clone(),
                 cost: runtime.context().gas_counter(),
+                trace: None,
             },
-            Err(e) => {
-                if let Some(host_error) = e.as_host_error() {
-                    // `ret` Trap is a success; downcast and attempt to extract result
-                    let downcasted_error = host_error.downcast_ref::<Error>().unwrap();
-                    match downcasted_error {
-                        Error::Ret(ref _ret_urefs) => {
-                            // NOTE: currently, ExecutionResult does not include runtime.result or
-                            // extra urefs  and thus we cannot get back
-                            // a value from the executed contract...
-                            // TODO?: add ability to include extra_urefs and runtime.result to
-                            // ExecutionResult::Success
-
-                            return ExecutionResult::Success {
-                                effect: runtime.context().effect(),
-                                cost: runtime.context().gas_counter(),
-                            };
-                        }
-                        Error::Revert(status) => {
-                            // Propagate revert as revert, instead of passing it as
-                            // InterpreterError.
-                            return ExecutionResult::Failure {
-                                error: Error::Revert(*status).into(),
-                                effect: effects_snapshot,
-                                cost: runtime.context().gas_counter(),
-                            };
-                        }
-                        _ => {}
-                    }
-                }
+            Err(e) => Self::direct_failure(e, &runtime, effects_snapshot),
+        };
+        execution_result.with_trace(trace)
+    }
 
-                ExecutionResult::Failure {
-                    error: Error::Interpreter(e).into(),
-                    effect: effects_snapshot,
-                    cost: runtime.context().gas_counter(),
+    /// Maps the error which stopped a direct execution to its result.
+    fn direct_failure<R: StateReader<Key, Value>>(
+        e: wasmi::Error,
+        runtime: &Runtime<R>,
+        effects_snapshot: ExecutionEffect,
+    ) -> ExecutionResult
+    where
+        R::Error: Into<Error>,
+    {
+        if let Some(host_error) = e.as_host_error() {
+            // `ret` Trap is a success; downcast and attempt to extract result
+            let downcasted_error = host_error.downcast_ref::<Error>().unwrap();
+            match downcasted_error {
+                Error::Ret(ref _ret_urefs) => {
+                    // NOTE: currently, ExecutionResult does not include runtime.result or
+                    // extra urefs  and thus we cannot get back
+                    // a value from the executed contract...
+                    // TODO?: add ability to include extra_urefs and runtime.result to
+                    // ExecutionResult::Success
+
+                    return ExecutionResult::Success {
+                        effect: runtime.context().effect(),
+                        events: runtime.context().events().borrow().clone(),
+                        cost: runtime.context().gas_counter(),
+                        trace: None,
+                    };
+                }
+                Error::Revert(status) => {
+                    // Propagate revert as revert, instead of passing it as
+                    // InterpreterError.
+                    return ExecutionResult::Failure {
+                        error: Error::Revert(*status).into(),
+                        effect: effects_snapshot,
+                        cost: runtime.context().gas_counter(),
+                        trace: None,
+                    };
                 }
+                _ => {}
             }
         }
+
+        ExecutionResult::Failure {
+            error: Error::Interpreter(e).into(),
+            effect: effects_snapshot,
+            cost: runtime.context().gas_counter(),
+            trace: None,
+        }
     }
 
     pub fn better_exec<R: StateReader<Key, Value>, T>(
@@ -328,6 +423,7 @@ impl Executor {
             gas_counter,
             FN_STORE_ID_INITIAL,
             address_generator,
+            Default::default(),
             protocol_version,
             correlation_id,
             phase,
@@ -5,15 +5,17 @@ mod executor;
 mod runtime;
 #[cfg(test)]
 mod tests;
+mod trace;
 
 pub use self::{
     address_generator::{AddressGenerator, AddressGeneratorBuilder},
     error::Error,
     executor::Executor,
     runtime::{
-        extract_access_rights_from_keys, extract_access_rights_from_urefs, instance_and_memory,
-        Runtime,
+        check_entry_point_access, extract_access_rights_from_keys,
+        extract_access_rights_from_urefs, instance_and_memory, select_entry_point, Runtime,
     },
+    trace::{ExecutionTrace, TraceEntry},
 };
 
 pub const MINT_NAME: &str = "mint";
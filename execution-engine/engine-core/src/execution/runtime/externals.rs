@@ -3,11 +3,11 @@ use std::convert::TryFrom;
 use wasmi::{Externals, RuntimeArgs, RuntimeValue, Trap};
 
 use contract_ffi::{
-    bytesrepr::{self, ToBytes},
+    bytesrepr::{self, FromBytes, ToBytes},
     contract_api::{self, system::TransferredTo},
     key::Key,
     value::{
-        account::{PublicKey, PurseId},
+        account::{BlockTime, PublicKey, PurseId},
         Value, U512,
     },
 };
@@ -16,7 +16,7 @@ use engine_shared::gas::Gas;
 use engine_storage::global_state::StateReader;
 
 use super::{args::Args, Error, Runtime};
-use crate::resolvers::v1_function_index::FunctionIndex;
+use crate::{execution::trace::trace_args, resolvers::v1_function_index::FunctionIndex};
 
 impl<'a, R: StateReader<Key, Value>> Externals for Runtime<'a, R>
 where
@@ -28,11 +28,42 @@ where
         args: RuntimeArgs,
     ) -> Result<Option<RuntimeValue>, Trap> {
         let func = FunctionIndex::try_from(index).expect("unknown function index");
+        let trace = match self.trace() {
+            Some(trace) => trace,
+            None => return self.invoke_host_function(func, args),
+        };
+
+        let name = func.name();
+        let traced_args = trace_args(&args);
+        let gas_before = self.context.gas_counter();
+        let result = self.invoke_host_function(func, args);
+        trace.borrow_mut().record_host_call(
+            name,
+            traced_args,
+            gas_before,
+            self.context.gas_counter(),
+            &result,
+        );
+        result
+    }
+}
+
+impl<'a, R: StateReader<Key, Value>> Runtime<'a, R>
+where
+    R::Error: Into<Error>,
+{
+    fn invoke_host_function(
+        &mut self,
+        func: FunctionIndex,
+        args: RuntimeArgs,
+    ) -> Result<Option<RuntimeValue>, Trap> {
+        let host_function_costs = *self.context.protocol_data().host_function_costs();
         match func {
             FunctionIndex::ReadFuncIndex => {
                 // args(0) = pointer to key in Wasm memory
                 // args(1) = size of key in Wasm memory
                 let (key_ptr, key_size) = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.read_value, key_size)?;
                 let size = self.read(key_ptr, key_size)?;
                 Ok(Some(RuntimeValue::I32(size as i32)))
             }
@@ -41,12 +72,14 @@ where
                 // args(0) = pointer to key bytes in Wasm memory
                 // args(1) = size of key bytes in Wasm memory
                 let (key_bytes_ptr, key_bytes_size) = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.read_value, key_bytes_size)?;
                 let size = self.read_local(key_bytes_ptr, key_bytes_size)?;
                 Ok(Some(RuntimeValue::I32(size as i32)))
             }
 
             FunctionIndex::SerNamedKeysFuncIndex => {
                 // No args, returns byte size of the known URefs.
+                self.charge_host_function(host_function_costs.list_named_keys, 0)?;
                 let size = self.serialize_named_keys()?;
                 Ok(Some(RuntimeValue::I32(size as i32)))
             }
@@ -56,7 +89,12 @@ where
                 // args(1) = size of key
                 // args(2) = pointer to value
                 // args(3) = size of value
-                let (key_ptr, key_size, value_ptr, value_size) = Args::parse(args)?;
+                let (key_ptr, key_size, value_ptr, value_size): (_, u32, _, u32) =
+                    Args::parse(args)?;
+                self.charge_host_function(
+                    host_function_costs.write,
+                    key_size.saturating_add(value_size),
+                )?;
                 self.write(key_ptr, key_size, value_ptr, value_size)?;
                 Ok(None)
             }
@@ -66,7 +104,12 @@ where
                 // args(1) = size of key
                 // args(2) = pointer to value
                 // args(3) = size of value
-                let (key_bytes_ptr, key_bytes_size, value_ptr, value_size) = Args::parse(args)?;
+                let (key_bytes_ptr, key_bytes_size, value_ptr, value_size): (_, u32, _, u32) =
+                    Args::parse(args)?;
+                self.charge_host_function(
+                    host_function_costs.write,
+                    key_bytes_size.saturating_add(value_size),
+                )?;
                 self.write_local(key_bytes_ptr, key_bytes_size, value_ptr, value_size)?;
                 Ok(None)
             }
@@ -76,7 +119,12 @@ where
                 // args(1) = size of key
                 // args(2) = pointer to value
                 // args(3) = size of value
-                let (key_ptr, key_size, value_ptr, value_size) = Args::parse(args)?;
+                let (key_ptr, key_size, value_ptr, value_size): (_, u32, _, u32) =
+                    Args::parse(args)?;
+                self.charge_host_function(
+                    host_function_costs.add,
+                    key_size.saturating_add(value_size),
+                )?;
                 self.add(key_ptr, key_size, value_ptr, value_size)?;
                 Ok(None)
             }
@@ -86,6 +134,7 @@ where
                 // args(1) = pointer to initial value
                 // args(2) = size of initial value
                 let (key_ptr, value_ptr, value_size) = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.new_uref, value_size)?;
                 self.new_uref(key_ptr, value_ptr, value_size)?;
                 Ok(None)
             }
@@ -93,6 +142,10 @@ where
             FunctionIndex::GetReadFuncIndex => {
                 // args(0) = pointer to destination in Wasm memory
                 let dest_ptr = Args::parse(args)?;
+                self.charge_host_function(
+                    host_function_costs.host_buffer,
+                    self.host_buf.len() as u32,
+                )?;
                 self.set_mem_from_buf(dest_ptr)?;
                 Ok(None)
             }
@@ -100,6 +153,10 @@ where
             FunctionIndex::GetFnFuncIndex => {
                 // args(0) = pointer to destination in Wasm memory
                 let dest_ptr = Args::parse(args)?;
+                self.charge_host_function(
+                    host_function_costs.host_buffer,
+                    self.host_buf.len() as u32,
+                )?;
                 self.set_mem_from_buf(dest_ptr)?;
                 Ok(None)
             }
@@ -107,6 +164,7 @@ where
             FunctionIndex::LoadArgFuncIndex => {
                 // args(0) = index of host runtime arg to load
                 let i: u32 = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.get_arg, 0)?;
                 let size = self.load_arg(i as usize);
                 Ok(Some(RuntimeValue::I32(size as i32)))
             }
@@ -115,6 +173,7 @@ where
                 // args(0) = index of host runtime arg to load
                 // args(1) = pointer to a argument size (output)
                 let (index, size_ptr): (u32, u32) = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.get_arg, 0)?;
                 let ret = self.get_arg_size(index as usize, size_ptr)?;
                 Ok(Some(RuntimeValue::I32(contract_api::i32_from(ret))))
             }
@@ -124,6 +183,7 @@ where
                 // args(1) = pointer to destination in Wasm memory
                 // args(2) = size of destination pointer memory
                 let (index, dest_ptr, dest_size): (u32, _, u32) = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.get_arg, dest_size)?;
                 let ret = self.get_arg(index as usize, dest_ptr, dest_size as usize)?;
                 Ok(Some(RuntimeValue::I32(contract_api::i32_from(ret))))
             }
@@ -135,6 +195,10 @@ where
                 // args(3) = size of extra urefs
                 let (value_ptr, value_size, extra_urefs_ptr, extra_urefs_size): (_, u32, _, u32) =
                     Args::parse(args)?;
+                self.charge_host_function(
+                    host_function_costs.ret,
+                    value_size.saturating_add(extra_urefs_size),
+                )?;
 
                 Err(self.ret(
                     value_ptr,
@@ -159,6 +223,13 @@ where
                 let _args_size_u32: u32 = args_size;
                 let _extra_urefs_size_u32: u32 = extra_urefs_size;
 
+                self.charge_host_function(
+                    host_function_costs.call_contract,
+                    key_size
+                        .saturating_add(args_size)
+                        .saturating_add(extra_urefs_size),
+                )?;
+
                 let key_contract: Key = self.key_from_mem(key_ptr, key_size)?;
                 let args_bytes: Vec<u8> = self.bytes_from_mem(args_ptr, args_size as usize)?;
                 let urefs_bytes =
@@ -171,6 +242,10 @@ where
             FunctionIndex::GetCallResultFuncIndex => {
                 // args(0) = pointer to destination in Wasm memory
                 let dest_ptr = Args::parse(args)?;
+                self.charge_host_function(
+                    host_function_costs.host_buffer,
+                    self.host_buf.len() as u32,
+                )?;
                 self.set_mem_from_buf(dest_ptr)?;
                 Ok(None)
             }
@@ -188,6 +263,10 @@ where
                     u32,
                     u32,
                 ) = Args::parse(args)?;
+                self.charge_host_function(
+                    host_function_costs.get_key,
+                    name_size.saturating_add(output_size),
+                )?;
                 let ret = self.load_key(
                     name_ptr,
                     name_size,
@@ -202,6 +281,7 @@ where
                 // args(0) = pointer to key name in Wasm memory
                 // args(1) = size of key name
                 let (name_ptr, name_size) = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.has_key, name_size)?;
                 let result = self.has_key(name_ptr, name_size)?;
                 Ok(Some(RuntimeValue::I32(result)))
             }
@@ -210,7 +290,11 @@ where
                 // args(0) = pointer to key name in Wasm memory
                 // args(1) = size of key name
                 // args(2) = pointer to destination in Wasm memory
-                let (name_ptr, name_size, key_ptr, key_size) = Args::parse(args)?;
+                let (name_ptr, name_size, key_ptr, key_size): (_, u32, _, u32) = Args::parse(args)?;
+                self.charge_host_function(
+                    host_function_costs.put_key,
+                    name_size.saturating_add(key_size),
+                )?;
                 self.put_key(name_ptr, name_size, key_ptr, key_size)?;
                 Ok(None)
             }
@@ -218,6 +302,7 @@ where
             FunctionIndex::ListNamedKeysFuncIndex => {
                 // args(0) = pointer to destination in Wasm memory
                 let ptr = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.list_named_keys, 0)?;
                 self.list_named_keys(ptr)?;
                 Ok(None)
             }
@@ -226,6 +311,7 @@ where
                 // args(0) = pointer to key name in Wasm memory
                 // args(1) = size of key name
                 let (name_ptr, name_size) = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.remove_key, name_size)?;
                 self.remove_key(name_ptr, name_size)?;
                 Ok(None)
             }
@@ -233,6 +319,7 @@ where
             FunctionIndex::GetCallerIndex => {
                 // args(0) = pointer to Wasm memory where to write.
                 let dest_ptr = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.context, 0)?;
                 self.get_caller(dest_ptr)?;
                 Ok(None)
             }
@@ -240,6 +327,7 @@ where
             FunctionIndex::GetBlocktimeIndex => {
                 // args(0) = pointer to Wasm memory where to write.
                 let dest_ptr = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.context, 0)?;
                 self.get_blocktime(dest_ptr)?;
                 Ok(None)
             }
@@ -258,9 +346,17 @@ where
                 // args(3) = size of the additional unforgable names
                 // args(4) = pointer to a Wasm memory where we will save
                 //           uref address of the new function
-                let (name_ptr, name_size, urefs_ptr, urefs_size, hash_ptr) = Args::parse(args)?;
-                let _uref_type: u32 = urefs_size;
+                let (name_ptr, name_size, urefs_ptr, urefs_size, hash_ptr): (_, u32, _, u32, _) =
+                    Args::parse(args)?;
+                self.charge_host_function(
+                    host_function_costs.store_function,
+                    name_size.saturating_add(urefs_size),
+                )?;
                 let fn_bytes = self.get_function_by_name(name_ptr, name_size)?;
+                self.charge_host_function_bytes(
+                    host_function_costs.store_function,
+                    fn_bytes.len() as u32,
+                )?;
                 let uref_bytes = self
                     .memory
                     .get(urefs_ptr, urefs_size as usize)
@@ -279,9 +375,17 @@ where
                 // args(3) = size of the additional unforgable names
                 // args(4) = pointer to a Wasm memory where we will save
                 //           hash of the new function
-                let (name_ptr, name_size, urefs_ptr, urefs_size, hash_ptr) = Args::parse(args)?;
-                let _uref_type: u32 = urefs_size;
+                let (name_ptr, name_size, urefs_ptr, urefs_size, hash_ptr): (_, u32, _, u32, _) =
+                    Args::parse(args)?;
+                self.charge_host_function(
+                    host_function_costs.store_function,
+                    name_size.saturating_add(urefs_size),
+                )?;
                 let fn_bytes = self.get_function_by_name(name_ptr, name_size)?;
+                self.charge_host_function_bytes(
+                    host_function_costs.store_function,
+                    fn_bytes.len() as u32,
+                )?;
                 let uref_bytes = self
                     .memory
                     .get(urefs_ptr, urefs_size as usize)
@@ -296,6 +400,7 @@ where
                 // args(0) = pointer to value to validate
                 // args(1) = size of value
                 let (value_ptr, value_size) = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.context, value_size)?;
 
                 if self.value_is_valid(value_ptr, value_size)? {
                     Ok(Some(RuntimeValue::I32(1)))
@@ -307,6 +412,7 @@ where
             FunctionIndex::RevertFuncIndex => {
                 // args(0) = status u32
                 let status = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.context, 0)?;
 
                 Err(self.revert(status))
             }
@@ -315,6 +421,7 @@ where
                 // args(0) = pointer to array of bytes of a public key
                 // args(1) = weight of the key
                 let (public_key_ptr, weight_value): (u32, u8) = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.manage_keys, 0)?;
                 let value = self.add_associated_key(public_key_ptr, weight_value)?;
                 Ok(Some(RuntimeValue::I32(value)))
             }
@@ -323,6 +430,7 @@ where
                 // args(0) = pointer to array of bytes of a public key
                 // args(1) = size of serialized bytes of public key
                 let public_key_ptr: u32 = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.manage_keys, 0)?;
                 let value = self.remove_associated_key(public_key_ptr)?;
                 Ok(Some(RuntimeValue::I32(value)))
             }
@@ -331,6 +439,7 @@ where
                 // args(0) = pointer to array of bytes of a public key
                 // args(1) = weight of the key
                 let (public_key_ptr, weight_value): (u32, u8) = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.manage_keys, 0)?;
                 let value = self.update_associated_key(public_key_ptr, weight_value)?;
                 Ok(Some(RuntimeValue::I32(value)))
             }
@@ -339,6 +448,7 @@ where
                 // args(0) = action type
                 // args(1) = new threshold
                 let (action_type_value, threshold_value): (u32, u8) = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.manage_keys, 0)?;
                 let value = self.set_action_threshold(action_type_value, threshold_value)?;
                 Ok(Some(RuntimeValue::I32(value)))
             }
@@ -347,6 +457,7 @@ where
                 // args(0) = pointer to array for return value
                 // args(1) = length of array for return value
                 let (dest_ptr, dest_size): (u32, u32) = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.create_purse, dest_size)?;
                 let purse_id = self.create_purse()?;
                 let purse_id_bytes = purse_id.to_bytes().map_err(Error::BytesRepr)?;
                 assert_eq!(dest_size, purse_id_bytes.len() as u32);
@@ -363,6 +474,10 @@ where
                 // args(3) = length of array of bytes of an amount
                 let (key_ptr, key_size, amount_ptr, amount_size): (u32, u32, u32, u32) =
                     Args::parse(args)?;
+                self.charge_host_function(
+                    host_function_costs.transfer,
+                    key_size.saturating_add(amount_size),
+                )?;
                 let public_key: PublicKey = {
                     let bytes = self.bytes_from_mem(key_ptr, key_size as usize)?;
                     bytesrepr::deserialize(&bytes).map_err(Error::BytesRepr)?
@@ -390,6 +505,12 @@ where
                     u32,
                     u32,
                 ) = Args::parse(args)?;
+                self.charge_host_function(
+                    host_function_costs.transfer,
+                    source_size
+                        .saturating_add(key_size)
+                        .saturating_add(amount_size),
+                )?;
 
                 let source_purse = {
                     let bytes = self.bytes_from_mem(source_ptr, source_size as usize)?;
@@ -416,6 +537,12 @@ where
                 // args(5) = length of array of bytes in Wasm memory of an amount
                 let (source_ptr, source_size, target_ptr, target_size, amount_ptr, amount_size) =
                     Args::parse(args)?;
+                self.charge_host_function(
+                    host_function_costs.transfer,
+                    source_size
+                        .saturating_add(target_size)
+                        .saturating_add(amount_size),
+                )?;
                 let ret = self.transfer_from_purse_to_purse(
                     source_ptr,
                     source_size,
@@ -431,6 +558,7 @@ where
                 // args(0) = pointer to purse_id input
                 // args(1) = length of purse_id
                 let (ptr, ptr_size): (u32, u32) = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.get_balance, ptr_size)?;
 
                 let purse_id: PurseId = {
                     let bytes = self.bytes_from_mem(ptr, ptr_size as usize)?;
@@ -452,6 +580,7 @@ where
             FunctionIndex::GetPhaseIndex => {
                 // args(0) = pointer to Wasm memory where to write.
                 let dest_ptr = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.context, 0)?;
                 self.get_phase(dest_ptr)?;
                 Ok(None)
             }
@@ -461,8 +590,17 @@ where
                 // args(1) = size of name in Wasm memory
                 // args(2) = pointer to key in Wasm memory
                 // args(3) = size of key
-                let (name_ptr, name_size, key_ptr, key_size) = Args::parse(args)?;
-                let ret = self.upgrade_contract_at_uref(name_ptr, name_size, key_ptr, key_size)?;
+                let (name_ptr, name_size, key_ptr, key_size): (_, u32, _, u32) = Args::parse(args)?;
+                self.charge_host_function(
+                    host_function_costs.upgrade_contract_at_uref,
+                    name_size.saturating_add(key_size),
+                )?;
+                let fn_bytes = self.get_function_by_name(name_ptr, name_size)?;
+                self.charge_host_function_bytes(
+                    host_function_costs.upgrade_contract_at_uref,
+                    fn_bytes.len() as u32,
+                )?;
+                let ret = self.upgrade_contract_at_uref(fn_bytes, key_ptr, key_size)?;
                 Ok(Some(RuntimeValue::I32(contract_api::i32_from(ret))))
             }
 
@@ -471,6 +609,7 @@ where
                 // args(1) = dest pointer for storing serialized result
                 // args(2) = dest pointer size
                 let (system_contract_index, dest_ptr, dest_size) = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.context, 0)?;
                 let ret = self.get_system_contract(system_contract_index, dest_ptr, dest_size)?;
                 Ok(Some(RuntimeValue::I32(contract_api::i32_from(ret))))
             }
@@ -478,9 +617,185 @@ where
             FunctionIndex::GetMainPurseIndex => {
                 // args(0) = pointer to Wasm memory where to write.
                 let dest_ptr = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.context, 0)?;
                 self.get_main_purse(dest_ptr)?;
                 Ok(None)
             }
+
+            FunctionIndex::EmitEventFuncIndex => {
+                // args(0) = pointer to event name in Wasm memory
+                // args(1) = size of event name
+                // args(2) = pointer to serialized event data in Wasm memory
+                // args(3) = size of serialized event data
+                let (name_ptr, name_size, data_ptr, data_size): (_, u32, _, u32) =
+                    Args::parse(args)?;
+                self.charge_host_function(
+                    host_function_costs.emit_event,
+                    name_size.saturating_add(data_size),
+                )?;
+                self.emit_event(name_ptr, name_size, data_ptr, data_size)?;
+                Ok(None)
+            }
+
+            FunctionIndex::StoreContractFuncIndex => {
+                // args(0) = pointer to serialized entry points in Wasm memory
+                // args(1) = size of serialized entry points
+                // args(2) = pointer to additional unforgable names
+                //           to be saved with the contract
+                // args(3) = size of the additional unforgable names
+                // args(4) = pointer to a Wasm memory where we will save
+                //           hash of the new contract
+                let (entry_points_ptr, entry_points_size, urefs_ptr, urefs_size, hash_ptr): (
+                    u32,
+                    u32,
+                    u32,
+                    u32,
+                    u32,
+                ) = Args::parse(args)?;
+                self.charge_host_function(
+                    host_function_costs.store_function,
+                    entry_points_size.saturating_add(urefs_size),
+                )?;
+                let entry_points_bytes =
+                    self.bytes_from_mem(entry_points_ptr, entry_points_size as usize)?;
+                let entry_points =
+                    bytesrepr::deserialize(&entry_points_bytes).map_err(Error::BytesRepr)?;
+                let module_bytes = self.get_module_with_entry_points(&entry_points)?;
+                self.charge_host_function_bytes(
+                    host_function_costs.store_function,
+                    module_bytes.len() as u32,
+                )?;
+                let urefs_bytes = self.bytes_from_mem(urefs_ptr, urefs_size as usize)?;
+                let urefs = bytesrepr::deserialize(&urefs_bytes).map_err(Error::BytesRepr)?;
+                let contract_hash = self.store_contract(module_bytes, entry_points, urefs)?;
+                self.function_address(contract_hash, hash_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::CallEntryPointFuncIndex => {
+                // args(0) = pointer to key where contract is at in global state
+                // args(1) = size of key
+                // args(2) = pointer to entry point name in Wasm memory
+                // args(3) = size of entry point name
+                // args(4) = pointer to function arguments in Wasm memory
+                // args(5) = size of arguments
+                // args(6) = pointer to extra supplied urefs
+                // args(7) = size of extra urefs
+                let (
+                    key_ptr,
+                    key_size,
+                    name_ptr,
+                    name_size,
+                    args_ptr,
+                    args_size,
+                    extra_urefs_ptr,
+                    extra_urefs_size,
+                ): (u32, u32, u32, u32, u32, u32, u32, u32) = Args::parse(args)?;
+
+                self.charge_host_function(
+                    host_function_costs.call_entry_point,
+                    key_size
+                        .saturating_add(name_size)
+                        .saturating_add(args_size)
+                        .saturating_add(extra_urefs_size),
+                )?;
+
+                let key_contract: Key = self.key_from_mem(key_ptr, key_size)?;
+                let entry_point_name = self.string_from_mem(name_ptr, name_size)?;
+                let args_bytes: Vec<u8> = self.bytes_from_mem(args_ptr, args_size as usize)?;
+                let urefs_bytes =
+                    self.bytes_from_mem(extra_urefs_ptr, extra_urefs_size as usize)?;
+
+                let size =
+                    self.call_entry_point(key_contract, entry_point_name, args_bytes, urefs_bytes)?;
+                Ok(Some(RuntimeValue::I32(size as i32)))
+            }
+
+            FunctionIndex::GetCallStackFuncIndex => {
+                // No args, returns byte size of the serialized call stack, which can be read with
+                // `get_call_result`.
+                self.charge_host_function(host_function_costs.get_call_stack, 0)?;
+                let size = self.serialize_call_stack()?;
+                Ok(Some(RuntimeValue::I32(size as i32)))
+            }
+
+            FunctionIndex::StoreModuleFuncIndex => {
+                // args(0) = pointer to additional unforgable names
+                //           to be saved with the contract
+                // args(1) = size of the additional unforgable names
+                // args(2) = pointer to a Wasm memory where we will save
+                //           hash of the new contract
+                let (urefs_ptr, urefs_size, hash_ptr): (u32, u32, u32) = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.store_function, urefs_size)?;
+                let module_bytes = self.get_module_bytes()?;
+                self.charge_host_function_bytes(
+                    host_function_costs.store_function,
+                    module_bytes.len() as u32,
+                )?;
+                let urefs_bytes = self.bytes_from_mem(urefs_ptr, urefs_size as usize)?;
+                let urefs = bytesrepr::deserialize(&urefs_bytes).map_err(Error::BytesRepr)?;
+                let contract_hash = self.store_module(module_bytes, urefs)?;
+                self.function_address(contract_hash, hash_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::DeleteFuncIndex => {
+                // args(0) = pointer to key in Wasm memory
+                // args(1) = size of key
+                let (key_ptr, key_size): (_, u32) = Args::parse(args)?;
+                self.charge_host_function(host_function_costs.delete, key_size)?;
+                self.delete(key_ptr, key_size)?;
+                Ok(None)
+            }
+
+            FunctionIndex::ScheduleCallFuncIndex => {
+                // args(0) = pointer to key where contract is at in global state
+                // args(1) = size of key
+                // args(2) = pointer to entry point name in Wasm memory
+                // args(3) = size of entry point name
+                // args(4) = pointer to function arguments in Wasm memory
+                // args(5) = size of arguments
+                // args(6) = pointer to the block time and gas purse of the call
+                // args(7) = size of the block time and gas purse
+                let (
+                    key_ptr,
+                    key_size,
+                    name_ptr,
+                    name_size,
+                    args_ptr,
+                    args_size,
+                    schedule_ptr,
+                    schedule_size,
+                ): (u32, u32, u32, u32, u32, u32, u32, u32) = Args::parse(args)?;
+
+                self.charge_host_function(
+                    host_function_costs.schedule_call,
+                    key_size
+                        .saturating_add(name_size)
+                        .saturating_add(args_size)
+                        .saturating_add(schedule_size),
+                )?;
+
+                let key_contract: Key = self.key_from_mem(key_ptr, key_size)?;
+                let entry_point_name = self.string_from_mem(name_ptr, name_size)?;
+                let args_bytes: Vec<u8> = self.bytes_from_mem(args_ptr, args_size as usize)?;
+                let (blocktime, gas_purse): (BlockTime, PurseId) = {
+                    let bytes = self.bytes_from_mem(schedule_ptr, schedule_size as usize)?;
+                    let (blocktime, rem) =
+                        BlockTime::from_bytes(&bytes).map_err(Error::BytesRepr)?;
+                    let gas_purse = bytesrepr::deserialize(rem).map_err(Error::BytesRepr)?;
+                    (blocktime, gas_purse)
+                };
+
+                let ret = self.schedule_call(
+                    key_contract,
+                    entry_point_name,
+                    args_bytes,
+                    blocktime,
+                    gas_purse,
+                )?;
+                Ok(Some(RuntimeValue::I32(contract_api::i32_from(ret))))
+            }
         }
     }
 }
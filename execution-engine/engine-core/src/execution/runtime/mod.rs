@@ -2,9 +2,11 @@ mod args;
 mod externals;
 
 use std::{
+    cell::RefCell,
     collections::{BTreeMap, HashMap, HashSet},
     convert::TryFrom,
     iter::IntoIterator,
+    rc::Rc,
 };
 
 use itertools::Itertools;
@@ -18,18 +20,23 @@ use contract_ffi::{
         system::{TransferResult, TransferredTo},
         Error as ApiError,
     },
+    execution::{CallStackElement, ScheduledCall},
     key::Key,
     system_contracts::{self, mint, SystemContract},
     uref::{AccessRights, URef},
     value::{
-        account::{ActionType, PublicKey, PurseId, Weight, PUBLIC_KEY_SERIALIZED_LENGTH},
-        Account, ProtocolVersion, Value, U512,
+        abi::{EntryPoint, EntryPointAccess},
+        account::{
+            ActionType, BlockTime, PublicKey, PurseId, Weight, PUBLIC_KEY_SERIALIZED_LENGTH,
+        },
+        Account, Contract, ProtocolVersion, Value, U512,
     },
 };
 use engine_shared::gas::Gas;
 use engine_storage::global_state::StateReader;
+use engine_wasm_prep::host_function_costs::HostFunctionCost;
 
-use super::{Error, MINT_NAME, POS_NAME};
+use super::{Error, ExecutionTrace, MINT_NAME, POS_NAME};
 use crate::{
     engine_state::system_contract_cache::SystemContractCache,
     resolvers::{create_module_resolver, memory_resolver::MemoryResolver},
@@ -44,6 +51,11 @@ pub struct Runtime<'a, R> {
     result: Vec<u8>,
     host_buf: Vec<u8>,
     context: RuntimeContext<'a, R>,
+    // Records host calls and call frames when executing in trace mode, shared with sub-calls
+    trace: Option<Rc<RefCell<ExecutionTrace>>>,
+    // The account whose session started the execution, followed by the key of each contract
+    // called since, ending with the currently executing one
+    call_stack: Vec<Key>,
 }
 
 /// Rename function called `name` in the `module` to `call`.
@@ -62,6 +74,67 @@ pub fn rename_export_to_call(module: &mut Module, name: String) {
     main_export.push_str("call");
 }
 
+/// Makes the function exported as `name` in the `module` its `call` entry point, dropping the
+/// function previously exported as `call`, if any.
+pub fn select_entry_point(module: &mut Module, name: &str) -> Result<(), Error> {
+    if name == "call" {
+        return Ok(());
+    }
+
+    match module.export_section_mut() {
+        Some(export_section)
+            if export_section
+                .entries()
+                .iter()
+                .any(|export_entry| export_entry.field() == name) =>
+        {
+            export_section
+                .entries_mut()
+                .retain(|export_entry| export_entry.field() != "call");
+        }
+        _ => return Err(Error::FunctionNotFound(name.to_string())),
+    }
+
+    rename_export_to_call(module, name.to_string());
+    Ok(())
+}
+
+/// Checks that the caller at the top of `call_stack` may call the entry point named
+/// `entry_point_name`, whose access policy is `access`.  `holds_uref` tells whether the caller
+/// holds a group [`URef`], with at least its access rights.
+pub fn check_entry_point_access<F: Fn(&URef) -> bool>(
+    entry_point_name: &str,
+    access: &EntryPointAccess,
+    call_stack: &[Key],
+    holds_uref: F,
+) -> Result<(), Error> {
+    let caller = match call_stack.last() {
+        Some(caller) => *caller,
+        None => return Err(Error::InvalidContext),
+    };
+
+    let is_allowed = match access {
+        EntryPointAccess::Public => true,
+        EntryPointAccess::AccountOnly => match caller {
+            Key::Account(_) => call_stack.len() == 1,
+            _ => false,
+        },
+        EntryPointAccess::Callers(callers) => callers
+            .iter()
+            .any(|allowed| allowed.normalize() == caller.normalize()),
+        EntryPointAccess::Groups(groups) => groups.iter().any(holds_uref),
+    };
+
+    if is_allowed {
+        Ok(())
+    } else {
+        Err(Error::EntryPointAccessDenied {
+            entry_point: entry_point_name.to_string(),
+            caller,
+        })
+    }
+}
+
 pub fn instance_and_memory(
     parity_module: Module,
     protocol_version: ProtocolVersion,
@@ -132,8 +205,10 @@ pub fn extract_access_rights_from_keys<I: IntoIterator<Item = Key>>(
         .collect()
 }
 
+#[allow(clippy::too_many_arguments)]
 fn sub_call<R: StateReader<Key, Value>>(
     parity_module: Module,
+    export_name: &str,
     args: Vec<Vec<u8>>,
     named_keys: &mut BTreeMap<String, Key>,
     key: Key,
@@ -178,14 +253,42 @@ where
             current_runtime.context.gas_counter(),
             current_runtime.context.fn_store_id(),
             current_runtime.context.address_generator(),
+            current_runtime.context.events(),
             protocol_version,
             current_runtime.context.correlation_id(),
             current_runtime.context.phase(),
             current_runtime.context.protocol_data(),
         ),
+        trace: current_runtime.trace(),
+        call_stack: {
+            let mut call_stack = current_runtime.call_stack.clone();
+            call_stack.push(key);
+            call_stack
+        },
     };
 
-    let result = instance.invoke_export("call", &[], &mut runtime);
+    if let Some(trace) = runtime.trace() {
+        trace.borrow_mut().enter(key);
+    }
+
+    // The state created by the system contracts (e.g. the balances of new purses) is shared by
+    // the whole network, so it is attributed to nobody rather than to the calling deploy.
+    let storage_owner = {
+        let protocol_data = runtime.context.protocol_data();
+        let system_contracts = [protocol_data.mint(), protocol_data.proof_of_stake()];
+        match key.as_uref() {
+            Some(uref) if system_contracts.iter().any(|sc| sc.addr() == uref.addr()) => None,
+            _ => Some(key),
+        }
+    };
+    let state = runtime.context.state();
+    let previous_storage_owner = state.borrow_mut().set_storage_owner(storage_owner);
+    let result = instance.invoke_export(export_name, &[], &mut runtime);
+    state.borrow_mut().set_storage_owner(previous_storage_owner);
+
+    if let Some(trace) = runtime.trace() {
+        trace.borrow_mut().exit(result.as_ref().err());
+    }
 
     match result {
         Ok(_) => Ok(runtime.result),
@@ -231,6 +334,7 @@ where
         module: Module,
         context: RuntimeContext<'a, R>,
     ) -> Self {
+        let call_stack = vec![context.base_key()];
         Runtime {
             system_contract_cache,
             memory,
@@ -238,9 +342,26 @@ where
             result: Vec::new(),
             host_buf: Vec::new(),
             context,
+            trace: None,
+            call_stack,
         }
     }
 
+    /// Records the execution of this runtime, and of any contracts it calls, to `trace`.
+    pub fn set_trace(&mut self, trace: Rc<RefCell<ExecutionTrace>>) {
+        self.trace = Some(trace);
+    }
+
+    fn trace(&self) -> Option<Rc<RefCell<ExecutionTrace>>> {
+        self.trace.as_ref().map(Rc::clone)
+    }
+
+    /// Returns the key of the account whose session started the execution, followed by the key
+    /// of each contract called since, the last one being the currently executing contract.
+    pub fn call_stack(&self) -> &[Key] {
+        &self.call_stack
+    }
+
     pub fn result(&self) -> &[u8] {
         self.result.as_slice()
     }
@@ -275,6 +396,49 @@ where
         }
     }
 
+    /// Updates the storage usage counters of the accounts and contracts whose global state was
+    /// changed by this execution, and charges gas for each byte by which their usage grew.
+    ///
+    /// The growth includes the records of which account or contract created each new key.  See
+    /// [`crate::tracking_copy::TrackingCopy::update_storage_usage`] for why storage is paid for with
+    /// gas rather than with a refundable deposit.
+    pub fn charge_storage_usage(&mut self) -> Result<(), Error> {
+        let growth = self
+            .context
+            .state()
+            .borrow_mut()
+            .update_storage_usage(self.context.correlation_id())
+            .map_err(Into::into)?;
+        let cost_per_byte = self.context.protocol_data().storage_cost_per_byte();
+        let amount = U512::from(growth) * U512::from(cost_per_byte);
+        if self.charge_gas(Gas::new(amount)) {
+            Ok(())
+        } else {
+            Err(Error::GasLimit)
+        }
+    }
+
+    /// Charges gas for a call to a host function which passes `bytes_count` bytes across the
+    /// host boundary.
+    fn charge_host_function(
+        &mut self,
+        cost: HostFunctionCost,
+        bytes_count: u32,
+    ) -> Result<(), Trap> {
+        let amount = U512::from(cost.base) + U512::from(cost.per_byte) * U512::from(bytes_count);
+        self.gas(Gas::new(amount))
+    }
+
+    /// Charges gas for `bytes_count` further bytes produced by a host function whose base cost
+    /// was already charged, e.g. the code it extracts from the running module.
+    fn charge_host_function_bytes(
+        &mut self,
+        cost: HostFunctionCost,
+        bytes_count: u32,
+    ) -> Result<(), Trap> {
+        self.charge_host_function(HostFunctionCost::new(0, cost.per_byte), bytes_count)
+    }
+
     fn bytes_from_mem(&self, ptr: u32, size: usize) -> Result<Vec<u8>, Error> {
         self.memory.get(ptr, size).map_err(Into::into)
     }
@@ -320,6 +484,11 @@ where
         }
     }
 
+    /// Serializes the whole module of the running contract.
+    fn get_module_bytes(&self) -> Result<Vec<u8>, Trap> {
+        parity_wasm::serialize(self.module.clone()).map_err(|e| Error::ParityWasm(e).into())
+    }
+
     pub fn value_is_valid(&mut self, value_ptr: u32, value_size: u32) -> Result<bool, Trap> {
         let value = self.value_from_mem(value_ptr, value_size)?;
 
@@ -449,6 +618,20 @@ where
         self.context.put_key(name, key).map_err(Into::into)
     }
 
+    /// Records an event with the name and serialized data read from Wasm memory.
+    pub fn emit_event(
+        &mut self,
+        name_ptr: u32,
+        name_size: u32,
+        data_ptr: u32,
+        data_size: u32,
+    ) -> Result<(), Trap> {
+        let name = self.string_from_mem(name_ptr, name_size)?;
+        let data = self.bytes_from_mem(data_ptr, data_size as usize)?;
+        self.context.emit_event(name, data);
+        Ok(())
+    }
+
     /// Writes current [self.host_buf] into [dest_ptr] location in Wasm memory
     /// for the contract to read.
     pub fn list_named_keys(&mut self, dest_ptr: u32) -> Result<(), Trap> {
@@ -541,13 +724,81 @@ where
     }
 
     /// Calls contract living under a `key`, with supplied `args` and extra
-    /// `urefs`.
+    /// `urefs`.  If the contract declares a `call` entry point, its access
+    /// policy is checked first.
     pub fn call_contract(
         &mut self,
         key: Key,
         args_bytes: Vec<u8>,
         urefs_bytes: Vec<u8>,
     ) -> Result<usize, Error> {
+        let contract = self.get_contract_to_call(key)?;
+        if let Some(entry_point) = contract.entry_point("call") {
+            self.check_access("call", entry_point)?;
+        }
+        let args: Vec<Vec<u8>> = deserialize(&args_bytes)?;
+        let extra_urefs = self.context.deserialize_keys(&urefs_bytes)?;
+
+        let result = self.execute_contract(key, contract, "call", args, extra_urefs)?;
+        self.host_buf = result;
+        Ok(self.host_buf.len())
+    }
+
+    /// Calls the entry point named `entry_point_name` of the contract living under `key`, with
+    /// supplied `args` and extra `urefs`.  If the contract declares its entry points, the caller
+    /// is checked against the entry point's access policy and the arguments against its
+    /// parameters before the call, and the returned value against its return type after it.
+    /// Otherwise any exported function can be called.
+    pub fn call_entry_point(
+        &mut self,
+        key: Key,
+        entry_point_name: String,
+        args_bytes: Vec<u8>,
+        urefs_bytes: Vec<u8>,
+    ) -> Result<usize, Error> {
+        let contract = self.get_contract_to_call(key)?;
+        let args: Vec<Vec<u8>> = deserialize(&args_bytes)?;
+        let entry_point = match contract.entry_point(&entry_point_name) {
+            Some(entry_point) => {
+                self.check_access(&entry_point_name, entry_point)?;
+                if let Err(error) = entry_point.check_args(&args) {
+                    return Err(Error::InvalidArguments {
+                        entry_point: entry_point_name,
+                        error,
+                    });
+                }
+                Some(entry_point.clone())
+            }
+            None if contract.entry_points().is_empty() => None,
+            None => return Err(Error::FunctionNotFound(entry_point_name)),
+        };
+        let extra_urefs = self.context.deserialize_keys(&urefs_bytes)?;
+
+        let result = self.execute_contract(key, contract, &entry_point_name, args, extra_urefs)?;
+        if let Some(entry_point) = entry_point {
+            if !entry_point.ret().is_valid(&result) {
+                return Err(Error::InvalidReturnValue {
+                    entry_point: entry_point_name,
+                });
+            }
+        }
+        self.host_buf = result;
+        Ok(self.host_buf.len())
+    }
+
+    /// Checks that the currently executing code may call `entry_point`, by its access policy.
+    fn check_access(&self, entry_point_name: &str, entry_point: &EntryPoint) -> Result<(), Error> {
+        check_entry_point_access(
+            entry_point_name,
+            entry_point.access(),
+            &self.call_stack,
+            |group| self.context.validate_uref(group).is_ok(),
+        )
+    }
+
+    /// Reads the contract living under `key`, checking that it can be called with the current
+    /// protocol version.
+    fn get_contract_to_call(&mut self, key: Key) -> Result<Contract, Error> {
         let contract = match self.context.read_gs(&key)? {
             Some(Value::Contract(contract)) => contract,
             Some(_) => {
@@ -569,8 +820,19 @@ where
             });
         }
 
-        let args: Vec<Vec<u8>> = deserialize(&args_bytes)?;
+        Ok(contract)
+    }
 
+    /// Executes the function exported as `export_name` by the module of `contract`, which lives
+    /// under `key`, and returns the value it returned.
+    fn execute_contract(
+        &mut self,
+        key: Key,
+        contract: Contract,
+        export_name: &str,
+        args: Vec<Vec<u8>>,
+        extra_urefs: Vec<Key>,
+    ) -> Result<Vec<u8>, Error> {
         let maybe_module = match key {
             Key::URef(uref) => self.system_contract_cache.get(&uref),
             _ => None,
@@ -581,21 +843,56 @@ where
             None => parity_wasm::deserialize_buffer(contract.bytes())?,
         };
 
-        let extra_urefs = self.context.deserialize_keys(&urefs_bytes)?;
+        let has_export = module
+            .export_section()
+            .map(|export_section| {
+                export_section
+                    .entries()
+                    .iter()
+                    .any(|export_entry| export_entry.field() == export_name)
+            })
+            .unwrap_or_default();
+        if !has_export {
+            return Err(Error::FunctionNotFound(export_name.to_string()));
+        }
 
+        let contract_version = contract.protocol_version();
         let mut refs = contract.take_named_keys();
 
-        let result = sub_call(
+        sub_call(
             module,
+            export_name,
             args,
             &mut refs,
             key,
             self,
             extra_urefs,
             contract_version,
-        )?;
-        self.host_buf = result;
-        Ok(self.host_buf.len())
+        )
+    }
+
+    /// Serializes the call stack, as seen by the contracts, into the host buffer and returns its
+    /// size.  URefs of called contracts are stripped of their access rights.
+    fn serialize_call_stack(&mut self) -> Result<usize, Trap> {
+        let phase = self.context.phase();
+        let call_stack: Vec<CallStackElement> = self
+            .call_stack
+            .iter()
+            .map(|key| match key {
+                Key::Account(addr) => CallStackElement::Session {
+                    account: PublicKey::new(*addr),
+                    phase,
+                },
+                key => CallStackElement::StoredContract {
+                    key: key.normalize(),
+                    phase,
+                },
+            })
+            .collect();
+        let bytes = call_stack.to_bytes().map_err(Error::BytesRepr)?;
+        let length = bytes.len();
+        self.host_buf = bytes;
+        Ok(length)
     }
 
     fn serialize_named_keys(&mut self) -> Result<usize, Trap> {
@@ -640,6 +937,68 @@ where
         Ok(new_hash)
     }
 
+    /// Stores `module_bytes`, the module of the currently executing code as returned by
+    /// `get_module_with_entry_points`, at an immutable address generated by the host, as a
+    /// contract whose entry points are the exported functions named in `entry_points`.
+    pub fn store_contract(
+        &mut self,
+        module_bytes: Vec<u8>,
+        entry_points: BTreeMap<String, EntryPoint>,
+        named_keys: BTreeMap<String, Key>,
+    ) -> Result<[u8; 32], Error> {
+        let contract = Contract::new(module_bytes, named_keys, self.context.protocol_version())
+            .with_entry_points(entry_points);
+        self.context.store_function_at_hash(contract.into())
+    }
+
+    /// Stores `module_bytes`, the whole module of the currently executing code, at an immutable
+    /// address generated by the host, as a contract whose entry points are all the functions it
+    /// exports.
+    pub fn store_module(
+        &mut self,
+        module_bytes: Vec<u8>,
+        named_keys: BTreeMap<String, Key>,
+    ) -> Result<[u8; 32], Error> {
+        let contract = Contract::new(module_bytes, named_keys, self.context.protocol_version());
+        self.context.store_function_at_hash(contract.into())
+    }
+
+    /// Returns the module of the currently executing code with all exports other than
+    /// `entry_points` removed.
+    fn get_module_with_entry_points(
+        &self,
+        entry_points: &BTreeMap<String, EntryPoint>,
+    ) -> Result<Vec<u8>, Error> {
+        let export_entries = self
+            .module
+            .export_section()
+            .map(|export_section| export_section.entries())
+            .unwrap_or_default();
+
+        if let Some(name) = entry_points.keys().find(|name| {
+            !export_entries
+                .iter()
+                .any(|export_entry| export_entry.field() == name.as_str())
+        }) {
+            return Err(Error::FunctionNotFound(name.clone()));
+        }
+
+        let mut module = self.module.clone();
+        // Only the entry points should be callable; `optimize` removes all code that is not
+        // reachable from them. It fails if the module has no exports, which after the check above
+        // means that no entry points were given.
+        pwasm_utils::optimize(
+            &mut module,
+            entry_points.keys().map(String::as_str).collect(),
+        )
+        .map_err(|_| {
+            let names: Vec<&str> = entry_points.keys().map(String::as_str).collect();
+            Error::FunctionNotFound(names.join(", "))
+        })?;
+
+        parity_wasm::serialize(module).map_err(Error::ParityWasm)
+    }
+
     /// Writes function address (`hash_bytes`) into the Wasm memory (at
     /// `dest_ptr` pointer).
     fn function_address(&mut self, hash_bytes: [u8; 32], dest_ptr: u32) -> Result<(), Trap> {
@@ -671,6 +1030,18 @@ where
         self.context.write_gs(key, value).map_err(Into::into)
     }
 
+    /// Deletes the value stored under the URef that `key` points at.  Only URefs with WRITE
+    /// rights can be deleted.
+    pub fn delete(&mut self, key_ptr: u32, key_size: u32) -> Result<(), Trap> {
+        match self.key_from_mem(key_ptr, key_size)? {
+            Key::URef(uref) => self.context.delete_gs(uref).map_err(Into::into),
+            _ => Err(Error::InvalidAccess {
+                required: AccessRights::WRITE,
+            }
+            .into()),
+        }
+    }
+
     /// Writes `value` under a key derived from `key` in the "local cluster" of
     /// GlobalState
     pub fn write_local(
@@ -1060,8 +1431,7 @@ where
     /// and writes them at the provided uref, overwriting existing value if any
     pub fn upgrade_contract_at_uref(
         &mut self,
-        name_ptr: u32,
-        name_size: u32,
+        bytes: Vec<u8>,
         key_ptr: u32,
         key_size: u32,
     ) -> Result<Result<(), ApiError>, Trap> {
@@ -1074,7 +1444,6 @@ where
                 key
             ))),
         }?;
-        let bytes = self.get_function_by_name(name_ptr, name_size)?;
         match self
             .context
             .upgrade_contract_at_uref(key, bytes, named_keys)
@@ -1103,4 +1472,34 @@ where
             Err(error) => Err(Error::Interpreter(error).into()),
         }
     }
+
+    /// Queues a call of `entry_point` on the stored contract at `contract` with `args`, to be run
+    /// by the engine once the block time reaches `blocktime`.  The call's gas is paid from
+    /// `gas_purse`, which has to be writeable by the current context.
+    pub fn schedule_call(
+        &mut self,
+        contract: Key,
+        entry_point: String,
+        args: Vec<u8>,
+        blocktime: BlockTime,
+        gas_purse: PurseId,
+    ) -> Result<Result<(), ApiError>, Error> {
+        match contract {
+            Key::Hash(_) => {}
+            Key::URef(_) if self.context.validate_key(&contract).is_ok() => {}
+            _ => return Ok(Err(ApiError::UnexpectedKeyVariant)),
+        }
+
+        let purse_key = Key::URef(gas_purse.value());
+        if self.context.validate_key(&purse_key).is_err() || !self.context.is_writeable(&purse_key)
+        {
+            return Ok(Err(ApiError::InvalidPurse));
+        }
+
+        let account = PublicKey::new(self.context.account().pub_key());
+        let scheduled_call =
+            ScheduledCall::new(contract, entry_point, args, blocktime, gas_purse, account);
+        self.context.schedule_call(scheduled_call)?;
+        Ok(Ok(()))
+    }
 }
@@ -13,7 +13,9 @@ fn on_fail_charge_test_helper<T>(
     let _result = on_fail_charge!(f(), error_cost);
     ExecutionResult::Success {
         effect: Default::default(),
+        events: Vec::new(),
         cost: success_cost,
+        trace: None,
     }
 }
 
@@ -56,7 +58,9 @@ fn on_fail_charge_with_action() {
         });
         ExecutionResult::Success {
             effect: Default::default(),
+            events: Vec::new(),
             cost: Gas::default(),
+            trace: None,
         }
     };
     match f() {
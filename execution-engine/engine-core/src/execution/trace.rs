@@ -0,0 +1,204 @@
+use wasmi::{RuntimeArgs, RuntimeValue, Trap, TrapKind};
+
+use contract_ffi::key::Key;
+use engine_shared::gas::Gas;
+
+use super::Error;
+
+/// A single step recorded while executing a deploy in trace mode.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TraceEntry {
+    /// A call frame was entered, either for the deploy itself or through `call_contract`.
+    Call { depth: usize, key: Key },
+    /// A host function returned to the frame at `depth`.
+    ///
+    /// Host calls are recorded when they return, so the `call_contract` host call follows the
+    /// entries of the frame it created.
+    HostCall {
+        depth: usize,
+        name: &'static str,
+        args: Vec<i64>,
+        gas_before: Gas,
+        gas_after: Gas,
+        result: String,
+    },
+    /// The frame at `depth` running `key` was aborted by a trap.
+    Trap {
+        depth: usize,
+        key: Key,
+        message: String,
+    },
+}
+
+/// The steps recorded while executing a deploy in trace mode.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExecutionTrace {
+    entries: Vec<TraceEntry>,
+    frames: Vec<Key>,
+}
+
+impl ExecutionTrace {
+    pub fn new() -> Self {
+        ExecutionTrace::default()
+    }
+
+    pub fn entries(&self) -> &[TraceEntry] {
+        &self.entries
+    }
+
+    pub fn into_entries(self) -> Vec<TraceEntry> {
+        self.entries
+    }
+
+    /// Appends the entries of `other`, e.g. those of a later phase of the same deploy.
+    pub fn extend(&mut self, other: ExecutionTrace) {
+        self.entries.extend(other.entries)
+    }
+
+    pub(crate) fn enter(&mut self, key: Key) {
+        self.entries.push(TraceEntry::Call {
+            depth: self.frames.len(),
+            key,
+        });
+        self.frames.push(key);
+    }
+
+    /// Leaves the current frame, recording a trap if it was aborted by anything other than
+    /// `ret`.
+    pub(crate) fn exit(&mut self, error: Option<&wasmi::Error>) {
+        let key = match self.frames.pop() {
+            Some(key) => key,
+            None => return,
+        };
+        let error = match error {
+            Some(error) => error,
+            None => return,
+        };
+        let message = match error
+            .as_host_error()
+            .and_then(|host_error| host_error.downcast_ref::<Error>())
+        {
+            Some(Error::Ret(_)) => return,
+            Some(other) => format!("{:?}", other),
+            None => format!("{:?}", error),
+        };
+        self.entries.push(TraceEntry::Trap {
+            depth: self.frames.len(),
+            key,
+            message,
+        });
+    }
+
+    pub(crate) fn record_host_call(
+        &mut self,
+        name: &'static str,
+        args: Vec<i64>,
+        gas_before: Gas,
+        gas_after: Gas,
+        result: &Result<Option<RuntimeValue>, Trap>,
+    ) {
+        let result = match result {
+            Ok(None) => String::new(),
+            Ok(Some(value)) => format!("{:?}", value),
+            Err(trap) => match trap.kind() {
+                TrapKind::Host(host_error) => match host_error.downcast_ref::<Error>() {
+                    Some(error) => format!("{:?}", error),
+                    None => format!("{:?}", trap),
+                },
+                _ => format!("{:?}", trap),
+            },
+        };
+        self.entries.push(TraceEntry::HostCall {
+            depth: self.frames.len().saturating_sub(1),
+            name,
+            args,
+            gas_before,
+            gas_after,
+            result,
+        });
+    }
+}
+
+/// Converts the arguments of a host call for recording.  Host functions only take integer
+/// arguments.
+pub(crate) fn trace_args(args: &RuntimeArgs) -> Vec<i64> {
+    args.as_ref()
+        .iter()
+        .map(|value| match value {
+            RuntimeValue::I32(value) => i64::from(*value),
+            RuntimeValue::I64(value) => *value,
+            _ => 0,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use wasmi::Trap;
+
+    use contract_ffi::key::Key;
+    use engine_shared::gas::Gas;
+
+    use super::{Error, ExecutionTrace, TraceEntry};
+
+    const DEPLOY_KEY: Key = Key::Account([1u8; 32]);
+    const CONTRACT_KEY: Key = Key::Hash([2u8; 32]);
+
+    fn host_trap(error: Error) -> wasmi::Error {
+        let trap: Trap = error.into();
+        wasmi::Error::Trap(trap)
+    }
+
+    #[test]
+    fn should_record_frames_and_host_calls_at_their_depth() {
+        let mut trace = ExecutionTrace::new();
+        trace.enter(DEPLOY_KEY);
+        trace.enter(CONTRACT_KEY);
+        trace.record_host_call(
+            "get_caller",
+            vec![8],
+            Gas::default(),
+            Gas::default(),
+            &Ok(None),
+        );
+        trace.exit(None);
+        trace.record_host_call(
+            "call_contract",
+            vec![1, 2, 3],
+            Gas::default(),
+            Gas::default(),
+            &Ok(None),
+        );
+        trace.exit(None);
+
+        let depths: Vec<usize> = trace
+            .entries()
+            .iter()
+            .map(|entry| match entry {
+                TraceEntry::Call { depth, .. }
+                | TraceEntry::HostCall { depth, .. }
+                | TraceEntry::Trap { depth, .. } => *depth,
+            })
+            .collect();
+        assert_eq!(depths, vec![0, 1, 1, 0]);
+    }
+
+    #[test]
+    fn should_record_trap_but_not_ret() {
+        let mut trace = ExecutionTrace::new();
+        trace.enter(DEPLOY_KEY);
+        trace.enter(CONTRACT_KEY);
+        trace.exit(Some(&host_trap(Error::Ret(Vec::new()))));
+        trace.exit(Some(&host_trap(Error::Revert(7))));
+
+        assert_eq!(trace.entries().len(), 3);
+        assert_eq!(
+            trace.entries()[2],
+            TraceEntry::Trap {
+                depth: 0,
+                key: DEPLOY_KEY,
+                message: format!("{:?}", Error::Revert(7)),
+            }
+        );
+    }
+}
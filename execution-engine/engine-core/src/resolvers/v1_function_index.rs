@@ -46,6 +46,68 @@ pub enum FunctionIndex {
     GetSystemContractIndex = 37,
     GetMainPurseIndex = 38,
     GetArgSizeFuncIndex = 39,
+    EmitEventFuncIndex = 40,
+    StoreContractFuncIndex = 41,
+    CallEntryPointFuncIndex = 42,
+    StoreModuleFuncIndex = 43,
+    GetCallStackFuncIndex = 44,
+    DeleteFuncIndex = 45,
+    ScheduleCallFuncIndex = 46,
+}
+
+impl FunctionIndex {
+    /// Returns the name under which the host function is imported by contracts.
+    pub fn name(&self) -> &'static str {
+        match self {
+            FunctionIndex::WriteFuncIndex => "write",
+            FunctionIndex::WriteLocalFuncIndex => "write_local",
+            FunctionIndex::ReadFuncIndex => "read_value",
+            FunctionIndex::ReadLocalFuncIndex => "read_value_local",
+            FunctionIndex::AddFuncIndex => "add",
+            FunctionIndex::NewFuncIndex => "new_uref",
+            FunctionIndex::GetReadFuncIndex => "get_read",
+            FunctionIndex::GetFnFuncIndex => "get_function",
+            FunctionIndex::LoadArgFuncIndex => "load_arg",
+            FunctionIndex::GetArgFuncIndex => "get_arg",
+            FunctionIndex::RetFuncIndex => "ret",
+            FunctionIndex::GetCallResultFuncIndex => "get_call_result",
+            FunctionIndex::CallContractFuncIndex => "call_contract",
+            FunctionIndex::GetKeyFuncIndex => "get_key",
+            FunctionIndex::GasFuncIndex => "gas",
+            FunctionIndex::HasKeyFuncIndex => "has_key",
+            FunctionIndex::PutKeyFuncIndex => "put_key",
+            FunctionIndex::StoreFnIndex => "store_function",
+            FunctionIndex::StoreFnAtHashIndex => "store_function_at_hash",
+            FunctionIndex::IsValidFnIndex => "is_valid",
+            FunctionIndex::RevertFuncIndex => "revert",
+            FunctionIndex::AddAssociatedKeyFuncIndex => "add_associated_key",
+            FunctionIndex::RemoveAssociatedKeyFuncIndex => "remove_associated_key",
+            FunctionIndex::UpdateAssociatedKeyFuncIndex => "update_associated_key",
+            FunctionIndex::SetActionThresholdFuncIndex => "set_action_threshold",
+            FunctionIndex::SerNamedKeysFuncIndex => "serialize_named_keys",
+            FunctionIndex::ListNamedKeysFuncIndex => "list_named_keys",
+            FunctionIndex::RemoveKeyFuncIndex => "remove_key",
+            FunctionIndex::GetCallerIndex => "get_caller",
+            FunctionIndex::GetBlocktimeIndex => "get_blocktime",
+            FunctionIndex::CreatePurseIndex => "create_purse",
+            FunctionIndex::TransferToAccountIndex => "transfer_to_account",
+            FunctionIndex::TransferFromPurseToAccountIndex => "transfer_from_purse_to_account",
+            FunctionIndex::TransferFromPurseToPurseIndex => "transfer_from_purse_to_purse",
+            FunctionIndex::GetBalanceIndex => "get_balance",
+            FunctionIndex::GetPhaseIndex => "get_phase",
+            FunctionIndex::UpgradeContractAtURefIndex => "upgrade_contract_at_uref",
+            FunctionIndex::GetSystemContractIndex => "get_system_contract",
+            FunctionIndex::GetMainPurseIndex => "get_main_purse",
+            FunctionIndex::GetArgSizeFuncIndex => "get_arg_size",
+            FunctionIndex::EmitEventFuncIndex => "emit_event",
+            FunctionIndex::StoreContractFuncIndex => "store_contract",
+            FunctionIndex::CallEntryPointFuncIndex => "call_entry_point",
+            FunctionIndex::StoreModuleFuncIndex => "store_module",
+            FunctionIndex::GetCallStackFuncIndex => "get_call_stack",
+            FunctionIndex::DeleteFuncIndex => "delete",
+            FunctionIndex::ScheduleCallFuncIndex => "schedule_call",
+        }
+    }
 }
 
 impl Into<usize> for FunctionIndex {
@@ -80,6 +142,10 @@ mod tests {
         assert_eq!(primitive, 19usize);
     }
     #[test]
+    fn name_matches_import() {
+        assert_eq!(FunctionIndex::GetArgSizeFuncIndex.name(), "get_arg_size");
+    }
+    #[test]
     #[should_panic]
     fn invalid_index() {
         FunctionIndex::try_from(123_456_789usize).unwrap();
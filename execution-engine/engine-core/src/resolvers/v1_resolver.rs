@@ -200,6 +200,34 @@ impl ModuleImportResolver for RuntimeModuleImportResolver {
                 Signature::new(&[ValueType::I32; 1][..], None),
                 FunctionIndex::GetMainPurseIndex.into(),
             ),
+            "emit_event" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 4][..], None),
+                FunctionIndex::EmitEventFuncIndex.into(),
+            ),
+            "store_contract" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 5][..], None),
+                FunctionIndex::StoreContractFuncIndex.into(),
+            ),
+            "call_entry_point" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 8][..], Some(ValueType::I32)),
+                FunctionIndex::CallEntryPointFuncIndex.into(),
+            ),
+            "store_module" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 3][..], None),
+                FunctionIndex::StoreModuleFuncIndex.into(),
+            ),
+            "get_call_stack" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 0][..], Some(ValueType::I32)),
+                FunctionIndex::GetCallStackFuncIndex.into(),
+            ),
+            "delete" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 2][..], None),
+                FunctionIndex::DeleteFuncIndex.into(),
+            ),
+            "schedule_call" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 8][..], Some(ValueType::I32)),
+                FunctionIndex::ScheduleCallFuncIndex.into(),
+            ),
             _ => {
                 return Err(InterpreterError::Function(format!(
                     "host module doesn't export function with name {}",
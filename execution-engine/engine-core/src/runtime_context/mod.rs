@@ -13,7 +13,7 @@ use blake2::{
 
 use contract_ffi::{
     bytesrepr::{deserialize, ToBytes},
-    execution::Phase,
+    execution::{Phase, ScheduledCall},
     key::{Key, LOCAL_SEED_LENGTH},
     uref::{AccessRights, URef},
     value::{
@@ -28,9 +28,11 @@ use engine_shared::{gas::Gas, newtypes::CorrelationId};
 use engine_storage::{global_state::StateReader, protocol_data::ProtocolData};
 
 use crate::{
-    engine_state::{execution_effect::ExecutionEffect, SYSTEM_ACCOUNT_ADDR},
+    engine_state::{
+        contract_event::ContractEvent, execution_effect::ExecutionEffect, SYSTEM_ACCOUNT_ADDR,
+    },
     execution::{AddressGenerator, Error},
-    tracking_copy::{AddResult, TrackingCopy},
+    tracking_copy::{AddResult, TrackingCopy, TrackingCopyExt},
     Address,
 };
 
@@ -71,6 +73,8 @@ pub struct RuntimeContext<'a, R> {
     gas_counter: Gas,
     fn_store_id: u32,
     address_generator: Rc<RefCell<AddressGenerator>>,
+    // Events emitted so far during the current execution, shared with sub-calls
+    events: Rc<RefCell<Vec<ContractEvent>>>,
     protocol_version: ProtocolVersion,
     correlation_id: CorrelationId,
     phase: Phase,
@@ -96,6 +100,7 @@ where
         gas_counter: Gas,
         fn_store_id: u32,
         address_generator: Rc<RefCell<AddressGenerator>>,
+        events: Rc<RefCell<Vec<ContractEvent>>>,
         protocol_version: ProtocolVersion,
         correlation_id: CorrelationId,
         phase: Phase,
@@ -115,6 +120,7 @@ where
             gas_counter,
             fn_store_id,
             address_generator,
+            events,
             protocol_version,
             correlation_id,
             phase,
@@ -236,6 +242,17 @@ where
         Rc::clone(&self.address_generator)
     }
 
+    pub fn events(&self) -> Rc<RefCell<Vec<ContractEvent>>> {
+        Rc::clone(&self.events)
+    }
+
+    /// Records an event emitted by the entity we are currently running.
+    pub fn emit_event(&mut self, name: String, data: Vec<u8>) {
+        let mut events = self.events.borrow_mut();
+        let ordinal = events.len() as u32;
+        events.push(ContractEvent::new(self.base_key, ordinal, name, data));
+    }
+
     pub fn state(&self) -> Rc<RefCell<TrackingCopy<R>>> {
         Rc::clone(&self.state)
     }
@@ -396,6 +413,29 @@ where
         Ok(())
     }
 
+    /// Deletes the value stored under `uref`.  The URef has to be known to this context with
+    /// WRITE rights.
+    pub fn delete_gs(&mut self, uref: URef) -> Result<(), Error> {
+        let key = Key::URef(uref);
+        self.validate_writeable(&key)?;
+        self.validate_key(&key)?;
+        self.state.borrow_mut().delete(key);
+        Ok(())
+    }
+
+    /// Adds `scheduled_call` to the queue of scheduled calls, after any calls due at the same or
+    /// an earlier block time.
+    pub fn schedule_call(&mut self, scheduled_call: ScheduledCall) -> Result<(), Error> {
+        let mut state = self.state.borrow_mut();
+        let mut scheduled_calls = state.get_scheduled_calls(self.correlation_id)?;
+        let index = scheduled_calls
+            .iter()
+            .position(|call| call.blocktime() > scheduled_call.blocktime())
+            .unwrap_or_else(|| scheduled_calls.len());
+        scheduled_calls.insert(index, scheduled_call);
+        state.put_scheduled_calls(scheduled_calls)
+    }
+
     pub fn read_account(&mut self, key: &Key) -> Result<Option<Value>, Error> {
         if let Key::Account(_) = key {
             self.validate_key(key)?;
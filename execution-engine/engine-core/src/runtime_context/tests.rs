@@ -129,6 +129,7 @@ fn mock_runtime_context<'a>(
         Gas::default(),
         0,
         Rc::new(RefCell::new(address_generator)),
+        Default::default(),
         ProtocolVersion::V1_0_0,
         CorrelationId::new(),
         Phase::Session,
@@ -440,6 +441,7 @@ fn contract_key_addable_valid() {
         Gas::default(),
         0,
         Rc::new(RefCell::new(address_generator)),
+        Default::default(),
         ProtocolVersion::V1_0_0,
         CorrelationId::new(),
         PHASE,
@@ -499,6 +501,7 @@ fn contract_key_addable_invalid() {
         Gas::default(),
         0,
         Rc::new(RefCell::new(address_generator)),
+        Default::default(),
         ProtocolVersion::V1_0_0,
         CorrelationId::new(),
         PHASE,
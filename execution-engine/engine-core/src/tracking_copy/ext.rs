@@ -1,5 +1,6 @@
 use contract_ffi::{
-    bytesrepr::ToBytes,
+    bytesrepr::{self, ToBytes},
+    execution::ScheduledCall,
     key::Key,
     uref::URef,
     value::{Account, Contract, Value},
@@ -8,6 +9,7 @@ use engine_shared::{motes::Motes, newtypes::CorrelationId, transform::TypeMismat
 use engine_storage::global_state::StateReader;
 
 use crate::{
+    engine_state::utils::scheduled_calls_key,
     execution,
     tracking_copy::{TrackingCopy, TrackingCopyQueryResult},
 };
@@ -43,6 +45,18 @@ pub trait TrackingCopyExt<R> {
         correlation_id: CorrelationId,
         key: Key,
     ) -> Result<Contract, Self::Error>;
+
+    /// Gets the queue of scheduled calls, ordered by block time
+    fn get_scheduled_calls(
+        &mut self,
+        correlation_id: CorrelationId,
+    ) -> Result<Vec<ScheduledCall>, Self::Error>;
+
+    /// Replaces the queue of scheduled calls
+    fn put_scheduled_calls(
+        &mut self,
+        scheduled_calls: Vec<ScheduledCall>,
+    ) -> Result<(), Self::Error>;
 }
 
 impl<R: StateReader<Key, Value>> TrackingCopyExt<R> for TrackingCopy<R>
@@ -125,4 +139,30 @@ where
             None => Err(execution::Error::KeyNotFound(key)),
         }
     }
+
+    fn get_scheduled_calls(
+        &mut self,
+        correlation_id: CorrelationId,
+    ) -> Result<Vec<ScheduledCall>, Self::Error> {
+        match self
+            .get(correlation_id, &scheduled_calls_key())
+            .map_err(Into::into)?
+        {
+            Some(Value::ByteArray(bytes)) => Ok(bytesrepr::deserialize(&bytes)?),
+            Some(other) => Err(execution::Error::TypeMismatch(TypeMismatch::new(
+                "Value::ByteArray".to_string(),
+                other.type_string(),
+            ))),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn put_scheduled_calls(
+        &mut self,
+        scheduled_calls: Vec<ScheduledCall>,
+    ) -> Result<(), Self::Error> {
+        let bytes = scheduled_calls.to_bytes()?;
+        self.write(scheduled_calls_key(), Value::ByteArray(bytes));
+        Ok(())
+    }
 }
@@ -4,7 +4,7 @@ pub(self) mod meter;
 #[cfg(test)]
 mod tests;
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use linked_hash_map::LinkedHashMap;
 
@@ -16,8 +16,13 @@ use engine_shared::{
 };
 use engine_storage::global_state::StateReader;
 
-use crate::engine_state::{execution_effect::ExecutionEffect, op::Op};
+use crate::engine_state::{
+    execution_effect::ExecutionEffect,
+    op::Op,
+    utils::{storage_creator_key, storage_usage_key},
+};
 
+use self::byte_size::ByteSize;
 pub use self::ext::TrackingCopyExt;
 use self::meter::{heap_meter::HeapSize, Meter};
 
@@ -35,6 +40,7 @@ pub struct TrackingCopyCache<M> {
     current_cache_size: usize,
     reads_cached: LinkedHashMap<Key, Value>,
     muts_cached: HashMap<Key, Value>,
+    deletes_cached: HashSet<Key>,
     meter: M,
 }
 
@@ -49,6 +55,7 @@ impl<M: Meter<Key, Value>> TrackingCopyCache<M> {
             current_cache_size: 0,
             reads_cached: LinkedHashMap::new(),
             muts_cached: HashMap::new(),
+            deletes_cached: HashSet::new(),
             meter,
         }
     }
@@ -71,9 +78,24 @@ impl<M: Meter<Key, Value>> TrackingCopyCache<M> {
 
     /// Inserts `key` and `value` pair to Write/Add cache.
     pub fn insert_write(&mut self, key: Key, value: Value) {
+        self.deletes_cached.remove(&key);
         self.muts_cached.insert(key, value.clone());
     }
 
+    /// Marks `key` as deleted, so that it is no longer found in the cache.
+    pub fn insert_delete(&mut self, key: Key) {
+        self.muts_cached.remove(&key);
+        if let Some(value) = self.reads_cached.remove(&key) {
+            self.current_cache_size -= Meter::measure(&self.meter, &key, &value);
+        }
+        self.deletes_cached.insert(key);
+    }
+
+    /// Returns `true` if `key` has been deleted.
+    pub fn is_deleted(&self, key: &Key) -> bool {
+        self.deletes_cached.contains(key)
+    }
+
     /// Gets value from `key` in the cache.
     pub fn get(&mut self, key: &Key) -> Option<&Value> {
         if let Some(value) = self.muts_cached.get(&key) {
@@ -89,6 +111,8 @@ pub struct TrackingCopy<R> {
     cache: TrackingCopyCache<HeapSize>,
     ops: AdditiveMap<Key, Op>,
     fns: AdditiveMap<Key, Transform>,
+    storage_owner: Option<Key>,
+    storage_writers: BTreeMap<Key, Option<Key>>,
 }
 
 #[derive(Debug)]
@@ -107,6 +131,8 @@ impl<R: StateReader<Key, Value>> TrackingCopy<R> {
                                                                  * limit? */
             ops: AdditiveMap::new(),
             fns: AdditiveMap::new(),
+            storage_owner: None,
+            storage_writers: BTreeMap::new(),
         }
     }
 
@@ -135,6 +161,9 @@ impl<R: StateReader<Key, Value>> TrackingCopy<R> {
         correlation_id: CorrelationId,
         key: &Key,
     ) -> Result<Option<Value>, R::Error> {
+        if self.cache.is_deleted(key) {
+            return Ok(None);
+        }
         if let Some(value) = self.cache.get(key) {
             return Ok(Some(value.to_owned()));
         }
@@ -157,17 +186,183 @@ impl<R: StateReader<Key, Value>> TrackingCopy<R> {
             self.fns.insert_add(normalized_key, Transform::Identity);
             Ok(Some(value))
         } else {
+            // The outcome still depends on the key being absent, so the read is recorded in the
+            // ops in order for conflicting writes to be detected.  There is no value for an
+            // identity transform to apply to though.
+            self.ops.insert_add(normalized_key, Op::Read);
             Ok(None)
         }
     }
 
+    /// Sets the account or contract on whose behalf global state is changed from now on,
+    /// returning the previous one.  Keys created while the owner is `None` are attributed to
+    /// nobody.
+    pub fn set_storage_owner(&mut self, owner: Option<Key>) -> Option<Key> {
+        std::mem::replace(&mut self.storage_owner, owner)
+    }
+
+    /// Records that `key` was changed, along with the storage owner at its first change, which
+    /// becomes the key's creator if it did not exist before.
+    fn record_storage_change(&mut self, key: Key) {
+        let owner = self.storage_owner;
+        self.storage_writers.entry(key).or_insert(owner);
+    }
+
+    /// Reads `key` as of the changes made so far, without recording the read.
+    fn peek(&self, correlation_id: CorrelationId, key: &Key) -> Result<Option<Value>, R::Error> {
+        if self.cache.is_deleted(key) {
+            return Ok(None);
+        }
+        match self.cache.muts_cached.get(key) {
+            Some(value) => Ok(Some(value.to_owned())),
+            None => self.reader.read(correlation_id, key),
+        }
+    }
+
+    /// Returns the account or contract to which the bytes stored under `key` are attributed.
+    ///
+    /// Accounts own their account key and contracts their hash key, whoever changes them.  Any
+    /// other key is owned by its creator, i.e. the storage owner when the key was first written,
+    /// as recorded under [`storage_creator_key`].  Keys without a recorded creator, such as the
+    /// state of the system contracts and the purse balances they maintain, are attributed to
+    /// nobody, so that a deploy is not charged for changes to state it does not own.
+    fn storage_owner_of(
+        &self,
+        correlation_id: CorrelationId,
+        key: &Key,
+        writer: Option<Key>,
+        existed: bool,
+    ) -> Result<Option<Key>, R::Error> {
+        match key {
+            Key::Account(_) | Key::Hash(_) => Ok(Some(*key)),
+            _ if !existed => Ok(writer),
+            _ => match self.peek(correlation_id, &storage_creator_key(key))? {
+                Some(Value::Key(creator)) => Ok(Some(creator)),
+                _ => Ok(None),
+            },
+        }
+    }
+
+    /// Returns the change in the number of bytes of global state attributed to each storage owner,
+    /// caused by the writes, adds and deletes performed on this `TrackingCopy`.
+    ///
+    /// This includes the records under [`storage_creator_key`] which
+    /// [`TrackingCopy::update_storage_usage`] adds for created keys and removes for deleted ones,
+    /// so that their creators pay for the bookkeeping as well.
+    pub fn storage_usage_deltas(
+        &self,
+        correlation_id: CorrelationId,
+    ) -> Result<BTreeMap<Key, i64>, R::Error> {
+        let mut deltas = BTreeMap::new();
+        for (key, writer) in self.storage_writers.iter() {
+            let old_value = self.reader.read(correlation_id, key)?;
+            let owner =
+                match self.storage_owner_of(correlation_id, key, *writer, old_value.is_some())? {
+                    Some(owner) => owner,
+                    None => continue,
+                };
+            let old_size = match old_value {
+                Some(value) => key.byte_size() + value.byte_size(),
+                None => 0,
+            };
+            let new_size = match self.cache.muts_cached.get(key) {
+                Some(value) if !self.cache.is_deleted(key) => key.byte_size() + value.byte_size(),
+                _ => 0,
+            };
+            let creator_record_size = match key {
+                Key::Account(_) | Key::Hash(_) => 0,
+                _ => storage_creator_key(key).byte_size() + Value::Key(owner).byte_size(),
+            };
+            let delta = match (old_size, new_size) {
+                (0, 0) => 0,
+                (0, new_size) => (new_size + creator_record_size) as i64,
+                (old_size, 0) => -((old_size + creator_record_size) as i64),
+                (old_size, new_size) => new_size as i64 - old_size as i64,
+            };
+            *deltas.entry(owner).or_insert(0) += delta;
+        }
+        Ok(deltas)
+    }
+
+    /// Adds the changes in usage computed by [`TrackingCopy::storage_usage_deltas`] to the usage
+    /// counters of their owners, stored under [`storage_usage_key`], and returns the total number
+    /// of bytes by which usage grew.  Usage that shrank does not offset growth of other owners.
+    /// The creators of the keys which were created are recorded, and those of the keys which were
+    /// deleted are removed.
+    ///
+    /// Storage is paid for with gas when it is used, rather than with a deposit held by the mint
+    /// and refunded when the storage is freed, so freeing storage refunds nothing.  Gas is
+    /// deducted from the deploy's payment like the rest of its execution cost, and limited by the
+    /// same gas limit, so no purse has to be tracked per owner.  A deposit would need new mint
+    /// entry points to lock and release motes, and would let whoever deletes a key collect the
+    /// refund for storage that someone else paid for.
+    ///
+    /// This is intended to be called once, after all the attributed changes have been made.
+    pub fn update_storage_usage(&mut self, correlation_id: CorrelationId) -> Result<u64, R::Error> {
+        let deltas = self.storage_usage_deltas(correlation_id)?;
+        let mut creators = Vec::new();
+        for (key, writer) in self.storage_writers.iter() {
+            if let Key::Account(_) | Key::Hash(_) = key {
+                continue;
+            }
+            let existed = self.reader.read(correlation_id, key)?.is_some();
+            let exists = self.cache.muts_cached.contains_key(key) && !self.cache.is_deleted(key);
+            match writer {
+                Some(creator) if !existed && exists => creators.push((*key, Some(*creator))),
+                _ if existed && !exists => creators.push((*key, None)),
+                _ => (),
+            }
+        }
+        for (key, creator) in creators {
+            let creator_key = storage_creator_key(&key);
+            match creator {
+                Some(creator) => self.write(creator_key, Value::Key(creator)),
+                None if self.peek(correlation_id, &creator_key)?.is_some() => {
+                    self.delete(creator_key)
+                }
+                None => (),
+            }
+        }
+
+        let mut growth: u64 = 0;
+        for (owner, delta) in deltas {
+            if delta == 0 {
+                continue;
+            }
+            let usage_key = storage_usage_key(&owner);
+            let usage = match self.read(correlation_id, &usage_key)? {
+                Some(Value::UInt64(usage)) => usage,
+                _ => 0,
+            };
+            let new_usage = if delta > 0 {
+                growth = growth.saturating_add(delta as u64);
+                usage.saturating_add(delta as u64)
+            } else {
+                usage.saturating_sub(delta.wrapping_neg() as u64)
+            };
+            self.write(usage_key, Value::UInt64(new_usage));
+        }
+        self.storage_writers.clear();
+        Ok(growth)
+    }
+
     pub fn write(&mut self, key: Key, value: Value) {
         let normalized_key = key.normalize();
+        self.record_storage_change(normalized_key);
         self.cache.insert_write(normalized_key, value.clone());
         self.ops.insert_add(normalized_key, Op::Write);
         self.fns.insert_add(normalized_key, Transform::Write(value));
     }
 
+    /// Removes the value under `key`.  Subsequent reads of `key` find nothing.
+    pub fn delete(&mut self, key: Key) {
+        let normalized_key = key.normalize();
+        self.record_storage_change(normalized_key);
+        self.cache.insert_delete(normalized_key);
+        self.ops.insert_add(normalized_key, Op::Write);
+        self.fns.insert_add(normalized_key, Transform::Delete);
+    }
+
     /// Ok(None) represents missing key to which we want to "add" some value.
     /// Ok(Some(unit)) represents successful operation.
     /// Err(error) is reserved for unexpected errors when accessing global
@@ -201,6 +396,7 @@ impl<R: StateReader<Key, Value>> TrackingCopy<R> {
                 };
                 match t.clone().apply(current_value) {
                     Ok(new_value) => {
+                        self.record_storage_change(normalized_key);
                         self.cache.insert_write(normalized_key, new_value);
                         self.ops.insert_add(normalized_key, Op::Add);
                         self.fns.insert_add(normalized_key, t);
@@ -218,6 +414,52 @@ impl<R: StateReader<Key, Value>> TrackingCopy<R> {
         ExecutionEffect::new(self.ops.clone(), self.fns.clone())
     }
 
+    /// Applies an [`ExecutionEffect`] produced elsewhere (e.g. by executing against a fork of this
+    /// `TrackingCopy`) as if its operations had been performed on this `TrackingCopy`.
+    ///
+    /// Subsequent reads observe the resulting values and the effect is folded into
+    /// [`TrackingCopy::effect`], which allows several executions to be chained without
+    /// committing intermediate results to global state. Nothing is applied unless every
+    /// transform in the effect can be applied.
+    pub fn apply_effect(
+        &mut self,
+        correlation_id: CorrelationId,
+        effect: &ExecutionEffect,
+    ) -> Result<AddResult, R::Error> {
+        let mut new_values = Vec::with_capacity(effect.transforms.len());
+        let mut deleted_keys = Vec::new();
+        for (key, transform) in effect.transforms.iter() {
+            let normalized_key = key.normalize();
+            match transform {
+                Transform::Identity => (),
+                Transform::Delete => deleted_keys.push(normalized_key),
+                Transform::Write(value) => new_values.push((normalized_key, value.to_owned())),
+                transform => match self.get(correlation_id, &normalized_key)? {
+                    None => return Ok(AddResult::KeyNotFound(normalized_key)),
+                    Some(current_value) => match transform.clone().apply(current_value) {
+                        Ok(new_value) => new_values.push((normalized_key, new_value)),
+                        Err(transform::Error::TypeMismatch(type_mismatch)) => {
+                            return Ok(AddResult::TypeMismatch(type_mismatch))
+                        }
+                    },
+                },
+            }
+        }
+        for (key, new_value) in new_values {
+            self.cache.insert_write(key, new_value);
+        }
+        for key in deleted_keys {
+            self.cache.insert_delete(key);
+        }
+        for (key, transform) in effect.transforms.iter() {
+            self.fns.insert_add(key.normalize(), transform.to_owned());
+        }
+        for (key, op) in effect.ops.iter() {
+            self.ops.insert_add(key.normalize(), *op);
+        }
+        Ok(AddResult::Success)
+    }
+
     pub fn query(
         &mut self,
         correlation_id: CorrelationId,
@@ -317,6 +559,9 @@ impl<R: StateReader<Key, Value>> StateReader<Key, Value> for &TrackingCopy<R> {
     type Error = R::Error;
 
     fn read(&self, correlation_id: CorrelationId, key: &Key) -> Result<Option<Value>, Self::Error> {
+        if self.cache.is_deleted(key) {
+            return Ok(None);
+        }
         if let Some(value) = self.cache.muts_cached.get(key) {
             return Ok(Some(value.to_owned()));
         }
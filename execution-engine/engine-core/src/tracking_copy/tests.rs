@@ -12,13 +12,18 @@ use contract_ffi::{
         Account, Contract, ProtocolVersion, Value,
     },
 };
-use engine_shared::{newtypes::CorrelationId, transform::Transform};
+use engine_shared::{additive_map::AdditiveMap, newtypes::CorrelationId, transform::Transform};
 use engine_storage::global_state::{in_memory::InMemoryGlobalState, StateProvider, StateReader};
 
 use super::{
-    meter::count_meter::Count, AddResult, TrackingCopy, TrackingCopyCache, TrackingCopyQueryResult,
+    byte_size::ByteSize, meter::count_meter::Count, AddResult, TrackingCopy, TrackingCopyCache,
+    TrackingCopyQueryResult,
+};
+use crate::engine_state::{
+    execution_effect::ExecutionEffect,
+    op::Op,
+    utils::{storage_creator_key, storage_usage_key},
 };
-use crate::engine_state::op::Op;
 
 struct CountingDb {
     count: Rc<Cell<i32>>,
@@ -141,6 +146,138 @@ fn tracking_copy_write() {
     assert_eq!(tc.ops.get(&k), Some(&Op::Write));
 }
 
+#[test]
+fn tracking_copy_delete() {
+    let correlation_id = CorrelationId::new();
+    let counter = Rc::new(Cell::new(0));
+    let db = CountingDb::new(Rc::clone(&counter));
+    let mut tc = TrackingCopy::new(db);
+    let k = Key::Hash([0u8; 32]);
+
+    // cache the value, then delete it
+    tc.read(correlation_id, &k).unwrap().unwrap();
+    tc.delete(k);
+    assert_eq!(tc.fns.get(&k), Some(&Transform::Delete));
+    assert_eq!(tc.ops.get(&k), Some(&Op::Write));
+
+    // the deleted value is not found, neither in the cache nor in the DB
+    assert_eq!(tc.read(correlation_id, &k).unwrap(), None);
+    assert_eq!(counter.get(), 1);
+    assert_matches!(
+        tc.add(correlation_id, k, Value::Int32(1)),
+        Ok(AddResult::KeyNotFound(_))
+    );
+
+    // writing again makes the value visible
+    let one = Value::Int32(1);
+    tc.write(k, one.clone());
+    assert_eq!(tc.read(correlation_id, &k).unwrap(), Some(one.clone()));
+    assert_eq!(tc.fns.get(&k), Some(&Transform::Write(one)));
+}
+
+#[test]
+fn tracking_copy_storage_usage() {
+    let correlation_id = CorrelationId::new();
+    let owner_a = Key::Account([1u8; 32]);
+    let owner_b = Key::Account([2u8; 32]);
+    let uref =
+        |byte: u8| Key::URef(URef::new([byte; 32], AccessRights::READ_ADD_WRITE)).normalize();
+    // created by owner_a, by owner_b, and by a system contract respectively
+    let (created_by_a, created_by_b, shared) = (uref(1), uref(2), uref(3));
+    let new_key = uref(4);
+    let initial_usage = 10;
+    let (gs, root_hash) = InMemoryGlobalState::from_pairs(
+        correlation_id,
+        &[
+            (owner_a, Value::UInt64(0)),
+            (created_by_a, Value::UInt64(0)),
+            (storage_creator_key(&created_by_a), Value::Key(owner_a)),
+            (created_by_b, Value::ByteArray(vec![0u8; 200])),
+            (storage_creator_key(&created_by_b), Value::Key(owner_b)),
+            (shared, Value::UInt64(0)),
+            (storage_usage_key(&owner_a), Value::UInt64(initial_usage)),
+        ],
+    )
+    .unwrap();
+    let view = gs.checkout(root_hash).unwrap().unwrap();
+    let mut tc = TrackingCopy::new(view);
+    let size = |key: &Key, value: &Value| (key.byte_size() + value.byte_size()) as i64;
+    let big_value = Value::ByteArray(vec![0u8; 100]);
+
+    // changes made on behalf of owner_b are attributed to the owners of the changed keys
+    assert_eq!(tc.set_storage_owner(Some(owner_b)), None);
+    tc.write(owner_a, big_value.clone());
+    tc.write(created_by_a, big_value.clone());
+    tc.write(shared, big_value.clone());
+    tc.delete(created_by_b);
+    tc.write(new_key, big_value.clone());
+    assert_eq!(tc.set_storage_owner(None), Some(owner_b));
+
+    // keys created while there is no storage owner are attributed to nobody
+    tc.write(uref(5), big_value.clone());
+
+    // the creators of created and deleted keys are also charged for their creator records
+    let created_by_a_now = uref(6);
+    assert_eq!(tc.set_storage_owner(Some(owner_a)), None);
+    tc.write(created_by_a_now, big_value.clone());
+    assert_eq!(tc.set_storage_owner(None), Some(owner_a));
+    let creator_record_size =
+        |key: &Key, owner: Key| size(&storage_creator_key(key), &Value::Key(owner));
+
+    let growth_a = size(&owner_a, &big_value) - size(&owner_a, &Value::UInt64(0))
+        + size(&created_by_a, &big_value)
+        - size(&created_by_a, &Value::UInt64(0))
+        + size(&created_by_a_now, &big_value)
+        + creator_record_size(&created_by_a_now, owner_a);
+    let delta_b = size(&new_key, &big_value) + creator_record_size(&new_key, owner_b)
+        - size(&created_by_b, &Value::ByteArray(vec![0u8; 200]))
+        - creator_record_size(&created_by_b, owner_b);
+    assert!(delta_b < 0);
+    let deltas = tc.storage_usage_deltas(correlation_id).unwrap();
+    assert_eq!(deltas.len(), 2);
+    assert_eq!(deltas.get(&owner_a), Some(&growth_a));
+    assert_eq!(deltas.get(&owner_b), Some(&delta_b));
+
+    // only growth is returned, and usage does not drop below zero
+    assert_eq!(
+        tc.update_storage_usage(correlation_id).unwrap(),
+        growth_a as u64
+    );
+    assert_eq!(
+        tc.read(correlation_id, &storage_usage_key(&owner_a))
+            .unwrap(),
+        Some(Value::UInt64(initial_usage + growth_a as u64))
+    );
+    assert_eq!(
+        tc.read(correlation_id, &storage_usage_key(&owner_b))
+            .unwrap(),
+        Some(Value::UInt64(0))
+    );
+
+    // the creator of the new key is recorded, and that of the deleted key removed
+    assert_eq!(
+        tc.read(correlation_id, &storage_creator_key(&new_key))
+            .unwrap(),
+        Some(Value::Key(owner_b))
+    );
+    assert_eq!(
+        tc.read(correlation_id, &storage_creator_key(&created_by_a_now))
+            .unwrap(),
+        Some(Value::Key(owner_a))
+    );
+    assert_eq!(
+        tc.read(correlation_id, &storage_creator_key(&created_by_b))
+            .unwrap(),
+        None
+    );
+    assert_eq!(
+        tc.read(correlation_id, &storage_creator_key(&uref(5)))
+            .unwrap(),
+        None
+    );
+    assert!(tc.storage_usage_deltas(correlation_id).unwrap().is_empty());
+}
+
 #[test]
 fn tracking_copy_add_i32() {
     let correlation_id = CorrelationId::new();
@@ -427,6 +564,69 @@ proptest! {
     }
 }
 
+#[test]
+fn tracking_copy_apply_effect() {
+    let correlation_id = CorrelationId::new();
+    let db = CountingDb::new_init(Value::Int32(3));
+    let mut tc = TrackingCopy::new(db);
+    let k1 = Key::Hash([1u8; 32]);
+    let k2 = Key::Hash([2u8; 32]);
+
+    // produce an effect on a fork, leaving the original untouched
+    let effect = {
+        let mut fork = tc.fork();
+        fork.write(k1, Value::Int32(1));
+        let add = fork.add(correlation_id, k2, Value::Int32(2));
+        assert_matches!(add, Ok(AddResult::Success));
+        fork.effect()
+    };
+    assert!(tc.fns.is_empty());
+    assert!(tc.ops.is_empty());
+
+    let result = tc.apply_effect(correlation_id, &effect);
+    assert_matches!(result, Ok(AddResult::Success));
+
+    // applied values are visible to subsequent reads
+    assert_eq!(tc.read(correlation_id, &k1).unwrap(), Some(Value::Int32(1)));
+    assert_eq!(tc.read(correlation_id, &k2).unwrap(), Some(Value::Int32(5)));
+
+    // and the effect is folded into the effect of the tracking copy
+    assert_eq!(tc.fns.get(&k1), Some(&Transform::Write(Value::Int32(1))));
+    assert_eq!(tc.fns.get(&k2), Some(&Transform::AddInt32(2)));
+    assert_eq!(tc.ops.get(&k1), Some(&Op::Write));
+    assert_eq!(tc.ops.get(&k2), Some(&Op::Write));
+}
+
+#[test]
+fn tracking_copy_apply_effect_type_mismatch() {
+    let correlation_id = CorrelationId::new();
+    let db = CountingDb::new_init(Value::String("hello".to_string()));
+    let mut tc = TrackingCopy::new(db);
+    let k1 = Key::Hash([1u8; 32]);
+    let k2 = Key::Hash([2u8; 32]);
+
+    let effect = {
+        let mut ops = AdditiveMap::new();
+        ops.insert(k1, Op::Write);
+        ops.insert(k2, Op::Add);
+        let mut transforms = AdditiveMap::new();
+        transforms.insert(k1, Transform::Write(Value::Int32(1)));
+        transforms.insert(k2, Transform::AddInt32(1));
+        ExecutionEffect::new(ops, transforms)
+    };
+
+    let result = tc.apply_effect(correlation_id, &effect);
+    assert_matches!(result, Ok(AddResult::TypeMismatch(_)));
+
+    // nothing was applied
+    assert!(tc.fns.is_empty());
+    assert!(tc.ops.is_empty());
+    assert_eq!(
+        tc.read(correlation_id, &k1).unwrap(),
+        Some(Value::String("hello".to_string()))
+    );
+}
+
 #[test]
 fn cache_reads_invalidation() {
     let mut tc_cache = TrackingCopyCache::new(2, Count);
@@ -0,0 +1,14 @@
+use engine_core::engine_state::contract_event::ContractEvent;
+
+use crate::engine_server::ipc;
+
+impl From<ContractEvent> for ipc::ContractEvent {
+    fn from(contract_event: ContractEvent) -> ipc::ContractEvent {
+        let mut pb_contract_event = ipc::ContractEvent::new();
+        pb_contract_event.set_emitter(contract_event.emitter.into());
+        pb_contract_event.set_ordinal(contract_event.ordinal);
+        pb_contract_event.set_name(contract_event.name);
+        pb_contract_event.set_data(contract_event.data);
+        pb_contract_event
+    }
+}
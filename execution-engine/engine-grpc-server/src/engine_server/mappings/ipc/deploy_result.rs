@@ -1,7 +1,7 @@
 use engine_core::{
     engine_state::{
-        execution_effect::ExecutionEffect, execution_result::ExecutionResult,
-        Error as EngineStateError,
+        contract_event::ContractEvent, execution_effect::ExecutionEffect,
+        execution_result::ExecutionResult, Error as EngineStateError,
     },
     execution::Error as ExecutionError,
 };
@@ -11,14 +11,28 @@ use crate::engine_server::ipc::{DeployError_OutOfGasError, DeployResult};
 
 impl From<ExecutionResult> for DeployResult {
     fn from(execution_result: ExecutionResult) -> DeployResult {
-        match execution_result {
-            ExecutionResult::Success { effect, cost } => detail::execution_success(effect, cost),
+        let (mut pb_deploy_result, trace) = match execution_result {
+            ExecutionResult::Success {
+                effect,
+                events,
+                cost,
+                trace,
+            } => (detail::execution_success(effect, events, cost), trace),
             ExecutionResult::Failure {
                 error,
                 effect,
                 cost,
-            } => (error, effect, cost).into(),
+                trace,
+            } => ((error, effect, cost).into(), trace),
+        };
+        if let Some(trace) = trace {
+            if pb_deploy_result.has_execution_result() {
+                pb_deploy_result
+                    .mut_execution_result()
+                    .set_trace(trace.into());
+            }
         }
+        pb_deploy_result
     }
 }
 
@@ -35,7 +49,8 @@ impl From<(EngineStateError, ExecutionEffect, Gas)> for DeployResult {
             | error @ EngineStateError::WasmPreprocessingError(_)
             | error @ EngineStateError::WasmSerializationError(_)
             | error @ EngineStateError::ExecError(ExecutionError::DeploymentAuthorizationFailure)
-            | error @ EngineStateError::AuthorizationError => {
+            | error @ EngineStateError::AuthorizationError
+            | error @ EngineStateError::GasPriceTooLow { .. } => {
                 detail::precondition_error(error.to_string())
             }
             EngineStateError::StorageError(storage_error) => {
@@ -95,12 +110,20 @@ impl From<(ExecutionError, ExecutionEffect, Gas)> for DeployResult {
 }
 
 mod detail {
-    use super::{DeployError_OutOfGasError, DeployResult, ExecutionEffect, Gas};
+    use super::{ContractEvent, DeployError_OutOfGasError, DeployResult, ExecutionEffect, Gas};
 
     /// Constructs an instance of `DeployResult` with no error set, i.e. a successful
-    /// result.
-    pub(super) fn execution_success(effect: ExecutionEffect, cost: Gas) -> DeployResult {
-        deploy_result(DeployErrorType::None, effect, cost)
+    /// result, carrying the events emitted during execution.
+    pub(super) fn execution_success(
+        effect: ExecutionEffect,
+        events: Vec<ContractEvent>,
+        cost: Gas,
+    ) -> DeployResult {
+        let mut pb_deploy_result = deploy_result(DeployErrorType::None, effect, cost);
+        pb_deploy_result
+            .mut_execution_result()
+            .set_events(events.into_iter().map(Into::into).collect());
+        pb_deploy_result
     }
 
     /// Constructs an instance of `DeployResult` with an error set to
@@ -187,9 +210,17 @@ mod tests {
         };
         let execution_effect = ExecutionEffect::new(AdditiveMap::new(), input_transforms.clone());
         let cost = Gas::new(U512::from(123));
+        let event = ContractEvent::new(
+            Key::Hash([2u8; 32]),
+            0,
+            "transfer".to_string(),
+            vec![1, 2, 3],
+        );
         let execution_result = ExecutionResult::Success {
             effect: execution_effect,
+            events: vec![event.clone()],
             cost,
+            trace: None,
         };
         let mut ipc_deploy_result: DeployResult = execution_result.into();
         assert!(ipc_deploy_result.has_execution_result());
@@ -197,6 +228,18 @@ mod tests {
         let execution_cost: U512 = success.take_cost().try_into().expect("should map to U512");
         assert_eq!(execution_cost, cost.value());
 
+        let ipc_events = success.take_events().into_vec();
+        assert_eq!(ipc_events.len(), 1);
+        assert_eq!(ipc_events[0].get_ordinal(), event.ordinal);
+        assert_eq!(ipc_events[0].get_name(), event.name);
+        assert_eq!(ipc_events[0].get_data(), event.data.as_slice());
+        let emitter: Key = ipc_events[0]
+            .get_emitter()
+            .clone()
+            .try_into()
+            .expect("should map to Key");
+        assert_eq!(emitter, event.emitter);
+
         // Extract transform map from the IPC message and parse it back to the domain
         let ipc_transforms: AdditiveMap<Key, Transform> = {
             let mut ipc_effects = success.take_effects();
@@ -215,6 +258,7 @@ mod tests {
             error: error.into(),
             effect: Default::default(),
             cost: expected_cost,
+            trace: None,
         };
         let mut ipc_deploy_result: DeployResult = execution_failure.into();
         assert!(ipc_deploy_result.has_execution_result());
@@ -258,6 +302,7 @@ mod tests {
             error: EngineStateError::ExecError(revert_error),
             effect: Default::default(),
             cost: Gas::new(amount),
+            trace: None,
         };
         let mut ipc_result: DeployResult = exec_result.into();
         assert!(
@@ -14,18 +14,21 @@ impl From<DeployPayload_oneof_payload> for ExecutableDeployItem {
             DeployPayload_oneof_payload::stored_contract_hash(pb_stored_contract_hash) => {
                 ExecutableDeployItem::StoredContractByHash {
                     hash: pb_stored_contract_hash.hash,
+                    entry_point_name: pb_stored_contract_hash.entry_point_name,
                     args: pb_stored_contract_hash.args,
                 }
             }
             DeployPayload_oneof_payload::stored_contract_name(pb_stored_contract_name) => {
                 ExecutableDeployItem::StoredContractByName {
                     name: pb_stored_contract_name.stored_contract_name,
+                    entry_point_name: pb_stored_contract_name.entry_point_name,
                     args: pb_stored_contract_name.args,
                 }
             }
             DeployPayload_oneof_payload::stored_contract_uref(pb_stored_contract_uref) => {
                 ExecutableDeployItem::StoredContractByURef {
                     uref: pb_stored_contract_uref.uref,
+                    entry_point_name: pb_stored_contract_uref.entry_point_name,
                     args: pb_stored_contract_uref.args,
                 }
             }
@@ -0,0 +1,56 @@
+use engine_core::execution::{ExecutionTrace, TraceEntry};
+
+use crate::engine_server::ipc::{self, ExecutionTrace_Entry};
+
+impl From<TraceEntry> for ExecutionTrace_Entry {
+    fn from(trace_entry: TraceEntry) -> ExecutionTrace_Entry {
+        let mut pb_entry = ExecutionTrace_Entry::new();
+        match trace_entry {
+            TraceEntry::Call { depth, key } => {
+                let pb_call = pb_entry.mut_call();
+                pb_call.set_depth(depth as u32);
+                pb_call.set_key(key.into());
+            }
+            TraceEntry::HostCall {
+                depth,
+                name,
+                args,
+                gas_before,
+                gas_after,
+                result,
+            } => {
+                let pb_host_call = pb_entry.mut_host_call();
+                pb_host_call.set_depth(depth as u32);
+                pb_host_call.set_name(name.to_string());
+                pb_host_call.set_args(args);
+                pb_host_call.set_gas_before(gas_before.value().into());
+                pb_host_call.set_gas_after(gas_after.value().into());
+                pb_host_call.set_result(result);
+            }
+            TraceEntry::Trap {
+                depth,
+                key,
+                message,
+            } => {
+                let pb_trap = pb_entry.mut_trap();
+                pb_trap.set_depth(depth as u32);
+                pb_trap.set_key(key.into());
+                pb_trap.set_message(message);
+            }
+        }
+        pb_entry
+    }
+}
+
+impl From<ExecutionTrace> for ipc::ExecutionTrace {
+    fn from(execution_trace: ExecutionTrace) -> ipc::ExecutionTrace {
+        let mut pb_execution_trace = ipc::ExecutionTrace::new();
+        let pb_entries: Vec<ExecutionTrace_Entry> = execution_trace
+            .into_entries()
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        pb_execution_trace.set_entries(pb_entries.into());
+        pb_execution_trace
+    }
+}
@@ -30,6 +30,16 @@ impl From<GenesisConfig> for ChainSpec_GenesisConfig {
             .mut_costs()
             .set_wasm(genesis_config.wasm_costs().into());
         pb_genesis_config
+            .mut_costs()
+            .set_host(genesis_config.host_function_costs().into());
+        pb_genesis_config
+            .mut_costs()
+            .set_min_gas_price(genesis_config.min_gas_price());
+        pb_genesis_config
+            .mut_costs()
+            .set_storage_cost_per_byte(genesis_config.storage_cost_per_byte());
+        pb_genesis_config.set_pos_params(genesis_config.proof_of_stake_params().into());
+        pb_genesis_config
     }
 }
 
@@ -45,7 +55,20 @@ impl TryFrom<ChainSpec_GenesisConfig> for GenesisConfig {
             .into_iter()
             .map(TryInto::try_into)
             .collect::<Result<Vec<GenesisAccount>, Self::Error>>()?;
-        let wasm_costs = pb_genesis_config.take_costs().take_wasm().into();
+        let (wasm_costs, host_function_costs, min_gas_price, storage_cost_per_byte) = {
+            let mut pb_costs = pb_genesis_config.take_costs();
+            (
+                pb_costs.take_wasm().into(),
+                pb_costs.take_host().into(),
+                pb_costs.get_min_gas_price(),
+                pb_costs.get_storage_cost_per_byte(),
+            )
+        };
+        let proof_of_stake_params = if pb_genesis_config.has_pos_params() {
+            pb_genesis_config.take_pos_params().try_into()?
+        } else {
+            Default::default()
+        };
         let mint_initializer_bytes = pb_genesis_config.mint_installer;
         let proof_of_stake_initializer_bytes = pb_genesis_config.pos_installer;
         Ok(GenesisConfig::new(
@@ -56,6 +79,10 @@ impl TryFrom<ChainSpec_GenesisConfig> for GenesisConfig {
             proof_of_stake_initializer_bytes,
             accounts,
             wasm_costs,
+            host_function_costs,
+            min_gas_price,
+            storage_cost_per_byte,
+            proof_of_stake_params,
         ))
     }
 }
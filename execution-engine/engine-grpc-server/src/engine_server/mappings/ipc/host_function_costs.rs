@@ -0,0 +1,111 @@
+use engine_wasm_prep::host_function_costs::{HostFunctionCost, HostFunctionCosts};
+
+use crate::engine_server::ipc::{
+    ChainSpec_CostTable_HostFunctionCost, ChainSpec_CostTable_HostFunctionCosts,
+};
+
+impl From<HostFunctionCost> for ChainSpec_CostTable_HostFunctionCost {
+    fn from(host_function_cost: HostFunctionCost) -> Self {
+        ChainSpec_CostTable_HostFunctionCost {
+            base: host_function_cost.base,
+            per_byte: host_function_cost.per_byte,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<ChainSpec_CostTable_HostFunctionCost> for HostFunctionCost {
+    fn from(pb_host_function_cost: ChainSpec_CostTable_HostFunctionCost) -> Self {
+        HostFunctionCost {
+            base: pb_host_function_cost.base,
+            per_byte: pb_host_function_cost.per_byte,
+        }
+    }
+}
+
+impl From<HostFunctionCosts> for ChainSpec_CostTable_HostFunctionCosts {
+    fn from(host_function_costs: HostFunctionCosts) -> Self {
+        let mut pb_host_function_costs = ChainSpec_CostTable_HostFunctionCosts::new();
+        pb_host_function_costs.set_read_value(host_function_costs.read_value.into());
+        pb_host_function_costs.set_write(host_function_costs.write.into());
+        pb_host_function_costs.set_add(host_function_costs.add.into());
+        pb_host_function_costs.set_new_uref(host_function_costs.new_uref.into());
+        pb_host_function_costs.set_get_arg(host_function_costs.get_arg.into());
+        pb_host_function_costs.set_ret(host_function_costs.ret.into());
+        pb_host_function_costs.set_call_contract(host_function_costs.call_contract.into());
+        pb_host_function_costs.set_get_key(host_function_costs.get_key.into());
+        pb_host_function_costs.set_has_key(host_function_costs.has_key.into());
+        pb_host_function_costs.set_put_key(host_function_costs.put_key.into());
+        pb_host_function_costs.set_remove_key(host_function_costs.remove_key.into());
+        pb_host_function_costs.set_list_named_keys(host_function_costs.list_named_keys.into());
+        pb_host_function_costs.set_store_function(host_function_costs.store_function.into());
+        pb_host_function_costs
+            .set_upgrade_contract_at_uref(host_function_costs.upgrade_contract_at_uref.into());
+        pb_host_function_costs.set_manage_keys(host_function_costs.manage_keys.into());
+        pb_host_function_costs.set_create_purse(host_function_costs.create_purse.into());
+        pb_host_function_costs.set_transfer(host_function_costs.transfer.into());
+        pb_host_function_costs.set_get_balance(host_function_costs.get_balance.into());
+        pb_host_function_costs.set_host_buffer(host_function_costs.host_buffer.into());
+        pb_host_function_costs.set_context(host_function_costs.context.into());
+        pb_host_function_costs.set_emit_event(host_function_costs.emit_event.into());
+        pb_host_function_costs.set_get_call_stack(host_function_costs.get_call_stack.into());
+        pb_host_function_costs.set_delete(host_function_costs.delete.into());
+        pb_host_function_costs.set_schedule_call(host_function_costs.schedule_call.into());
+        pb_host_function_costs.set_call_entry_point(host_function_costs.call_entry_point.into());
+        pb_host_function_costs
+    }
+}
+
+impl From<ChainSpec_CostTable_HostFunctionCosts> for HostFunctionCosts {
+    fn from(mut pb_host_function_costs: ChainSpec_CostTable_HostFunctionCosts) -> Self {
+        HostFunctionCosts {
+            read_value: pb_host_function_costs.take_read_value().into(),
+            write: pb_host_function_costs.take_write().into(),
+            add: pb_host_function_costs.take_add().into(),
+            new_uref: pb_host_function_costs.take_new_uref().into(),
+            get_arg: pb_host_function_costs.take_get_arg().into(),
+            ret: pb_host_function_costs.take_ret().into(),
+            call_contract: pb_host_function_costs.take_call_contract().into(),
+            get_key: pb_host_function_costs.take_get_key().into(),
+            has_key: pb_host_function_costs.take_has_key().into(),
+            put_key: pb_host_function_costs.take_put_key().into(),
+            remove_key: pb_host_function_costs.take_remove_key().into(),
+            list_named_keys: pb_host_function_costs.take_list_named_keys().into(),
+            store_function: pb_host_function_costs.take_store_function().into(),
+            upgrade_contract_at_uref: pb_host_function_costs
+                .take_upgrade_contract_at_uref()
+                .into(),
+            manage_keys: pb_host_function_costs.take_manage_keys().into(),
+            create_purse: pb_host_function_costs.take_create_purse().into(),
+            transfer: pb_host_function_costs.take_transfer().into(),
+            get_balance: pb_host_function_costs.take_get_balance().into(),
+            host_buffer: pb_host_function_costs.take_host_buffer().into(),
+            context: pb_host_function_costs.take_context().into(),
+            emit_event: pb_host_function_costs.take_emit_event().into(),
+            get_call_stack: pb_host_function_costs.take_get_call_stack().into(),
+            delete: pb_host_function_costs.take_delete().into(),
+            schedule_call: pb_host_function_costs.take_schedule_call().into(),
+            call_entry_point: pb_host_function_costs.take_call_entry_point().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use engine_wasm_prep::host_function_costs::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(host_function_costs in gens::host_function_costs_arb()) {
+            test_utils::protobuf_round_trip::<
+                HostFunctionCosts,
+                ChainSpec_CostTable_HostFunctionCosts,
+            >(host_function_costs);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::list_keys::{KeyKind, ListKeysRequest};
+use engine_shared::newtypes::BLAKE2B_DIGEST_LENGTH;
+
+use crate::engine_server::{ipc, mappings::MappingError};
+
+fn key_kind(pb_key_kind: ipc::ListKeysRequest_KeyKind) -> Option<KeyKind> {
+    match pb_key_kind {
+        ipc::ListKeysRequest_KeyKind::ANY => None,
+        ipc::ListKeysRequest_KeyKind::ACCOUNT => Some(KeyKind::Account),
+        ipc::ListKeysRequest_KeyKind::HASH => Some(KeyKind::Hash),
+        ipc::ListKeysRequest_KeyKind::UREF => Some(KeyKind::URef),
+        ipc::ListKeysRequest_KeyKind::LOCAL => Some(KeyKind::Local),
+    }
+}
+
+impl TryFrom<ipc::ListKeysRequest> for ListKeysRequest {
+    type Error = MappingError;
+
+    fn try_from(mut list_keys_request: ipc::ListKeysRequest) -> Result<Self, Self::Error> {
+        let state_hash = {
+            let state_hash = list_keys_request.get_state_hash();
+            let length = state_hash.len();
+            if length != BLAKE2B_DIGEST_LENGTH {
+                return Err(MappingError::InvalidStateHashLength {
+                    expected: BLAKE2B_DIGEST_LENGTH,
+                    actual: length,
+                });
+            }
+            state_hash
+                .try_into()
+                .map_err(|_| MappingError::TryFromSliceError)?
+        };
+
+        let key_kind = key_kind(list_keys_request.get_key_kind());
+
+        let prefix = list_keys_request.take_prefix();
+
+        let cursor = if list_keys_request.has_cursor() {
+            let cursor = list_keys_request
+                .take_cursor()
+                .try_into()
+                .map_err(MappingError::ParsingError)?;
+            Some(cursor)
+        } else {
+            None
+        };
+
+        let limit = match list_keys_request.get_limit() {
+            0 => None,
+            limit => Some(limit as usize),
+        };
+
+        Ok(ListKeysRequest::new(
+            state_hash, key_kind, prefix, cursor, limit,
+        ))
+    }
+}
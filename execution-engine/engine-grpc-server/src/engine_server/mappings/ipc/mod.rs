@@ -2,12 +2,19 @@
 //! defined in protobuf/io/casperlabs/ipc/ipc.proto
 
 mod bond;
+mod contract_event;
 mod deploy_item;
 mod deploy_result;
 mod executable_deploy_item;
 mod execution_effect;
+mod execution_trace;
 mod genesis_account;
 mod genesis_config;
+mod host_function_costs;
+mod list_keys_request;
+mod proof_of_stake_params;
 mod query_request;
+mod trie_merkle_proof;
+mod unbond_payout;
 mod upgrade_request;
 mod wasm_costs;
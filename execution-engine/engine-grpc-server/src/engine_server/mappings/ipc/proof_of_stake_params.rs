@@ -0,0 +1,89 @@
+use std::convert::{TryFrom, TryInto};
+
+use contract_ffi::system_contracts::pos::Params;
+
+use crate::engine_server::{ipc::ChainSpec_ProofOfStakeParams, mappings::MappingError};
+
+impl From<Params> for ChainSpec_ProofOfStakeParams {
+    fn from(params: Params) -> Self {
+        let mut pb_params = ChainSpec_ProofOfStakeParams::new();
+
+        pb_params.set_bond_delay(params.bond_delay);
+        pb_params.set_unbond_delay(params.unbond_delay);
+        pb_params.set_max_bond_len(params.max_bond_len);
+        pb_params.set_max_unbond_len(params.max_unbond_len);
+        pb_params.set_max_spread(params.max_spread.into());
+        pb_params.set_max_increase(params.max_increase.into());
+        pb_params.set_max_decrease(params.max_decrease.into());
+        pb_params.set_max_rel_increase(params.max_rel_increase);
+        pb_params.set_max_rel_decrease(params.max_rel_decrease);
+        pb_params.set_proposer_reward_share(params.proposer_reward_share);
+        pb_params.set_commission_rate(params.commission_rate);
+
+        pb_params
+    }
+}
+
+impl TryFrom<ChainSpec_ProofOfStakeParams> for Params {
+    type Error = MappingError;
+
+    fn try_from(mut pb_params: ChainSpec_ProofOfStakeParams) -> Result<Self, Self::Error> {
+        let params = Params {
+            bond_delay: pb_params.get_bond_delay(),
+            unbond_delay: pb_params.get_unbond_delay(),
+            max_bond_len: pb_params.get_max_bond_len(),
+            max_unbond_len: pb_params.get_max_unbond_len(),
+            max_spread: pb_params.take_max_spread().try_into()?,
+            max_increase: pb_params.take_max_increase().try_into()?,
+            max_decrease: pb_params.take_max_decrease().try_into()?,
+            max_rel_increase: pb_params.get_max_rel_increase(),
+            max_rel_decrease: pb_params.get_max_rel_decrease(),
+            proposer_reward_share: pb_params.get_proposer_reward_share(),
+            commission_rate: pb_params.get_commission_rate(),
+        };
+        params
+            .validate()
+            .map_err(|_| MappingError::InvalidProofOfStakeParams)?;
+        Ok(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use contract_ffi::value::U512;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    #[test]
+    fn round_trip() {
+        test_utils::protobuf_round_trip::<Params, ChainSpec_ProofOfStakeParams>(Params::default());
+        let params = Params {
+            bond_delay: 1,
+            unbond_delay: 2,
+            max_bond_len: 3,
+            max_unbond_len: 4,
+            max_spread: U512::from(5),
+            max_increase: U512::from(6),
+            max_decrease: U512::from(7),
+            max_rel_increase: 8,
+            max_rel_decrease: 9,
+            proposer_reward_share: 10,
+            commission_rate: 11,
+        };
+        test_utils::protobuf_round_trip::<Params, ChainSpec_ProofOfStakeParams>(params);
+    }
+
+    #[test]
+    fn should_reject_invalid_params() {
+        let params = Params {
+            commission_rate: 1_000_001,
+            ..Default::default()
+        };
+        let pb_params = ChainSpec_ProofOfStakeParams::from(params);
+        match Params::try_from(pb_params) {
+            Err(MappingError::InvalidProofOfStakeParams) => {}
+            result => panic!("expected invalid params error, got {:?}", result),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::convert::{TryFrom, TryInto};
+
+use contract_ffi::{
+    bytesrepr::{self, ToBytes},
+    key::Key,
+    value::Value,
+};
+use engine_storage::trie::{merkle_proof::TrieMerkleProof, Trie};
+
+use crate::engine_server::{ipc, mappings::ParsingError};
+
+impl TryFrom<TrieMerkleProof<Key, Value>> for ipc::TrieMerkleProof {
+    type Error = ParsingError;
+
+    fn try_from(proof: TrieMerkleProof<Key, Value>) -> Result<Self, Self::Error> {
+        let mut pb_proof = ipc::TrieMerkleProof::new();
+        pb_proof.set_key((*proof.key()).into());
+        let trie_elements = proof
+            .path()
+            .iter()
+            .map(ToBytes::to_bytes)
+            .collect::<Result<Vec<Vec<u8>>, bytesrepr::Error>>()?;
+        pb_proof.set_trie_elements(trie_elements.into());
+        Ok(pb_proof)
+    }
+}
+
+impl TryFrom<ipc::TrieMerkleProof> for TrieMerkleProof<Key, Value> {
+    type Error = ParsingError;
+
+    fn try_from(mut pb_proof: ipc::TrieMerkleProof) -> Result<Self, Self::Error> {
+        let key = pb_proof.take_key().try_into()?;
+        let path = pb_proof
+            .take_trie_elements()
+            .iter()
+            .map(|trie_bytes| bytesrepr::deserialize(trie_bytes))
+            .collect::<Result<Vec<Trie<Key, Value>>, bytesrepr::Error>>()?;
+        Ok(TrieMerkleProof::new(key, path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use engine_shared::newtypes::Blake2bHash;
+    use engine_storage::trie::Pointer;
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let key = Key::Account([1u8; 32]);
+        let leaf = Trie::leaf(key, Value::Int32(1));
+        let leaf_hash = Blake2bHash::new(&leaf.to_bytes().unwrap());
+        let root = Trie::node(&[(0, Pointer::LeafPointer(leaf_hash))]);
+        let proof = TrieMerkleProof::new(key, vec![root, leaf]);
+
+        let pb_proof = ipc::TrieMerkleProof::try_from(proof.clone()).unwrap();
+        let parsed = TrieMerkleProof::try_from(pb_proof).unwrap();
+        assert_eq!(proof, parsed);
+    }
+}
@@ -0,0 +1,46 @@
+use std::convert::{TryFrom, TryInto};
+
+use contract_ffi::system_contracts::pos::UnbondPayout;
+
+use crate::engine_server::{ipc, mappings::MappingError};
+
+impl From<UnbondPayout> for ipc::UnbondPayout {
+    fn from(unbond_payout: UnbondPayout) -> Self {
+        let mut pb_unbond_payout = ipc::UnbondPayout::new();
+        pb_unbond_payout.set_public_key(unbond_payout.account.to_vec());
+        pb_unbond_payout.set_amount(unbond_payout.amount.into());
+        pb_unbond_payout
+    }
+}
+
+impl TryFrom<ipc::UnbondPayout> for UnbondPayout {
+    type Error = MappingError;
+
+    fn try_from(mut pb_unbond_payout: ipc::UnbondPayout) -> Result<Self, Self::Error> {
+        let account = pb_unbond_payout.get_public_key().try_into().map_err(|_| {
+            MappingError::invalid_public_key_length(pb_unbond_payout.public_key.len())
+        })?;
+
+        let amount = pb_unbond_payout.take_amount().try_into()?;
+
+        Ok(UnbondPayout::new(account, amount))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use contract_ffi::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(public_key in gens::public_key_arb(), u512 in gens::u512_arb()) {
+            let unbond_payout = UnbondPayout::new(public_key, u512);
+            test_utils::protobuf_round_trip::<UnbondPayout, ipc::UnbondPayout>(unbond_payout);
+        }
+    }
+}
@@ -30,10 +30,40 @@ impl TryFrom<UpgradeRequest> for UpgradeConfig {
                 (bytes, args)
             };
 
-        let wasm_costs = if !upgrade_point.has_new_costs() {
+        let (wasm_costs, host_function_costs, min_gas_price, storage_cost_per_byte) =
+            if !upgrade_point.has_new_costs() {
+                (None, None, None, None)
+            } else {
+                let new_costs = upgrade_point.mut_new_costs();
+                let wasm_costs = if !new_costs.has_wasm() {
+                    None
+                } else {
+                    Some(new_costs.take_wasm().into())
+                };
+                let host_function_costs = if !new_costs.has_host() {
+                    None
+                } else {
+                    Some(new_costs.take_host().into())
+                };
+                let min_gas_price = match new_costs.get_min_gas_price() {
+                    0 => None,
+                    min_gas_price => Some(min_gas_price),
+                };
+                let storage_cost_per_byte = match new_costs.get_storage_cost_per_byte() {
+                    0 => None,
+                    storage_cost_per_byte => Some(storage_cost_per_byte),
+                };
+                (
+                    wasm_costs,
+                    host_function_costs,
+                    min_gas_price,
+                    storage_cost_per_byte,
+                )
+            };
+        let proof_of_stake_params = if !upgrade_point.has_new_pos_params() {
             None
         } else {
-            Some(upgrade_point.mut_new_costs().take_wasm().into())
+            Some(upgrade_point.take_new_pos_params().try_into()?)
         };
         let activation_point = if !upgrade_point.has_activation_point() {
             None
@@ -48,6 +78,10 @@ impl TryFrom<UpgradeRequest> for UpgradeConfig {
             upgrade_installer_args,
             upgrade_installer_bytes,
             wasm_costs,
+            host_function_costs,
+            min_gas_price,
+            storage_cost_per_byte,
+            proof_of_stake_params,
             activation_point,
         ))
     }
@@ -32,6 +32,7 @@ pub enum MappingError {
     InvalidStateHash(String),
     MissingPayload,
     TryFromSliceError,
+    InvalidProofOfStakeParams,
 }
 
 impl MappingError {
@@ -88,6 +89,9 @@ impl Display for MappingError {
             MappingError::InvalidStateHash(message) => write!(f, "Invalid hash: {}", message),
             MappingError::MissingPayload => write!(f, "Missing payload"),
             MappingError::TryFromSliceError => write!(f, "Unable to convert from slice"),
+            MappingError::InvalidProofOfStakeParams => {
+                write!(f, "Invalid proof-of-stake params")
+            }
         }
     }
 }
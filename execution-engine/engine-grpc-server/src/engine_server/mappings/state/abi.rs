@@ -0,0 +1,228 @@
+use std::convert::{TryFrom, TryInto};
+
+use contract_ffi::{
+    key::Key,
+    uref::URef,
+    value::abi::{AbiType, EntryPoint, EntryPointAccess, Parameter},
+};
+
+use crate::engine_server::{
+    mappings::ParsingError,
+    state::{
+        self, AbiType_List, AbiType_Map, AbiType_Option, AbiType_Simple, AbiType_oneof_variants,
+        Contract_Access, Contract_Access_AccountOnly, Contract_Access_Callers,
+        Contract_Access_Groups, Contract_Access_Public, Contract_Access_oneof_variants,
+        Contract_EntryPoint, Contract_Parameter,
+    },
+};
+
+impl From<AbiType> for state::AbiType {
+    fn from(abi_type: AbiType) -> Self {
+        let mut pb_abi_type = state::AbiType::new();
+        match abi_type {
+            AbiType::I32 => pb_abi_type.set_simple_type(AbiType_Simple::I32),
+            AbiType::U8 => pb_abi_type.set_simple_type(AbiType_Simple::U8),
+            AbiType::U32 => pb_abi_type.set_simple_type(AbiType_Simple::U32),
+            AbiType::U64 => pb_abi_type.set_simple_type(AbiType_Simple::U64),
+            AbiType::U128 => pb_abi_type.set_simple_type(AbiType_Simple::U128),
+            AbiType::U256 => pb_abi_type.set_simple_type(AbiType_Simple::U256),
+            AbiType::U512 => pb_abi_type.set_simple_type(AbiType_Simple::U512),
+            AbiType::Unit => pb_abi_type.set_simple_type(AbiType_Simple::UNIT),
+            AbiType::String => pb_abi_type.set_simple_type(AbiType_Simple::STRING),
+            AbiType::Key => pb_abi_type.set_simple_type(AbiType_Simple::KEY),
+            AbiType::URef => pb_abi_type.set_simple_type(AbiType_Simple::UREF),
+            AbiType::PublicKey => pb_abi_type.set_simple_type(AbiType_Simple::PUBLIC_KEY),
+            AbiType::Value => pb_abi_type.set_simple_type(AbiType_Simple::VALUE),
+            AbiType::Any => pb_abi_type.set_simple_type(AbiType_Simple::ANY),
+            AbiType::List(item_type) => {
+                let mut pb_list = AbiType_List::new();
+                pb_list.set_item((*item_type).into());
+                pb_abi_type.set_list_type(pb_list);
+            }
+            AbiType::Option(inner_type) => {
+                let mut pb_option = AbiType_Option::new();
+                pb_option.set_inner((*inner_type).into());
+                pb_abi_type.set_option_type(pb_option);
+            }
+            AbiType::Map(key_type, value_type) => {
+                let mut pb_map = AbiType_Map::new();
+                pb_map.set_key((*key_type).into());
+                pb_map.set_value((*value_type).into());
+                pb_abi_type.set_map_type(pb_map);
+            }
+        }
+        pb_abi_type
+    }
+}
+
+impl TryFrom<state::AbiType> for AbiType {
+    type Error = ParsingError;
+
+    fn try_from(pb_abi_type: state::AbiType) -> Result<Self, Self::Error> {
+        let pb_abi_type = pb_abi_type
+            .variants
+            .ok_or_else(|| ParsingError::from("Unable to parse Protobuf AbiType"))?;
+
+        let abi_type = match pb_abi_type {
+            AbiType_oneof_variants::simple_type(simple_type) => match simple_type {
+                AbiType_Simple::I32 => AbiType::I32,
+                AbiType_Simple::U8 => AbiType::U8,
+                AbiType_Simple::U32 => AbiType::U32,
+                AbiType_Simple::U64 => AbiType::U64,
+                AbiType_Simple::U128 => AbiType::U128,
+                AbiType_Simple::U256 => AbiType::U256,
+                AbiType_Simple::U512 => AbiType::U512,
+                AbiType_Simple::UNIT => AbiType::Unit,
+                AbiType_Simple::STRING => AbiType::String,
+                AbiType_Simple::KEY => AbiType::Key,
+                AbiType_Simple::UREF => AbiType::URef,
+                AbiType_Simple::PUBLIC_KEY => AbiType::PublicKey,
+                AbiType_Simple::VALUE => AbiType::Value,
+                AbiType_Simple::ANY => AbiType::Any,
+            },
+            AbiType_oneof_variants::list_type(mut pb_list) => {
+                AbiType::List(Box::new(pb_list.take_item().try_into()?))
+            }
+            AbiType_oneof_variants::option_type(mut pb_option) => {
+                AbiType::Option(Box::new(pb_option.take_inner().try_into()?))
+            }
+            AbiType_oneof_variants::map_type(mut pb_map) => AbiType::Map(
+                Box::new(pb_map.take_key().try_into()?),
+                Box::new(pb_map.take_value().try_into()?),
+            ),
+        };
+        Ok(abi_type)
+    }
+}
+
+impl From<Parameter> for Contract_Parameter {
+    fn from(parameter: Parameter) -> Self {
+        let mut pb_parameter = Contract_Parameter::new();
+        pb_parameter.set_name(parameter.name().to_string());
+        pb_parameter.set_abi_type(parameter.abi_type().clone().into());
+        pb_parameter
+    }
+}
+
+impl TryFrom<Contract_Parameter> for Parameter {
+    type Error = ParsingError;
+
+    fn try_from(mut pb_parameter: Contract_Parameter) -> Result<Self, Self::Error> {
+        let abi_type = pb_parameter.take_abi_type().try_into()?;
+        Ok(Parameter::new(pb_parameter.get_name(), abi_type))
+    }
+}
+
+impl From<EntryPointAccess> for Contract_Access {
+    fn from(access: EntryPointAccess) -> Self {
+        let mut pb_access = Contract_Access::new();
+        match access {
+            EntryPointAccess::Public => pb_access.set_public(Contract_Access_Public::new()),
+            EntryPointAccess::AccountOnly => {
+                pb_access.set_account_only(Contract_Access_AccountOnly::new())
+            }
+            EntryPointAccess::Callers(callers) => {
+                let mut pb_callers = Contract_Access_Callers::new();
+                let pb_keys: Vec<state::Key> = callers.into_iter().map(Into::into).collect();
+                pb_callers.set_keys(pb_keys.into());
+                pb_access.set_callers(pb_callers);
+            }
+            EntryPointAccess::Groups(groups) => {
+                let mut pb_groups = Contract_Access_Groups::new();
+                let pb_urefs: Vec<state::Key_URef> = groups.into_iter().map(Into::into).collect();
+                pb_groups.set_urefs(pb_urefs.into());
+                pb_access.set_groups(pb_groups);
+            }
+        }
+        pb_access
+    }
+}
+
+impl TryFrom<Contract_Access> for EntryPointAccess {
+    type Error = ParsingError;
+
+    fn try_from(pb_access: Contract_Access) -> Result<Self, Self::Error> {
+        let pb_access = pb_access
+            .variants
+            .ok_or_else(|| ParsingError::from("Unable to parse Protobuf Contract.Access"))?;
+
+        let access = match pb_access {
+            Contract_Access_oneof_variants::public(_) => EntryPointAccess::Public,
+            Contract_Access_oneof_variants::account_only(_) => EntryPointAccess::AccountOnly,
+            Contract_Access_oneof_variants::callers(mut pb_callers) => {
+                let callers = pb_callers
+                    .take_keys()
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<Key>, ParsingError>>()?;
+                EntryPointAccess::Callers(callers)
+            }
+            Contract_Access_oneof_variants::groups(mut pb_groups) => {
+                let groups = pb_groups
+                    .take_urefs()
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<URef>, ParsingError>>()?;
+                EntryPointAccess::Groups(groups)
+            }
+        };
+        Ok(access)
+    }
+}
+
+impl From<(String, EntryPoint)> for Contract_EntryPoint {
+    fn from((name, entry_point): (String, EntryPoint)) -> Self {
+        let mut pb_entry_point = Contract_EntryPoint::new();
+        pb_entry_point.set_name(name);
+        let pb_parameters: Vec<Contract_Parameter> = entry_point
+            .parameters()
+            .iter()
+            .cloned()
+            .map(Into::into)
+            .collect();
+        pb_entry_point.set_parameters(pb_parameters.into());
+        pb_entry_point.set_ret(entry_point.ret().clone().into());
+        pb_entry_point.set_access(entry_point.access().clone().into());
+        pb_entry_point
+    }
+}
+
+impl TryFrom<Contract_EntryPoint> for (String, EntryPoint) {
+    type Error = ParsingError;
+
+    fn try_from(mut pb_entry_point: Contract_EntryPoint) -> Result<Self, Self::Error> {
+        let parameters = pb_entry_point
+            .take_parameters()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<Parameter>, ParsingError>>()?;
+        let ret = pb_entry_point.take_ret().try_into()?;
+        let access = pb_entry_point.take_access().try_into()?;
+        Ok((
+            pb_entry_point.name,
+            EntryPoint::new(parameters, ret).with_access(access),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use contract_ffi::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(abi_type in gens::abi_type_arb()) {
+            test_utils::protobuf_round_trip::<AbiType, state::AbiType>(abi_type);
+        }
+
+        #[test]
+        fn access_round_trip(access in gens::entry_point_access_arb()) {
+            test_utils::protobuf_round_trip::<EntryPointAccess, Contract_Access>(access);
+        }
+    }
+}
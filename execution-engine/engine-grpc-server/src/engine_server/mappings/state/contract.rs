@@ -1,21 +1,31 @@
-use std::convert::{TryFrom, TryInto};
+use std::{
+    collections::BTreeMap,
+    convert::{TryFrom, TryInto},
+};
 
-use contract_ffi::value::Contract;
+use contract_ffi::value::{abi::EntryPoint, Contract};
 
 use super::NamedKeyMap;
 use crate::engine_server::{
     mappings::ParsingError,
-    state::{self, NamedKey},
+    state::{self, Contract_EntryPoint, NamedKey},
 };
 
 impl From<Contract> for state::Contract {
     fn from(contract: Contract) -> Self {
+        let entry_points: Vec<Contract_EntryPoint> = contract
+            .entry_points()
+            .clone()
+            .into_iter()
+            .map(Into::into)
+            .collect();
         let (bytes, named_keys, protocol_version) = contract.destructure();
         let mut pb_contract = state::Contract::new();
         let named_keys: Vec<NamedKey> = NamedKeyMap::new(named_keys).into();
         pb_contract.set_body(bytes);
         pb_contract.set_named_keys(named_keys.into());
         pb_contract.set_protocol_version(protocol_version.into());
+        pb_contract.set_entry_points(entry_points.into());
         pb_contract
     }
 }
@@ -26,7 +36,13 @@ impl TryFrom<state::Contract> for Contract {
     fn try_from(mut pb_contract: state::Contract) -> Result<Self, Self::Error> {
         let named_keys: NamedKeyMap = pb_contract.take_named_keys().into_vec().try_into()?;
         let protocol_version = pb_contract.take_protocol_version().into();
-        let contract = Contract::new(pb_contract.body, named_keys.into_inner(), protocol_version);
+        let entry_points = pb_contract
+            .take_entry_points()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<BTreeMap<String, EntryPoint>, ParsingError>>()?;
+        let contract = Contract::new(pb_contract.body, named_keys.into_inner(), protocol_version)
+            .with_entry_points(entry_points);
         Ok(contract)
     }
 }
@@ -1,6 +1,7 @@
 //! Functions for converting between CasperLabs types and their Protobuf equivalents which are
 //! defined in protobuf/io/casperlabs/casper/consensus/state.proto
 
+mod abi;
 mod account;
 mod big_int;
 mod contract;
@@ -25,6 +25,9 @@ impl From<Transform> for transforms::Transform {
             Transform::Write(value) => {
                 pb_transform.mut_write().set_value(value.into());
             }
+            Transform::Delete => {
+                pb_transform.set_delete(Default::default());
+            }
             Transform::AddKeys(keys_map) => {
                 let pb_named_keys: Vec<NamedKey> = NamedKeyMap::new(keys_map).into();
                 pb_transform.mut_add_keys().set_value(pb_named_keys.into());
@@ -77,6 +80,7 @@ impl TryFrom<transforms::Transform> for Transform {
                 let value = Value::try_from(pb_write.take_value())?;
                 Transform::Write(value)
             }
+            Transform_oneof_transform_instance::delete(_) => Transform::Delete,
             Transform_oneof_transform_instance::failure(pb_failure) => {
                 let error = TransformError::try_from(pb_failure)?;
                 Transform::Failure(error)
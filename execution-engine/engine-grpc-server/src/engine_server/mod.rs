@@ -9,19 +9,24 @@ use std::{
     convert::{TryFrom, TryInto},
     fmt::Debug,
     io::ErrorKind,
-    iter::FromIterator,
+    iter::{self, FromIterator},
     marker::{Send, Sync},
     time::Instant,
 };
 
-use grpc::{RequestOptions, ServerBuilder, SingleResponse};
+use grpc::{RequestOptions, ServerBuilder, SingleResponse, StreamingResponse};
 
-use contract_ffi::value::{account::BlockTime, ProtocolVersion};
+use contract_ffi::value::{
+    account::{BlockTime, PublicKey},
+    ProtocolVersion,
+};
 use engine_core::{
     engine_state::{
         deploy_item::DeployItem,
+        error::RootNotFound,
         execution_result::ExecutionResult,
         genesis::{GenesisConfig, GenesisResult},
+        list_keys::{ListKeysRequest, ListKeysResult},
         query::{QueryRequest, QueryResult},
         upgrade::{UpgradeConfig, UpgradeResult},
         EngineState, Error as EngineError,
@@ -32,13 +37,19 @@ use engine_shared::{
     logging::{self, log_duration, log_info, log_level::LogLevel},
     newtypes::{Blake2bHash, CorrelationId, BLAKE2B_DIGEST_LENGTH},
 };
-use engine_storage::global_state::{CommitResult, StateProvider};
+use engine_storage::{
+    global_state::{CommitResult, StateProvider},
+    trie_store::{DiffEntry, DiffResult, PruneResult},
+};
 use engine_wasm_prep::Preprocessor;
 
 use self::{
     ipc::{
-        ChainSpec_GenesisConfig, CommitRequest, CommitResponse, ExecuteRequest, ExecuteResponse,
-        GenesisResponse, QueryResponse, UpgradeRequest, UpgradeResponse,
+        ChainSpec_GenesisConfig, CommitRequest, CommitResponse, DistributeRewardsRequest,
+        EstimateRequest, EstimateResponse, ExecuteRequest, ExecuteResponse, GenesisResponse,
+        ListKeysResponse, PruneRequest, PruneResponse, QueryResponse, RunScheduledCallsRequest,
+        SlashRequest, StateDiffRequest, StateDiffResponse, StateDiffResponse_Entry, StepRequest,
+        UpgradeRequest, UpgradeResponse,
     },
     ipc_grpc::{ExecutionEngineService, ExecutionEngineServiceServer},
     mappings::{MappingError, ParsingError, TransformMap},
@@ -49,12 +60,28 @@ const METRIC_DURATION_EXEC: &str = "exec_duration";
 const METRIC_DURATION_QUERY: &str = "query_duration";
 const METRIC_DURATION_GENESIS: &str = "genesis_duration";
 const METRIC_DURATION_UPGRADE: &str = "upgrade_duration";
+const METRIC_DURATION_LIST_KEYS: &str = "list_keys_duration";
+const METRIC_DURATION_PRUNE: &str = "prune_duration";
+const METRIC_DURATION_STATE_DIFF: &str = "state_diff_duration";
+const METRIC_DURATION_ESTIMATE: &str = "estimate_duration";
+const METRIC_DURATION_RUN_SCHEDULED_CALLS: &str = "run_scheduled_calls_duration";
+const METRIC_DURATION_DISTRIBUTE_REWARDS: &str = "distribute_rewards_duration";
+const METRIC_DURATION_SLASH: &str = "slash_duration";
+const METRIC_DURATION_STEP: &str = "step_duration";
 
 const TAG_RESPONSE_COMMIT: &str = "commit_response";
 const TAG_RESPONSE_EXEC: &str = "exec_response";
 const TAG_RESPONSE_QUERY: &str = "query_response";
 const TAG_RESPONSE_GENESIS: &str = "genesis_response";
 const TAG_RESPONSE_UPGRADE: &str = "upgrade_response";
+const TAG_RESPONSE_LIST_KEYS: &str = "list_keys_response";
+const TAG_RESPONSE_PRUNE: &str = "prune_response";
+const TAG_RESPONSE_STATE_DIFF: &str = "state_diff_response";
+const TAG_RESPONSE_ESTIMATE: &str = "estimate_response";
+const TAG_RESPONSE_RUN_SCHEDULED_CALLS: &str = "run_scheduled_calls_response";
+const TAG_RESPONSE_DISTRIBUTE_REWARDS: &str = "distribute_rewards_response";
+const TAG_RESPONSE_SLASH: &str = "slash_response";
+const TAG_RESPONSE_STEP: &str = "step_response";
 
 const DEFAULT_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
 
@@ -65,7 +92,7 @@ const DEFAULT_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
 // (outer layer) leading to cleaner design.
 impl<S> ExecutionEngineService for EngineState<S>
 where
-    S: StateProvider,
+    S: StateProvider + Sync,
     EngineError: From<S::Error>,
     S::Error: Into<engine_core::execution::Error> + Debug,
 {
@@ -77,6 +104,8 @@ where
         let start = Instant::now();
         let correlation_id = CorrelationId::new();
 
+        let include_proofs = query_request.get_include_proofs();
+
         let request: QueryRequest = match query_request.try_into() {
             Ok(ret) => ret,
             Err(err) => {
@@ -94,9 +123,16 @@ where
             }
         };
 
-        let result = self.run_query(correlation_id, request);
+        let (result, proofs) = if include_proofs {
+            match self.run_query_with_proofs(correlation_id, request) {
+                Ok((query_result, proofs)) => (Ok(query_result), proofs),
+                Err(error) => (Err(error), Vec::new()),
+            }
+        } else {
+            (self.run_query(correlation_id, request), Vec::new())
+        };
 
-        let response = match result {
+        let mut response = match result {
             Ok(QueryResult::Success(value)) => {
                 let log_message = format!("query successful; correlation_id: {}", correlation_id);
                 log_info(&log_message);
@@ -127,6 +163,19 @@ where
             }
         };
 
+        match proofs
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<ipc::TrieMerkleProof>, ParsingError>>()
+        {
+            Ok(pb_proofs) => response.set_proofs(pb_proofs.into()),
+            Err(err) => {
+                let log_message = format!("{:?}", err);
+                logging::log_error(&log_message);
+                response.set_failure(log_message);
+            }
+        }
+
         log_duration(
             correlation_id,
             METRIC_DURATION_QUERY,
@@ -163,20 +212,71 @@ where
         let protocol_version = exec_request.take_protocol_version().into();
         // TODO: do not unwrap
         let wasm_costs = self.wasm_costs(protocol_version).unwrap().unwrap();
-        let executor = Executor;
+        let executor = Executor::new(exec_request.get_trace());
         let preprocessor = Preprocessor::new(wasm_costs);
 
         let mut exec_response = ExecuteResponse::new();
         let mut results: Vec<ExecutionResult> = Vec::new();
 
-        for result in exec_request
+        let deploy_items = exec_request
             .take_deploys()
             .into_iter()
-            .map::<Result<DeployItem, MappingError>, _>(TryInto::try_into)
-        {
+            .map::<Result<DeployItem, MappingError>, _>(TryInto::try_into);
+
+        // Deploys which fail to parse have no effects, so only the valid ones are executed and
+        // their results are slotted back in between the mapping errors afterwards.
+        let mut valid_deploy_items = Vec::new();
+        let mut mapping_errors = Vec::new();
+        for result in deploy_items {
             match result {
                 Ok(deploy_item) => {
-                    let result = self.deploy(
+                    valid_deploy_items.push(deploy_item);
+                    mapping_errors.push(None);
+                }
+                Err(mapping_error) => mapping_errors.push(Some(mapping_error)),
+            }
+        }
+
+        let result = if exec_request.get_parallel() {
+            self.deploy_in_parallel(
+                correlation_id,
+                &executor,
+                &preprocessor,
+                protocol_version,
+                parent_state_hash,
+                block_time,
+                valid_deploy_items,
+            )
+            .map(|maybe_result| {
+                maybe_result.map(|parallel_result| {
+                    (
+                        parallel_result.execution_results,
+                        Some(parallel_result.effect),
+                        Some(parallel_result.commutes),
+                    )
+                })
+            })
+        } else if exec_request.get_sequential() {
+            self.deploy_sequentially(
+                correlation_id,
+                &executor,
+                &preprocessor,
+                protocol_version,
+                parent_state_hash,
+                block_time,
+                valid_deploy_items,
+            )
+            .map(|maybe_result| {
+                maybe_result.map(|(execution_results, combined_effect)| {
+                    (execution_results, Some(combined_effect), None)
+                })
+            })
+        } else {
+            // Each deploy is executed against `parent_state_hash` on its own
+            valid_deploy_items
+                .into_iter()
+                .map(|deploy_item| {
+                    self.deploy(
                         correlation_id,
                         &executor,
                         &preprocessor,
@@ -184,34 +284,90 @@ where
                         parent_state_hash,
                         block_time,
                         deploy_item,
-                    );
-                    match result {
-                        Ok(result) => results.push(result),
-                        Err(error) => {
-                            logging::log_error("deploy results error: RootNotFound");
-                            exec_response
-                                .mut_missing_parent()
-                                .set_hash(error.0.to_vec());
-                            log_duration(
-                                correlation_id,
-                                METRIC_DURATION_EXEC,
-                                TAG_RESPONSE_EXEC,
-                                start.elapsed(),
+                    )
+                })
+                .collect::<Result<Vec<ExecutionResult>, RootNotFound>>()
+                .map_or(Ok(None), |execution_results| {
+                    Ok(Some((execution_results, None, None)))
+                })
+        };
+
+        match result {
+            Ok(Some((execution_results, maybe_combined_effect, maybe_commutes))) => {
+                let mut execution_results = execution_results.into_iter();
+                let mut maybe_commutes = maybe_commutes.map(Vec::into_iter);
+                let mut commutes = Vec::new();
+                for mapping_error in mapping_errors {
+                    match mapping_error {
+                        Some(mapping_error) => {
+                            // A deploy which failed to parse has no effects to conflict with
+                            results
+                                .push(ExecutionResult::precondition_failure(mapping_error.into()));
+                            commutes.push(true);
+                        }
+                        None => {
+                            results.push(
+                                execution_results
+                                    .next()
+                                    .expect("should have a result for each valid deploy"),
                             );
-                            return SingleResponse::completed(exec_response);
+                            if let Some(deploy_commutes) = maybe_commutes.as_mut() {
+                                commutes.push(
+                                    deploy_commutes
+                                        .next()
+                                        .expect("should have a flag for each valid deploy"),
+                                );
+                            }
                         }
-                    };
+                    }
                 }
-                Err(mapping_error) => {
-                    results.push(ExecutionResult::precondition_failure(mapping_error.into()))
+
+                if let Some(combined_effect) = maybe_combined_effect {
+                    if exec_request.get_commit() {
+                        let commit_result = self.apply_effect(
+                            correlation_id,
+                            protocol_version,
+                            parent_state_hash,
+                            combined_effect.transforms.clone(),
+                        );
+                        exec_response
+                            .mut_success()
+                            .set_commit_response(commit_response(parent_state_hash, commit_result));
+                    }
+                    exec_response
+                        .mut_success()
+                        .set_combined_effect(combined_effect.into());
                 }
+
+                let protobuf_results_iter = results.into_iter().map(Into::into);
+                let exec_result = exec_response.mut_success();
+                exec_result.set_deploy_results(FromIterator::from_iter(protobuf_results_iter));
+                if maybe_commutes.is_some() {
+                    exec_result.set_commutes(commutes);
+                }
+            }
+            Ok(None) => {
+                logging::log_error("deploy results error: RootNotFound");
+                exec_response
+                    .mut_missing_parent()
+                    .set_hash(parent_state_hash.to_vec());
+            }
+            Err(error) => {
+                let log_message = format!("{:?}", error);
+                logging::log_error(&log_message);
+                let protobuf_results_iter = mapping_errors.into_iter().map(|_| {
+                    let mut pb_deploy_result = ipc::DeployResult::new();
+                    pb_deploy_result
+                        .mut_precondition_failure()
+                        .set_message(error.to_string());
+                    pb_deploy_result
+                });
+                exec_response
+                    .mut_success()
+                    .set_deploy_results(FromIterator::from_iter(protobuf_results_iter));
             }
         }
 
-        let protobuf_results_iter = results.into_iter().map(Into::into);
-        exec_response
-            .mut_success()
-            .set_deploy_results(FromIterator::from_iter(protobuf_results_iter));
         log_duration(
             correlation_id,
             METRIC_DURATION_EXEC,
@@ -267,57 +423,9 @@ where
         };
 
         // "Apply" effects to global state
-        let commit_response = {
-            let mut ret = CommitResponse::new();
-
-            match self.apply_effect(correlation_id, protocol_version, pre_state_hash, transforms) {
-                Ok(CommitResult::Success {
-                    state_root,
-                    bonded_validators,
-                }) => {
-                    let properties = {
-                        let mut tmp = BTreeMap::new();
-                        tmp.insert("post-state-hash".to_string(), format!("{:?}", state_root));
-                        tmp.insert("success".to_string(), true.to_string());
-                        tmp
-                    };
-                    logging::log_details(
-                        LogLevel::Info,
-                        "effects applied; new state hash is: {post-state-hash}".to_owned(),
-                        properties,
-                    );
-
-                    let bonds = bonded_validators.into_iter().map(Into::into).collect();
-                    let commit_result = ret.mut_success();
-                    commit_result.set_poststate_hash(state_root.to_vec());
-                    commit_result.set_bonded_validators(bonds);
-                }
-                Ok(CommitResult::RootNotFound) => {
-                    logging::log_warning("RootNotFound");
-
-                    ret.mut_missing_prestate().set_hash(pre_state_hash.to_vec());
-                }
-                Ok(CommitResult::KeyNotFound(key)) => {
-                    logging::log_warning("KeyNotFound");
-
-                    ret.set_key_not_found(key.into());
-                }
-                Ok(CommitResult::TypeMismatch(type_mismatch)) => {
-                    logging::log_warning("TypeMismatch");
-
-                    ret.set_type_mismatch(type_mismatch.into());
-                }
-                Err(error) => {
-                    let log_message = format!("State error {:?} when applying transforms", error);
-                    logging::log_error(&log_message);
-
-                    ret.mut_failed_transform()
-                        .set_message(format!("{:?}", error));
-                }
-            }
-
-            ret
-        };
+        let commit_result =
+            self.apply_effect(correlation_id, protocol_version, pre_state_hash, transforms);
+        let commit_response = commit_response(pre_state_hash, commit_result);
 
         log_duration(
             correlation_id,
@@ -460,6 +568,706 @@ where
 
         SingleResponse::completed(upgrade_response)
     }
+
+    fn list_keys(
+        &self,
+        _request_options: RequestOptions,
+        list_keys_request: ipc::ListKeysRequest,
+    ) -> StreamingResponse<ListKeysResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let request: ListKeysRequest = match list_keys_request.try_into() {
+            Ok(ret) => ret,
+            Err(err) => {
+                let log_message = format!("{:?}", err);
+                logging::log_error(&log_message);
+                let mut result = ListKeysResponse::new();
+                result.set_failure(log_message);
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_LIST_KEYS,
+                    TAG_RESPONSE_LIST_KEYS,
+                    start.elapsed(),
+                );
+                return StreamingResponse::iter(iter::once(result));
+            }
+        };
+
+        let state_hash = request.state_hash();
+
+        let responses = match self.run_list_keys(correlation_id, request) {
+            Ok(ListKeysResult::Success(pairs)) => {
+                let log_message = format!(
+                    "list keys successful; {} entries; correlation_id: {}",
+                    pairs.len(),
+                    correlation_id
+                );
+                log_info(&log_message);
+                pairs
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let mut result = ListKeysResponse::new();
+                        let entry = result.mut_entry();
+                        entry.set_key(key.into());
+                        entry.set_value(value.into());
+                        result
+                    })
+                    .collect()
+            }
+            Ok(ListKeysResult::RootNotFound) => {
+                let log_message = format!("Root not found: {}", state_hash);
+                logging::log_error(&log_message);
+                let mut result = ListKeysResponse::new();
+                result.mut_missing_state().set_hash(state_hash.to_vec());
+                vec![result]
+            }
+            Err(err) => {
+                let log_message = format!("{:?}", err);
+                logging::log_error(&log_message);
+                let mut result = ListKeysResponse::new();
+                result.set_failure(log_message);
+                vec![result]
+            }
+        };
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_LIST_KEYS,
+            TAG_RESPONSE_LIST_KEYS,
+            start.elapsed(),
+        );
+
+        StreamingResponse::iter(responses.into_iter())
+    }
+
+    fn prune(
+        &self,
+        _request_options: RequestOptions,
+        prune_request: PruneRequest,
+    ) -> SingleResponse<PruneResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let live_state_hashes: Result<Vec<Blake2bHash>, _> = prune_request
+            .get_live_state_hashes()
+            .iter()
+            .map(|hash| Blake2bHash::try_from(hash.as_slice()))
+            .collect();
+
+        let prune_response = match live_state_hashes {
+            Err(_) => {
+                let log_message = "Could not parse live state hash".to_string();
+                logging::log_error(&log_message);
+                let mut result = PruneResponse::new();
+                result.set_failure(log_message);
+                result
+            }
+            // Pruning with no live state hashes would delete all global state
+            Ok(ref live_state_hashes) if live_state_hashes.is_empty() => {
+                let log_message = "At least one live state hash is required".to_string();
+                logging::log_error(&log_message);
+                let mut result = PruneResponse::new();
+                result.set_failure(log_message);
+                result
+            }
+            Ok(live_state_hashes) => match self.run_prune(correlation_id, &live_state_hashes) {
+                Ok(PruneResult::Pruned { retained, deleted }) => {
+                    let log_message = format!(
+                        "prune successful; retained: {}, deleted: {}; correlation_id: {}",
+                        retained, deleted, correlation_id
+                    );
+                    log_info(&log_message);
+                    let mut result = PruneResponse::new();
+                    let success = result.mut_success();
+                    success.set_retained_count(retained as u64);
+                    success.set_deleted_count(deleted as u64);
+                    result
+                }
+                Ok(PruneResult::RootNotFound(state_hash)) => {
+                    let log_message = format!("Root not found: {}", state_hash);
+                    logging::log_error(&log_message);
+                    let mut result = PruneResponse::new();
+                    result.mut_missing_state().set_hash(state_hash.to_vec());
+                    result
+                }
+                Err(err) => {
+                    let log_message = format!("{:?}", err);
+                    logging::log_error(&log_message);
+                    let mut result = PruneResponse::new();
+                    result.set_failure(log_message);
+                    result
+                }
+            },
+        };
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_PRUNE,
+            TAG_RESPONSE_PRUNE,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(prune_response)
+    }
+
+    fn state_diff(
+        &self,
+        _request_options: RequestOptions,
+        state_diff_request: StateDiffRequest,
+    ) -> SingleResponse<StateDiffResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let pre_state_hash = Blake2bHash::try_from(state_diff_request.get_pre_state_hash());
+        let post_state_hash = Blake2bHash::try_from(state_diff_request.get_post_state_hash());
+
+        let state_diff_response = match (pre_state_hash, post_state_hash) {
+            (Ok(pre_state_hash), Ok(post_state_hash)) => {
+                match self.run_diff(correlation_id, pre_state_hash, post_state_hash) {
+                    Ok(DiffResult::Diffed(diff_entries)) => {
+                        let log_message = format!(
+                            "state diff successful; entries: {}; correlation_id: {}",
+                            diff_entries.len(),
+                            correlation_id
+                        );
+                        log_info(&log_message);
+                        let mut result = StateDiffResponse::new();
+                        let entries = result.mut_success().mut_entries();
+                        for diff_entry in diff_entries {
+                            let mut entry = StateDiffResponse_Entry::new();
+                            match diff_entry {
+                                DiffEntry::Added { key, value } => {
+                                    entry.set_key(key.into());
+                                    entry.set_post_value(value.into());
+                                }
+                                DiffEntry::Removed { key, value } => {
+                                    entry.set_key(key.into());
+                                    entry.set_pre_value(value.into());
+                                }
+                                DiffEntry::Changed {
+                                    key,
+                                    pre_value,
+                                    post_value,
+                                } => {
+                                    entry.set_key(key.into());
+                                    entry.set_pre_value(pre_value.into());
+                                    entry.set_post_value(post_value.into());
+                                }
+                            }
+                            entries.push(entry);
+                        }
+                        result
+                    }
+                    Ok(DiffResult::RootNotFound(state_hash)) => {
+                        let log_message = format!("Root not found: {}", state_hash);
+                        logging::log_error(&log_message);
+                        let mut result = StateDiffResponse::new();
+                        result.mut_missing_state().set_hash(state_hash.to_vec());
+                        result
+                    }
+                    Err(err) => {
+                        let log_message = format!("{:?}", err);
+                        logging::log_error(&log_message);
+                        let mut result = StateDiffResponse::new();
+                        result.set_failure(log_message);
+                        result
+                    }
+                }
+            }
+            _ => {
+                let log_message = "Could not parse state hash".to_string();
+                logging::log_error(&log_message);
+                let mut result = StateDiffResponse::new();
+                result.set_failure(log_message);
+                result
+            }
+        };
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_STATE_DIFF,
+            TAG_RESPONSE_STATE_DIFF,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(state_diff_response)
+    }
+
+    fn estimate(
+        &self,
+        _request_options: RequestOptions,
+        mut estimate_request: EstimateRequest,
+    ) -> SingleResponse<EstimateResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let parent_state_hash = Blake2bHash::try_from(estimate_request.get_parent_state_hash());
+        let deploy_item: Result<DeployItem, MappingError> =
+            estimate_request.take_deploy().try_into();
+
+        let estimate_response = match (parent_state_hash, deploy_item) {
+            (Ok(parent_state_hash), Ok(deploy_item)) => {
+                let block_time = BlockTime::new(estimate_request.get_block_time());
+                let protocol_version = estimate_request.take_protocol_version().into();
+                // TODO: do not unwrap
+                let wasm_costs = self.wasm_costs(protocol_version).unwrap().unwrap();
+                let preprocessor = Preprocessor::new(wasm_costs);
+
+                match self.estimate(
+                    correlation_id,
+                    &Executor::default(),
+                    &preprocessor,
+                    protocol_version,
+                    parent_state_hash,
+                    block_time,
+                    deploy_item,
+                    estimate_request.get_skip_authorization(),
+                ) {
+                    Ok(estimate_result) => {
+                        let mut result = EstimateResponse::new();
+                        let success = result.mut_success();
+                        success.set_deploy_result(estimate_result.execution_result.into());
+                        success.set_payment(estimate_result.payment.value().into());
+                        result
+                    }
+                    Err(error) => {
+                        logging::log_error("estimate error: RootNotFound");
+                        let mut result = EstimateResponse::new();
+                        result.mut_missing_parent().set_hash(error.0.to_vec());
+                        result
+                    }
+                }
+            }
+            (Err(_), _) => {
+                let log_message = "Could not parse parent state hash".to_string();
+                logging::log_error(&log_message);
+                let mut result = EstimateResponse::new();
+                result.set_failure(log_message);
+                result
+            }
+            (_, Err(mapping_error)) => {
+                let log_message = format!("{:?}", mapping_error);
+                logging::log_error(&log_message);
+                let mut result = EstimateResponse::new();
+                result.set_failure(log_message);
+                result
+            }
+        };
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_ESTIMATE,
+            TAG_RESPONSE_ESTIMATE,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(estimate_response)
+    }
+
+    fn run_scheduled_calls(
+        &self,
+        _request_options: RequestOptions,
+        mut run_scheduled_calls_request: RunScheduledCallsRequest,
+    ) -> SingleResponse<ExecuteResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let parent_state_hash =
+            match Blake2bHash::try_from(run_scheduled_calls_request.get_parent_state_hash()) {
+                Ok(hash) => hash,
+                Err(_) => {
+                    let log_message = "Could not parse parent state hash".to_string();
+                    logging::log_error(&log_message);
+                    let mut exec_response = ExecuteResponse::new();
+                    exec_response.set_failure(log_message);
+                    log_duration(
+                        correlation_id,
+                        METRIC_DURATION_RUN_SCHEDULED_CALLS,
+                        TAG_RESPONSE_RUN_SCHEDULED_CALLS,
+                        start.elapsed(),
+                    );
+                    return SingleResponse::completed(exec_response);
+                }
+            };
+        let block_time = BlockTime::new(run_scheduled_calls_request.get_block_time());
+        let protocol_version = run_scheduled_calls_request.take_protocol_version().into();
+        // TODO: do not unwrap
+        let wasm_costs = self.wasm_costs(protocol_version).unwrap().unwrap();
+        let preprocessor = Preprocessor::new(wasm_costs);
+
+        let mut exec_response = ExecuteResponse::new();
+
+        match self.run_scheduled_calls(
+            correlation_id,
+            &Executor::default(),
+            &preprocessor,
+            protocol_version,
+            parent_state_hash,
+            block_time,
+        ) {
+            Ok(Some((execution_results, combined_effect))) => {
+                if run_scheduled_calls_request.get_commit() {
+                    let commit_result = self.apply_effect(
+                        correlation_id,
+                        protocol_version,
+                        parent_state_hash,
+                        combined_effect.transforms.clone(),
+                    );
+                    exec_response
+                        .mut_success()
+                        .set_commit_response(commit_response(parent_state_hash, commit_result));
+                }
+
+                let protobuf_results_iter = execution_results.into_iter().map(Into::into);
+                let exec_result = exec_response.mut_success();
+                exec_result.set_deploy_results(FromIterator::from_iter(protobuf_results_iter));
+                exec_result.set_combined_effect(combined_effect.into());
+            }
+            Ok(None) => {
+                logging::log_error("scheduled call results error: RootNotFound");
+                exec_response
+                    .mut_missing_parent()
+                    .set_hash(parent_state_hash.to_vec());
+            }
+            Err(error) => {
+                // The queue couldn't be read, so no call was run
+                let log_message = format!("{:?}", error);
+                logging::log_error(&log_message);
+                exec_response.set_failure(log_message);
+            }
+        }
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_RUN_SCHEDULED_CALLS,
+            TAG_RESPONSE_RUN_SCHEDULED_CALLS,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(exec_response)
+    }
+
+    fn distribute_rewards(
+        &self,
+        _request_options: RequestOptions,
+        mut distribute_rewards_request: DistributeRewardsRequest,
+    ) -> SingleResponse<ExecuteResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let parent_state_hash =
+            match Blake2bHash::try_from(distribute_rewards_request.get_parent_state_hash()) {
+                Ok(hash) => hash,
+                Err(_) => {
+                    let log_message = "Could not parse parent state hash".to_string();
+                    logging::log_error(&log_message);
+                    let mut exec_response = ExecuteResponse::new();
+                    exec_response.set_failure(log_message);
+                    log_duration(
+                        correlation_id,
+                        METRIC_DURATION_DISTRIBUTE_REWARDS,
+                        TAG_RESPONSE_DISTRIBUTE_REWARDS,
+                        start.elapsed(),
+                    );
+                    return SingleResponse::completed(exec_response);
+                }
+            };
+        let block_time = BlockTime::new(distribute_rewards_request.get_block_time());
+        let protocol_version = distribute_rewards_request.take_protocol_version().into();
+
+        // The proposer followed by the finality signers
+        let public_keys = iter::once(distribute_rewards_request.get_proposer())
+            .chain(
+                distribute_rewards_request
+                    .get_finality_signers()
+                    .iter()
+                    .map(Vec::as_slice),
+            )
+            .map(|bytes| {
+                PublicKey::try_from(bytes)
+                    .map_err(|_| MappingError::invalid_public_key_length(bytes.len()))
+            })
+            .collect::<Result<Vec<PublicKey>, MappingError>>();
+
+        let result = match public_keys {
+            Ok(mut public_keys) => {
+                let finality_signers = public_keys.split_off(1);
+                self.distribute_rewards(
+                    correlation_id,
+                    &Executor::default(),
+                    protocol_version,
+                    parent_state_hash,
+                    block_time,
+                    public_keys[0],
+                    finality_signers,
+                )
+            }
+            Err(mapping_error) => Ok(ExecutionResult::precondition_failure(mapping_error.into())),
+        };
+
+        let mut exec_response = ExecuteResponse::new();
+
+        match result {
+            Ok(execution_result) => {
+                // A failed distribution is reported through its deploy result and not committed
+                if distribute_rewards_request.get_commit() && execution_result.is_success() {
+                    let commit_result = self.apply_effect(
+                        correlation_id,
+                        protocol_version,
+                        parent_state_hash,
+                        execution_result.effect().transforms.clone(),
+                    );
+                    exec_response
+                        .mut_success()
+                        .set_commit_response(commit_response(parent_state_hash, commit_result));
+                }
+                exec_response
+                    .mut_success()
+                    .set_deploy_results(FromIterator::from_iter(iter::once(
+                        execution_result.into(),
+                    )));
+            }
+            Err(error) => {
+                logging::log_error("distribute rewards error: RootNotFound");
+                exec_response
+                    .mut_missing_parent()
+                    .set_hash(error.0.to_vec());
+            }
+        }
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_DISTRIBUTE_REWARDS,
+            TAG_RESPONSE_DISTRIBUTE_REWARDS,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(exec_response)
+    }
+
+    fn slash(
+        &self,
+        _request_options: RequestOptions,
+        mut slash_request: SlashRequest,
+    ) -> SingleResponse<ExecuteResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let parent_state_hash = match Blake2bHash::try_from(slash_request.get_parent_state_hash()) {
+            Ok(hash) => hash,
+            Err(_) => {
+                let log_message = "Could not parse parent state hash".to_string();
+                logging::log_error(&log_message);
+                let mut exec_response = ExecuteResponse::new();
+                exec_response.set_failure(log_message);
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_SLASH,
+                    TAG_RESPONSE_SLASH,
+                    start.elapsed(),
+                );
+                return SingleResponse::completed(exec_response);
+            }
+        };
+        let block_time = BlockTime::new(slash_request.get_block_time());
+        let protocol_version = slash_request.take_protocol_version().into();
+
+        let validators = slash_request
+            .get_validators()
+            .iter()
+            .map(|bytes| {
+                PublicKey::try_from(bytes.as_slice())
+                    .map_err(|_| MappingError::invalid_public_key_length(bytes.len()))
+            })
+            .collect::<Result<Vec<PublicKey>, MappingError>>();
+
+        let result = match validators {
+            Ok(validators) => self.slash(
+                correlation_id,
+                &Executor::default(),
+                protocol_version,
+                parent_state_hash,
+                block_time,
+                validators,
+            ),
+            Err(mapping_error) => Ok(ExecutionResult::precondition_failure(mapping_error.into())),
+        };
+
+        let mut exec_response = ExecuteResponse::new();
+
+        match result {
+            Ok(execution_result) => {
+                // A failed slashing is reported through its deploy result and not committed
+                if slash_request.get_commit() && execution_result.is_success() {
+                    let commit_result = self.apply_effect(
+                        correlation_id,
+                        protocol_version,
+                        parent_state_hash,
+                        execution_result.effect().transforms.clone(),
+                    );
+                    exec_response
+                        .mut_success()
+                        .set_commit_response(commit_response(parent_state_hash, commit_result));
+                }
+                exec_response
+                    .mut_success()
+                    .set_deploy_results(FromIterator::from_iter(iter::once(
+                        execution_result.into(),
+                    )));
+            }
+            Err(error) => {
+                logging::log_error("slash error: RootNotFound");
+                exec_response
+                    .mut_missing_parent()
+                    .set_hash(error.0.to_vec());
+            }
+        }
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_SLASH,
+            TAG_RESPONSE_SLASH,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(exec_response)
+    }
+
+    fn step(
+        &self,
+        _request_options: RequestOptions,
+        mut step_request: StepRequest,
+    ) -> SingleResponse<ExecuteResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let parent_state_hash = match Blake2bHash::try_from(step_request.get_parent_state_hash()) {
+            Ok(hash) => hash,
+            Err(_) => {
+                let log_message = "Could not parse parent state hash".to_string();
+                logging::log_error(&log_message);
+                let mut exec_response = ExecuteResponse::new();
+                exec_response.set_failure(log_message);
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_STEP,
+                    TAG_RESPONSE_STEP,
+                    start.elapsed(),
+                );
+                return SingleResponse::completed(exec_response);
+            }
+        };
+        let block_time = BlockTime::new(step_request.get_block_time());
+        let protocol_version = step_request.take_protocol_version().into();
+
+        let result = self.step(
+            correlation_id,
+            &Executor::default(),
+            protocol_version,
+            parent_state_hash,
+            block_time,
+        );
+
+        let mut exec_response = ExecuteResponse::new();
+
+        match result {
+            Ok(execution_result) => {
+                // A failed step is reported through its deploy result and not committed, so that
+                // the due requests stay queued and are processed by a later step
+                if step_request.get_commit() && execution_result.is_success() {
+                    let commit_result = self.apply_effect(
+                        correlation_id,
+                        protocol_version,
+                        parent_state_hash,
+                        execution_result.effect().transforms.clone(),
+                    );
+                    exec_response
+                        .mut_success()
+                        .set_commit_response(commit_response(parent_state_hash, commit_result));
+                }
+                exec_response
+                    .mut_success()
+                    .set_deploy_results(FromIterator::from_iter(iter::once(
+                        execution_result.into(),
+                    )));
+            }
+            Err(error) => {
+                logging::log_error("step error: RootNotFound");
+                exec_response
+                    .mut_missing_parent()
+                    .set_hash(error.0.to_vec());
+            }
+        }
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_STEP,
+            TAG_RESPONSE_STEP,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(exec_response)
+    }
+}
+
+/// Maps the outcome of applying effects to global state to a [`CommitResponse`].
+fn commit_response(
+    pre_state_hash: Blake2bHash,
+    commit_result: Result<CommitResult, EngineError>,
+) -> CommitResponse {
+    let mut ret = CommitResponse::new();
+
+    match commit_result {
+        Ok(CommitResult::Success {
+            state_root,
+            bonded_validators,
+            unbond_payouts,
+        }) => {
+            let properties = {
+                let mut tmp = BTreeMap::new();
+                tmp.insert("post-state-hash".to_string(), format!("{:?}", state_root));
+                tmp.insert("success".to_string(), true.to_string());
+                tmp
+            };
+            logging::log_details(
+                LogLevel::Info,
+                "effects applied; new state hash is: {post-state-hash}".to_owned(),
+                properties,
+            );
+
+            let bonds = bonded_validators.into_iter().map(Into::into).collect();
+            let payouts = unbond_payouts.into_iter().map(Into::into).collect();
+            let commit_result = ret.mut_success();
+            commit_result.set_poststate_hash(state_root.to_vec());
+            commit_result.set_bonded_validators(bonds);
+            commit_result.set_unbond_payouts(payouts);
+        }
+        Ok(CommitResult::RootNotFound) => {
+            logging::log_warning("RootNotFound");
+
+            ret.mut_missing_prestate().set_hash(pre_state_hash.to_vec());
+        }
+        Ok(CommitResult::KeyNotFound(key)) => {
+            logging::log_warning("KeyNotFound");
+
+            ret.set_key_not_found(key.into());
+        }
+        Ok(CommitResult::TypeMismatch(type_mismatch)) => {
+            logging::log_warning("TypeMismatch");
+
+            ret.set_type_mismatch(type_mismatch.into());
+        }
+        Err(error) => {
+            let log_message = format!("State error {:?} when applying transforms", error);
+            logging::log_error(&log_message);
+
+            ret.mut_failed_transform()
+                .set_message(format!("{:?}", error));
+        }
+    }
+
+    ret
 }
 
 // Helper method which returns single DeployResult that is set to be a
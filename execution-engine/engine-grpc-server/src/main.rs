@@ -1,6 +1,8 @@
 use std::{
     collections::BTreeMap,
-    fs,
+    convert::TryFrom,
+    fs::{self, File},
+    io::{BufReader, BufWriter},
     path::PathBuf,
     str::FromStr,
     sync::{
@@ -11,6 +13,7 @@ use std::{
 };
 
 use clap::{App, Arg, ArgMatches};
+use contract_ffi::value::ProtocolVersion;
 use dirs::home_dir;
 use engine_core::engine_state::{EngineConfig, EngineState};
 use lazy_static::lazy_static;
@@ -21,12 +24,14 @@ use engine_shared::{
         self, log_level,
         log_settings::{self, LogLevelFilter, LogSettings},
     },
+    newtypes::{Blake2bHash, CorrelationId},
     os::get_page_size,
     socket,
 };
 use engine_storage::{
-    global_state::lmdb::LmdbGlobalState, transaction_source::lmdb::LmdbEnvironment,
-    trie_store::lmdb::LmdbTrieStore,
+    global_state::lmdb::LmdbGlobalState,
+    transaction_source::lmdb::LmdbEnvironment,
+    trie_store::{lmdb::LmdbTrieStore, PruneResult},
 };
 
 use casperlabs_engine_grpc_server::engine_server;
@@ -73,6 +78,41 @@ const ARG_SOCKET_EXPECT: &str = "socket required";
 const REMOVING_SOCKET_FILE_MESSAGE: &str = "removing old socket file";
 const REMOVING_SOCKET_FILE_EXPECT: &str = "failed to remove old socket file";
 
+// prune
+const ARG_PRUNE: &str = "prune";
+const ARG_PRUNE_VALUE: &str = "STATE_HASH";
+const ARG_PRUNE_HELP: &str =
+    "Deletes all global state which is not reachable from any of the given hex-encoded state \
+     hashes, then exits.  The server must not be running on the same data directory.";
+const ARG_PRUNE_EXPECT: &str = "prune requires at least one state hash";
+const STATE_HASH_EXPECT: &str = "Could not parse state hash";
+const PRUNE_START_MESSAGE: &str = "pruning global state";
+const PRUNE_EXPECT: &str = "Could not prune global state";
+const PRUNE_ROOT_NOT_FOUND_EXPECT: &str = "Could not prune global state; state hash not found";
+
+// snapshots
+const ARG_EXPORT_SNAPSHOT: &str = "export-snapshot";
+const ARG_EXPORT_SNAPSHOT_VALUES: [&str; 2] = ["STATE_HASH", "FILE"];
+const ARG_EXPORT_SNAPSHOT_HELP: &str =
+    "Writes a snapshot of the global state at the given hex-encoded state hash to the given \
+     file, then exits";
+const ARG_IMPORT_SNAPSHOT: &str = "import-snapshot";
+const ARG_IMPORT_SNAPSHOT_VALUE: &str = "FILE";
+const ARG_IMPORT_SNAPSHOT_HELP: &str =
+    "Reads a snapshot from the given file into the global state, then exits.  The server must \
+     not be running on the same data directory.";
+const ARG_PROTOCOL_VERSION: &str = "protocol-version";
+const ARG_PROTOCOL_VERSION_VALUE: &str = "MAJOR.MINOR.PATCH";
+const ARG_PROTOCOL_VERSION_DEFAULT: &str = "1.0.0";
+const ARG_PROTOCOL_VERSION_HELP: &str =
+    "Sets the protocol version whose protocol data is included in an exported snapshot";
+const ARG_PROTOCOL_VERSION_EXPECT: &str = "Could not parse protocol version";
+const SNAPSHOT_FILE_EXPECT: &str = "Could not open snapshot file";
+const EXPORT_SNAPSHOT_START_MESSAGE: &str = "exporting global state snapshot";
+const EXPORT_SNAPSHOT_EXPECT: &str = "Could not export global state snapshot";
+const IMPORT_SNAPSHOT_START_MESSAGE: &str = "importing global state snapshot";
+const IMPORT_SNAPSHOT_EXPECT: &str = "Could not import global state snapshot";
+
 // loglevel
 const ARG_LOG_LEVEL: &str = "loglevel";
 const ARG_LOG_LEVEL_VALUE: &str = "LOGLEVEL";
@@ -105,10 +145,25 @@ fn main() {
 
     log_settings::set_log_settings_provider(&*LOG_SETTINGS);
 
-    logging::log_info(SERVER_START_MESSAGE);
-
     let matches: &clap::ArgMatches = &*ARG_MATCHES;
 
+    if matches.is_present(ARG_PRUNE) {
+        prune(matches);
+        return;
+    }
+
+    if matches.is_present(ARG_EXPORT_SNAPSHOT) {
+        export_snapshot(matches);
+        return;
+    }
+
+    if matches.is_present(ARG_IMPORT_SNAPSHOT) {
+        import_snapshot(matches);
+        return;
+    }
+
+    logging::log_info(SERVER_START_MESSAGE);
+
     let socket = get_socket(matches);
 
     match socket.remove_file() {
@@ -196,9 +251,42 @@ fn get_args() -> ArgMatches<'static> {
                 .value_name(ARG_THREAD_COUNT_VALUE)
                 .help(ARG_THREAD_COUNT_HELP),
         )
+        .arg(
+            Arg::with_name(ARG_PRUNE)
+                .long(ARG_PRUNE)
+                .takes_value(true)
+                .multiple(true)
+                .value_name(ARG_PRUNE_VALUE)
+                .help(ARG_PRUNE_HELP)
+                .conflicts_with_all(&[ARG_EXPORT_SNAPSHOT, ARG_IMPORT_SNAPSHOT]),
+        )
+        .arg(
+            Arg::with_name(ARG_EXPORT_SNAPSHOT)
+                .long(ARG_EXPORT_SNAPSHOT)
+                .takes_value(true)
+                .number_of_values(2)
+                .value_names(&ARG_EXPORT_SNAPSHOT_VALUES)
+                .help(ARG_EXPORT_SNAPSHOT_HELP)
+                .conflicts_with(ARG_IMPORT_SNAPSHOT),
+        )
+        .arg(
+            Arg::with_name(ARG_IMPORT_SNAPSHOT)
+                .long(ARG_IMPORT_SNAPSHOT)
+                .takes_value(true)
+                .value_name(ARG_IMPORT_SNAPSHOT_VALUE)
+                .help(ARG_IMPORT_SNAPSHOT_HELP),
+        )
+        .arg(
+            Arg::with_name(ARG_PROTOCOL_VERSION)
+                .long(ARG_PROTOCOL_VERSION)
+                .takes_value(true)
+                .default_value(ARG_PROTOCOL_VERSION_DEFAULT)
+                .value_name(ARG_PROTOCOL_VERSION_VALUE)
+                .help(ARG_PROTOCOL_VERSION_HELP),
+        )
         .arg(
             Arg::with_name(ARG_SOCKET)
-                .required(true)
+                .required_unless_one(&[ARG_PRUNE, ARG_EXPORT_SNAPSHOT, ARG_IMPORT_SNAPSHOT])
                 .help(ARG_SOCKET_HELP)
                 .index(1),
         )
@@ -256,6 +344,134 @@ fn get_thread_count(matches: &ArgMatches) -> usize {
         .expect(ARG_THREAD_COUNT_EXPECT)
 }
 
+/// Parses a hex-encoded state hash
+fn parse_state_hash(value: &str) -> Blake2bHash {
+    base16::decode(value)
+        .ok()
+        .and_then(|bytes| Blake2bHash::try_from(bytes.as_slice()).ok())
+        .unwrap_or_else(|| panic!("{}: {}", STATE_HASH_EXPECT, value))
+}
+
+/// Parses the values of the prune argument
+fn get_live_state_hashes(matches: &ArgMatches) -> Vec<Blake2bHash> {
+    matches
+        .values_of(ARG_PRUNE)
+        .expect(ARG_PRUNE_EXPECT)
+        .map(parse_state_hash)
+        .collect()
+}
+
+/// Parses value of protocol-version argument
+fn get_protocol_version(matches: &ArgMatches) -> ProtocolVersion {
+    let value = matches
+        .value_of(ARG_PROTOCOL_VERSION)
+        .expect(ARG_PROTOCOL_VERSION_EXPECT);
+    let parts: Vec<u32> = value
+        .split('.')
+        .map(u32::from_str)
+        .collect::<Result<_, _>>()
+        .unwrap_or_else(|_| panic!("{}: {}", ARG_PROTOCOL_VERSION_EXPECT, value));
+    match parts.as_slice() {
+        [major, minor, patch] => ProtocolVersion::from_parts(*major, *minor, *patch),
+        _ => panic!("{}: {}", ARG_PROTOCOL_VERSION_EXPECT, value),
+    }
+}
+
+/// Writes a snapshot of the global state at the state hash given to the export-snapshot argument
+fn export_snapshot(matches: &ArgMatches) {
+    logging::log_info(EXPORT_SNAPSHOT_START_MESSAGE);
+
+    let mut values = matches
+        .values_of(ARG_EXPORT_SNAPSHOT)
+        .expect(EXPORT_SNAPSHOT_EXPECT);
+    let state_hash = parse_state_hash(values.next().expect(EXPORT_SNAPSHOT_EXPECT));
+    let path = PathBuf::from(values.next().expect(EXPORT_SNAPSHOT_EXPECT));
+
+    let protocol_version = get_protocol_version(matches);
+
+    let data_dir = get_data_dir(matches);
+
+    let map_size = get_map_size(matches);
+
+    let engine_config: EngineConfig = get_engine_config(matches);
+
+    let engine_state = get_engine_state(data_dir, map_size, engine_config);
+
+    let mut writer = BufWriter::new(File::create(&path).expect(SNAPSHOT_FILE_EXPECT));
+    let summary = engine_state
+        .run_export_snapshot(state_hash, protocol_version, &mut writer)
+        .expect(EXPORT_SNAPSHOT_EXPECT);
+    writer.into_inner().expect(EXPORT_SNAPSHOT_EXPECT);
+
+    let message = format!(
+        "exported global state snapshot; state root: {}, trie elements: {}, chunks: {}",
+        summary.state_root, summary.trie_count, summary.chunk_count
+    );
+    logging::log_info(&message);
+}
+
+/// Reads the snapshot given to the import-snapshot argument into global state
+fn import_snapshot(matches: &ArgMatches) {
+    logging::log_info(IMPORT_SNAPSHOT_START_MESSAGE);
+
+    let path = PathBuf::from(
+        matches
+            .value_of(ARG_IMPORT_SNAPSHOT)
+            .expect(IMPORT_SNAPSHOT_EXPECT),
+    );
+
+    let data_dir = get_data_dir(matches);
+
+    let map_size = get_map_size(matches);
+
+    let engine_config: EngineConfig = get_engine_config(matches);
+
+    let engine_state = get_engine_state(data_dir, map_size, engine_config);
+
+    let mut reader = BufReader::new(File::open(&path).expect(SNAPSHOT_FILE_EXPECT));
+    let summary = engine_state
+        .run_import_snapshot(&mut reader)
+        .expect(IMPORT_SNAPSHOT_EXPECT);
+
+    let message = format!(
+        "imported global state snapshot; state root: {}, trie elements: {}, chunks: {}",
+        summary.state_root, summary.trie_count, summary.chunk_count
+    );
+    logging::log_info(&message);
+}
+
+/// Deletes all global state which is not reachable from the state hashes given to the prune
+/// argument
+fn prune(matches: &ArgMatches) {
+    logging::log_info(PRUNE_START_MESSAGE);
+
+    let live_state_hashes = get_live_state_hashes(matches);
+
+    let data_dir = get_data_dir(matches);
+
+    let map_size = get_map_size(matches);
+
+    let engine_config: EngineConfig = get_engine_config(matches);
+
+    let engine_state = get_engine_state(data_dir, map_size, engine_config);
+
+    match engine_state
+        .run_prune(CorrelationId::new(), &live_state_hashes)
+        .expect(PRUNE_EXPECT)
+    {
+        PruneResult::Pruned { retained, deleted } => {
+            let message = format!(
+                "pruned global state; retained: {}, deleted: {}",
+                retained, deleted
+            );
+            logging::log_info(&message);
+        }
+        PruneResult::RootNotFound(state_hash) => {
+            panic!("{}: {}", PRUNE_ROOT_NOT_FOUND_EXPECT, state_hash)
+        }
+    }
+}
+
 /// Returns an [`EngineConfig`].
 fn get_engine_config(_matches: &ArgMatches) -> EngineConfig {
     // feature flags go here
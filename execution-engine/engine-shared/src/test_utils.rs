@@ -11,7 +11,10 @@ use contract_ffi::{
     uref::{AccessRights, URef},
     value::{account::PurseId, Account, Value},
 };
-use engine_wasm_prep::wasm_costs::WasmCosts;
+use engine_wasm_prep::{
+    host_function_costs::{HostFunctionCost, HostFunctionCosts},
+    wasm_costs::WasmCosts,
+};
 
 /// Returns the serialized form of an empty Wasm Module
 pub fn create_empty_wasm_module_bytes() -> Vec<u8> {
@@ -59,3 +62,37 @@ pub fn wasm_costs_free() -> WasmCosts {
         opcodes_div: 1,
     }
 }
+
+pub fn host_function_costs_mock() -> HostFunctionCosts {
+    HostFunctionCosts {
+        read_value: HostFunctionCost::new(100, 1),
+        write: HostFunctionCost::new(200, 2),
+        add: HostFunctionCost::new(200, 2),
+        new_uref: HostFunctionCost::new(200, 2),
+        get_arg: HostFunctionCost::new(10, 1),
+        ret: HostFunctionCost::new(10, 1),
+        call_contract: HostFunctionCost::new(500, 1),
+        get_key: HostFunctionCost::new(50, 1),
+        has_key: HostFunctionCost::new(50, 1),
+        put_key: HostFunctionCost::new(100, 1),
+        remove_key: HostFunctionCost::new(100, 1),
+        list_named_keys: HostFunctionCost::new(50, 1),
+        store_function: HostFunctionCost::new(500, 2),
+        upgrade_contract_at_uref: HostFunctionCost::new(500, 2),
+        manage_keys: HostFunctionCost::new(100, 1),
+        create_purse: HostFunctionCost::new(500, 0),
+        transfer: HostFunctionCost::new(500, 0),
+        get_balance: HostFunctionCost::new(100, 0),
+        host_buffer: HostFunctionCost::new(10, 1),
+        context: HostFunctionCost::new(10, 0),
+        emit_event: HostFunctionCost::new(100, 1),
+        get_call_stack: HostFunctionCost::new(10, 0),
+        delete: HostFunctionCost::new(200, 2),
+        schedule_call: HostFunctionCost::new(500, 1),
+        call_entry_point: HostFunctionCost::new(500, 1),
+    }
+}
+
+pub fn host_function_costs_free() -> HostFunctionCosts {
+    HostFunctionCosts::default()
+}
@@ -52,6 +52,8 @@ pub enum Transform {
     AddUInt256(U256),
     AddUInt512(U512),
     AddKeys(BTreeMap<String, Key>),
+    /// Removes the value stored under the key from global state.
+    Delete,
     Failure(Error),
 }
 
@@ -129,6 +131,10 @@ where
 }
 
 impl Transform {
+    /// Applies the transform to the given value.
+    ///
+    /// A [`Transform::Delete`] does not produce a value, so it cannot be applied and has to be
+    /// handled by the caller instead.
     pub fn apply(self, v: Value) -> Result<Value, Error> {
         match self {
             Transform::Identity => Ok(v),
@@ -184,6 +190,11 @@ impl Transform {
                     .into())
                 }
             },
+            Transform::Delete => Err(TypeMismatch {
+                expected: String::from("Write or Add"),
+                found: String::from("Delete"),
+            }
+            .into()),
             Transform::Failure(error) => Err(error),
         }
     }
@@ -231,6 +242,15 @@ impl Add for Transform {
             (a @ Transform::Failure(_), _) => a,
             (_, b @ Transform::Failure(_)) => b,
             (_, b @ Transform::Write(_)) => b,
+            (_, b @ Transform::Delete) => b,
+            // nothing is left to add to once the value has been deleted
+            (Transform::Delete, other) => Transform::Failure(
+                TypeMismatch {
+                    expected: "Write".to_owned(),
+                    found: format!("{:?}", other),
+                }
+                .into(),
+            ),
             (Transform::Write(v), b) => {
                 // second transform changes value being written
                 match b.apply(v) {
@@ -312,6 +332,7 @@ pub mod gens {
     pub fn transform_arb() -> impl Strategy<Value = Transform> {
         prop_oneof![
             Just(Transform::Identity),
+            Just(Transform::Delete),
             value_arb().prop_map(Transform::Write),
             any::<i32>().prop_map(Transform::AddInt32),
             any::<u64>().prop_map(Transform::AddUInt64),
@@ -421,4 +442,22 @@ mod tests {
             super::u64_wrapping_addition(base_u64, i32::max_value())
         )
     }
+
+    #[test]
+    fn delete_combines_with_other_transforms() {
+        let write = Transform::Write(Value::Int32(1));
+
+        assert_eq!(write.clone() + Transform::Delete, Transform::Delete);
+        assert_eq!(
+            Transform::AddInt32(1) + Transform::Delete,
+            Transform::Delete
+        );
+        assert_eq!(Transform::Delete + write.clone(), write);
+        assert_eq!(Transform::Delete + Transform::Identity, Transform::Delete);
+        match Transform::Delete + Transform::AddInt32(1) {
+            Transform::Failure(_) => (),
+            other => panic!("expected failure, got {:?}", other),
+        }
+        assert!(Transform::Delete.apply(Value::Int32(1)).is_err());
+    }
 }
@@ -0,0 +1,594 @@
+use std::{
+    collections::BTreeSet, convert::TryFrom, path::PathBuf, process, str::FromStr, sync::Arc,
+};
+
+use clap::{App, Arg, ArgMatches, SubCommand};
+use dirs::home_dir;
+
+use contract_ffi::{
+    bytesrepr::{self, ToBytes},
+    key::Key,
+    uref::{AccessRights, URef},
+    value::{account::Account, contract::Contract, ProtocolVersion, Value},
+};
+use engine_core::engine_state::{
+    query::{QueryRequest, QueryResult},
+    EngineConfig, EngineState,
+};
+use engine_shared::{
+    newtypes::{Blake2bHash, CorrelationId},
+    os::get_page_size,
+};
+use engine_storage::{
+    global_state::{lmdb::LmdbGlobalState, StateProvider},
+    protocol_data_store::lmdb::LmdbProtocolDataStore,
+    store::Store,
+    transaction_source::{lmdb::LmdbEnvironment, Readable, TransactionSource},
+    trie::{Pointer, Trie},
+    trie_store::lmdb::LmdbTrieStore,
+};
+
+// exe / proc
+const APP_NAME: &str = "CasperLabs Execution Engine State Inspector";
+const APP_ABOUT: &str = "Inspects the global state stored in an execution engine data directory.  \
+                         The data directory is opened read-only.";
+
+// data-dir / lmdb
+const ARG_DATA_DIR: &str = "data-dir";
+const ARG_DATA_DIR_SHORT: &str = "d";
+const ARG_DATA_DIR_VALUE: &str = "DIR";
+const ARG_DATA_DIR_HELP: &str = "Sets the data directory";
+const DEFAULT_DATA_DIR_RELATIVE: &str = ".casperlabs";
+const GLOBAL_STATE_DIR: &str = "global_state";
+const GET_HOME_DIR_EXPECT: &str = "Could not get home directory";
+const LMDB_ENVIRONMENT_EXPECT: &str = "Could not open LmdbEnvironment";
+const LMDB_TRIE_STORE_EXPECT: &str = "Could not open LmdbTrieStore";
+const LMDB_PROTOCOL_DATA_STORE_EXPECT: &str = "Could not open LmdbProtocolDataStore";
+const LMDB_GLOBAL_STATE_EXPECT: &str = "Could not open LmdbGlobalState";
+const LMDB_READ_EXPECT: &str = "Could not read from global state";
+
+// pages / lmdb
+const ARG_PAGES: &str = "pages";
+const ARG_PAGES_SHORT: &str = "p";
+const ARG_PAGES_VALUE: &str = "NUM";
+const ARG_PAGES_HELP: &str = "Sets the max number of pages to use for lmdb's mmap";
+const GET_PAGES_EXPECT: &str = "Could not parse pages argument";
+// 750 GiB = 805306368000 bytes
+// page size on x86_64 linux = 4096 bytes
+// 805306368000 / 4096 = 196608000
+const DEFAULT_PAGES: usize = 196_608_000;
+
+// roots
+const CMD_ROOTS: &str = "roots";
+const CMD_ROOTS_ABOUT: &str =
+    "Lists state hashes of all stored tries which are not referenced by any other trie";
+
+// protocol-versions
+const CMD_PROTOCOL_VERSIONS: &str = "protocol-versions";
+const CMD_PROTOCOL_VERSIONS_ABOUT: &str =
+    "Lists stored protocol versions along with their system contracts";
+
+// query
+const CMD_QUERY: &str = "query";
+const CMD_QUERY_ABOUT: &str = "Prints the value under a key, optionally following a path of \
+                               named keys the same way the query RPC does";
+const ARG_PATH: &str = "PATH";
+const ARG_PATH_HELP: &str = "Names of keys to follow from the base key";
+
+// trie
+const CMD_TRIE: &str = "trie";
+const CMD_TRIE_ABOUT: &str = "Dumps the trie subtree rooted at the given hash";
+const ARG_TRIE_HASH: &str = "TRIE_HASH";
+const ARG_TRIE_HASH_HELP: &str = "Hex-encoded hash of the trie to dump";
+const ARG_DEPTH: &str = "depth";
+const ARG_DEPTH_VALUE: &str = "NUM";
+const ARG_DEPTH_HELP: &str = "Limits how many levels below the given trie are printed";
+const ARG_DEPTH_EXPECT: &str = "Could not parse depth argument";
+
+// balance
+const CMD_BALANCE: &str = "balance";
+const CMD_BALANCE_ABOUT: &str = "Prints the balance of an account's main purse or of a purse \
+                                 URef, resolved through the mint";
+const ARG_PROTOCOL_VERSION: &str = "protocol-version";
+const ARG_PROTOCOL_VERSION_VALUE: &str = "MAJOR.MINOR.PATCH";
+const ARG_PROTOCOL_VERSION_HELP: &str = "Protocol version whose mint is used to resolve \
+                                         balances.  Defaults to the latest stored version";
+const ARG_PROTOCOL_VERSION_EXPECT: &str = "Could not parse protocol version";
+
+// shared args
+const ARG_STATE_HASH: &str = "STATE_HASH";
+const ARG_STATE_HASH_HELP: &str = "Hex-encoded state hash to read from";
+const ARG_KEY: &str = "KEY";
+const ARG_KEY_HELP: &str = "Key in the form account-<hex>, hash-<hex>, uref-<hex>[-<rights>] or \
+                            local-<hex>";
+const STATE_HASH_EXPECT: &str = "Could not parse state hash";
+const KEY_EXPECT: &str = "Could not parse key";
+
+const INDENT: &str = "  ";
+
+fn main() {
+    let matches = get_args();
+
+    let global_state = get_global_state(&matches);
+
+    match matches.subcommand() {
+        (CMD_ROOTS, Some(_)) => roots(&global_state),
+        (CMD_PROTOCOL_VERSIONS, Some(_)) => protocol_versions(&global_state),
+        (CMD_QUERY, Some(sub_matches)) => query(global_state, sub_matches),
+        (CMD_TRIE, Some(sub_matches)) => trie(&global_state, sub_matches),
+        (CMD_BALANCE, Some(sub_matches)) => balance(global_state, sub_matches),
+        _ => unreachable!("clap requires a subcommand"),
+    }
+}
+
+/// Parses arguments and returns matches
+fn get_args() -> ArgMatches<'static> {
+    let state_hash_arg = Arg::with_name(ARG_STATE_HASH)
+        .required(true)
+        .help(ARG_STATE_HASH_HELP);
+    let key_arg = Arg::with_name(ARG_KEY).required(true).help(ARG_KEY_HELP);
+
+    App::new(APP_NAME)
+        .version(env!("CARGO_PKG_VERSION"))
+        .about(APP_ABOUT)
+        .setting(clap::AppSettings::SubcommandRequiredElseHelp)
+        .arg(
+            Arg::with_name(ARG_DATA_DIR)
+                .short(ARG_DATA_DIR_SHORT)
+                .long(ARG_DATA_DIR)
+                .value_name(ARG_DATA_DIR_VALUE)
+                .help(ARG_DATA_DIR_HELP)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(ARG_PAGES)
+                .short(ARG_PAGES_SHORT)
+                .long(ARG_PAGES)
+                .value_name(ARG_PAGES_VALUE)
+                .help(ARG_PAGES_HELP)
+                .takes_value(true),
+        )
+        .subcommand(SubCommand::with_name(CMD_ROOTS).about(CMD_ROOTS_ABOUT))
+        .subcommand(SubCommand::with_name(CMD_PROTOCOL_VERSIONS).about(CMD_PROTOCOL_VERSIONS_ABOUT))
+        .subcommand(
+            SubCommand::with_name(CMD_QUERY)
+                .about(CMD_QUERY_ABOUT)
+                .arg(state_hash_arg.clone())
+                .arg(key_arg.clone())
+                .arg(Arg::with_name(ARG_PATH).multiple(true).help(ARG_PATH_HELP)),
+        )
+        .subcommand(
+            SubCommand::with_name(CMD_TRIE)
+                .about(CMD_TRIE_ABOUT)
+                .arg(
+                    Arg::with_name(ARG_TRIE_HASH)
+                        .required(true)
+                        .help(ARG_TRIE_HASH_HELP),
+                )
+                .arg(
+                    Arg::with_name(ARG_DEPTH)
+                        .long(ARG_DEPTH)
+                        .value_name(ARG_DEPTH_VALUE)
+                        .help(ARG_DEPTH_HELP)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name(CMD_BALANCE)
+                .about(CMD_BALANCE_ABOUT)
+                .arg(state_hash_arg)
+                .arg(key_arg)
+                .arg(
+                    Arg::with_name(ARG_PROTOCOL_VERSION)
+                        .long(ARG_PROTOCOL_VERSION)
+                        .value_name(ARG_PROTOCOL_VERSION_VALUE)
+                        .help(ARG_PROTOCOL_VERSION_HELP)
+                        .takes_value(true),
+                ),
+        )
+        .get_matches()
+}
+
+/// Gets value of data-dir argument.  Unlike the server, the directory is never created.
+fn get_data_dir(matches: &ArgMatches) -> PathBuf {
+    let mut buf = matches.value_of(ARG_DATA_DIR).map_or(
+        {
+            let mut dir = home_dir().expect(GET_HOME_DIR_EXPECT);
+            dir.push(DEFAULT_DATA_DIR_RELATIVE);
+            dir
+        },
+        PathBuf::from,
+    );
+    buf.push(GLOBAL_STATE_DIR);
+    buf
+}
+
+///  Parses pages argument and returns map size
+fn get_map_size(matches: &ArgMatches) -> usize {
+    let page_size = get_page_size().unwrap();
+    let pages = matches
+        .value_of(ARG_PAGES)
+        .map_or(Ok(DEFAULT_PAGES), usize::from_str)
+        .expect(GET_PAGES_EXPECT);
+    page_size * pages
+}
+
+/// Opens the global state in the data directory without write access
+fn get_global_state(matches: &ArgMatches) -> LmdbGlobalState {
+    let data_dir = get_data_dir(matches);
+    let map_size = get_map_size(matches);
+
+    let environment = {
+        let ret = LmdbEnvironment::open_read_only(&data_dir, map_size)
+            .unwrap_or_else(|_| panic!("{}: {:?}", LMDB_ENVIRONMENT_EXPECT, data_dir));
+        Arc::new(ret)
+    };
+
+    let trie_store = {
+        let ret = LmdbTrieStore::open(&environment, None).expect(LMDB_TRIE_STORE_EXPECT);
+        Arc::new(ret)
+    };
+
+    let protocol_data_store = {
+        let ret =
+            LmdbProtocolDataStore::open(&environment, None).expect(LMDB_PROTOCOL_DATA_STORE_EXPECT);
+        Arc::new(ret)
+    };
+
+    LmdbGlobalState::open(environment, trie_store, protocol_data_store)
+        .expect(LMDB_GLOBAL_STATE_EXPECT)
+}
+
+/// Parses a hex-encoded state hash
+fn parse_state_hash(value: &str) -> Blake2bHash {
+    base16::decode(value)
+        .ok()
+        .and_then(|bytes| Blake2bHash::try_from(bytes.as_slice()).ok())
+        .unwrap_or_else(|| exit_with_error(&format!("{}: {}", STATE_HASH_EXPECT, value)))
+}
+
+/// Parses a key formatted the same way as [`Key::as_string`]
+fn parse_key(value: &str) -> Key {
+    fn decode_addr(hex: &str) -> Option<[u8; 32]> {
+        let bytes = base16::decode(hex).ok()?;
+        <[u8; 32]>::try_from(bytes.as_slice()).ok()
+    }
+
+    let mut parts = value.splitn(2, '-');
+    let maybe_key = match (parts.next(), parts.next()) {
+        (Some("account"), Some(hex)) => decode_addr(hex).map(Key::Account),
+        (Some("hash"), Some(hex)) => decode_addr(hex).map(Key::Hash),
+        (Some("local"), Some(hex)) => decode_addr(hex).map(Key::Local),
+        // Access rights are not part of the stored key, so any suffix is ignored
+        (Some("uref"), Some(rest)) => rest
+            .split('-')
+            .next()
+            .and_then(decode_addr)
+            .map(|addr| Key::URef(URef::new(addr, AccessRights::READ)).normalize()),
+        _ => None,
+    };
+    maybe_key.unwrap_or_else(|| exit_with_error(&format!("{}: {}", KEY_EXPECT, value)))
+}
+
+/// Parses a protocol version given as MAJOR.MINOR.PATCH
+fn parse_protocol_version(value: &str) -> ProtocolVersion {
+    let parts: Vec<u32> = value
+        .split('.')
+        .map(u32::from_str)
+        .collect::<Result<_, _>>()
+        .unwrap_or_else(|_| {
+            exit_with_error(&format!("{}: {}", ARG_PROTOCOL_VERSION_EXPECT, value))
+        });
+    match parts.as_slice() {
+        [major, minor, patch] => ProtocolVersion::from_parts(*major, *minor, *patch),
+        _ => exit_with_error(&format!("{}: {}", ARG_PROTOCOL_VERSION_EXPECT, value)),
+    }
+}
+
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1)
+}
+
+fn hash_as_string(hash: &Blake2bHash) -> String {
+    base16::encode_lower(&hash.to_vec())
+}
+
+/// Reads every hash stored in the trie store
+fn stored_trie_hashes(global_state: &LmdbGlobalState) -> Vec<Blake2bHash> {
+    let txn = global_state
+        .environment
+        .create_read_txn()
+        .expect(LMDB_READ_EXPECT);
+    let keys = txn
+        .keys(Store::<Blake2bHash, Trie<Key, Value>>::handle(
+            &*global_state.trie_store,
+        ))
+        .expect(LMDB_READ_EXPECT);
+    keys.iter()
+        .filter_map(|key| Blake2bHash::try_from(key.as_slice()).ok())
+        .collect()
+}
+
+fn read_trie(global_state: &LmdbGlobalState, hash: &Blake2bHash) -> Option<Trie<Key, Value>> {
+    let txn = global_state
+        .environment
+        .create_read_txn()
+        .expect(LMDB_READ_EXPECT);
+    global_state
+        .trie_store
+        .get(&txn, hash)
+        .expect(LMDB_READ_EXPECT)
+}
+
+fn child_pointers(trie: &Trie<Key, Value>) -> Vec<(Option<usize>, Pointer)> {
+    match trie {
+        Trie::Leaf { .. } => vec![],
+        Trie::Node { pointer_block } => pointer_block[..]
+            .iter()
+            .enumerate()
+            .filter_map(|(index, maybe_pointer)| {
+                maybe_pointer.map(|pointer| (Some(index), pointer))
+            })
+            .collect(),
+        Trie::Extension { pointer, .. } => vec![(None, *pointer)],
+    }
+}
+
+/// Lists hashes of stored tries which no other stored trie points to.  Every state hash ever
+/// committed (and not pruned) appears in this list.
+fn roots(global_state: &LmdbGlobalState) {
+    let hashes = stored_trie_hashes(global_state);
+
+    let mut referenced = BTreeSet::new();
+    for hash in &hashes {
+        if let Some(trie) = read_trie(global_state, hash) {
+            for (_, pointer) in child_pointers(&trie) {
+                referenced.insert(*pointer.hash());
+            }
+        }
+    }
+
+    for hash in hashes.iter().filter(|hash| !referenced.contains(*hash)) {
+        if *hash == global_state.empty_root_hash {
+            println!("{} (empty)", hash_as_string(hash));
+        } else {
+            println!("{}", hash_as_string(hash));
+        }
+    }
+}
+
+/// Reads every protocol version stored in the protocol data store, in ascending order
+fn stored_protocol_versions(global_state: &LmdbGlobalState) -> Vec<ProtocolVersion> {
+    let txn = global_state
+        .environment
+        .create_read_txn()
+        .expect(LMDB_READ_EXPECT);
+    let keys = txn
+        .keys(global_state.protocol_data_store.handle())
+        .expect(LMDB_READ_EXPECT);
+    let mut versions: Vec<ProtocolVersion> = keys
+        .iter()
+        .filter_map(|key| bytesrepr::deserialize(key).ok())
+        .collect();
+    versions.sort();
+    versions
+}
+
+fn protocol_versions(global_state: &LmdbGlobalState) {
+    for protocol_version in stored_protocol_versions(global_state) {
+        let protocol_data = global_state
+            .get_protocol_data(protocol_version)
+            .expect(LMDB_READ_EXPECT);
+        match protocol_data {
+            Some(protocol_data) => println!(
+                "{} mint: {} proof of stake: {}",
+                protocol_version,
+                protocol_data.mint().as_string(),
+                protocol_data.proof_of_stake().as_string()
+            ),
+            None => println!("{}", protocol_version),
+        }
+    }
+}
+
+/// Runs a query the same way the query RPC does, exiting if it does not succeed
+fn run_query(
+    engine_state: &EngineState<LmdbGlobalState>,
+    state_hash: Blake2bHash,
+    key: Key,
+    path: Vec<String>,
+) -> Value {
+    let correlation_id = CorrelationId::new();
+    let query_request = QueryRequest::new(state_hash, key, path);
+    match engine_state.run_query(correlation_id, query_request) {
+        Ok(QueryResult::Success(value)) => value,
+        Ok(QueryResult::ValueNotFound(message)) => {
+            exit_with_error(&format!("Value not found: {}", message))
+        }
+        Ok(QueryResult::RootNotFound) => {
+            exit_with_error(&format!("Root not found: {}", hash_as_string(&state_hash)))
+        }
+        Err(error) => exit_with_error(&format!("Query failed: {:?}", error)),
+    }
+}
+
+fn query(global_state: LmdbGlobalState, matches: &ArgMatches) {
+    let state_hash = parse_state_hash(matches.value_of(ARG_STATE_HASH).expect(STATE_HASH_EXPECT));
+    let key = parse_key(matches.value_of(ARG_KEY).expect(KEY_EXPECT));
+    let path = matches
+        .values_of(ARG_PATH)
+        .map(|values| values.map(String::from).collect())
+        .unwrap_or_default();
+
+    let engine_state = EngineState::new(global_state, EngineConfig::new());
+    let value = run_query(&engine_state, state_hash, key, path);
+    print_value(&value);
+}
+
+fn print_named_keys<'a>(named_keys: impl Iterator<Item = (&'a String, &'a Key)>) {
+    println!("{}named keys:", INDENT);
+    for (name, key) in named_keys {
+        println!("{}{}{}: {}", INDENT, INDENT, name, key.as_string());
+    }
+}
+
+fn print_account(account: &Account) {
+    println!("Account");
+    println!(
+        "{}public key: {}",
+        INDENT,
+        base16::encode_lower(&account.pub_key())
+    );
+    println!(
+        "{}main purse: {}",
+        INDENT,
+        account.purse_id().value().as_string()
+    );
+    println!("{}associated keys:", INDENT);
+    for (public_key, weight) in account.get_associated_keys() {
+        println!(
+            "{}{}{}: {}",
+            INDENT,
+            INDENT,
+            base16::encode_lower(&public_key.value()),
+            weight.value()
+        );
+    }
+    println!(
+        "{}action thresholds: deployment {}, key management {}",
+        INDENT,
+        account.action_thresholds().deployment().value(),
+        account.action_thresholds().key_management().value()
+    );
+    print_named_keys(account.named_keys().iter());
+}
+
+fn print_contract(contract: &Contract) {
+    println!("Contract");
+    println!(
+        "{}protocol version: {}",
+        INDENT,
+        contract.protocol_version()
+    );
+    println!("{}wasm: {} bytes", INDENT, contract.bytes().len());
+    print_named_keys(contract.named_keys().iter());
+}
+
+fn print_value(value: &Value) {
+    match value {
+        Value::Account(account) => print_account(account),
+        Value::Contract(contract) => print_contract(contract),
+        Value::Key(key) => println!("Key({})", key.as_string()),
+        Value::NamedKey(name, key) => println!("NamedKey({}, {})", name, key.as_string()),
+        other => println!("{:?}", other),
+    }
+}
+
+fn print_trie(
+    global_state: &LmdbGlobalState,
+    hash: &Blake2bHash,
+    label: &str,
+    indent: usize,
+    remaining_depth: Option<usize>,
+) {
+    let prefix = INDENT.repeat(indent);
+    let trie = match read_trie(global_state, hash) {
+        Some(trie) => trie,
+        None => {
+            println!("{}{}{} missing", prefix, label, hash_as_string(hash));
+            return;
+        }
+    };
+
+    match &trie {
+        Trie::Leaf { key, value } => println!(
+            "{}{}{} leaf {} = {}",
+            prefix,
+            label,
+            hash_as_string(hash),
+            key.as_string(),
+            value.type_string()
+        ),
+        Trie::Node { .. } => println!("{}{}{} node", prefix, label, hash_as_string(hash)),
+        Trie::Extension { affix, .. } => println!(
+            "{}{}{} extension {}",
+            prefix,
+            label,
+            hash_as_string(hash),
+            base16::encode_lower(affix)
+        ),
+    }
+
+    let children = child_pointers(&trie);
+    match remaining_depth {
+        Some(0) if !children.is_empty() => {
+            println!("{}{}...", prefix, INDENT);
+        }
+        _ => {
+            for (maybe_index, pointer) in children {
+                let child_label = match maybe_index {
+                    Some(index) => format!("[{:02x}] ", index),
+                    None => String::new(),
+                };
+                print_trie(
+                    global_state,
+                    pointer.hash(),
+                    &child_label,
+                    indent + 1,
+                    remaining_depth.map(|depth| depth - 1),
+                );
+            }
+        }
+    }
+}
+
+fn trie(global_state: &LmdbGlobalState, matches: &ArgMatches) {
+    let hash = parse_state_hash(matches.value_of(ARG_TRIE_HASH).expect(STATE_HASH_EXPECT));
+    let maybe_depth = matches
+        .value_of(ARG_DEPTH)
+        .map(|value| usize::from_str(value).expect(ARG_DEPTH_EXPECT));
+    print_trie(global_state, &hash, "", 0, maybe_depth);
+}
+
+/// Resolves a purse's balance through the mint the same way the engine does: the mint stores a
+/// local key derived from its own URef and the purse, which in turn points at the balance.
+fn balance(global_state: LmdbGlobalState, matches: &ArgMatches) {
+    let state_hash = parse_state_hash(matches.value_of(ARG_STATE_HASH).expect(STATE_HASH_EXPECT));
+    let key = parse_key(matches.value_of(ARG_KEY).expect(KEY_EXPECT));
+    let protocol_version = match matches.value_of(ARG_PROTOCOL_VERSION) {
+        Some(value) => parse_protocol_version(value),
+        None => stored_protocol_versions(&global_state)
+            .pop()
+            .unwrap_or_else(|| exit_with_error("No protocol data stored")),
+    };
+
+    let engine_state = EngineState::new(global_state, EngineConfig::new());
+
+    let mint = match engine_state.get_protocol_data(protocol_version) {
+        Ok(Some(protocol_data)) => protocol_data.mint(),
+        Ok(None) => exit_with_error(&format!("Protocol data not found: {}", protocol_version)),
+        Err(error) => exit_with_error(&format!("Could not read protocol data: {:?}", error)),
+    };
+
+    let purse = match key {
+        Key::Account(_) => match run_query(&engine_state, state_hash, key, vec![]) {
+            Value::Account(account) => account.purse_id().value(),
+            other => exit_with_error(&format!("Expected Account, found {}", other.type_string())),
+        },
+        Key::URef(uref) => uref,
+        _ => exit_with_error(&format!("Not an account or purse: {}", key.as_string())),
+    };
+
+    let local_key_bytes = purse.addr().to_bytes().expect(KEY_EXPECT);
+    let balance_mapping_key = Key::local(mint.addr(), &local_key_bytes);
+    let balance_key = match run_query(&engine_state, state_hash, balance_mapping_key, vec![]) {
+        Value::Key(balance_key) => balance_key,
+        other => exit_with_error(&format!("Expected Key, found {}", other.type_string())),
+    };
+
+    match run_query(&engine_state, state_hash, balance_key, vec![]) {
+        Value::UInt512(balance) => println!("{}: {}", purse.as_string(), balance),
+        other => exit_with_error(&format!("Expected UInt512, found {}", other.type_string())),
+    }
+}
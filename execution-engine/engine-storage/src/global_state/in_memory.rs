@@ -1,4 +1,8 @@
-use std::{ops::Deref, sync::Arc};
+use std::{
+    io::{Read, Write},
+    ops::Deref,
+    sync::Arc,
+};
 
 use contract_ffi::{
     key::Key,
@@ -15,15 +19,17 @@ use crate::{
     global_state::{commit, CommitResult, StateProvider, StateReader},
     protocol_data::ProtocolData,
     protocol_data_store::in_memory::InMemoryProtocolDataStore,
+    snapshot::{self, SnapshotSummary},
     store::Store,
     transaction_source::{
         in_memory::{InMemoryEnvironment, InMemoryReadTransaction},
         Transaction, TransactionSource,
     },
-    trie::{operations::create_hashed_empty_trie, Trie},
+    trie::{merkle_proof::TrieMerkleProof, operations::create_hashed_empty_trie, Trie},
     trie_store::{
         in_memory::InMemoryTrieStore,
         operations::{self, read, ReadResult, WriteResult},
+        DiffResult, PruneResult,
     },
 };
 
@@ -186,6 +192,113 @@ impl StateProvider for InMemoryGlobalState {
         Ok(result)
     }
 
+    fn read_with_prefix(
+        &self,
+        correlation_id: CorrelationId,
+        state_hash: Blake2bHash,
+        prefix: &[u8],
+        start_after: Option<&[u8]>,
+        limit: Option<usize>,
+    ) -> Result<Option<Vec<(Key, Value)>>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let ret = match operations::read_with_prefix::<Key, Value, _, _, Self::Error>(
+            correlation_id,
+            &txn,
+            self.trie_store.deref(),
+            &state_hash,
+            prefix,
+            start_after,
+            limit,
+        )? {
+            ReadResult::Found(pairs) => Some(pairs),
+            ReadResult::NotFound | ReadResult::RootNotFound => None,
+        };
+        txn.commit()?;
+        Ok(ret)
+    }
+
+    fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        state_hash: Blake2bHash,
+        key: &Key,
+    ) -> Result<Option<TrieMerkleProof<Key, Value>>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let maybe_proof = operations::read_with_proof::<Key, Value, _, _, Self::Error>(
+            correlation_id,
+            &txn,
+            self.trie_store.deref(),
+            &state_hash,
+            key,
+        )?;
+        txn.commit()?;
+        Ok(maybe_proof)
+    }
+
+    fn diff(
+        &self,
+        correlation_id: CorrelationId,
+        pre_state_hash: Blake2bHash,
+        post_state_hash: Blake2bHash,
+    ) -> Result<DiffResult<Key, Value>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let diff_result = operations::diff::<Key, Value, _, _, Self::Error>(
+            correlation_id,
+            &txn,
+            self.trie_store.deref(),
+            &pre_state_hash,
+            &post_state_hash,
+        )?;
+        txn.commit()?;
+        Ok(diff_result)
+    }
+
+    fn prune(
+        &self,
+        correlation_id: CorrelationId,
+        live_roots: &[Blake2bHash],
+    ) -> Result<PruneResult, Self::Error> {
+        let mut live_roots = live_roots.to_vec();
+        live_roots.push(self.empty_root_hash);
+        let mut txn = self.environment.create_read_write_txn()?;
+        let result = operations::prune::<Key, Value, _, _, Self::Error>(
+            correlation_id,
+            &mut txn,
+            self.trie_store.deref(),
+            &live_roots,
+        )?;
+        // On `RootNotFound` the transaction is dropped without being committed
+        if let PruneResult::Pruned { .. } = result {
+            txn.commit()?;
+        }
+        Ok(result)
+    }
+
+    fn export_snapshot(
+        &self,
+        state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        writer: &mut dyn Write,
+    ) -> Result<SnapshotSummary, snapshot::Error> {
+        snapshot::export(
+            self.environment.deref(),
+            self.trie_store.deref(),
+            self.protocol_data_store.deref(),
+            state_hash,
+            protocol_version,
+            writer,
+        )
+    }
+
+    fn import_snapshot(&self, reader: &mut dyn Read) -> Result<SnapshotSummary, snapshot::Error> {
+        snapshot::import(
+            self.environment.deref(),
+            self.trie_store.deref(),
+            self.protocol_data_store.deref(),
+            reader,
+        )
+    }
+
     fn empty_root(&self) -> Blake2bHash {
         self.empty_root_hash
     }
@@ -193,7 +306,10 @@ impl StateProvider for InMemoryGlobalState {
 
 #[cfg(test)]
 mod tests {
+    use contract_ffi::bytesrepr::ToBytes;
+
     use super::*;
+    use crate::{trie::merkle_proof::verify_proof, trie_store::DiffEntry};
 
     #[derive(Debug, Clone)]
     struct TestPair {
@@ -259,6 +375,82 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn read_with_prefix_returns_expected_pairs() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state();
+        let account_tag = [0u8];
+
+        let pairs = state
+            .read_with_prefix(correlation_id, root_hash, &account_tag, None, None)
+            .unwrap()
+            .unwrap();
+        let expected: Vec<(Key, Value)> = TEST_PAIRS
+            .iter()
+            .cloned()
+            .map(|TestPair { key, value }| (key, value))
+            .collect();
+        assert_eq!(expected, pairs);
+
+        let first_key_bytes = TEST_PAIRS[0].key.to_bytes().unwrap();
+        let pairs = state
+            .read_with_prefix(
+                correlation_id,
+                root_hash,
+                &account_tag,
+                Some(&first_key_bytes),
+                Some(1),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(expected[1..], pairs[..]);
+
+        let hash_tag = [1u8];
+        let pairs = state
+            .read_with_prefix(correlation_id, root_hash, &hash_tag, None, None)
+            .unwrap()
+            .unwrap();
+        assert!(pairs.is_empty());
+    }
+
+    #[test]
+    fn read_with_prefix_fails_if_unknown_hash_is_given() {
+        let correlation_id = CorrelationId::new();
+        let (state, _) = create_test_state();
+        let fake_hash: Blake2bHash = [1u8; 32].into();
+        let result = state
+            .read_with_prefix(correlation_id, fake_hash, &[], None, None)
+            .unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn read_with_proof_returns_verifiable_proofs() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state();
+
+        for TestPair { key, value } in TEST_PAIRS.iter().cloned() {
+            let proof = state
+                .read_with_proof(correlation_id, root_hash, &key)
+                .unwrap()
+                .unwrap();
+            assert_eq!(Ok(Some(&value)), verify_proof(&root_hash, &proof));
+        }
+
+        let missing_key = Key::Account([3u8; 32]);
+        let proof = state
+            .read_with_proof(correlation_id, root_hash, &missing_key)
+            .unwrap()
+            .unwrap();
+        assert_eq!(Ok(None), verify_proof(&root_hash, &proof));
+
+        let fake_hash: Blake2bHash = [1u8; 32].into();
+        let result = state
+            .read_with_proof(correlation_id, fake_hash, &missing_key)
+            .unwrap();
+        assert!(result.is_none());
+    }
+
     #[test]
     fn commit_updates_state() {
         let correlation_id = CorrelationId::new();
@@ -288,6 +480,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn diff_returns_committed_changes() {
+        let correlation_id = CorrelationId::new();
+        let test_pairs_updated = create_test_pairs_updated();
+
+        let (state, root_hash) = create_test_state();
+
+        let effects: AdditiveMap<Key, Transform> = test_pairs_updated
+            .iter()
+            .cloned()
+            .map(|TestPair { key, value }| (key, Transform::Write(value)))
+            .collect();
+
+        let updated_hash = match state.commit(correlation_id, root_hash, effects).unwrap() {
+            CommitResult::Success { state_root, .. } => state_root,
+            _ => panic!("commit failed"),
+        };
+
+        let expected = vec![
+            DiffEntry::Changed {
+                key: TEST_PAIRS[0].key,
+                pre_value: TEST_PAIRS[0].value.clone(),
+                post_value: test_pairs_updated[0].value.clone(),
+            },
+            DiffEntry::Changed {
+                key: TEST_PAIRS[1].key,
+                pre_value: TEST_PAIRS[1].value.clone(),
+                post_value: test_pairs_updated[1].value.clone(),
+            },
+            DiffEntry::Added {
+                key: test_pairs_updated[2].key,
+                value: test_pairs_updated[2].value.clone(),
+            },
+        ];
+        assert_eq!(
+            DiffResult::Diffed(expected),
+            state.diff(correlation_id, root_hash, updated_hash).unwrap()
+        );
+        assert_eq!(
+            DiffResult::Diffed(vec![]),
+            state
+                .diff(correlation_id, updated_hash, updated_hash)
+                .unwrap()
+        );
+
+        let fake_hash: Blake2bHash = [1u8; 32].into();
+        assert_eq!(
+            DiffResult::RootNotFound(fake_hash),
+            state.diff(correlation_id, root_hash, fake_hash).unwrap()
+        );
+    }
+
     #[test]
     fn commit_updates_state_and_original_state_stays_intact() {
         let correlation_id = CorrelationId::new();
@@ -331,6 +575,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn commit_deletes_values() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state();
+
+        let effects: AdditiveMap<Key, Transform> = {
+            let mut tmp = AdditiveMap::new();
+            tmp.insert(TEST_PAIRS[0].key, Transform::Delete);
+            // deleting a missing key is not an error
+            tmp.insert(Key::Account([3u8; 32]), Transform::Delete);
+            tmp
+        };
+
+        let updated_hash = match state.commit(correlation_id, root_hash, effects).unwrap() {
+            CommitResult::Success { state_root, .. } => state_root,
+            _ => panic!("commit failed"),
+        };
+
+        let updated_checkout = state.checkout(updated_hash).unwrap().unwrap();
+        assert_eq!(
+            None,
+            updated_checkout
+                .read(correlation_id, &TEST_PAIRS[0].key)
+                .unwrap()
+        );
+        assert_eq!(
+            Some(TEST_PAIRS[1].value.clone()),
+            updated_checkout
+                .read(correlation_id, &TEST_PAIRS[1].key)
+                .unwrap()
+        );
+
+        let (_, expected_hash) = InMemoryGlobalState::from_pairs(
+            correlation_id,
+            &[(TEST_PAIRS[1].key, TEST_PAIRS[1].value.clone())],
+        )
+        .unwrap();
+        assert_eq!(expected_hash, updated_hash);
+    }
+
     #[test]
     fn initial_state_has_the_expected_hash() {
         let correlation_id = CorrelationId::new();
@@ -1,4 +1,8 @@
-use std::{ops::Deref, sync::Arc};
+use std::{
+    io::{Read, Write},
+    ops::Deref,
+    sync::Arc,
+};
 
 use lmdb;
 
@@ -17,12 +21,14 @@ use crate::{
     global_state::{commit, CommitResult, StateProvider, StateReader},
     protocol_data::ProtocolData,
     protocol_data_store::lmdb::LmdbProtocolDataStore,
+    snapshot::{self, SnapshotSummary},
     store::Store,
     transaction_source::{lmdb::LmdbEnvironment, Transaction, TransactionSource},
-    trie::{operations::create_hashed_empty_trie, Trie},
+    trie::{merkle_proof::TrieMerkleProof, operations::create_hashed_empty_trie, Trie},
     trie_store::{
         lmdb::LmdbTrieStore,
-        operations::{read, ReadResult},
+        operations::{self, read, ReadResult},
+        DiffResult, PruneResult,
     },
 };
 
@@ -62,6 +68,24 @@ impl LmdbGlobalState {
         ))
     }
 
+    /// Creates a state from an existing environment and trie_store without writing to it.
+    ///
+    /// Unlike [`LmdbGlobalState::empty`], the empty root is not stored, so this is suitable for
+    /// environments opened with [`LmdbEnvironment::open_read_only`].
+    pub fn open(
+        environment: Arc<LmdbEnvironment>,
+        trie_store: Arc<LmdbTrieStore>,
+        protocol_data_store: Arc<LmdbProtocolDataStore>,
+    ) -> Result<Self, error::Error> {
+        let (empty_root_hash, _) = create_hashed_empty_trie::<Key, Value>()?;
+        Ok(LmdbGlobalState::new(
+            environment,
+            trie_store,
+            protocol_data_store,
+            empty_root_hash,
+        ))
+    }
+
     /// Creates a state from an existing environment, store, and root_hash.
     /// Intended to be used for testing.
     pub(crate) fn new(
@@ -154,6 +178,113 @@ impl StateProvider for LmdbGlobalState {
         Ok(result)
     }
 
+    fn read_with_prefix(
+        &self,
+        correlation_id: CorrelationId,
+        state_hash: Blake2bHash,
+        prefix: &[u8],
+        start_after: Option<&[u8]>,
+        limit: Option<usize>,
+    ) -> Result<Option<Vec<(Key, Value)>>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let ret = match operations::read_with_prefix::<Key, Value, _, _, Self::Error>(
+            correlation_id,
+            &txn,
+            self.trie_store.deref(),
+            &state_hash,
+            prefix,
+            start_after,
+            limit,
+        )? {
+            ReadResult::Found(pairs) => Some(pairs),
+            ReadResult::NotFound | ReadResult::RootNotFound => None,
+        };
+        txn.commit()?;
+        Ok(ret)
+    }
+
+    fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        state_hash: Blake2bHash,
+        key: &Key,
+    ) -> Result<Option<TrieMerkleProof<Key, Value>>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let maybe_proof = operations::read_with_proof::<Key, Value, _, _, Self::Error>(
+            correlation_id,
+            &txn,
+            self.trie_store.deref(),
+            &state_hash,
+            key,
+        )?;
+        txn.commit()?;
+        Ok(maybe_proof)
+    }
+
+    fn diff(
+        &self,
+        correlation_id: CorrelationId,
+        pre_state_hash: Blake2bHash,
+        post_state_hash: Blake2bHash,
+    ) -> Result<DiffResult<Key, Value>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let diff_result = operations::diff::<Key, Value, _, _, Self::Error>(
+            correlation_id,
+            &txn,
+            self.trie_store.deref(),
+            &pre_state_hash,
+            &post_state_hash,
+        )?;
+        txn.commit()?;
+        Ok(diff_result)
+    }
+
+    fn prune(
+        &self,
+        correlation_id: CorrelationId,
+        live_roots: &[Blake2bHash],
+    ) -> Result<PruneResult, Self::Error> {
+        let mut live_roots = live_roots.to_vec();
+        live_roots.push(self.empty_root_hash);
+        let mut txn = self.environment.create_read_write_txn()?;
+        let result = operations::prune::<Key, Value, _, _, Self::Error>(
+            correlation_id,
+            &mut txn,
+            self.trie_store.deref(),
+            &live_roots,
+        )?;
+        // On `RootNotFound` the transaction is dropped without being committed
+        if let PruneResult::Pruned { .. } = result {
+            txn.commit()?;
+        }
+        Ok(result)
+    }
+
+    fn export_snapshot(
+        &self,
+        state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        writer: &mut dyn Write,
+    ) -> Result<SnapshotSummary, snapshot::Error> {
+        snapshot::export(
+            self.environment.deref(),
+            self.trie_store.deref(),
+            self.protocol_data_store.deref(),
+            state_hash,
+            protocol_version,
+            writer,
+        )
+    }
+
+    fn import_snapshot(&self, reader: &mut dyn Read) -> Result<SnapshotSummary, snapshot::Error> {
+        snapshot::import(
+            self.environment.deref(),
+            self.trie_store.deref(),
+            self.protocol_data_store.deref(),
+            reader,
+        )
+    }
+
     fn empty_root(&self) -> Blake2bHash {
         self.empty_root_hash
     }
@@ -205,8 +336,7 @@ mod tests {
         ]
     }
 
-    fn create_test_state() -> (LmdbGlobalState, Blake2bHash) {
-        let correlation_id = CorrelationId::new();
+    fn create_empty_state() -> LmdbGlobalState {
         let _temp_dir = tempdir().unwrap();
         let environment = Arc::new(
             LmdbEnvironment::new(&_temp_dir.path().to_path_buf(), *TEST_MAP_SIZE).unwrap(),
@@ -216,7 +346,12 @@ mod tests {
         let protocol_data_store = Arc::new(
             LmdbProtocolDataStore::new(&environment, None, DatabaseFlags::empty()).unwrap(),
         );
-        let ret = LmdbGlobalState::empty(environment, trie_store, protocol_data_store).unwrap();
+        LmdbGlobalState::empty(environment, trie_store, protocol_data_store).unwrap()
+    }
+
+    fn create_test_state() -> (LmdbGlobalState, Blake2bHash) {
+        let correlation_id = CorrelationId::new();
+        let ret = create_empty_state();
         let mut current_root = ret.empty_root_hash;
         {
             let mut txn = ret.environment.create_read_write_txn().unwrap();
@@ -335,4 +470,87 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    fn prune_removes_unreachable_states() {
+        let correlation_id = CorrelationId::new();
+        let test_pairs_updated = create_test_pairs_updated();
+
+        let (state, root_hash) = create_test_state();
+        let protocol_data = ProtocolData::default();
+        state
+            .put_protocol_data(ProtocolVersion::V1_0_0, &protocol_data)
+            .unwrap();
+
+        let effects: AdditiveMap<Key, Transform> = {
+            let mut tmp = AdditiveMap::new();
+            for TestPair { key, value } in &test_pairs_updated {
+                tmp.insert(*key, Transform::Write(value.to_owned()));
+            }
+            tmp
+        };
+
+        let updated_hash = match state.commit(correlation_id, root_hash, effects).unwrap() {
+            CommitResult::Success { state_root, .. } => state_root,
+            _ => panic!("commit failed"),
+        };
+
+        let missing_hash: Blake2bHash = [1u8; 32].into();
+        assert_eq!(
+            PruneResult::RootNotFound(missing_hash),
+            state
+                .prune(correlation_id, &[updated_hash, missing_hash])
+                .unwrap()
+        );
+        assert!(state.checkout(root_hash).unwrap().is_some());
+
+        match state.prune(correlation_id, &[updated_hash]).unwrap() {
+            PruneResult::Pruned { deleted, .. } => assert!(deleted > 0),
+            PruneResult::RootNotFound(_) => panic!("prune failed"),
+        }
+
+        assert!(state.checkout(root_hash).unwrap().is_none());
+        assert!(state.checkout(state.empty_root()).unwrap().is_some());
+        let updated_checkout = state.checkout(updated_hash).unwrap().unwrap();
+        for TestPair { key, value } in test_pairs_updated.iter().cloned() {
+            assert_eq!(
+                Some(value),
+                updated_checkout.read(correlation_id, &key).unwrap()
+            );
+        }
+        assert_eq!(
+            Some(protocol_data),
+            state.get_protocol_data(ProtocolVersion::V1_0_0).unwrap()
+        );
+    }
+
+    #[test]
+    fn imported_snapshot_matches_exported_state() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state();
+        let protocol_data = ProtocolData::default();
+        state
+            .put_protocol_data(ProtocolVersion::V1_0_0, &protocol_data)
+            .unwrap();
+
+        let mut snapshot = Vec::new();
+        let exported = state
+            .export_snapshot(root_hash, ProtocolVersion::V1_0_0, &mut snapshot)
+            .unwrap();
+
+        let fresh_state = create_empty_state();
+        let imported = fresh_state.import_snapshot(&mut &snapshot[..]).unwrap();
+        assert_eq!(exported, imported);
+
+        let checkout = fresh_state.checkout(root_hash).unwrap().unwrap();
+        for TestPair { key, value } in TEST_PAIRS.iter().cloned() {
+            assert_eq!(Some(value), checkout.read(correlation_id, &key).unwrap());
+        }
+        assert_eq!(
+            Some(protocol_data),
+            fresh_state
+                .get_protocol_data(ProtocolVersion::V1_0_0)
+                .unwrap()
+        );
+    }
 }
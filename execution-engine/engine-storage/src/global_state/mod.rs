@@ -1,10 +1,17 @@
 pub mod in_memory;
 pub mod lmdb;
 
-use std::{collections::HashMap, fmt, hash::BuildHasher, time::Instant};
+use std::{
+    collections::HashMap,
+    fmt,
+    hash::BuildHasher,
+    io::{Read, Write},
+    time::Instant,
+};
 
 use contract_ffi::{
     key::Key,
+    system_contracts::pos::UnbondPayout,
     value::{account::PublicKey, ProtocolVersion, Value, U512},
 };
 use engine_shared::{
@@ -16,11 +23,12 @@ use engine_shared::{
 
 use crate::{
     protocol_data::ProtocolData,
+    snapshot::{self, SnapshotSummary},
     transaction_source::{Transaction, TransactionSource},
-    trie::Trie,
+    trie::{merkle_proof::TrieMerkleProof, Trie},
     trie_store::{
-        operations::{read, write, ReadResult, WriteResult},
-        TrieStore,
+        operations::{delete, read, write, DeleteResult, ReadResult, WriteResult},
+        DiffResult, PruneResult, TrieStore,
     },
 };
 
@@ -46,6 +54,7 @@ pub enum CommitResult {
     Success {
         state_root: Blake2bHash,
         bonded_validators: HashMap<PublicKey, U512>,
+        unbond_payouts: Vec<UnbondPayout>,
     },
     KeyNotFound(Key),
     TypeMismatch(TypeMismatch),
@@ -58,10 +67,11 @@ impl fmt::Display for CommitResult {
             CommitResult::Success {
                 state_root,
                 bonded_validators,
+                unbond_payouts,
             } => write!(
                 f,
-                "Success: state_root: {}, bonded_validators: {:?}",
-                state_root, bonded_validators
+                "Success: state_root: {}, bonded_validators: {:?}, unbond_payouts: {:?}",
+                state_root, bonded_validators, unbond_payouts
             ),
             CommitResult::KeyNotFound(key) => write!(f, "Key not found: {}", key),
             CommitResult::TypeMismatch(type_mismatch) => {
@@ -108,6 +118,60 @@ pub trait StateProvider {
         protocol_version: ProtocolVersion,
     ) -> Result<Option<ProtocolData>, Self::Error>;
 
+    /// Returns the key-value pairs at the post state of a specific block whose serialized keys
+    /// begin with `prefix`, in ascending order of their serialized keys.
+    ///
+    /// Only keys which serialize to bytes greater than `start_after` are returned, and at most
+    /// `limit` pairs are returned.  Returns `None` if the state hash is not found.
+    fn read_with_prefix(
+        &self,
+        correlation_id: CorrelationId,
+        state_hash: Blake2bHash,
+        prefix: &[u8],
+        start_after: Option<&[u8]>,
+        limit: Option<usize>,
+    ) -> Result<Option<Vec<(Key, Value)>>, Self::Error>;
+
+    /// Returns a proof of the presence or absence of `key` at the post state of a specific
+    /// block, or `None` if the state hash is not found.
+    fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        state_hash: Blake2bHash,
+        key: &Key,
+    ) -> Result<Option<TrieMerkleProof<Key, Value>>, Self::Error>;
+
+    /// Returns the keys whose values were added, removed or changed going from the post state of
+    /// one block to the post state of another.
+    fn diff(
+        &self,
+        correlation_id: CorrelationId,
+        pre_state_hash: Blake2bHash,
+        post_state_hash: Blake2bHash,
+    ) -> Result<DiffResult<Key, Value>, Self::Error>;
+
+    /// Deletes all trie elements which are not reachable from any of `live_roots` or from the
+    /// empty root.
+    ///
+    /// Nothing is deleted if any of the live roots is not found.
+    fn prune(
+        &self,
+        correlation_id: CorrelationId,
+        live_roots: &[Blake2bHash],
+    ) -> Result<PruneResult, Self::Error>;
+
+    /// Writes a [`snapshot`] of the post state of a specific block, and of the protocol data for
+    /// `protocol_version`, to `writer`.
+    fn export_snapshot(
+        &self,
+        state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        writer: &mut dyn Write,
+    ) -> Result<SnapshotSummary, snapshot::Error>;
+
+    /// Reads a [`snapshot`] from `reader` and adds its state and protocol data to this state.
+    fn import_snapshot(&self, reader: &mut dyn Read) -> Result<SnapshotSummary, snapshot::Error>;
+
     fn empty_root(&self) -> Blake2bHash;
 }
 
@@ -139,6 +203,29 @@ where
     let mut writes: i32 = 0;
 
     for (key, transform) in effects.into_iter() {
+        if let Transform::Delete = transform {
+            let delete_result =
+                delete::<_, _, _, _, E>(correlation_id, &mut txn, store, &state_root, &key)?;
+
+            log_duration(
+                correlation_id,
+                GLOBAL_STATE_COMMIT_WRITE_DURATION,
+                COMMIT,
+                start.elapsed(),
+            );
+
+            match delete_result {
+                DeleteResult::Deleted(root_hash) => {
+                    state_root = root_hash;
+                    writes += 1;
+                }
+                // The key may have been written and deleted again by the same effects
+                DeleteResult::DoesNotExist => (),
+                _x @ DeleteResult::RootNotFound => panic!(stringify!(_x)),
+            }
+            continue;
+        }
+
         let read_result = read::<_, _, _, _, E>(correlation_id, &txn, store, &state_root, &key)?;
 
         log_duration(
@@ -208,9 +295,11 @@ where
     );
 
     let bonded_validators = Default::default();
+    let unbond_payouts = Default::default();
 
     Ok(CommitResult::Success {
         state_root,
         bonded_validators,
+        unbond_payouts,
     })
 }
@@ -1,16 +1,40 @@
 use contract_ffi::{
-    bytesrepr::{self, FromBytes, ToBytes},
+    bytesrepr::{
+        self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH, U64_SERIALIZED_LENGTH,
+        U8_SERIALIZED_LENGTH,
+    },
     uref::{AccessRights, URef, UREF_SERIALIZED_LENGTH},
 };
-use engine_wasm_prep::wasm_costs::{WasmCosts, WASM_COSTS_SERIALIZED_LENGTH};
+use engine_wasm_prep::{
+    host_function_costs::{HostFunctionCosts, HOST_FUNCTION_COSTS_SERIALIZED_LENGTH},
+    wasm_costs::{WasmCosts, WASM_COSTS_SERIALIZED_LENGTH},
+};
+
+/// Precedes the layout version of protocol data stored since host function costs were
+/// introduced. Protocol data stored before begins with the regular opcode cost instead, which is
+/// never `u32::MAX`: that cost would run every contract out of gas at its first instruction.
+const PROTOCOL_DATA_VERSION_TAG: u32 = u32::MAX;
 
-const PROTOCOL_DATA_SERIALIZED_LENGTH: usize =
-    WASM_COSTS_SERIALIZED_LENGTH + UREF_SERIALIZED_LENGTH + UREF_SERIALIZED_LENGTH;
+/// The version of the current layout, in which the system contracts are followed by the host
+/// function costs, the minimum gas price and the storage cost per byte.
+const PROTOCOL_DATA_VERSION: u8 = 1;
+
+const PROTOCOL_DATA_SERIALIZED_LENGTH: usize = U32_SERIALIZED_LENGTH
+    + U8_SERIALIZED_LENGTH
+    + WASM_COSTS_SERIALIZED_LENGTH
+    + UREF_SERIALIZED_LENGTH
+    + UREF_SERIALIZED_LENGTH
+    + HOST_FUNCTION_COSTS_SERIALIZED_LENGTH
+    + U64_SERIALIZED_LENGTH
+    + U64_SERIALIZED_LENGTH;
 
 /// Represents a protocol's data. Intended to be associated with a given protocol version.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct ProtocolData {
     wasm_costs: WasmCosts,
+    host_function_costs: HostFunctionCosts,
+    min_gas_price: u64,
+    storage_cost_per_byte: u64,
     mint: URef,
     proof_of_stake: URef,
 }
@@ -23,6 +47,9 @@ impl Default for ProtocolData {
     fn default() -> ProtocolData {
         ProtocolData {
             wasm_costs: WasmCosts::default(),
+            host_function_costs: HostFunctionCosts::default(),
+            min_gas_price: 0,
+            storage_cost_per_byte: 0,
             mint: URef::new([0; 32], AccessRights::READ),
             proof_of_stake: URef::new([0; 32], AccessRights::READ),
         }
@@ -30,10 +57,21 @@ impl Default for ProtocolData {
 }
 
 impl ProtocolData {
-    /// Creates a new [`ProtocolData`] value from a given [`WasmCosts`] value.
-    pub fn new(wasm_costs: WasmCosts, mint: URef, proof_of_stake: URef) -> Self {
+    /// Creates a new [`ProtocolData`] value from given [`WasmCosts`] and [`HostFunctionCosts`]
+    /// values, a minimum gas price and a storage cost per byte.
+    pub fn new(
+        wasm_costs: WasmCosts,
+        host_function_costs: HostFunctionCosts,
+        min_gas_price: u64,
+        storage_cost_per_byte: u64,
+        mint: URef,
+        proof_of_stake: URef,
+    ) -> Self {
         ProtocolData {
             wasm_costs,
+            host_function_costs,
+            min_gas_price,
+            storage_cost_per_byte,
             mint,
             proof_of_stake,
         }
@@ -44,6 +82,22 @@ impl ProtocolData {
         &self.wasm_costs
     }
 
+    /// Gets the [`HostFunctionCosts`] value from a given [`ProtocolData`] value.
+    pub fn host_function_costs(&self) -> &HostFunctionCosts {
+        &self.host_function_costs
+    }
+
+    /// Gets the minimum gas price (in motes per unit of gas) a deploy must offer to be executed.
+    pub fn min_gas_price(&self) -> u64 {
+        self.min_gas_price
+    }
+
+    /// Gets the amount of gas charged for each byte of global state a deploy adds to the usage
+    /// of an account or contract.
+    pub fn storage_cost_per_byte(&self) -> u64 {
+        self.storage_cost_per_byte
+    }
+
     pub fn mint(&self) -> URef {
         self.mint
     }
@@ -68,21 +122,58 @@ impl ProtocolData {
 impl ToBytes for ProtocolData {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut ret: Vec<u8> = Vec::with_capacity(PROTOCOL_DATA_SERIALIZED_LENGTH);
+        ret.append(&mut PROTOCOL_DATA_VERSION_TAG.to_bytes()?);
+        ret.append(&mut PROTOCOL_DATA_VERSION.to_bytes()?);
         ret.append(&mut self.wasm_costs.to_bytes()?);
         ret.append(&mut self.mint.to_bytes()?);
         ret.append(&mut self.proof_of_stake.to_bytes()?);
+        ret.append(&mut self.host_function_costs.to_bytes()?);
+        ret.append(&mut self.min_gas_price.to_bytes()?);
+        ret.append(&mut self.storage_cost_per_byte.to_bytes()?);
         Ok(ret)
     }
 }
 
+impl ProtocolData {
+    /// Deserializes protocol data stored without a version, before host function costs, a
+    /// minimum gas price and storage costs were introduced. Those are free, as they were at the
+    /// time.
+    fn from_bytes_unversioned(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (wasm_costs, rem): (WasmCosts, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (mint_reference, rem): (URef, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (proof_of_stake_reference, rem): (URef, &[u8]) = FromBytes::from_bytes(rem)?;
+        let protocol_data = ProtocolData {
+            wasm_costs,
+            mint: mint_reference,
+            proof_of_stake: proof_of_stake_reference,
+            ..Default::default()
+        };
+        Ok((protocol_data, rem))
+    }
+}
+
 impl FromBytes for ProtocolData {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (wasm_costs, rem): (WasmCosts, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (tag, rem): (u32, &[u8]) = FromBytes::from_bytes(bytes)?;
+        if tag != PROTOCOL_DATA_VERSION_TAG {
+            return ProtocolData::from_bytes_unversioned(bytes);
+        }
+        let (version, rem): (u8, &[u8]) = FromBytes::from_bytes(rem)?;
+        if version != PROTOCOL_DATA_VERSION {
+            return Err(bytesrepr::Error::FormattingError);
+        }
+        let (wasm_costs, rem): (WasmCosts, &[u8]) = FromBytes::from_bytes(rem)?;
         let (mint_reference, rem): (URef, &[u8]) = FromBytes::from_bytes(rem)?;
         let (proof_of_stake_reference, rem): (URef, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (host_function_costs, rem): (HostFunctionCosts, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (min_gas_price, rem): (u64, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (storage_cost_per_byte, rem): (u64, &[u8]) = FromBytes::from_bytes(rem)?;
         Ok((
             ProtocolData {
                 wasm_costs,
+                host_function_costs,
+                min_gas_price,
+                storage_cost_per_byte,
                 mint: mint_reference,
                 proof_of_stake: proof_of_stake_reference,
             },
@@ -93,21 +184,29 @@ impl FromBytes for ProtocolData {
 
 #[cfg(test)]
 pub(crate) mod gens {
-    use proptest::prop_compose;
+    use proptest::{num, prop_compose};
 
     use contract_ffi::gens;
-    use engine_wasm_prep::wasm_costs::gens as wasm_costs_gens;
+    use engine_wasm_prep::{
+        host_function_costs::gens as host_function_costs_gens, wasm_costs::gens as wasm_costs_gens,
+    };
 
     use super::ProtocolData;
 
     prop_compose! {
         pub fn protocol_data_arb()(
             wasm_costs in wasm_costs_gens::wasm_costs_arb(),
+            host_function_costs in host_function_costs_gens::host_function_costs_arb(),
+            min_gas_price in num::u64::ANY,
+            storage_cost_per_byte in num::u64::ANY,
             mint in gens::uref_arb(),
             proof_of_stake in gens::uref_arb(),
         ) -> ProtocolData {
             ProtocolData {
                 wasm_costs,
+                host_function_costs,
+                min_gas_price,
+                storage_cost_per_byte,
                 mint,
                 proof_of_stake,
             }
@@ -120,38 +219,113 @@ mod tests {
     use proptest::proptest;
 
     use contract_ffi::{
-        bytesrepr,
+        bytesrepr::{self, ToBytes},
         uref::{AccessRights, URef},
     };
     use engine_shared::test_utils;
+    use engine_wasm_prep::host_function_costs::HostFunctionCosts;
 
-    use super::{gens, ProtocolData};
+    use super::{gens, ProtocolData, PROTOCOL_DATA_VERSION, PROTOCOL_DATA_VERSION_TAG};
+
+    const MIN_GAS_PRICE: u64 = 1;
+    const STORAGE_COST_PER_BYTE: u64 = 1;
 
     #[test]
     fn should_serialize_and_deserialize() {
         let mock = {
             let costs = test_utils::wasm_costs_mock();
+            let host_function_costs = test_utils::host_function_costs_mock();
             let mint_reference = URef::new([0u8; 32], AccessRights::READ_ADD_WRITE);
             let proof_of_stake_reference = URef::new([1u8; 32], AccessRights::READ_ADD_WRITE);
-            ProtocolData::new(costs, mint_reference, proof_of_stake_reference)
+            ProtocolData::new(
+                costs,
+                host_function_costs,
+                MIN_GAS_PRICE,
+                STORAGE_COST_PER_BYTE,
+                mint_reference,
+                proof_of_stake_reference,
+            )
         };
         let free = {
             let costs = test_utils::wasm_costs_free();
+            let host_function_costs = test_utils::host_function_costs_free();
             let mint_reference = URef::new([0u8; 32], AccessRights::READ_ADD_WRITE);
             let proof_of_stake_reference = URef::new([1u8; 32], AccessRights::READ_ADD_WRITE);
-            ProtocolData::new(costs, mint_reference, proof_of_stake_reference)
+            ProtocolData::new(
+                costs,
+                host_function_costs,
+                MIN_GAS_PRICE,
+                STORAGE_COST_PER_BYTE,
+                mint_reference,
+                proof_of_stake_reference,
+            )
         };
         bytesrepr::test_serialization_roundtrip(&mock);
         bytesrepr::test_serialization_roundtrip(&free);
     }
 
+    #[test]
+    fn should_not_deserialize_unknown_version() {
+        let protocol_data = ProtocolData::new(
+            test_utils::wasm_costs_mock(),
+            test_utils::host_function_costs_mock(),
+            MIN_GAS_PRICE,
+            STORAGE_COST_PER_BYTE,
+            URef::new([0u8; 32], AccessRights::READ_ADD_WRITE),
+            URef::new([1u8; 32], AccessRights::READ_ADD_WRITE),
+        );
+        let mut bytes = protocol_data.to_bytes().expect("should serialize");
+        assert_eq!(&bytes[..4], &PROTOCOL_DATA_VERSION_TAG.to_le_bytes()[..]);
+        assert_eq!(bytes[4], PROTOCOL_DATA_VERSION);
+
+        bytes[4] = PROTOCOL_DATA_VERSION + 1;
+        assert_eq!(
+            bytesrepr::deserialize::<ProtocolData>(&bytes),
+            Err(bytesrepr::Error::FormattingError)
+        );
+    }
+
+    #[test]
+    fn should_deserialize_protocol_data_without_version() {
+        let costs = test_utils::wasm_costs_mock();
+        let mint_reference = URef::new([0u8; 32], AccessRights::READ_ADD_WRITE);
+        let proof_of_stake_reference = URef::new([1u8; 32], AccessRights::READ_ADD_WRITE);
+
+        // The layout before host function costs, the minimum gas price and storage costs
+        let mut bytes = costs.to_bytes().expect("should serialize");
+        bytes.append(&mut mint_reference.to_bytes().expect("should serialize"));
+        bytes.append(
+            &mut proof_of_stake_reference
+                .to_bytes()
+                .expect("should serialize"),
+        );
+
+        let expected = ProtocolData::new(
+            costs,
+            HostFunctionCosts::default(),
+            0,
+            0,
+            mint_reference,
+            proof_of_stake_reference,
+        );
+        assert_eq!(bytesrepr::deserialize(&bytes), Ok(expected));
+    }
+
     #[test]
     fn should_return_all_system_contracts() {
         let mint_reference = URef::new([197u8; 32], AccessRights::READ_ADD_WRITE);
         let proof_of_stake_reference = URef::new([198u8; 32], AccessRights::READ_ADD_WRITE);
         let protocol_data = {
             let costs = test_utils::wasm_costs_mock();
-            ProtocolData::new(costs, mint_reference, proof_of_stake_reference)
+            let host_function_costs = test_utils::host_function_costs_mock();
+            ProtocolData::new(
+                costs,
+                host_function_costs,
+                MIN_GAS_PRICE,
+                STORAGE_COST_PER_BYTE,
+                mint_reference,
+                proof_of_stake_reference,
+            )
         };
 
         let actual = {
@@ -173,7 +347,15 @@ mod tests {
         let proof_of_stake_reference = URef::new([0u8; 32], AccessRights::READ);
         let protocol_data = {
             let costs = test_utils::wasm_costs_mock();
-            ProtocolData::new(costs, mint_reference, proof_of_stake_reference)
+            let host_function_costs = test_utils::host_function_costs_mock();
+            ProtocolData::new(
+                costs,
+                host_function_costs,
+                MIN_GAS_PRICE,
+                STORAGE_COST_PER_BYTE,
+                mint_reference,
+                proof_of_stake_reference,
+            )
         };
 
         let actual = {
@@ -0,0 +1,533 @@
+//! Portable snapshots of global state.
+//!
+//! A snapshot holds every trie element reachable from a single state root, together with the
+//! [`ProtocolData`] for one protocol version.  It can be imported into another data directory to
+//! bootstrap a node without replaying blocks.
+//!
+//! # Format
+//!
+//! A snapshot file begins with [`MAGIC`] and a little-endian `u32` format version, followed by a
+//! sequence of chunks.  Each chunk is a little-endian `u32` body length, the body, and the 32-byte
+//! BLAKE2b hash of the body as a checksum.
+//!
+//! The body of the first chunk is the serialized header, which holds the state root, the protocol
+//! version and the protocol data.  The body of every following chunk is a serialized list of
+//! serialized trie elements.  Their hashes are not stored, since they are recomputed on import.
+
+use std::{
+    collections::HashSet,
+    io::{self, Read, Write},
+};
+
+use failure::Fail;
+
+use contract_ffi::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    key::Key,
+    value::{ProtocolVersion, Value},
+};
+use engine_shared::newtypes::{Blake2bHash, BLAKE2B_DIGEST_LENGTH};
+
+use crate::{
+    error::{self, in_memory},
+    protocol_data::ProtocolData,
+    protocol_data_store::ProtocolDataStore,
+    transaction_source::{Transaction, TransactionSource},
+    trie::Trie,
+    trie_store::TrieStore,
+};
+
+/// The bytes with which every snapshot begins.
+pub const MAGIC: [u8; 8] = *b"CLSNAPSH";
+
+/// The version of the snapshot format written by [`export`].
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Trie elements are collected into a chunk until its body reaches this size.
+const CHUNK_SIZE_TARGET: usize = 4 * 1024 * 1024;
+
+/// Chunks with longer bodies are rejected, to avoid large allocations when reading corrupt files.
+const MAX_CHUNK_LENGTH: usize = 64 * 1024 * 1024;
+
+#[derive(Debug, Fail)]
+pub enum Error {
+    #[fail(display = "{}", _0)]
+    Io(#[fail(cause)] io::Error),
+
+    #[fail(display = "{}", _0)]
+    BytesRepr(#[fail(cause)] bytesrepr::Error),
+
+    #[fail(display = "{}", _0)]
+    Storage(#[fail(cause)] error::Error),
+
+    #[fail(display = "Not a global state snapshot")]
+    InvalidMagic,
+
+    #[fail(display = "Unsupported snapshot format version: {}", _0)]
+    UnsupportedVersion(u32),
+
+    #[fail(display = "Snapshot has no header")]
+    MissingHeader,
+
+    #[fail(display = "Checksum mismatch in chunk {}", _0)]
+    ChecksumMismatch(usize),
+
+    #[fail(display = "Chunk {} is too large: {} bytes", _0, _1)]
+    ChunkTooLarge(usize, usize),
+
+    #[fail(display = "State root not found: {}", _0)]
+    RootNotFound(Blake2bHash),
+
+    #[fail(display = "Protocol data not found for protocol version {:?}", _0)]
+    ProtocolDataNotFound(ProtocolVersion),
+
+    #[fail(display = "Trie element missing from snapshot: {}", _0)]
+    MissingTrieElement(Blake2bHash),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<bytesrepr::Error> for Error {
+    fn from(error: bytesrepr::Error) -> Self {
+        Error::BytesRepr(error)
+    }
+}
+
+impl From<error::Error> for Error {
+    fn from(error: error::Error) -> Self {
+        Error::Storage(error)
+    }
+}
+
+impl From<lmdb::Error> for Error {
+    fn from(error: lmdb::Error) -> Self {
+        Error::Storage(error.into())
+    }
+}
+
+impl From<in_memory::Error> for Error {
+    fn from(error: in_memory::Error) -> Self {
+        Error::Storage(error.into())
+    }
+}
+
+/// A description of an exported or imported snapshot.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SnapshotSummary {
+    pub state_root: Blake2bHash,
+    pub protocol_version: ProtocolVersion,
+    pub trie_count: usize,
+    pub chunk_count: usize,
+}
+
+struct SnapshotHeader {
+    state_root: Blake2bHash,
+    protocol_version: ProtocolVersion,
+    protocol_data: ProtocolData,
+}
+
+impl ToBytes for SnapshotHeader {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = Vec::new();
+        ret.append(&mut self.state_root.to_bytes()?);
+        ret.append(&mut self.protocol_version.to_bytes()?);
+        ret.append(&mut self.protocol_data.to_bytes()?);
+        Ok(ret)
+    }
+}
+
+impl FromBytes for SnapshotHeader {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (state_root, rem): (Blake2bHash, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (protocol_version, rem): (ProtocolVersion, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (protocol_data, rem): (ProtocolData, &[u8]) = FromBytes::from_bytes(rem)?;
+        Ok((
+            SnapshotHeader {
+                state_root,
+                protocol_version,
+                protocol_data,
+            },
+            rem,
+        ))
+    }
+}
+
+struct ChunkWriter<'w> {
+    writer: &'w mut dyn Write,
+    chunk_count: usize,
+}
+
+impl<'w> ChunkWriter<'w> {
+    fn new(writer: &'w mut dyn Write) -> Result<Self, Error> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&FORMAT_VERSION.to_le_bytes())?;
+        Ok(ChunkWriter {
+            writer,
+            chunk_count: 0,
+        })
+    }
+
+    fn write_chunk(&mut self, body: &[u8]) -> Result<(), Error> {
+        if body.len() > MAX_CHUNK_LENGTH {
+            return Err(Error::ChunkTooLarge(self.chunk_count, body.len()));
+        }
+        let checksum = Blake2bHash::new(body);
+        self.writer.write_all(&(body.len() as u32).to_le_bytes())?;
+        self.writer.write_all(body)?;
+        self.writer.write_all(&checksum.to_vec())?;
+        self.chunk_count += 1;
+        Ok(())
+    }
+}
+
+struct ChunkReader<'r> {
+    reader: &'r mut dyn Read,
+    chunk_count: usize,
+}
+
+impl<'r> ChunkReader<'r> {
+    fn new(reader: &'r mut dyn Read) -> Result<Self, Error> {
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(Error::InvalidMagic);
+        }
+        let mut version_bytes = [0u8; 4];
+        reader.read_exact(&mut version_bytes)?;
+        let version = u32::from_le_bytes(version_bytes);
+        if version != FORMAT_VERSION {
+            return Err(Error::UnsupportedVersion(version));
+        }
+        Ok(ChunkReader {
+            reader,
+            chunk_count: 0,
+        })
+    }
+
+    /// Returns the body of the next chunk, or `None` at the end of the snapshot.
+    fn read_chunk(&mut self) -> Result<Option<Vec<u8>>, Error> {
+        let mut length_bytes = [0u8; 4];
+        if !read_exact_or_eof(self.reader, &mut length_bytes)? {
+            return Ok(None);
+        }
+        let length = u32::from_le_bytes(length_bytes) as usize;
+        if length > MAX_CHUNK_LENGTH {
+            return Err(Error::ChunkTooLarge(self.chunk_count, length));
+        }
+        let mut body = vec![0u8; length];
+        self.reader.read_exact(&mut body)?;
+        let mut checksum = [0u8; BLAKE2B_DIGEST_LENGTH];
+        self.reader.read_exact(&mut checksum)?;
+        if Blake2bHash::new(&body) != Blake2bHash::from(checksum) {
+            return Err(Error::ChecksumMismatch(self.chunk_count));
+        }
+        self.chunk_count += 1;
+        Ok(Some(body))
+    }
+}
+
+/// Fills `buf` from `reader`, returning `false` if the reader is already at its end.
+fn read_exact_or_eof(reader: &mut dyn Read, buf: &mut [u8]) -> io::Result<bool> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) if filled == 0 => return Ok(false),
+            Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(read) => filled += read,
+            Err(ref error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(true)
+}
+
+/// Pushes the hashes of the children of `trie` which have not been visited onto `pending`.
+fn push_children(
+    trie: &Trie<Key, Value>,
+    visited: &HashSet<Blake2bHash>,
+    pending: &mut Vec<Blake2bHash>,
+) {
+    match trie {
+        Trie::Leaf { .. } => {}
+        Trie::Node { pointer_block } => {
+            for pointer in pointer_block[..].iter().flatten() {
+                if !visited.contains(pointer.hash()) {
+                    pending.push(*pointer.hash());
+                }
+            }
+        }
+        Trie::Extension { pointer, .. } => {
+            if !visited.contains(pointer.hash()) {
+                pending.push(*pointer.hash());
+            }
+        }
+    }
+}
+
+/// Writes a snapshot of the state at `state_root` and of the protocol data for
+/// `protocol_version` to `writer`.
+pub fn export<'a, R, S, P>(
+    environment: &'a R,
+    trie_store: &S,
+    protocol_data_store: &P,
+    state_root: Blake2bHash,
+    protocol_version: ProtocolVersion,
+    writer: &mut dyn Write,
+) -> Result<SnapshotSummary, Error>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<Key, Value>,
+    P: ProtocolDataStore<Handle = S::Handle>,
+    S::Error: From<R::Error>,
+    P::Error: From<R::Error>,
+    Error: From<R::Error> + From<S::Error> + From<P::Error>,
+{
+    let txn = environment.create_read_txn()?;
+
+    let protocol_data = protocol_data_store
+        .get(&txn, &protocol_version)?
+        .ok_or_else(|| Error::ProtocolDataNotFound(protocol_version))?;
+    if trie_store.get(&txn, &state_root)?.is_none() {
+        return Err(Error::RootNotFound(state_root));
+    }
+
+    let mut chunk_writer = ChunkWriter::new(writer)?;
+    let header = SnapshotHeader {
+        state_root,
+        protocol_version,
+        protocol_data,
+    };
+    chunk_writer.write_chunk(&header.to_bytes()?)?;
+
+    let mut trie_count: usize = 0;
+    let mut visited: HashSet<Blake2bHash> = HashSet::new();
+    let mut pending: Vec<Blake2bHash> = vec![state_root];
+    let mut chunk: Vec<Vec<u8>> = Vec::new();
+    let mut chunk_size: usize = 0;
+
+    while let Some(hash) = pending.pop() {
+        if !visited.insert(hash) {
+            continue;
+        }
+        let trie = trie_store
+            .get(&txn, &hash)?
+            .ok_or_else(|| Error::MissingTrieElement(hash))?;
+        push_children(&trie, &visited, &mut pending);
+
+        let trie_bytes = trie.to_bytes()?;
+        chunk_size += trie_bytes.len();
+        chunk.push(trie_bytes);
+        trie_count += 1;
+
+        if chunk_size >= CHUNK_SIZE_TARGET {
+            chunk_writer.write_chunk(&chunk.to_bytes()?)?;
+            chunk.clear();
+            chunk_size = 0;
+        }
+    }
+    if !chunk.is_empty() {
+        chunk_writer.write_chunk(&chunk.to_bytes()?)?;
+    }
+
+    txn.commit()?;
+
+    Ok(SnapshotSummary {
+        state_root,
+        protocol_version,
+        trie_count,
+        chunk_count: chunk_writer.chunk_count,
+    })
+}
+
+/// Reads a snapshot from `reader` and writes its trie elements and protocol data to the given
+/// stores.
+///
+/// Every trie element reachable from the snapshot's state root must be present once the snapshot
+/// has been read, which verifies the state root since trie elements are stored at their hashes.
+/// Nothing is written if the snapshot is corrupt or incomplete.
+pub fn import<'a, R, S, P>(
+    environment: &'a R,
+    trie_store: &S,
+    protocol_data_store: &P,
+    reader: &mut dyn Read,
+) -> Result<SnapshotSummary, Error>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<Key, Value>,
+    P: ProtocolDataStore<Handle = S::Handle>,
+    S::Error: From<R::Error>,
+    P::Error: From<R::Error>,
+    Error: From<R::Error> + From<S::Error> + From<P::Error>,
+{
+    let mut chunk_reader = ChunkReader::new(reader)?;
+    let header: SnapshotHeader = match chunk_reader.read_chunk()? {
+        Some(body) => bytesrepr::deserialize(&body)?,
+        None => return Err(Error::MissingHeader),
+    };
+
+    let mut txn = environment.create_read_write_txn()?;
+
+    let mut trie_count: usize = 0;
+    while let Some(body) = chunk_reader.read_chunk()? {
+        let elements: Vec<Vec<u8>> = bytesrepr::deserialize(&body)?;
+        for trie_bytes in elements {
+            let trie: Trie<Key, Value> = bytesrepr::deserialize(&trie_bytes)?;
+            trie_store.put(&mut txn, &Blake2bHash::new(&trie_bytes), &trie)?;
+            trie_count += 1;
+        }
+    }
+
+    let mut visited: HashSet<Blake2bHash> = HashSet::new();
+    let mut pending: Vec<Blake2bHash> = vec![header.state_root];
+    while let Some(hash) = pending.pop() {
+        if !visited.insert(hash) {
+            continue;
+        }
+        let trie = trie_store
+            .get(&txn, &hash)?
+            .ok_or_else(|| Error::MissingTrieElement(hash))?;
+        push_children(&trie, &visited, &mut pending);
+    }
+
+    protocol_data_store.put(&mut txn, &header.protocol_version, &header.protocol_data)?;
+    txn.commit()?;
+
+    Ok(SnapshotSummary {
+        state_root: header.state_root,
+        protocol_version: header.protocol_version,
+        trie_count,
+        chunk_count: chunk_reader.chunk_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use engine_shared::newtypes::CorrelationId;
+
+    use super::*;
+    use crate::global_state::{in_memory::InMemoryGlobalState, StateProvider, StateReader};
+
+    const TEST_PAIRS: [(Key, Value); 3] = [
+        (Key::Account([1u8; 32]), Value::Int32(1)),
+        (Key::Account([2u8; 32]), Value::Int32(2)),
+        (Key::Hash([3u8; 32]), Value::Int32(3)),
+    ];
+
+    fn create_snapshot() -> (Blake2bHash, ProtocolData, Vec<u8>) {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) =
+            InMemoryGlobalState::from_pairs(correlation_id, &TEST_PAIRS).unwrap();
+        let protocol_data = ProtocolData::default();
+        state
+            .put_protocol_data(ProtocolVersion::V1_0_0, &protocol_data)
+            .unwrap();
+
+        let mut snapshot = Vec::new();
+        let summary = export(
+            state.environment.as_ref(),
+            state.trie_store.as_ref(),
+            state.protocol_data_store.as_ref(),
+            root_hash,
+            ProtocolVersion::V1_0_0,
+            &mut snapshot,
+        )
+        .unwrap();
+        assert_eq!(summary.state_root, root_hash);
+        assert_eq!(summary.chunk_count, 2);
+        (root_hash, protocol_data, snapshot)
+    }
+
+    fn import_into_empty_state(
+        snapshot: &[u8],
+    ) -> (InMemoryGlobalState, Result<SnapshotSummary, Error>) {
+        let state = InMemoryGlobalState::empty().unwrap();
+        let result = import(
+            state.environment.as_ref(),
+            state.trie_store.as_ref(),
+            state.protocol_data_store.as_ref(),
+            &mut &snapshot[..],
+        );
+        (state, result)
+    }
+
+    #[test]
+    fn import_restores_exported_state() {
+        let correlation_id = CorrelationId::new();
+        let (root_hash, protocol_data, snapshot) = create_snapshot();
+
+        let (state, result) = import_into_empty_state(&snapshot);
+        let summary = result.unwrap();
+        assert_eq!(summary.state_root, root_hash);
+        assert_eq!(summary.protocol_version, ProtocolVersion::V1_0_0);
+
+        let checkout = state.checkout(root_hash).unwrap().unwrap();
+        for (key, value) in TEST_PAIRS.iter().cloned() {
+            assert_eq!(Some(value), checkout.read(correlation_id, &key).unwrap());
+        }
+        assert_eq!(
+            Some(protocol_data),
+            state.get_protocol_data(ProtocolVersion::V1_0_0).unwrap()
+        );
+    }
+
+    #[test]
+    fn import_rejects_corrupt_chunks() {
+        let (root_hash, _, mut snapshot) = create_snapshot();
+        let last_index = snapshot.len() - BLAKE2B_DIGEST_LENGTH - 1;
+        snapshot[last_index] ^= 0xff;
+
+        let (state, result) = import_into_empty_state(&snapshot);
+        match result {
+            Err(Error::ChecksumMismatch(1)) => {}
+            other => panic!("expected a checksum mismatch, got {:?}", other),
+        }
+        assert!(state.checkout(root_hash).unwrap().is_none());
+    }
+
+    #[test]
+    fn import_rejects_truncated_snapshots() {
+        let (root_hash, _, snapshot) = create_snapshot();
+
+        let (state, result) = import_into_empty_state(&snapshot[..snapshot.len() - 1]);
+        match result {
+            Err(Error::Io(_)) => {}
+            other => panic!("expected an io error, got {:?}", other),
+        }
+        assert!(state.checkout(root_hash).unwrap().is_none());
+    }
+
+    #[test]
+    fn import_rejects_other_files() {
+        let (_, result) = import_into_empty_state(b"not a snapshot");
+        match result {
+            Err(Error::InvalidMagic) => {}
+            other => panic!("expected invalid magic, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn export_fails_if_unknown_hash_is_given() {
+        let state = InMemoryGlobalState::empty().unwrap();
+        state
+            .put_protocol_data(ProtocolVersion::V1_0_0, &ProtocolData::default())
+            .unwrap();
+        let missing_hash: Blake2bHash = [1u8; 32].into();
+
+        let result = export(
+            state.environment.as_ref(),
+            state.trie_store.as_ref(),
+            state.protocol_data_store.as_ref(),
+            missing_hash,
+            ProtocolVersion::V1_0_0,
+            &mut Vec::new(),
+        );
+        match result {
+            Err(Error::RootNotFound(hash)) => assert_eq!(hash, missing_hash),
+            other => panic!("expected root not found, got {:?}", other),
+        }
+    }
+}
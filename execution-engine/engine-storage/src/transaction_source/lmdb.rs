@@ -1,6 +1,8 @@
 use std::path::PathBuf;
 
-use lmdb::{self, Database, Environment, RoTransaction, RwTransaction, WriteFlags};
+use lmdb::{
+    self, Cursor, Database, Environment, EnvironmentFlags, RoTransaction, RwTransaction, WriteFlags,
+};
 
 use crate::{
     error,
@@ -26,6 +28,11 @@ impl<'a> Readable for RoTransaction<'a> {
             Err(e) => Err(e),
         }
     }
+
+    fn keys(&self, handle: Self::Handle) -> Result<Vec<Vec<u8>>, Self::Error> {
+        let mut cursor = lmdb::Transaction::open_ro_cursor(self, handle)?;
+        Ok(cursor.iter_start().map(|(key, _)| key.to_vec()).collect())
+    }
 }
 
 impl<'a> Transaction for RwTransaction<'a> {
@@ -46,6 +53,11 @@ impl<'a> Readable for RwTransaction<'a> {
             Err(e) => Err(e),
         }
     }
+
+    fn keys(&self, handle: Self::Handle) -> Result<Vec<Vec<u8>>, Self::Error> {
+        let mut cursor = lmdb::Transaction::open_ro_cursor(self, handle)?;
+        Ok(cursor.iter_start().map(|(key, _)| key.to_vec()).collect())
+    }
 }
 
 impl<'a> Writable for RwTransaction<'a> {
@@ -53,6 +65,13 @@ impl<'a> Writable for RwTransaction<'a> {
         self.put(handle, &key, &value, WriteFlags::empty())
             .map_err(Into::into)
     }
+
+    fn delete(&mut self, handle: Self::Handle, key: &[u8]) -> Result<(), Self::Error> {
+        match self.del(handle, &key, None) {
+            Ok(()) | Err(lmdb::Error::NotFound) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
 }
 
 /// The environment for an LMDB-backed trie store.
@@ -74,6 +93,20 @@ impl LmdbEnvironment {
         Ok(LmdbEnvironment { path, env })
     }
 
+    /// Opens an existing environment without write access.
+    ///
+    /// Databases in a read-only environment must be opened with `open` rather than created, and
+    /// attempting to begin a read-write transaction will fail.
+    pub fn open_read_only(path: &PathBuf, map_size: usize) -> Result<Self, error::Error> {
+        let env = Environment::new()
+            .set_flags(EnvironmentFlags::READ_ONLY)
+            .set_max_dbs(MAX_DBS)
+            .set_map_size(map_size)
+            .open(path)?;
+        let path = path.to_owned();
+        Ok(LmdbEnvironment { path, env })
+    }
+
     pub fn path(&self) -> &PathBuf {
         &self.path
     }
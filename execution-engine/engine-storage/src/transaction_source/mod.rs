@@ -25,12 +25,18 @@ pub trait Transaction: Sized {
 pub trait Readable: Transaction {
     /// Returns the value from the corresponding key from a given [`Transaction::Handle`].
     fn read(&self, handle: Self::Handle, key: &[u8]) -> Result<Option<Vec<u8>>, Self::Error>;
+
+    /// Returns all the keys in a given [`Transaction::Handle`].
+    fn keys(&self, handle: Self::Handle) -> Result<Vec<Vec<u8>>, Self::Error>;
 }
 
 /// A transaction with the capability to write to a given [`Handle`](Transaction::Handle).
 pub trait Writable: Transaction {
     /// Inserts a key-value pair into a given [`Transaction::Handle`].
     fn write(&mut self, handle: Self::Handle, key: &[u8], value: &[u8]) -> Result<(), Self::Error>;
+
+    /// Removes a key and its value from a given [`Transaction::Handle`], if present.
+    fn delete(&mut self, handle: Self::Handle, key: &[u8]) -> Result<(), Self::Error>;
 }
 
 /// A source of transactions e.g. values that implement [`Readable`]
@@ -0,0 +1,135 @@
+//! Merkle proofs of the presence or absence of a key in a [`Trie`].
+//!
+//! A proof can be checked against a state root hash with [`verify_proof`] without access to the
+//! trie store, which makes it suitable for light clients.
+
+use failure::Fail;
+
+use contract_ffi::bytesrepr::{self, ToBytes};
+use engine_shared::newtypes::Blake2bHash;
+
+use crate::trie::Trie;
+
+/// A proof that a key is, or is not, present in a trie with a given root hash.
+///
+/// The proof consists of the trie elements visited when reading the key, starting with the root
+/// and ending with the element at which the read stopped.  That is either the leaf holding the
+/// key, or an element which shows that the key is absent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrieMerkleProof<K, V> {
+    key: K,
+    path: Vec<Trie<K, V>>,
+}
+
+impl<K, V> TrieMerkleProof<K, V> {
+    pub fn new(key: K, path: Vec<Trie<K, V>>) -> Self {
+        TrieMerkleProof { key, path }
+    }
+
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn path(&self) -> &[Trie<K, V>] {
+        &self.path
+    }
+
+    pub fn into_path(self) -> Vec<Trie<K, V>> {
+        self.path
+    }
+}
+
+#[derive(Debug, Clone, Fail, PartialEq, Eq)]
+pub enum ProofError {
+    #[fail(
+        display = "Hash of proof element {} does not match the expected hash",
+        _0
+    )]
+    HashMismatch(usize),
+
+    #[fail(display = "Proof ends before showing the presence or absence of the key")]
+    Truncated,
+
+    #[fail(display = "Proof has {} unexpected trailing elements", _0)]
+    TrailingElements(usize),
+
+    #[fail(display = "Proof path is longer than the key")]
+    KeyTooShort,
+
+    #[fail(display = "{}", _0)]
+    BytesRepr(#[fail(cause)] bytesrepr::Error),
+}
+
+impl From<bytesrepr::Error> for ProofError {
+    fn from(error: bytesrepr::Error) -> Self {
+        ProofError::BytesRepr(error)
+    }
+}
+
+/// Verifies a proof against the given root hash.
+///
+/// Returns the value at the proof's key if the proof shows that the key is present, or `None` if
+/// it shows that the key is absent.
+pub fn verify_proof<'a, K, V>(
+    root_hash: &Blake2bHash,
+    proof: &'a TrieMerkleProof<K, V>,
+) -> Result<Option<&'a V>, ProofError>
+where
+    K: ToBytes + Eq,
+    V: ToBytes,
+{
+    let key_bytes = proof.key.to_bytes()?;
+    let path_length = proof.path.len();
+
+    // Checks that the element at `index` is the last one in the proof.
+    let finish = |index: usize, result: Option<&'a V>| -> Result<Option<&'a V>, ProofError> {
+        let trailing = path_length - index - 1;
+        if trailing > 0 {
+            return Err(ProofError::TrailingElements(trailing));
+        }
+        Ok(result)
+    };
+
+    let mut expected_hash = *root_hash;
+    let mut depth: usize = 0;
+
+    for (index, trie) in proof.path.iter().enumerate() {
+        let hash = Blake2bHash::new(&trie.to_bytes()?);
+        if hash != expected_hash {
+            return Err(ProofError::HashMismatch(index));
+        }
+
+        let maybe_next_hash = match trie {
+            Trie::Leaf { key, value } => {
+                let result = if *key == proof.key {
+                    Some(value)
+                } else {
+                    // Keys may not match in the case of a compressed path from a Node directly
+                    // to a Leaf
+                    None
+                };
+                return finish(index, result);
+            }
+            Trie::Node { pointer_block } => {
+                let path_index = *key_bytes.get(depth).ok_or(ProofError::KeyTooShort)?;
+                depth += 1;
+                pointer_block[usize::from(path_index)].map(|pointer| *pointer.hash())
+            }
+            Trie::Extension { affix, pointer } => {
+                if key_bytes[depth..].starts_with(affix) {
+                    depth += affix.len();
+                    Some(*pointer.hash())
+                } else {
+                    None
+                }
+            }
+        };
+
+        match maybe_next_hash {
+            Some(next_hash) => expected_hash = next_hash,
+            None => return finish(index, None),
+        }
+    }
+
+    Err(ProofError::Truncated)
+}
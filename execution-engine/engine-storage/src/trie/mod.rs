@@ -5,6 +5,8 @@ use std::{mem::size_of, ops::Deref};
 use contract_ffi::bytesrepr::{self, FromBytes, ToBytes};
 use engine_shared::newtypes::Blake2bHash;
 
+pub mod merkle_proof;
+
 #[cfg(test)]
 pub mod gens;
 
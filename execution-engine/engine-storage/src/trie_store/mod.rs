@@ -12,6 +12,8 @@ use engine_shared::newtypes::Blake2bHash;
 
 use crate::{store::Store, trie::Trie};
 
+pub use self::operations::{DiffEntry, DiffResult, PruneResult};
+
 const NAME: &str = "TRIE_STORE";
 
 /// An entity which persists [`Trie`] values at their hashes.
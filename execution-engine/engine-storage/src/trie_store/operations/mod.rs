@@ -1,7 +1,11 @@
 #[cfg(test)]
 mod tests;
 
-use std::time::Instant;
+use std::{
+    cmp,
+    collections::{BTreeMap, HashSet},
+    time::Instant,
+};
 
 use contract_ffi::bytesrepr::{self, FromBytes, ToBytes};
 use engine_shared::{
@@ -11,7 +15,7 @@ use engine_shared::{
 
 use crate::{
     transaction_source::{Readable, Writable},
-    trie::{self, Parents, Pointer, Trie, RADIX},
+    trie::{self, merkle_proof::TrieMerkleProof, Parents, Pointer, Trie, RADIX},
     trie_store::TrieStore,
 };
 
@@ -21,11 +25,19 @@ const TRIE_STORE_SCAN_DURATION: &str = "trie_store_scan_duration";
 const TRIE_STORE_SCAN_GETS: &str = "trie_store_scan_gets";
 const TRIE_STORE_WRITE_DURATION: &str = "trie_store_write_duration";
 const TRIE_STORE_WRITE_PUTS: &str = "trie_store_write_puts";
+const TRIE_STORE_DELETE_DURATION: &str = "trie_store_delete_duration";
+const TRIE_STORE_DELETE_PUTS: &str = "trie_store_delete_puts";
+const TRIE_STORE_PRUNE_DURATION: &str = "trie_store_prune_duration";
+const TRIE_STORE_PRUNE_DELETES: &str = "trie_store_prune_deletes";
+const TRIE_STORE_DIFF_DURATION: &str = "trie_store_diff_duration";
 const READ: &str = "read";
 const GET: &str = "get";
 const SCAN: &str = "scan";
 const WRITE: &str = "write";
 const PUT: &str = "put";
+const PRUNE: &str = "prune";
+const DELETE: &str = "delete";
+const DIFF: &str = "diff";
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum ReadResult<V> {
@@ -610,6 +622,71 @@ where
     })
 }
 
+/// Returns a proof of the presence or absence of a key at a given root in a given store, or
+/// `None` if the root is not found.
+pub fn read_with_proof<K, V, T, S, E>(
+    _correlation_id: CorrelationId,
+    txn: &T,
+    store: &S,
+    root: &Blake2bHash,
+    key: &K,
+) -> Result<Option<TrieMerkleProof<K, V>>, E>
+where
+    K: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
+    V: ToBytes + FromBytes,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<contract_ffi::bytesrepr::Error>,
+{
+    let path: Vec<u8> = key.to_bytes()?;
+
+    let mut depth: usize = 0;
+    let mut proof_path: Vec<Trie<K, V>> = Vec::new();
+    let mut current: Trie<K, V> = match store.get(txn, root)? {
+        Some(root) => root,
+        None => return Ok(None),
+    };
+
+    loop {
+        let maybe_next_hash: Option<Blake2bHash> = match current {
+            Trie::Leaf { .. } => None,
+            Trie::Node { ref pointer_block } => {
+                let index: usize = {
+                    assert!(depth < path.len(), "depth must be < {}", path.len());
+                    path[depth].into()
+                };
+                depth += 1;
+                pointer_block[index].map(|pointer| *pointer.hash())
+            }
+            Trie::Extension {
+                ref affix,
+                ref pointer,
+            } => {
+                if path[depth..].starts_with(affix) {
+                    depth += affix.len();
+                    Some(*pointer.hash())
+                } else {
+                    None
+                }
+            }
+        };
+
+        proof_path.push(current);
+
+        match maybe_next_hash {
+            Some(next_hash) => match store.get(txn, &next_hash)? {
+                Some(next) => current = next,
+                None => panic!(
+                    "No trie value at key: {:?} (reading from key: {:?})",
+                    next_hash, key
+                ),
+            },
+            None => return Ok(Some(TrieMerkleProof::new(key.clone(), proof_path))),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum WriteResult {
     Written(Blake2bHash),
@@ -730,6 +807,126 @@ where
     }
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub enum DeleteResult {
+    Deleted(Blake2bHash),
+    DoesNotExist,
+    RootNotFound,
+}
+
+/// Removes the leaf with the given key from the trie at a given root, returning the new root.
+///
+/// The resulting trie is the same one that writing all the remaining leaves would produce: a node
+/// left with a single child is collapsed into its parent, merging any extensions around it.
+pub fn delete<K, V, T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &mut T,
+    store: &S,
+    root: &Blake2bHash,
+    key: &K,
+) -> Result<DeleteResult, E>
+where
+    K: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
+    V: ToBytes + FromBytes + Clone + Eq,
+    T: Readable<Handle = S::Handle> + Writable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<contract_ffi::bytesrepr::Error>,
+{
+    let start = Instant::now();
+    let mut put_counter: i32 = 0;
+
+    let current_root = match store.get(txn, root)? {
+        None => return Ok(DeleteResult::RootNotFound),
+        Some(current_root) => current_root,
+    };
+    let path: Vec<u8> = key.to_bytes()?;
+    let TrieScan { tip, mut parents } =
+        scan::<K, V, T, S, E>(correlation_id, txn, store, &path, &current_root)?;
+    match tip {
+        Trie::Leaf {
+            key: ref leaf_key, ..
+        } if key == leaf_key => (),
+        _ => {
+            log_duration(
+                correlation_id,
+                TRIE_STORE_DELETE_DURATION,
+                DELETE,
+                start.elapsed(),
+            );
+            return Ok(DeleteResult::DoesNotExist);
+        }
+    }
+
+    // A leaf always hangs off a node
+    let (leaf_index, mut pointer_block) = match parents.pop() {
+        Some((index, Trie::Node { pointer_block })) => (index, pointer_block),
+        _ => panic!("A leaf should have a node for its parent"),
+    };
+    pointer_block[leaf_index.into()] = None;
+
+    let remaining_children: Vec<(usize, Pointer)> = pointer_block[..]
+        .iter()
+        .enumerate()
+        .filter_map(|(index, maybe_pointer)| maybe_pointer.map(|pointer| (index, pointer)))
+        .collect();
+    // The root node is kept regardless of how many children it has left, as is any node which
+    // still has at least two children.  Otherwise the node is replaced by its only remaining
+    // child, absorbing the extension above the node if there is one.
+    let new_elements: Vec<(Blake2bHash, Trie<K, V>)> =
+        if remaining_children.len() == 1 && !parents.is_empty() {
+            let (index, pointer) = remaining_children[0];
+            let mut affix = match parents.pop() {
+                Some((_, Trie::Extension { affix, .. })) => affix,
+                Some(node) => {
+                    parents.push(node);
+                    Vec::new()
+                }
+                None => unreachable!(),
+            };
+            affix.push(index as u8);
+            let child: Trie<K, V> = match store.get(txn, pointer.hash())? {
+                Some(child) => child,
+                None => panic!("No trie value at key: {:?}", pointer.hash()),
+            };
+            let new_tip = match child {
+                leaf @ Trie::Leaf { .. } => leaf,
+                Trie::Node { .. } => Trie::extension(affix, pointer),
+                Trie::Extension {
+                    affix: child_affix,
+                    pointer: child_pointer,
+                } => {
+                    affix.extend(child_affix);
+                    Trie::extension(affix, child_pointer)
+                }
+            };
+            rehash(new_tip, parents)?
+        } else {
+            rehash(Trie::Node { pointer_block }, parents)?
+        };
+
+    let mut root_hash = root.to_owned();
+    for (hash, element) in new_elements.iter() {
+        put_counter += 1;
+        store.put(txn, hash, element)?;
+        root_hash = *hash;
+    }
+    log_metric(
+        correlation_id,
+        TRIE_STORE_DELETE_PUTS,
+        PUT,
+        GAUGE,
+        f64::from(put_counter),
+    );
+    log_duration(
+        correlation_id,
+        TRIE_STORE_DELETE_DURATION,
+        DELETE,
+        start.elapsed(),
+    );
+    Ok(DeleteResult::Deleted(root_hash))
+}
+
 /// Returns the keys at a given root hash.
 ///
 /// Notes:
@@ -805,3 +1002,485 @@ where
 
     Ok(ret)
 }
+
+/// Returns the key-value pairs at a given root hash whose serialized keys begin with `prefix`.
+///
+/// Pairs are returned in ascending order of their serialized keys.  If `start_after` is given,
+/// only pairs whose serialized keys are strictly greater than it are returned, which allows a
+/// caller to resume a previous enumeration from the last key it received.  At most `limit` pairs
+/// are returned if a limit is given.
+///
+/// Sub-tries which cannot contain a matching key are not visited.
+pub fn read_with_prefix<K, V, T, S, E>(
+    _correlation_id: CorrelationId,
+    txn: &T,
+    store: &S,
+    root: &Blake2bHash,
+    prefix: &[u8],
+    start_after: Option<&[u8]>,
+    limit: Option<usize>,
+) -> Result<ReadResult<Vec<(K, V)>>, E>
+where
+    K: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
+    V: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<contract_ffi::bytesrepr::Error>,
+{
+    // Returns `true` if a sub-trie at `path` may contain keys which match both `prefix` and
+    // `start_after`.
+    let may_contain = |path: &[u8]| -> bool {
+        let len = cmp::min(path.len(), prefix.len());
+        if path[..len] != prefix[..len] {
+            return false;
+        }
+        match start_after {
+            Some(start_after) => {
+                let len = cmp::min(path.len(), start_after.len());
+                path[..len] >= start_after[..len]
+            }
+            None => true,
+        }
+    };
+
+    let mut ret = Vec::new();
+
+    #[allow(clippy::type_complexity)]
+    let mut visited: Vec<(Trie<K, V>, Option<usize>, Vec<u8>)> = {
+        let root = match store.get(txn, root)? {
+            None => return Ok(ReadResult::RootNotFound),
+            Some(current_root) => current_root,
+        };
+        if limit == Some(0) {
+            return Ok(ReadResult::Found(ret));
+        }
+        vec![(root, None, vec![])]
+    };
+
+    while let Some((trie, maybe_index, mut path)) = visited.pop() {
+        let mut maybe_next_trie: Option<Trie<K, V>> = None;
+
+        match trie {
+            Trie::Leaf { key, value } => {
+                let key_bytes = key.to_bytes()?;
+                debug_assert!(key_bytes.starts_with(&path));
+                let is_after_start = match start_after {
+                    Some(start_after) => key_bytes.as_slice() > start_after,
+                    None => true,
+                };
+                if key_bytes.starts_with(prefix) && is_after_start {
+                    ret.push((key, value));
+                    if Some(ret.len()) == limit {
+                        break;
+                    }
+                }
+            }
+            Trie::Node { ref pointer_block } => {
+                let mut index: usize = maybe_index.unwrap_or_default();
+                while index < RADIX {
+                    if let Some(ref pointer) = pointer_block[index] {
+                        let mut child_path = path.clone();
+                        child_path.push(index as u8);
+                        if may_contain(&child_path) {
+                            maybe_next_trie = store.get(txn, pointer.hash())?;
+                            debug_assert!(maybe_next_trie.is_some());
+                            visited.push((trie, Some(index + 1), path));
+                            path = child_path;
+                            break;
+                        }
+                    }
+                    index += 1;
+                }
+            }
+            Trie::Extension { affix, pointer } => {
+                path.extend(affix);
+                if may_contain(&path) {
+                    maybe_next_trie = store.get(txn, pointer.hash())?;
+                    debug_assert!({
+                        match &maybe_next_trie {
+                            Some(Trie::Node { .. }) => true,
+                            _ => false,
+                        }
+                    });
+                }
+            }
+        }
+
+        if let Some(next_trie) = maybe_next_trie {
+            visited.push((next_trie, None, path));
+        }
+    }
+
+    Ok(ReadResult::Found(ret))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PruneResult {
+    Pruned { retained: usize, deleted: usize },
+    RootNotFound(Blake2bHash),
+}
+
+/// Deletes every trie element in a given store which is not reachable from any of the given live
+/// roots.
+///
+/// Entries whose keys are not trie hashes are kept.
+///
+/// All the live roots are checked before anything is deleted, and if any of them is missing
+/// nothing is deleted and [`PruneResult::RootNotFound`] is returned.  The caller is expected to
+/// discard the transaction in that case.
+pub fn prune<K, V, T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &mut T,
+    store: &S,
+    live_roots: &[Blake2bHash],
+) -> Result<PruneResult, E>
+where
+    K: ToBytes + FromBytes,
+    V: ToBytes + FromBytes,
+    T: Readable<Handle = S::Handle> + Writable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<contract_ffi::bytesrepr::Error>,
+{
+    let start = Instant::now();
+
+    // Mark
+    let mut live: HashSet<Blake2bHash> = HashSet::new();
+    let mut pending: Vec<Blake2bHash> = Vec::new();
+    for root in live_roots {
+        let maybe_root: Option<Trie<K, V>> = store.get(txn, root)?;
+        if maybe_root.is_none() {
+            return Ok(PruneResult::RootNotFound(*root));
+        }
+        pending.push(*root);
+    }
+    while let Some(hash) = pending.pop() {
+        if !live.insert(hash) {
+            continue;
+        }
+        let trie: Trie<K, V> = match store.get(txn, &hash)? {
+            Some(trie) => trie,
+            None => panic!("No trie value at key: {:?}", hash),
+        };
+        match trie {
+            Trie::Leaf { .. } => {}
+            Trie::Node { pointer_block } => {
+                for pointer in pointer_block[..].iter().flatten() {
+                    match pointer {
+                        // Leaves have no children, so there is no need to read them
+                        Pointer::LeafPointer(leaf_hash) => {
+                            live.insert(*leaf_hash);
+                        }
+                        Pointer::NodePointer(node_hash) => {
+                            if !live.contains(node_hash) {
+                                pending.push(*node_hash);
+                            }
+                        }
+                    }
+                }
+            }
+            Trie::Extension { pointer, .. } => {
+                if !live.contains(pointer.hash()) {
+                    pending.push(*pointer.hash());
+                }
+            }
+        }
+    }
+
+    // Sweep
+    let mut deleted: usize = 0;
+    let stored_keys = txn.keys(store.handle()).map_err(Into::<S::Error>::into)?;
+    for key_bytes in stored_keys {
+        // The store's database may be shared with other stores, so keys which are not trie hashes
+        // are left alone
+        let hash: Blake2bHash = match bytesrepr::deserialize(&key_bytes) {
+            Ok(hash) => hash,
+            Err(_) => continue,
+        };
+        if !live.contains(&hash) {
+            store.delete(txn, &hash)?;
+            deleted += 1;
+        }
+    }
+
+    log_metric(
+        correlation_id,
+        TRIE_STORE_PRUNE_DELETES,
+        DELETE,
+        GAUGE,
+        deleted as f64,
+    );
+    log_duration(
+        correlation_id,
+        TRIE_STORE_PRUNE_DURATION,
+        PRUNE,
+        start.elapsed(),
+    );
+    Ok(PruneResult::Pruned {
+        retained: live.len(),
+        deleted,
+    })
+}
+
+/// A key whose value differs between two tries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffEntry<K, V> {
+    Added { key: K, value: V },
+    Removed { key: K, value: V },
+    Changed { key: K, pre_value: V, post_value: V },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DiffResult<K, V> {
+    Diffed(Vec<DiffEntry<K, V>>),
+    RootNotFound(Blake2bHash),
+}
+
+/// A sub-trie compared by [`diff`].
+///
+/// When an extension is compared against a node, it is consumed one affix byte at a time, so the
+/// cursor may point at the remainder of an extension which is not itself stored.
+enum DiffCursor {
+    Stored(Blake2bHash),
+    PartialExtension { affix: Vec<u8>, pointer: Pointer },
+}
+
+/// Returns the keys whose values were added, removed or changed going from the trie at
+/// `pre_root` to the trie at `post_root`.
+///
+/// Sub-tries which are referenced by the same hash on both sides are identical and are not
+/// visited, so the cost is proportional to the size of the difference rather than to the size of
+/// either trie.  Entries are returned in ascending order of their serialized keys.
+pub fn diff<K, V, T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &T,
+    store: &S,
+    pre_root: &Blake2bHash,
+    post_root: &Blake2bHash,
+) -> Result<DiffResult<K, V>, E>
+where
+    K: ToBytes + FromBytes + Clone + Eq,
+    V: ToBytes + FromBytes + Clone + Eq,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<contract_ffi::bytesrepr::Error>,
+{
+    let start = Instant::now();
+
+    for root in &[pre_root, post_root] {
+        let maybe_root: Option<Trie<K, V>> = store.get(txn, root)?;
+        if maybe_root.is_none() {
+            return Ok(DiffResult::RootNotFound(**root));
+        }
+    }
+
+    let mut ret = Vec::new();
+    diff_cursors::<K, V, T, S, E>(
+        txn,
+        store,
+        Some(DiffCursor::Stored(*pre_root)),
+        Some(DiffCursor::Stored(*post_root)),
+        &mut ret,
+    )?;
+
+    log_duration(
+        correlation_id,
+        TRIE_STORE_DIFF_DURATION,
+        DIFF,
+        start.elapsed(),
+    );
+    Ok(DiffResult::Diffed(ret))
+}
+
+fn load_cursor<K, V, T, S, E>(txn: &T, store: &S, cursor: DiffCursor) -> Result<Trie<K, V>, E>
+where
+    K: FromBytes,
+    V: FromBytes,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error>,
+{
+    match cursor {
+        DiffCursor::Stored(hash) => match store.get(txn, &hash)? {
+            Some(trie) => Ok(trie),
+            None => panic!("No trie value at key: {:?}", hash),
+        },
+        DiffCursor::PartialExtension { affix, pointer } => Ok(Trie::Extension { affix, pointer }),
+    }
+}
+
+/// Returns the child of a node or extension at `index`, treating an extension as a node with a
+/// single child at the first byte of its affix.
+fn child_cursor<K, V>(trie: &Trie<K, V>, index: usize) -> Option<DiffCursor> {
+    match trie {
+        Trie::Leaf { .. } => None,
+        Trie::Node { pointer_block } => pointer_block[index]
+            .as_ref()
+            .map(|pointer| DiffCursor::Stored(*pointer.hash())),
+        Trie::Extension { affix, pointer } => match affix.split_first() {
+            Some((first, rest)) if *first as usize == index => {
+                if rest.is_empty() {
+                    Some(DiffCursor::Stored(*pointer.hash()))
+                } else {
+                    Some(DiffCursor::PartialExtension {
+                        affix: rest.to_vec(),
+                        pointer: *pointer,
+                    })
+                }
+            }
+            _ => None,
+        },
+    }
+}
+
+fn diff_cursors<K, V, T, S, E>(
+    txn: &T,
+    store: &S,
+    pre: Option<DiffCursor>,
+    post: Option<DiffCursor>,
+    ret: &mut Vec<DiffEntry<K, V>>,
+) -> Result<(), E>
+where
+    K: ToBytes + FromBytes + Clone + Eq,
+    V: ToBytes + FromBytes + Clone + Eq,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<contract_ffi::bytesrepr::Error>,
+{
+    if let (Some(DiffCursor::Stored(pre_hash)), Some(DiffCursor::Stored(post_hash))) = (&pre, &post)
+    {
+        if pre_hash == post_hash {
+            return Ok(());
+        }
+    }
+
+    let pre = match pre {
+        Some(cursor) => Some(load_cursor::<K, V, T, S, E>(txn, store, cursor)?),
+        None => None,
+    };
+    let post = match post {
+        Some(cursor) => Some(load_cursor::<K, V, T, S, E>(txn, store, cursor)?),
+        None => None,
+    };
+
+    if let (
+        Some(Trie::Extension {
+            affix: pre_affix,
+            pointer: pre_pointer,
+        }),
+        Some(Trie::Extension {
+            affix: post_affix,
+            pointer: post_pointer,
+        }),
+    ) = (&pre, &post)
+    {
+        if pre_affix == post_affix {
+            return diff_cursors::<K, V, T, S, E>(
+                txn,
+                store,
+                Some(DiffCursor::Stored(*pre_pointer.hash())),
+                Some(DiffCursor::Stored(*post_pointer.hash())),
+                ret,
+            );
+        }
+    }
+
+    let is_leaf = |maybe_trie: &Option<Trie<K, V>>| match maybe_trie {
+        Some(Trie::Leaf { .. }) => true,
+        _ => false,
+    };
+
+    if !is_leaf(&pre) && !is_leaf(&post) {
+        // Neither side is a leaf, so compare them one branch at a time
+        if pre.is_some() || post.is_some() {
+            for index in 0..RADIX {
+                let pre_child = pre.as_ref().and_then(|trie| child_cursor(trie, index));
+                let post_child = post.as_ref().and_then(|trie| child_cursor(trie, index));
+                diff_cursors::<K, V, T, S, E>(txn, store, pre_child, post_child, ret)?;
+            }
+        }
+        return Ok(());
+    }
+
+    // At least one side is a single leaf, so the sub-tries are small enough to compare in full
+    let pre_leaves = collect_leaves::<K, V, T, S, E>(txn, store, pre)?;
+    let mut post_leaves = collect_leaves::<K, V, T, S, E>(txn, store, post)?;
+    let mut entries: BTreeMap<Vec<u8>, DiffEntry<K, V>> = BTreeMap::new();
+    for (key_bytes, (key, pre_value)) in pre_leaves {
+        match post_leaves.remove(&key_bytes) {
+            Some((_, post_value)) => {
+                if pre_value != post_value {
+                    entries.insert(
+                        key_bytes,
+                        DiffEntry::Changed {
+                            key,
+                            pre_value,
+                            post_value,
+                        },
+                    );
+                }
+            }
+            None => {
+                entries.insert(
+                    key_bytes,
+                    DiffEntry::Removed {
+                        key,
+                        value: pre_value,
+                    },
+                );
+            }
+        }
+    }
+    for (key_bytes, (key, value)) in post_leaves {
+        entries.insert(key_bytes, DiffEntry::Added { key, value });
+    }
+    ret.extend(entries.into_iter().map(|(_, entry)| entry));
+    Ok(())
+}
+
+/// Returns all the leaves below `maybe_trie`, keyed by their serialized keys.
+#[allow(clippy::type_complexity)]
+fn collect_leaves<K, V, T, S, E>(
+    txn: &T,
+    store: &S,
+    maybe_trie: Option<Trie<K, V>>,
+) -> Result<BTreeMap<Vec<u8>, (K, V)>, E>
+where
+    K: ToBytes + FromBytes,
+    V: ToBytes + FromBytes,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<contract_ffi::bytesrepr::Error>,
+{
+    let mut ret = BTreeMap::new();
+    let mut pending: Vec<Trie<K, V>> = maybe_trie.into_iter().collect();
+    while let Some(trie) = pending.pop() {
+        match trie {
+            Trie::Leaf { key, value } => {
+                ret.insert(key.to_bytes()?, (key, value));
+            }
+            Trie::Node { pointer_block } => {
+                for pointer in pointer_block[..].iter().flatten() {
+                    pending.push(load_cursor::<K, V, T, S, E>(
+                        txn,
+                        store,
+                        DiffCursor::Stored(*pointer.hash()),
+                    )?);
+                }
+            }
+            Trie::Extension { pointer, .. } => {
+                pending.push(load_cursor::<K, V, T, S, E>(
+                    txn,
+                    store,
+                    DiffCursor::Stored(*pointer.hash()),
+                )?);
+            }
+        }
+    }
+    Ok(ret)
+}
@@ -0,0 +1,160 @@
+//! This module contains tests for [`delete`](operations::delete).
+//!
+//! Each leaf is deleted in turn from the tries from
+//! [`TEST_TRIE_GENERATORS`](super::TEST_TRIE_GENERATORS).  The tests check that the resulting root
+//! is the same as the one obtained by writing only the remaining leaves to an empty trie, and
+//! that the deleted leaf is gone while the others can still be read.
+
+use super::*;
+use crate::{
+    error::{self, in_memory},
+    trie_store::operations::DeleteResult,
+};
+
+fn delete_leaf<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    root: &Blake2bHash,
+    key: &TestKey,
+) -> Result<DeleteResult, E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<contract_ffi::bytesrepr::Error>,
+{
+    let mut txn = environment.create_read_write_txn()?;
+    let result = operations::delete::<TestKey, TestValue, _, _, E>(
+        correlation_id,
+        &mut txn,
+        store,
+        root,
+        key,
+    )?;
+    txn.commit()?;
+    Ok(result)
+}
+
+fn delete_and_check<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    states: &[Blake2bHash],
+) -> Result<(), E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<contract_ffi::bytesrepr::Error>,
+{
+    let empty_root = states[0];
+    for (num_leaves, state) in states.iter().enumerate() {
+        for (deleted_index, deleted) in TEST_LEAVES[..num_leaves].iter().enumerate() {
+            let remaining: Vec<TestTrie> = TEST_LEAVES[..num_leaves]
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| *index != deleted_index)
+                .map(|(_, leaf)| leaf.to_owned())
+                .collect();
+
+            let expected_root = write_leaves::<_, _, _, _, E>(
+                correlation_id,
+                environment,
+                store,
+                &empty_root,
+                &remaining,
+            )?
+            .into_iter()
+            .fold(empty_root, |root, result| match result {
+                WriteResult::Written(hash) => hash,
+                _ => root,
+            });
+
+            let key = deleted.key().expect("should be a leaf");
+            let new_root =
+                match delete_leaf::<_, _, E>(correlation_id, environment, store, state, key)? {
+                    DeleteResult::Deleted(new_root) => new_root,
+                    other => panic!("leaf should be deleted, got {:?}", other),
+                };
+            assert_eq!(expected_root, new_root);
+
+            check_leaves::<_, _, _, _, E>(
+                correlation_id,
+                environment,
+                store,
+                &new_root,
+                &remaining,
+                &[deleted.to_owned()],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn lmdb_delete_gives_same_root_as_writing_remaining_leaves() {
+    let correlation_id = CorrelationId::new();
+    let (states, tries) = all_states();
+    let context = LmdbTestContext::new(&tries).unwrap();
+    delete_and_check::<_, _, error::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &states,
+    )
+    .unwrap();
+}
+
+#[test]
+fn in_memory_delete_gives_same_root_as_writing_remaining_leaves() {
+    let correlation_id = CorrelationId::new();
+    let (states, tries) = all_states();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    delete_and_check::<_, _, in_memory::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &states,
+    )
+    .unwrap();
+}
+
+#[test]
+fn in_memory_delete_missing_key_does_nothing() {
+    let correlation_id = CorrelationId::new();
+    let (states, tries) = all_states();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    for (num_leaves, state) in states.iter().enumerate() {
+        for leaf in TEST_LEAVES[num_leaves..].iter() {
+            let key = leaf.key().expect("should be a leaf");
+            let result = delete_leaf::<_, _, in_memory::Error>(
+                correlation_id,
+                &context.environment,
+                &context.store,
+                state,
+                key,
+            )
+            .unwrap();
+            assert_eq!(DeleteResult::DoesNotExist, result);
+        }
+    }
+}
+
+#[test]
+fn in_memory_delete_with_missing_root_returns_root_not_found() {
+    let correlation_id = CorrelationId::new();
+    let (_, tries) = all_states();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    let missing_root = Blake2bHash::new(b"missing root");
+    let key = TEST_LEAVES[0].key().expect("should be a leaf");
+    let result = delete_leaf::<_, _, in_memory::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &missing_root,
+        key,
+    )
+    .unwrap();
+    assert_eq!(DeleteResult::RootNotFound, result);
+}
@@ -0,0 +1,251 @@
+//! This module contains tests for [`diff`](operations::diff).
+//!
+//! The tries from all of [`TEST_TRIE_GENERATORS`](super::TEST_TRIE_GENERATORS) are stored
+//! together and diffed pairwise, and against tries with the values from
+//! [`TEST_LEAVES_UPDATED`](super::TEST_LEAVES_UPDATED) written over them.  The expected results
+//! are computed directly from the leaves.
+
+use std::collections::BTreeMap;
+
+use super::*;
+use crate::{
+    error::{self, in_memory},
+    trie_store::operations::{DiffEntry, DiffResult},
+};
+
+fn leaf_map(leaves: &[TestTrie]) -> BTreeMap<TestKey, TestValue> {
+    leaves
+        .iter()
+        .filter_map(|leaf| match leaf {
+            Trie::Leaf { key, value } => Some((*key, *value)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn expected_diff(
+    pre_leaves: &[TestTrie],
+    post_leaves: &[TestTrie],
+) -> Vec<DiffEntry<TestKey, TestValue>> {
+    let pre = leaf_map(pre_leaves);
+    let post = leaf_map(post_leaves);
+    let mut keys: Vec<TestKey> = pre.keys().chain(post.keys()).cloned().collect();
+    keys.sort();
+    keys.dedup();
+    keys.into_iter()
+        .filter_map(|key| match (pre.get(&key), post.get(&key)) {
+            (Some(value), None) => Some(DiffEntry::Removed { key, value: *value }),
+            (None, Some(value)) => Some(DiffEntry::Added { key, value: *value }),
+            (Some(pre_value), Some(post_value)) if pre_value != post_value => {
+                Some(DiffEntry::Changed {
+                    key,
+                    pre_value: *pre_value,
+                    post_value: *post_value,
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn check_diff<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    pre_root: &Blake2bHash,
+    post_root: &Blake2bHash,
+    pre_leaves: &[TestTrie],
+    post_leaves: &[TestTrie],
+) -> Result<(), E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<contract_ffi::bytesrepr::Error>,
+{
+    let txn = environment.create_read_txn()?;
+    let actual = operations::diff::<TestKey, TestValue, _, _, E>(
+        correlation_id,
+        &txn,
+        store,
+        pre_root,
+        post_root,
+    )?;
+    txn.commit()?;
+    assert_eq!(
+        DiffResult::Diffed(expected_diff(pre_leaves, post_leaves)),
+        actual
+    );
+    Ok(())
+}
+
+fn diff_all_states<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    states: &[Blake2bHash],
+) -> Result<(), E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<contract_ffi::bytesrepr::Error>,
+{
+    let test_leaves = TEST_LEAVES;
+    for (pre_index, pre_root) in states.iter().enumerate() {
+        for (post_index, post_root) in states.iter().enumerate() {
+            check_diff::<_, _, E>(
+                correlation_id,
+                environment,
+                store,
+                pre_root,
+                post_root,
+                &test_leaves[..pre_index],
+                &test_leaves[..post_index],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn diff_updated_states<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    states: &[Blake2bHash],
+) -> Result<(), E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<contract_ffi::bytesrepr::Error>,
+{
+    let test_leaves = TEST_LEAVES;
+    let updated_leaves = TEST_LEAVES_UPDATED;
+    for (num_leaves, root) in states.iter().enumerate() {
+        for num_updated in 1..=TEST_LEAVES_LENGTH {
+            let updated = &updated_leaves[..num_updated];
+            let updated_root = match write_leaves::<_, _, _, _, E>(
+                correlation_id,
+                environment,
+                store,
+                root,
+                updated,
+            )?
+            .into_iter()
+            .filter_map(|result| match result {
+                WriteResult::Written(hash) => Some(hash),
+                _ => None,
+            })
+            .last()
+            {
+                Some(hash) => hash,
+                None => continue,
+            };
+
+            let mut expected_post = leaf_map(&test_leaves[..num_leaves]);
+            expected_post.extend(leaf_map(updated));
+            let post_leaves: Vec<TestTrie> = expected_post
+                .into_iter()
+                .map(|(key, value)| Trie::Leaf { key, value })
+                .collect();
+
+            check_diff::<_, _, E>(
+                correlation_id,
+                environment,
+                store,
+                root,
+                &updated_root,
+                &test_leaves[..num_leaves],
+                &post_leaves,
+            )?;
+            check_diff::<_, _, E>(
+                correlation_id,
+                environment,
+                store,
+                &updated_root,
+                root,
+                &post_leaves,
+                &test_leaves[..num_leaves],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn lmdb_diff_between_n_leaf_tries_had_expected_results() {
+    let correlation_id = CorrelationId::new();
+    let (states, tries) = all_states();
+    let context = LmdbTestContext::new(&tries).unwrap();
+    diff_all_states::<_, _, error::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &states,
+    )
+    .unwrap();
+}
+
+#[test]
+fn in_memory_diff_between_n_leaf_tries_had_expected_results() {
+    let correlation_id = CorrelationId::new();
+    let (states, tries) = all_states();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    diff_all_states::<_, _, in_memory::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &states,
+    )
+    .unwrap();
+}
+
+#[test]
+fn lmdb_diff_after_updates_had_expected_results() {
+    let correlation_id = CorrelationId::new();
+    let (states, tries) = all_states();
+    let context = LmdbTestContext::new(&tries).unwrap();
+    diff_updated_states::<_, _, error::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &states,
+    )
+    .unwrap();
+}
+
+#[test]
+fn in_memory_diff_after_updates_had_expected_results() {
+    let correlation_id = CorrelationId::new();
+    let (states, tries) = all_states();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    diff_updated_states::<_, _, in_memory::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &states,
+    )
+    .unwrap();
+}
+
+#[test]
+fn in_memory_diff_with_missing_root_is_root_not_found() {
+    let correlation_id = CorrelationId::new();
+    let (states, tries) = all_states();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    let missing_root = Blake2bHash::new(b"missing root");
+
+    let txn = context.environment.create_read_txn().unwrap();
+    let result = operations::diff::<TestKey, TestValue, _, _, in_memory::Error>(
+        correlation_id,
+        &txn,
+        &context.store,
+        &states[6],
+        &missing_root,
+    )
+    .unwrap();
+    txn.commit().unwrap();
+
+    assert_eq!(DiffResult::RootNotFound(missing_root), result);
+}
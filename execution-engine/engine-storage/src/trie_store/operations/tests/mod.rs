@@ -1,7 +1,12 @@
+mod delete;
+mod diff;
 mod ee_699;
 mod keys;
 mod proptests;
+mod prune;
 mod read;
+mod read_with_prefix;
+mod read_with_proof;
 mod scan;
 mod write;
 
@@ -472,6 +477,19 @@ fn create_6_leaf_trie() -> Result<(Blake2bHash, Vec<HashedTestTrie>), bytesrepr:
     Ok((root_hash, tries))
 }
 
+/// Returns the roots of the tries from all of [`TEST_TRIE_GENERATORS`], indexed by their number
+/// of leaves, along with all of their elements.
+fn all_states() -> (Vec<Blake2bHash>, Vec<HashedTestTrie>) {
+    let mut states = Vec::new();
+    let mut all_tries = Vec::new();
+    for generator in TEST_TRIE_GENERATORS.iter() {
+        let (root_hash, tries) = generator().unwrap();
+        states.push(root_hash);
+        all_tries.extend(tries);
+    }
+    (states, all_tries)
+}
+
 fn put_tries<'a, K, V, R, S, E>(
     environment: &'a R,
     store: &S,
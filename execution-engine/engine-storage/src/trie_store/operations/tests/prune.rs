@@ -0,0 +1,143 @@
+//! This module contains tests for [`prune`](operations::prune).
+//!
+//! The tries from all of [`TEST_TRIE_GENERATORS`](super::TEST_TRIE_GENERATORS) are stored
+//! together, then pruned with some of their roots kept live.  The tests check that the live roots
+//! can still be read in full, that the other roots are gone, and that nothing else is left in the
+//! store.
+
+use super::*;
+use crate::{
+    error::{self, in_memory},
+    trie_store::operations::PruneResult,
+};
+
+fn count_stored<'a, R>(environment: &'a R, handle: R::Handle) -> Result<usize, R::Error>
+where
+    R: TransactionSource<'a>,
+{
+    let txn: R::ReadTransaction = environment.create_read_txn()?;
+    let count = txn.keys(handle)?.len();
+    txn.commit()?;
+    Ok(count)
+}
+
+fn prune_and_check<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    states: &[Blake2bHash],
+    live_indices: &[usize],
+) -> Result<(), E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<contract_ffi::bytesrepr::Error>,
+{
+    let stored_before = count_stored(environment, store.handle())?;
+    let live_roots: Vec<Blake2bHash> = live_indices.iter().map(|index| states[*index]).collect();
+
+    let mut txn = environment.create_read_write_txn()?;
+    let result = operations::prune::<TestKey, TestValue, _, _, E>(
+        correlation_id,
+        &mut txn,
+        store,
+        &live_roots,
+    )?;
+    txn.commit()?;
+
+    let stored_after = count_stored(environment, store.handle())?;
+    match result {
+        PruneResult::Pruned { retained, deleted } => {
+            assert_eq!(stored_after, retained);
+            assert_eq!(stored_before, retained + deleted);
+        }
+        PruneResult::RootNotFound(hash) => panic!("live root {:?} should be found", hash),
+    }
+
+    let txn = environment.create_read_txn()?;
+    for (num_leaves, state) in states.iter().enumerate() {
+        let maybe_root: Option<TestTrie> = store.get(&txn, state)?;
+        if live_indices.contains(&num_leaves) {
+            assert!(maybe_root.is_some());
+        } else {
+            assert!(maybe_root.is_none());
+        }
+    }
+    txn.commit()?;
+
+    for index in live_indices {
+        let test_leaves = TEST_LEAVES;
+        let (used, unused) = test_leaves.split_at(*index);
+        check_leaves::<_, _, _, _, E>(
+            correlation_id,
+            environment,
+            store,
+            &states[*index],
+            used,
+            unused,
+        )?;
+    }
+    Ok(())
+}
+
+const TEST_LIVE_INDICES: [&[usize]; 4] = [&[6], &[0], &[2, 5], &[0, 1, 2, 3, 4, 5, 6]];
+
+#[test]
+fn lmdb_prune_keeps_only_live_tries() {
+    for live_indices in TEST_LIVE_INDICES.iter() {
+        let correlation_id = CorrelationId::new();
+        let (states, tries) = all_states();
+        let context = LmdbTestContext::new(&tries).unwrap();
+        prune_and_check::<_, _, error::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &states,
+            live_indices,
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn in_memory_prune_keeps_only_live_tries() {
+    for live_indices in TEST_LIVE_INDICES.iter() {
+        let correlation_id = CorrelationId::new();
+        let (states, tries) = all_states();
+        let context = InMemoryTestContext::new(&tries).unwrap();
+        prune_and_check::<_, _, in_memory::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &states,
+            live_indices,
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn in_memory_prune_with_missing_root_deletes_nothing() {
+    let correlation_id = CorrelationId::new();
+    let (states, tries) = all_states();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    let missing_root = Blake2bHash::new(b"missing root");
+    let stored_before = count_stored(&context.environment, context.store.handle()).unwrap();
+
+    let mut txn = context.environment.create_read_write_txn().unwrap();
+    let result = operations::prune::<TestKey, TestValue, _, _, in_memory::Error>(
+        correlation_id,
+        &mut txn,
+        &context.store,
+        &[states[6], missing_root],
+    )
+    .unwrap();
+    txn.commit().unwrap();
+
+    assert_eq!(PruneResult::RootNotFound(missing_root), result);
+    assert_eq!(
+        stored_before,
+        count_stored(&context.environment, context.store.handle()).unwrap()
+    );
+}
@@ -0,0 +1,203 @@
+//! This module contains tests for [`read_with_prefix`](operations::read_with_prefix).
+//!
+//! The expected results are computed directly from [`TEST_LEAVES`](super::TEST_LEAVES), by
+//! filtering and sorting their keys, and compared with the results of walking the tries.
+
+use super::*;
+use crate::error::{self, in_memory};
+
+const TEST_PREFIXES: [&[u8]; 7] = [&[], &[0], &[0, 0], &[0, 0, 0], &[0, 0, 2], &[0, 1], &[1]];
+
+const TEST_LIMITS: [Option<usize>; 4] = [None, Some(0), Some(1), Some(2)];
+
+fn expected_pairs(
+    leaves: &[TestTrie],
+    prefix: &[u8],
+    start_after: Option<&[u8]>,
+    limit: Option<usize>,
+) -> Vec<(TestKey, TestValue)> {
+    let mut ret: Vec<(TestKey, TestValue)> = leaves
+        .iter()
+        .filter_map(|leaf| match leaf {
+            Trie::Leaf { key, value } => Some((*key, *value)),
+            _ => None,
+        })
+        .filter(|(key, _)| key.0.starts_with(prefix))
+        .filter(|(key, _)| start_after.map_or(true, |start_after| &key.0[..] > start_after))
+        .collect();
+    ret.sort_by_key(|(key, _)| *key);
+    if let Some(limit) = limit {
+        ret.truncate(limit);
+    }
+    ret
+}
+
+fn check_read_with_prefix<T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &T,
+    store: &S,
+    root: &Blake2bHash,
+    leaves: &[TestTrie],
+) -> Result<(), E>
+where
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<contract_ffi::bytesrepr::Error>,
+{
+    let mut cursors: Vec<Option<Vec<u8>>> = vec![None, Some(vec![0, 0, 1])];
+    cursors.extend(
+        leaves
+            .iter()
+            .filter_map(Trie::key)
+            .map(|key| Some(key.0.to_vec())),
+    );
+
+    for prefix in TEST_PREFIXES.iter() {
+        for start_after in cursors.iter() {
+            for limit in TEST_LIMITS.iter() {
+                let start_after = start_after.as_ref().map(Vec::as_slice);
+                let expected = expected_pairs(leaves, prefix, start_after, *limit);
+                let actual = operations::read_with_prefix::<_, _, _, _, E>(
+                    correlation_id,
+                    txn,
+                    store,
+                    root,
+                    prefix,
+                    start_after,
+                    *limit,
+                )?;
+                assert_eq!(ReadResult::Found(expected), actual);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_paging<T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &T,
+    store: &S,
+    root: &Blake2bHash,
+    leaves: &[TestTrie],
+    page_size: usize,
+) -> Result<(), E>
+where
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<contract_ffi::bytesrepr::Error>,
+{
+    let mut actual: Vec<(TestKey, TestValue)> = Vec::new();
+    let mut cursor: Option<Vec<u8>> = None;
+    loop {
+        let page = match operations::read_with_prefix::<_, _, _, _, E>(
+            correlation_id,
+            txn,
+            store,
+            root,
+            &[],
+            cursor.as_ref().map(Vec::as_slice),
+            Some(page_size),
+        )? {
+            ReadResult::Found(page) => page,
+            _ => panic!("should find root"),
+        };
+        assert!(page.len() <= page_size);
+        match page.last() {
+            Some((key, _)) => cursor = Some(key.to_bytes()?),
+            None => break,
+        }
+        actual.extend(page);
+    }
+    assert_eq!(expected_pairs(leaves, &[], None, None), actual);
+    Ok(())
+}
+
+#[test]
+fn lmdb_read_with_prefix_from_n_leaf_partial_trie_had_expected_results() {
+    for (num_leaves, generator) in TEST_TRIE_GENERATORS.iter().enumerate() {
+        let correlation_id = CorrelationId::new();
+        let (root_hash, tries) = generator().unwrap();
+        let context = LmdbTestContext::new(&tries).unwrap();
+        let test_leaves = TEST_LEAVES;
+        let (used, _) = test_leaves.split_at(num_leaves);
+
+        let txn = context.environment.create_read_txn().unwrap();
+        check_read_with_prefix::<_, _, error::Error>(
+            correlation_id,
+            &txn,
+            &context.store,
+            &root_hash,
+            used,
+        )
+        .unwrap();
+        for page_size in 1..=TEST_LEAVES_LENGTH {
+            check_paging::<_, _, error::Error>(
+                correlation_id,
+                &txn,
+                &context.store,
+                &root_hash,
+                used,
+                page_size,
+            )
+            .unwrap();
+        }
+        txn.commit().unwrap();
+    }
+}
+
+#[test]
+fn in_memory_read_with_prefix_from_n_leaf_partial_trie_had_expected_results() {
+    for (num_leaves, generator) in TEST_TRIE_GENERATORS.iter().enumerate() {
+        let correlation_id = CorrelationId::new();
+        let (root_hash, tries) = generator().unwrap();
+        let context = InMemoryTestContext::new(&tries).unwrap();
+        let test_leaves = TEST_LEAVES;
+        let (used, _) = test_leaves.split_at(num_leaves);
+
+        let txn = context.environment.create_read_txn().unwrap();
+        check_read_with_prefix::<_, _, in_memory::Error>(
+            correlation_id,
+            &txn,
+            &context.store,
+            &root_hash,
+            used,
+        )
+        .unwrap();
+        for page_size in 1..=TEST_LEAVES_LENGTH {
+            check_paging::<_, _, in_memory::Error>(
+                correlation_id,
+                &txn,
+                &context.store,
+                &root_hash,
+                used,
+                page_size,
+            )
+            .unwrap();
+        }
+        txn.commit().unwrap();
+    }
+}
+
+#[test]
+fn in_memory_read_with_prefix_from_missing_root_is_root_not_found() {
+    let correlation_id = CorrelationId::new();
+    let (_, tries) = create_6_leaf_trie().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    let missing_root = Blake2bHash::new(b"missing root");
+
+    let txn = context.environment.create_read_txn().unwrap();
+    let result = operations::read_with_prefix::<TestKey, TestValue, _, _, in_memory::Error>(
+        correlation_id,
+        &txn,
+        &context.store,
+        &missing_root,
+        &[],
+        None,
+        None,
+    )
+    .unwrap();
+    txn.commit().unwrap();
+    assert_eq!(ReadResult::RootNotFound, result);
+}
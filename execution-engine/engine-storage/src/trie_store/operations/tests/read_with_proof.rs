@@ -0,0 +1,182 @@
+//! This module contains tests for [`read_with_proof`](operations::read_with_proof) and
+//! [`verify_proof`].
+//!
+//! Proofs are read for every leaf in [`TEST_LEAVES`](super::TEST_LEAVES) from tries which contain
+//! only some of them, so that we check proofs of both presence and absence.
+
+use super::*;
+use crate::{
+    error::{self, in_memory},
+    trie::merkle_proof::{verify_proof, ProofError, TrieMerkleProof},
+};
+
+fn read_proof<T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &T,
+    store: &S,
+    root: &Blake2bHash,
+    key: &TestKey,
+) -> Result<TrieMerkleProof<TestKey, TestValue>, E>
+where
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<contract_ffi::bytesrepr::Error>,
+{
+    let maybe_proof =
+        operations::read_with_proof::<_, _, _, _, E>(correlation_id, txn, store, root, key)?;
+    Ok(maybe_proof.expect("should find root"))
+}
+
+fn check_proofs<T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &T,
+    store: &S,
+    root: &Blake2bHash,
+    present: &[TestTrie],
+    absent: &[TestTrie],
+) -> Result<(), E>
+where
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<contract_ffi::bytesrepr::Error>,
+{
+    for leaf in present {
+        if let Trie::Leaf { key, value } = leaf {
+            let proof = read_proof::<_, _, E>(correlation_id, txn, store, root, key)?;
+            assert_eq!(Ok(Some(value)), verify_proof(root, &proof));
+        } else {
+            panic!("leaves should only contain leaves")
+        }
+    }
+    for leaf in absent {
+        if let Trie::Leaf { key, .. } = leaf {
+            let proof = read_proof::<_, _, E>(correlation_id, txn, store, root, key)?;
+            assert_eq!(Ok(None), verify_proof(root, &proof));
+        } else {
+            panic!("leaves should only contain leaves")
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn lmdb_proofs_from_n_leaf_partial_trie_are_valid() {
+    for (num_leaves, generator) in TEST_TRIE_GENERATORS.iter().enumerate() {
+        let correlation_id = CorrelationId::new();
+        let (root_hash, tries) = generator().unwrap();
+        let context = LmdbTestContext::new(&tries).unwrap();
+        let test_leaves = TEST_LEAVES;
+        let (used, unused) = test_leaves.split_at(num_leaves);
+
+        let txn = context.environment.create_read_txn().unwrap();
+        check_proofs::<_, _, error::Error>(
+            correlation_id,
+            &txn,
+            &context.store,
+            &root_hash,
+            used,
+            unused,
+        )
+        .unwrap();
+        txn.commit().unwrap();
+    }
+}
+
+#[test]
+fn in_memory_proofs_from_n_leaf_partial_trie_are_valid() {
+    for (num_leaves, generator) in TEST_TRIE_GENERATORS.iter().enumerate() {
+        let correlation_id = CorrelationId::new();
+        let (root_hash, tries) = generator().unwrap();
+        let context = InMemoryTestContext::new(&tries).unwrap();
+        let test_leaves = TEST_LEAVES;
+        let (used, unused) = test_leaves.split_at(num_leaves);
+
+        let txn = context.environment.create_read_txn().unwrap();
+        check_proofs::<_, _, in_memory::Error>(
+            correlation_id,
+            &txn,
+            &context.store,
+            &root_hash,
+            used,
+            unused,
+        )
+        .unwrap();
+        txn.commit().unwrap();
+    }
+}
+
+#[test]
+fn in_memory_read_with_proof_from_missing_root_is_none() {
+    let correlation_id = CorrelationId::new();
+    let (_, tries) = create_6_leaf_trie().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    let missing_root = Blake2bHash::new(b"missing root");
+    let key = TEST_LEAVES[0].key().unwrap();
+
+    let txn = context.environment.create_read_txn().unwrap();
+    let maybe_proof = operations::read_with_proof::<TestKey, TestValue, _, _, in_memory::Error>(
+        correlation_id,
+        &txn,
+        &context.store,
+        &missing_root,
+        key,
+    )
+    .unwrap();
+    txn.commit().unwrap();
+    assert!(maybe_proof.is_none());
+}
+
+#[test]
+fn tampered_proofs_are_rejected() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = create_6_leaf_trie().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    let key = TEST_LEAVES[0].key().unwrap();
+
+    let txn = context.environment.create_read_txn().unwrap();
+    let proof =
+        read_proof::<_, _, in_memory::Error>(correlation_id, &txn, &context.store, &root_hash, key)
+            .unwrap();
+    txn.commit().unwrap();
+    let path = proof.clone().into_path();
+    let last_index = path.len() - 1;
+
+    // Wrong root
+    let wrong_root = Blake2bHash::new(b"wrong root");
+    assert_eq!(
+        Err(ProofError::HashMismatch(0)),
+        verify_proof(&wrong_root, &proof)
+    );
+
+    // Altered value
+    let mut altered_path = path.clone();
+    altered_path[last_index] = Trie::leaf(*key, TestValue(*b"valueX"));
+    let altered = TrieMerkleProof::new(*key, altered_path);
+    assert_eq!(
+        Err(ProofError::HashMismatch(last_index)),
+        verify_proof(&root_hash, &altered)
+    );
+
+    // Altered key
+    let other_key = TEST_LEAVES[1].key().unwrap();
+    let altered = TrieMerkleProof::new(*other_key, path.clone());
+    assert!(verify_proof(&root_hash, &altered).is_err());
+
+    // Truncated
+    let truncated = TrieMerkleProof::new(*key, path[..last_index].to_vec());
+    assert_eq!(
+        Err(ProofError::Truncated),
+        verify_proof(&root_hash, &truncated)
+    );
+
+    // Trailing elements
+    let mut extended_path = path.clone();
+    extended_path.push(path[last_index].clone());
+    let extended = TrieMerkleProof::new(*key, extended_path);
+    assert_eq!(
+        Err(ProofError::TrailingElements(1)),
+        verify_proof(&root_hash, &extended)
+    );
+}
@@ -97,6 +97,7 @@ where
         gas_counter,
         fn_store_id,
         address_generator,
+        Default::default(),
         protocol_version,
         correlation_id,
         phase,
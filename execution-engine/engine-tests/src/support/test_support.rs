@@ -10,6 +10,7 @@ use contract_ffi::{
     args_parser::ArgsParser,
     bytesrepr::ToBytes,
     key::Key,
+    system_contracts::pos,
     uref::URef,
     value::{
         account::{Account, PublicKey, PurseId},
@@ -20,17 +21,21 @@ use contract_ffi::{
 use engine_core::{
     engine_state::{
         genesis::{GenesisAccount, GenesisConfig},
-        EngineConfig, EngineState, SYSTEM_ACCOUNT_ADDR,
+        EngineConfig, EngineState, CONV_RATE, SYSTEM_ACCOUNT_ADDR,
     },
     execution,
 };
 use engine_grpc_server::engine_server::{
     ipc::{
-        ChainSpec_ActivationPoint, ChainSpec_CostTable_WasmCosts, ChainSpec_UpgradePoint,
-        CommitRequest, CommitResponse, DeployCode, DeployItem, DeployPayload, DeployResult,
-        DeployResult_ExecutionResult, DeployResult_PreconditionFailure, ExecuteRequest,
-        ExecuteResponse, GenesisResponse, QueryRequest, StoredContractHash, StoredContractName,
-        StoredContractURef, UpgradeRequest, UpgradeResponse,
+        ChainSpec_ActivationPoint, ChainSpec_CostTable_HostFunctionCosts,
+        ChainSpec_CostTable_WasmCosts, ChainSpec_UpgradePoint, CommitRequest, CommitResponse,
+        DeployCode, DeployItem, DeployPayload, DeployResult, DeployResult_ExecutionResult,
+        DeployResult_PreconditionFailure, DistributeRewardsRequest, EstimateRequest,
+        EstimateResponse, ExecuteRequest, ExecuteResponse, GenesisResponse, ListKeysRequest,
+        ListKeysRequest_KeyKind, PruneRequest, PruneResponse, QueryRequest,
+        RunScheduledCallsRequest, SlashRequest, StateDiffRequest, StateDiffResponse, StepRequest,
+        StoredContractHash, StoredContractName, StoredContractURef, UpgradeRequest,
+        UpgradeResponse,
     },
     ipc_grpc::ExecutionEngineService,
     mappings::{MappingError, TransformMap},
@@ -45,15 +50,18 @@ use engine_storage::{
     global_state::{in_memory::InMemoryGlobalState, lmdb::LmdbGlobalState, StateProvider},
     protocol_data_store::lmdb::LmdbProtocolDataStore,
     transaction_source::lmdb::LmdbEnvironment,
+    trie::merkle_proof::TrieMerkleProof,
     trie_store::lmdb::LmdbTrieStore,
 };
-use engine_wasm_prep::wasm_costs::WasmCosts;
+use engine_wasm_prep::{host_function_costs::HostFunctionCosts, wasm_costs::WasmCosts};
 use protobuf::RepeatedField;
 use transforms::TransformEntry;
 
 use crate::test::{
     CONTRACT_MINT_INSTALL, CONTRACT_POS_INSTALL, CONTRACT_STANDARD_PAYMENT, DEFAULT_CHAIN_NAME,
-    DEFAULT_GENESIS_TIMESTAMP, DEFAULT_PAYMENT, DEFAULT_PROTOCOL_VERSION, DEFAULT_WASM_COSTS,
+    DEFAULT_GENESIS_TIMESTAMP, DEFAULT_HOST_FUNCTION_COSTS, DEFAULT_MIN_GAS_PRICE, DEFAULT_PAYMENT,
+    DEFAULT_PROOF_OF_STAKE_PARAMS, DEFAULT_PROTOCOL_VERSION, DEFAULT_STORAGE_COST_PER_BYTE,
+    DEFAULT_WASM_COSTS,
 };
 
 pub const STANDARD_PAYMENT_CONTRACT: &str = "standard_payment.wasm";
@@ -203,6 +211,28 @@ impl DeployItemBuilder {
         self
     }
 
+    /// Names the entry point invoked by a stored session contract, which must be set before.
+    pub fn with_session_entry_point(mut self, entry_point_name: &str) -> Self {
+        let session = self.deploy_item.mut_session();
+        let entry_point_name = entry_point_name.to_owned();
+        if session.has_stored_contract_hash() {
+            session
+                .mut_stored_contract_hash()
+                .set_entry_point_name(entry_point_name);
+        } else if session.has_stored_contract_name() {
+            session
+                .mut_stored_contract_name()
+                .set_entry_point_name(entry_point_name);
+        } else if session.has_stored_contract_uref() {
+            session
+                .mut_stored_contract_uref()
+                .set_entry_point_name(entry_point_name);
+        } else {
+            panic!("session should be a stored contract");
+        }
+        self
+    }
+
     pub fn with_authorization_keys(mut self, authorization_keys: &[PublicKey]) -> Self {
         let authorization_keys = authorization_keys
             .iter()
@@ -217,6 +247,11 @@ impl DeployItemBuilder {
         self
     }
 
+    pub fn with_gas_price(mut self, gas_price: u64) -> Self {
+        self.deploy_item.set_gas_price(gas_price);
+        self
+    }
+
     pub fn build(self) -> DeployItem {
         self.deploy_item
     }
@@ -225,7 +260,7 @@ impl DeployItemBuilder {
 impl Default for DeployItemBuilder {
     fn default() -> Self {
         let mut deploy_item = DeployItem::new();
-        deploy_item.set_gas_price(1);
+        deploy_item.set_gas_price(CONV_RATE);
         DeployItemBuilder { deploy_item }
     }
 }
@@ -272,6 +307,26 @@ impl ExecuteRequestBuilder {
         self
     }
 
+    pub fn with_sequential(mut self, sequential: bool) -> Self {
+        self.execute_request.set_sequential(sequential);
+        self
+    }
+
+    pub fn with_commit(mut self, commit: bool) -> Self {
+        self.execute_request.set_commit(commit);
+        self
+    }
+
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.execute_request.set_parallel(parallel);
+        self
+    }
+
+    pub fn with_trace(mut self, trace: bool) -> Self {
+        self.execute_request.set_trace(trace);
+        self
+    }
+
     pub fn build(mut self) -> ExecuteRequest {
         let mut deploys = RepeatedField::<DeployItem>::new();
         for deploy in self.deploy_items {
@@ -344,6 +399,10 @@ pub struct UpgradeRequestBuilder {
     new_protocol_version: ProtocolVersion,
     upgrade_installer: DeployCode,
     new_costs: Option<ChainSpec_CostTable_WasmCosts>,
+    new_host_function_costs: Option<ChainSpec_CostTable_HostFunctionCosts>,
+    new_min_gas_price: Option<u64>,
+    new_storage_cost_per_byte: Option<u64>,
+    new_proof_of_stake_params: Option<pos::Params>,
     activation_point: ChainSpec_ActivationPoint,
 }
 
@@ -406,6 +465,26 @@ impl UpgradeRequestBuilder {
         self
     }
 
+    pub fn with_new_host_function_costs(mut self, host_function_costs: HostFunctionCosts) -> Self {
+        self.new_host_function_costs = Some(host_function_costs.into());
+        self
+    }
+
+    pub fn with_new_min_gas_price(mut self, min_gas_price: u64) -> Self {
+        self.new_min_gas_price = Some(min_gas_price);
+        self
+    }
+
+    pub fn with_new_storage_cost_per_byte(mut self, storage_cost_per_byte: u64) -> Self {
+        self.new_storage_cost_per_byte = Some(storage_cost_per_byte);
+        self
+    }
+
+    pub fn with_new_proof_of_stake_params(mut self, proof_of_stake_params: pos::Params) -> Self {
+        self.new_proof_of_stake_params = Some(proof_of_stake_params);
+        self
+    }
+
     pub fn with_activation_point(mut self, rank: u64) -> Self {
         self.activation_point = {
             let mut ret = ChainSpec_ActivationPoint::new();
@@ -418,14 +497,28 @@ impl UpgradeRequestBuilder {
     pub fn build(self) -> UpgradeRequest {
         let mut upgrade_point = ChainSpec_UpgradePoint::new();
         upgrade_point.set_activation_point(self.activation_point);
-        match self.new_costs {
-            None => {}
-            Some(new_costs) => {
-                let mut cost_table =
-                    engine_grpc_server::engine_server::ipc::ChainSpec_CostTable::new();
+        if self.new_costs.is_some()
+            || self.new_host_function_costs.is_some()
+            || self.new_min_gas_price.is_some()
+            || self.new_storage_cost_per_byte.is_some()
+        {
+            let mut cost_table = engine_grpc_server::engine_server::ipc::ChainSpec_CostTable::new();
+            if let Some(new_costs) = self.new_costs {
                 cost_table.set_wasm(new_costs);
-                upgrade_point.set_new_costs(cost_table);
             }
+            if let Some(new_host_function_costs) = self.new_host_function_costs {
+                cost_table.set_host(new_host_function_costs);
+            }
+            if let Some(new_min_gas_price) = self.new_min_gas_price {
+                cost_table.set_min_gas_price(new_min_gas_price);
+            }
+            if let Some(new_storage_cost_per_byte) = self.new_storage_cost_per_byte {
+                cost_table.set_storage_cost_per_byte(new_storage_cost_per_byte);
+            }
+            upgrade_point.set_new_costs(cost_table);
+        }
+        if let Some(new_proof_of_stake_params) = self.new_proof_of_stake_params {
+            upgrade_point.set_new_pos_params(new_proof_of_stake_params.into());
         }
         upgrade_point.set_protocol_version(self.new_protocol_version);
         upgrade_point.set_upgrade_installer(self.upgrade_installer);
@@ -445,6 +538,10 @@ impl Default for UpgradeRequestBuilder {
             new_protocol_version: Default::default(),
             upgrade_installer: Default::default(),
             new_costs: None,
+            new_host_function_costs: None,
+            new_min_gas_price: None,
+            new_storage_cost_per_byte: None,
+            new_proof_of_stake_params: None,
             activation_point: Default::default(),
         }
     }
@@ -746,6 +843,123 @@ where
         }
     }
 
+    /// Runs a query which also returns proofs of the presence or absence of every key it reads.
+    pub fn query_with_proofs(
+        &self,
+        maybe_post_state: Option<Vec<u8>>,
+        base_key: Key,
+        path: &[&str],
+    ) -> (Option<Value>, Vec<TrieMerkleProof<Key, Value>>) {
+        let post_state = maybe_post_state
+            .or_else(|| self.post_state_hash.clone())
+            .expect("builder must have a post-state hash");
+
+        let path_vec: Vec<String> = path.iter().map(|s| String::from(*s)).collect();
+
+        let mut query_request = create_query_request(post_state, base_key, path_vec);
+        query_request.set_include_proofs(true);
+
+        let mut query_response = self
+            .engine_state
+            .query(RequestOptions::new(), query_request)
+            .wait_drop_metadata()
+            .expect("should get query response");
+
+        let proofs = query_response
+            .take_proofs()
+            .into_iter()
+            .map(|pb_proof| pb_proof.try_into().expect("should parse proof"))
+            .collect();
+
+        let maybe_value = if query_response.has_success() {
+            query_response.take_success().try_into().ok()
+        } else {
+            None
+        };
+
+        (maybe_value, proofs)
+    }
+
+    /// Lists the key-value pairs of the given kind at the given post state, or at the current
+    /// post state if none is given.
+    pub fn list_keys(
+        &self,
+        maybe_post_state: Option<Vec<u8>>,
+        key_kind: ListKeysRequest_KeyKind,
+        cursor: Option<Key>,
+        limit: u32,
+    ) -> Vec<(Key, Value)> {
+        let post_state = maybe_post_state
+            .or_else(|| self.post_state_hash.clone())
+            .expect("builder must have a post-state hash");
+
+        let list_keys_request = create_list_keys_request(post_state, key_kind, cursor, limit);
+
+        self.engine_state
+            .list_keys(RequestOptions::new(), list_keys_request)
+            .wait_drop_metadata()
+            .map(|result| {
+                let mut list_keys_response = result.expect("should get list keys response");
+                assert!(
+                    list_keys_response.has_entry(),
+                    "list keys failed: {:?}",
+                    list_keys_response
+                );
+                let mut entry = list_keys_response.take_entry();
+                let key = entry.take_key().try_into().expect("should parse key");
+                let value = entry.take_value().try_into().expect("should parse value");
+                (key, value)
+            })
+            .collect()
+    }
+
+    /// Deletes all global state which is not reachable from any of the given post states.
+    pub fn prune(&self, live_state_hashes: Vec<Vec<u8>>) -> PruneResponse {
+        let mut prune_request = PruneRequest::new();
+        prune_request.set_live_state_hashes(live_state_hashes.into());
+
+        self.engine_state
+            .prune(RequestOptions::new(), prune_request)
+            .wait_drop_metadata()
+            .expect("should get prune response")
+    }
+
+    /// Lists the keys whose values differ between two post states.
+    pub fn state_diff(
+        &self,
+        pre_state_hash: Vec<u8>,
+        post_state_hash: Vec<u8>,
+    ) -> StateDiffResponse {
+        let mut state_diff_request = StateDiffRequest::new();
+        state_diff_request.set_pre_state_hash(pre_state_hash);
+        state_diff_request.set_post_state_hash(post_state_hash);
+
+        self.engine_state
+            .state_diff(RequestOptions::new(), state_diff_request)
+            .wait_drop_metadata()
+            .expect("should get state diff response")
+    }
+
+    /// Executes the session code of `deploy_item` against the current post state without
+    /// committing anything.
+    pub fn estimate(&self, deploy_item: DeployItem, skip_authorization: bool) -> EstimateResponse {
+        let mut estimate_request = EstimateRequest::new();
+        estimate_request.set_parent_state_hash(
+            self.post_state_hash
+                .clone()
+                .expect("expected post_state_hash"),
+        );
+        estimate_request.set_block_time(DEFAULT_BLOCK_TIME);
+        estimate_request.set_deploy(deploy_item);
+        estimate_request.set_protocol_version(get_protocol_version());
+        estimate_request.set_skip_authorization(skip_authorization);
+
+        self.engine_state
+            .estimate(RequestOptions::new(), estimate_request)
+            .wait_drop_metadata()
+            .expect("should get estimate response")
+    }
+
     pub fn exec(&mut self, mut exec_request: ExecuteRequest) -> &mut Self {
         let exec_request = {
             let hash = self
@@ -760,6 +974,63 @@ where
             .execute(RequestOptions::new(), exec_request)
             .wait_drop_metadata()
             .expect("should exec");
+        self.record_exec_response(exec_response)
+    }
+
+    /// Distributes the rewards accumulated in the PoS contract as of the current post state.
+    ///
+    /// The response is recorded like that of an exec call, so that the effects of the
+    /// distribution can be committed with [`commit`](Self::commit).
+    pub fn distribute_rewards(
+        &mut self,
+        proposer: PublicKey,
+        finality_signers: Vec<PublicKey>,
+    ) -> &mut Self {
+        let mut distribute_rewards_request = DistributeRewardsRequest::new();
+        distribute_rewards_request.set_parent_state_hash(
+            self.post_state_hash
+                .clone()
+                .expect("expected post_state_hash"),
+        );
+        distribute_rewards_request.set_block_time(DEFAULT_BLOCK_TIME);
+        distribute_rewards_request.set_protocol_version(get_protocol_version());
+        distribute_rewards_request.set_proposer(proposer.to_vec());
+        distribute_rewards_request
+            .set_finality_signers(finality_signers.iter().map(PublicKey::to_vec).collect());
+
+        let exec_response = self
+            .engine_state
+            .distribute_rewards(RequestOptions::new(), distribute_rewards_request)
+            .wait_drop_metadata()
+            .expect("should distribute rewards");
+        self.record_exec_response(exec_response)
+    }
+
+    /// Slashes the given validators as of the current post state.
+    ///
+    /// The response is recorded like that of an exec call, so that the effects of slashing can be
+    /// committed with [`commit`](Self::commit).
+    pub fn slash(&mut self, validators: Vec<PublicKey>) -> &mut Self {
+        let mut slash_request = SlashRequest::new();
+        slash_request.set_parent_state_hash(
+            self.post_state_hash
+                .clone()
+                .expect("expected post_state_hash"),
+        );
+        slash_request.set_block_time(DEFAULT_BLOCK_TIME);
+        slash_request.set_protocol_version(get_protocol_version());
+        slash_request.set_validators(validators.iter().map(PublicKey::to_vec).collect());
+
+        let exec_response = self
+            .engine_state
+            .slash(RequestOptions::new(), slash_request)
+            .wait_drop_metadata()
+            .expect("should slash");
+        self.record_exec_response(exec_response)
+    }
+
+    /// Records `exec_response` and caches the effects of its first deploy result.
+    fn record_exec_response(&mut self, exec_response: ExecuteResponse) -> &mut Self {
         self.exec_responses.push(exec_response.clone());
         assert!(exec_response.has_success());
         // Parse deploy results
@@ -781,6 +1052,60 @@ where
         self
     }
 
+    /// Runs the PoS `step` at `block_time` against the current post state.
+    ///
+    /// The response is recorded like that of an exec call, so that the effects of the step can be
+    /// committed with [`commit`](Self::commit).
+    pub fn step(&mut self, block_time: u64) -> &mut Self {
+        let mut step_request = StepRequest::new();
+        step_request.set_parent_state_hash(
+            self.post_state_hash
+                .clone()
+                .expect("expected post_state_hash"),
+        );
+        step_request.set_block_time(block_time);
+        step_request.set_protocol_version(get_protocol_version());
+
+        let exec_response = self
+            .engine_state
+            .step(RequestOptions::new(), step_request)
+            .wait_drop_metadata()
+            .expect("should step");
+        self.record_exec_response(exec_response)
+    }
+
+    /// Runs the calls scheduled to run at or before `block_time` against the current post state.
+    ///
+    /// The response is recorded like that of an exec call, along with the combined effect of the
+    /// calls, so that it can be committed with [`commit`](Self::commit).
+    pub fn run_scheduled_calls(&mut self, block_time: u64) -> &mut Self {
+        let mut run_scheduled_calls_request = RunScheduledCallsRequest::new();
+        run_scheduled_calls_request.set_parent_state_hash(
+            self.post_state_hash
+                .clone()
+                .expect("expected post_state_hash"),
+        );
+        run_scheduled_calls_request.set_block_time(block_time);
+        run_scheduled_calls_request.set_protocol_version(get_protocol_version());
+
+        let exec_response = self
+            .engine_state
+            .run_scheduled_calls(RequestOptions::new(), run_scheduled_calls_request)
+            .wait_drop_metadata()
+            .expect("should run scheduled calls");
+        self.exec_responses.push(exec_response.clone());
+        assert!(exec_response.has_success());
+        let combined_transforms: TransformMap = exec_response
+            .get_success()
+            .get_combined_effect()
+            .get_transform_map()
+            .to_vec()
+            .try_into()
+            .expect("should convert");
+        self.transforms.push(combined_transforms.into_inner());
+        self
+    }
+
     /// Commit effects of previous exec call on the latest post-state hash.
     pub fn commit(&mut self) -> &mut Self {
         let prestate_hash = self
@@ -1054,6 +1379,10 @@ pub fn create_genesis_config(accounts: Vec<GenesisAccount>) -> GenesisConfig {
     let proof_of_stake_installer_bytes = read_wasm_file_bytes(CONTRACT_POS_INSTALL);
     let protocol_version = *DEFAULT_PROTOCOL_VERSION;
     let wasm_costs = *DEFAULT_WASM_COSTS;
+    let host_function_costs = *DEFAULT_HOST_FUNCTION_COSTS;
+    let min_gas_price = DEFAULT_MIN_GAS_PRICE;
+    let storage_cost_per_byte = DEFAULT_STORAGE_COST_PER_BYTE;
+    let proof_of_stake_params = *DEFAULT_PROOF_OF_STAKE_PARAMS;
     GenesisConfig::new(
         name,
         timestamp,
@@ -1062,6 +1391,10 @@ pub fn create_genesis_config(accounts: Vec<GenesisAccount>) -> GenesisConfig {
         proof_of_stake_installer_bytes,
         accounts,
         wasm_costs,
+        host_function_costs,
+        min_gas_price,
+        storage_cost_per_byte,
+        proof_of_stake_params,
     )
 }
 
@@ -1075,6 +1408,24 @@ pub fn create_query_request(post_state: Vec<u8>, base_key: Key, path: Vec<String
     query_request
 }
 
+pub fn create_list_keys_request(
+    post_state: Vec<u8>,
+    key_kind: ListKeysRequest_KeyKind,
+    cursor: Option<Key>,
+    limit: u32,
+) -> ListKeysRequest {
+    let mut list_keys_request = ListKeysRequest::new();
+
+    list_keys_request.set_state_hash(post_state);
+    list_keys_request.set_key_kind(key_kind);
+    if let Some(cursor) = cursor {
+        list_keys_request.set_cursor(cursor.into());
+    }
+    list_keys_request.set_limit(limit);
+
+    list_keys_request
+}
+
 #[allow(clippy::implicit_hasher)]
 pub fn create_commit_request(
     prestate_hash: &[u8],
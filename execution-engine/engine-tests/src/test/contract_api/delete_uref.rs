@@ -0,0 +1,60 @@
+use engine_shared::transform::Transform;
+
+use crate::{
+    support::test_support::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    test::{DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG},
+};
+
+const CONTRACT_DELETE_UREF: &str = "delete_uref.wasm";
+const SCRATCH_NAME: &str = "scratch";
+
+#[ignore]
+#[test]
+fn should_delete_uref_with_write_access() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_DELETE_UREF, ("delete",))
+            .build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let scratch_key = account
+        .named_keys()
+        .get(SCRATCH_NAME)
+        .expect("should have scratch key");
+    assert_eq!(builder.query(None, *scratch_key, &[]), None);
+
+    let transforms = builder.get_transforms();
+    let transform = transforms[0]
+        .get(&scratch_key.normalize())
+        .expect("should have transform for scratch key");
+    assert_eq!(transform, &Transform::Delete);
+}
+
+#[ignore]
+#[test]
+fn should_not_delete_uref_without_write_access() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_DELETE_UREF, ("read_only",))
+            .build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit();
+
+    let error_message = builder
+        .exec_error_message(0)
+        .expect("should have exec response");
+    assert!(
+        error_message.contains("InvalidAccess"),
+        "unexpected error: {}",
+        error_message
+    );
+}
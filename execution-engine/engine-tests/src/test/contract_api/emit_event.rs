@@ -0,0 +1,82 @@
+use std::convert::TryInto;
+
+use contract_ffi::{bytesrepr::ToBytes, key::Key};
+
+use crate::{
+    support::test_support::{self, ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    test::{DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG},
+};
+
+const CONTRACT_EMIT_EVENT: &str = "emit_event.wasm";
+const MODE_EMIT: &str = "emit";
+const MODE_REVERT: &str = "revert";
+
+#[ignore]
+#[test]
+fn should_return_emitted_events_in_deploy_result() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_EMIT_EVENT,
+        (String::from(MODE_EMIT),),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    let execution_result = test_support::get_success_result(response);
+    let events = execution_result.get_events();
+
+    assert_eq!(events.len(), 2, "should have two events");
+
+    let expected = [
+        ("first", 1u32.to_bytes().unwrap()),
+        ("second", String::from("two").to_bytes().unwrap()),
+    ];
+    for (ordinal, (event, (name, data))) in events.iter().zip(expected.iter()).enumerate() {
+        let emitter: Key = event
+            .get_emitter()
+            .clone()
+            .try_into()
+            .expect("should map to Key");
+        assert_eq!(emitter, Key::Account(DEFAULT_ACCOUNT_ADDR));
+        assert_eq!(event.get_ordinal(), ordinal as u32);
+        assert_eq!(event.get_name(), *name);
+        assert_eq!(event.get_data(), data.as_slice());
+    }
+}
+
+#[ignore]
+#[test]
+fn should_discard_events_on_revert() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_EMIT_EVENT,
+        (String::from(MODE_REVERT),),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit();
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    let execution_result = test_support::get_success_result(response);
+
+    assert!(execution_result.has_error(), "deploy should have failed");
+    assert!(
+        execution_result.get_events().is_empty(),
+        "events should be discarded on revert"
+    );
+}
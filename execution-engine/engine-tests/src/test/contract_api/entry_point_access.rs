@@ -0,0 +1,76 @@
+use crate::{
+    support::test_support::{self, ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    test::{DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG},
+};
+
+const CONTRACT_ENTRY_POINT_ACCESS: &str = "entry_point_access.wasm";
+const ENTRY_POINTS: [&str; 4] = ["public", "account_only", "callers", "groups"];
+const MODE_DIRECT: &str = "direct";
+const MODE_PROXY: &str = "proxy";
+
+fn execute(mode: &str, entry_point: &str) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ENTRY_POINT_ACCESS,
+        (String::from(mode), String::from(entry_point)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+fn assert_access_denied(entry_point: &str) {
+    let builder = execute(MODE_PROXY, entry_point);
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    let execution_result = test_support::get_success_result(response);
+    assert!(
+        execution_result.has_error(),
+        "calling {} from a contract should fail",
+        entry_point
+    );
+    let message = execution_result.get_error().get_exec_error().get_message();
+    assert!(
+        message.contains("EntryPointAccessDenied"),
+        "unexpected error: {}",
+        message
+    );
+}
+
+#[ignore]
+#[test]
+fn should_allow_account_to_call_any_entry_point() {
+    for entry_point in ENTRY_POINTS.iter() {
+        execute(MODE_DIRECT, entry_point).expect_success();
+    }
+}
+
+#[ignore]
+#[test]
+fn should_allow_contract_to_call_public_entry_point() {
+    execute(MODE_PROXY, "public").expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_deny_contract_calling_account_only_entry_point() {
+    assert_access_denied("account_only");
+}
+
+#[ignore]
+#[test]
+fn should_deny_contract_not_among_callers() {
+    assert_access_denied("callers");
+}
+
+#[ignore]
+#[test]
+fn should_deny_contract_not_holding_group_uref() {
+    assert_access_denied("groups");
+}
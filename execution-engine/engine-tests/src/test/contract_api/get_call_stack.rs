@@ -0,0 +1,18 @@
+use crate::{
+    support::test_support::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    test::{DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG},
+};
+
+const CONTRACT_GET_CALL_STACK: &str = "get_call_stack.wasm";
+
+#[ignore]
+#[test]
+fn should_run_get_call_stack_contract() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_GET_CALL_STACK, ()).build();
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+}
@@ -1,10 +1,18 @@
 #[cfg(test)]
 mod create_purse;
 #[cfg(test)]
+mod delete_uref;
+#[cfg(test)]
+mod emit_event;
+#[cfg(test)]
+mod entry_point_access;
+#[cfg(test)]
 mod get_arg;
 #[cfg(test)]
 mod get_blocktime;
 #[cfg(test)]
+mod get_call_stack;
+#[cfg(test)]
 mod get_caller;
 #[cfg(test)]
 mod get_phase;
@@ -24,6 +32,8 @@ mod transfer;
 mod transfer_purse_to_account;
 #[cfg(test)]
 mod transfer_purse_to_purse;
+#[cfg(test)]
+mod typed_entry_points;
 
 #[cfg(test)]
 pub mod account;
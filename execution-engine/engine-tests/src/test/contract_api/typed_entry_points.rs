@@ -0,0 +1,68 @@
+use crate::{
+    support::test_support::{self, ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    test::{DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG},
+};
+
+const CONTRACT_TYPED_ENTRY_POINTS: &str = "typed_entry_points.wasm";
+
+fn execute(mode: &str) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TYPED_ENTRY_POINTS,
+        (String::from(mode),),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+fn assert_call_failed_with(mode: &str, expected_error: &str) {
+    let builder = execute(mode);
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    let execution_result = test_support::get_success_result(response);
+    assert!(execution_result.has_error(), "{} should fail", mode);
+    let message = execution_result.get_error().get_exec_error().get_message();
+    assert!(
+        message.contains(expected_error),
+        "expected {} in {}",
+        expected_error,
+        message
+    );
+}
+
+#[ignore]
+#[test]
+fn should_call_entry_point_with_arguments_of_declared_types() {
+    execute("valid").expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_reject_arguments_of_wrong_type() {
+    assert_call_failed_with("wrong_type", "InvalidArguments");
+}
+
+#[ignore]
+#[test]
+fn should_reject_wrong_number_of_arguments() {
+    assert_call_failed_with("wrong_count", "Count { expected: 2, actual: 1 }");
+}
+
+#[ignore]
+#[test]
+fn should_reject_return_value_of_wrong_type() {
+    assert_call_failed_with("bad_return", "InvalidReturnValue");
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_call_undeclared_entry_point() {
+    assert_call_failed_with("missing", "FunctionNotFound");
+}
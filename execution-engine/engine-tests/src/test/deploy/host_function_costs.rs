@@ -0,0 +1,66 @@
+use contract_ffi::value::{account::PURSE_ID_SERIALIZED_LENGTH, U512};
+use engine_core::engine_state::genesis::GenesisConfig;
+use engine_shared::gas::Gas;
+use engine_wasm_prep::host_function_costs::{HostFunctionCost, HostFunctionCosts};
+
+use crate::{
+    support::test_support::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    test::{DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG},
+};
+
+const CONTRACT_CREATE_PURSE_01: &str = "create_purse_01.wasm";
+const TEST_PURSE_NAME: &str = "test_purse";
+const CREATE_PURSE_BASE_COST: u32 = 10_000;
+const CREATE_PURSE_PER_BYTE_COST: u32 = 100;
+
+/// Returns the cost of a deploy creating a purse, on a chain with the given host function costs.
+fn create_purse_cost(host_function_costs: HostFunctionCosts) -> Gas {
+    let genesis_config = {
+        let default_config = &*DEFAULT_GENESIS_CONFIG;
+        GenesisConfig::new(
+            default_config.name().to_string(),
+            default_config.timestamp(),
+            default_config.protocol_version(),
+            default_config.mint_installer_bytes().to_vec(),
+            default_config.proof_of_stake_installer_bytes().to_vec(),
+            default_config.accounts().to_vec(),
+            default_config.wasm_costs(),
+            host_function_costs,
+            default_config.min_gas_price(),
+            default_config.storage_cost_per_byte(),
+            default_config.proof_of_stake_params(),
+        )
+    };
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_CREATE_PURSE_01,
+        (String::from(TEST_PURSE_NAME),),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&genesis_config)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder.exec_costs(0)[0]
+}
+
+#[ignore]
+#[test]
+fn should_charge_host_function_costs() {
+    let free_cost = create_purse_cost(HostFunctionCosts::default());
+
+    // Neither the payment code nor the system contracts create purses, so only the session's
+    // single call is charged the additional cost.
+    let cost = create_purse_cost(HostFunctionCosts {
+        create_purse: HostFunctionCost::new(CREATE_PURSE_BASE_COST, CREATE_PURSE_PER_BYTE_COST),
+        ..Default::default()
+    });
+
+    let create_purse_cost = U512::from(CREATE_PURSE_BASE_COST)
+        + U512::from(CREATE_PURSE_PER_BYTE_COST) * U512::from(PURSE_ID_SERIALIZED_LENGTH);
+    assert_eq!(cost, free_cost + Gas::new(create_purse_cost));
+}
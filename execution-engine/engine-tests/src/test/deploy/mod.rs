@@ -1,6 +1,18 @@
 #[cfg(test)]
+mod host_function_costs;
+#[cfg(test)]
+mod multiple_entry_points;
+#[cfg(test)]
+mod parallel;
+#[cfg(test)]
 mod payment_code;
 #[cfg(test)]
 mod preconditions;
 #[cfg(test)]
+mod scheduled_calls;
+#[cfg(test)]
+mod sequential;
+#[cfg(test)]
+mod storage_usage;
+#[cfg(test)]
 mod stored_contracts;
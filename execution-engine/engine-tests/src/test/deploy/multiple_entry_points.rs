@@ -0,0 +1,99 @@
+use contract_ffi::value::{Value, U512};
+
+use crate::{
+    support::test_support::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
+        STANDARD_PAYMENT_CONTRACT,
+    },
+    test::{DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_KEY, DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT},
+};
+
+const CONTRACT_MULTIPLE_ENTRY_POINTS: &str = "multiple_entry_points.wasm";
+const CONTRACT_NAME: &str = "multiple_entry_points";
+const FIRST_NAME: &str = "first";
+const SECOND_NAME: &str = "second";
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_MULTIPLE_ENTRY_POINTS, ())
+            .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+fn call_entry_point(builder: &mut InMemoryWasmTestBuilder, entry_point_name: &str) {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_stored_session_named_key(CONTRACT_NAME, ())
+        .with_session_entry_point(entry_point_name)
+        .with_payment_code(STANDARD_PAYMENT_CONTRACT, (*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[*DEFAULT_ACCOUNT_KEY])
+        .with_deploy_hash([2; 32])
+        .build();
+    let exec_request = ExecuteRequestBuilder::new().push_deploy(deploy).build();
+
+    builder.exec(exec_request).commit();
+}
+
+#[ignore]
+#[test]
+fn should_store_module_with_multiple_entry_points() {
+    let builder = setup();
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let contract_key = account
+        .named_keys()
+        .get(CONTRACT_NAME)
+        .expect("should have contract");
+    match builder.query(None, *contract_key, &[]) {
+        Some(Value::Contract(contract)) => assert!(contract.entry_points().is_empty()),
+        other => panic!("should be contract, got {:?}", other),
+    }
+}
+
+#[ignore]
+#[test]
+fn should_call_entry_point_named_in_deploy() {
+    let mut builder = setup();
+
+    call_entry_point(&mut builder, SECOND_NAME);
+    builder.expect_success();
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert!(!account.named_keys().contains_key(FIRST_NAME));
+    let second_key = account
+        .named_keys()
+        .get(SECOND_NAME)
+        .expect("should have key written by second entry point");
+    let value = builder
+        .query(None, *second_key, &[])
+        .expect("should have value");
+    assert_eq!(value, U512::from(2).into());
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_call_missing_entry_point() {
+    let mut builder = setup();
+
+    call_entry_point(&mut builder, "third");
+
+    let error_message = builder
+        .exec_error_message(1)
+        .expect("should have exec response");
+    assert!(
+        error_message.contains("FunctionNotFound"),
+        "unexpected error: {}",
+        error_message
+    );
+}
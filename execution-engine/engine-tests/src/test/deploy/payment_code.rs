@@ -8,7 +8,10 @@ use contract_ffi::{
         Value, U512,
     },
 };
-use engine_core::engine_state::{genesis::POS_REWARDS_PURSE, CONV_RATE, MAX_PAYMENT};
+use engine_core::engine_state::{
+    genesis::{GenesisConfig, POS_REWARDS_PURSE},
+    CONV_RATE, MAX_PAYMENT,
+};
 use engine_shared::{gas::Gas, motes::Motes, transform::Transform};
 
 use crate::{
@@ -495,6 +498,132 @@ fn should_correctly_charge_when_session_code_succeeds() {
     )
 }
 
+#[ignore]
+#[test]
+fn should_charge_according_to_deploy_gas_price() {
+    let account_1_public_key = PublicKey::new(ACCOUNT_1_ADDR);
+    let payment_purse_amount = 10_000_000;
+    let transferred_amount = 1;
+    let gas_price = CONV_RATE * 2;
+
+    let exec_request = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(DEFAULT_ACCOUNT_ADDR)
+            .with_deploy_hash([1; 32])
+            .with_session_code(
+                "transfer_purse_to_account.wasm",
+                (account_1_public_key, U512::from(transferred_amount)),
+            )
+            .with_payment_code(STANDARD_PAYMENT_WASM, (U512::from(payment_purse_amount),))
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_KEY])
+            .with_gas_price(gas_price)
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy).build()
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    let transfer_result = builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let default_account = transfer_result
+        .builder()
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    let modified_balance: U512 = transfer_result
+        .builder()
+        .get_purse_balance(default_account.purse_id());
+    let initial_balance: U512 = U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE);
+
+    let response = transfer_result
+        .builder()
+        .get_exec_response(0)
+        .expect("there should be a response")
+        .clone();
+
+    let mut success_result = test_support::get_success_result(&response);
+    let cost = success_result
+        .take_cost()
+        .try_into()
+        .expect("should map to U512");
+    let gas = Gas::new(cost);
+    let motes = Motes::from_gas(gas, gas_price).expect("should have motes");
+    let tally = motes.value() + U512::from(transferred_amount) + modified_balance;
+
+    assert_eq!(
+        initial_balance, tally,
+        "deploy should be charged at its own gas price"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_charge_for_zero_gas_price_when_minimum_is_zero() {
+    let account_1_public_key = PublicKey::new(ACCOUNT_1_ADDR);
+    let transferred_amount = 1;
+
+    let genesis_config = {
+        let default_config = &*DEFAULT_GENESIS_CONFIG;
+        GenesisConfig::new(
+            default_config.name().to_string(),
+            default_config.timestamp(),
+            default_config.protocol_version(),
+            default_config.mint_installer_bytes().to_vec(),
+            default_config.proof_of_stake_installer_bytes().to_vec(),
+            default_config.accounts().to_vec(),
+            default_config.wasm_costs(),
+            default_config.host_function_costs(),
+            0,
+            default_config.storage_cost_per_byte(),
+            default_config.proof_of_stake_params(),
+        )
+    };
+
+    let exec_request = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(DEFAULT_ACCOUNT_ADDR)
+            .with_deploy_hash([1; 32])
+            .with_session_code(
+                "transfer_purse_to_account.wasm",
+                (account_1_public_key, U512::from(transferred_amount)),
+            )
+            .with_payment_code(STANDARD_PAYMENT_WASM, (U512::from(MAX_PAYMENT),))
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_KEY])
+            .with_gas_price(0)
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy).build()
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&genesis_config)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    let modified_balance: U512 = builder.get_purse_balance(default_account.purse_id());
+    let initial_balance: U512 = U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE);
+
+    assert!(
+        builder.exec_costs(0)[0] > Gas::default(),
+        "gas should still be metered at a gas price of 0"
+    );
+    assert_eq!(
+        initial_balance,
+        modified_balance + U512::from(transferred_amount),
+        "deploy should not be charged at a gas price of 0"
+    );
+}
+
 fn get_pos_purse_id_by_name(
     builder: &InMemoryWasmTestBuilder,
     purse_name: &str,
@@ -2,7 +2,10 @@ use contract_ffi::value::{account::PublicKey, U512};
 
 use crate::{
     support::test_support::{DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder},
-    test::{CONTRACT_STANDARD_PAYMENT, DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG},
+    test::{
+        CONTRACT_STANDARD_PAYMENT, DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_KEY,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_MIN_GAS_PRICE,
+    },
 };
 
 const ACCOUNT_1_ADDR: [u8; 32] = [42u8; 32];
@@ -127,3 +130,44 @@ fn should_raise_precondition_authorization_failure_invalid_authorized_keys() {
         "expected authorization failure"
     );
 }
+
+#[ignore]
+#[test]
+fn should_raise_precondition_gas_price_too_low() {
+    let gas_price = DEFAULT_MIN_GAS_PRICE - 1;
+
+    let exec_request = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(DEFAULT_ACCOUNT_ADDR)
+            .with_session_code("do_nothing.wasm", ())
+            .with_payment_code(CONTRACT_STANDARD_PAYMENT, (U512::from(10_000_000),))
+            .with_deploy_hash([1; 32])
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_KEY])
+            .with_gas_price(gas_price)
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy).build()
+    };
+
+    let transfer_result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .finish();
+
+    let response = transfer_result
+        .builder()
+        .get_exec_response(0)
+        .expect("there should be a response")
+        .clone();
+
+    let precondition_failure = crate::support::test_support::get_precondition_failure(&response);
+
+    assert_eq!(
+        precondition_failure.message,
+        format!(
+            "Gas price {} is below the minimum gas price {}",
+            gas_price, DEFAULT_MIN_GAS_PRICE
+        ),
+        "expected gas price precondition failure"
+    );
+}
@@ -0,0 +1,110 @@
+use contract_ffi::{
+    key::Key,
+    value::{account::PurseId, Value, U512},
+};
+
+use crate::{
+    support::test_support::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_BLOCK_TIME},
+    test::{DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG, DEFAULT_MIN_GAS_PRICE},
+};
+
+const CONTRACT_SCHEDULE_CALL: &str = "schedule_call.wasm";
+const GAS_PURSE_NAME: &str = "gas_purse";
+const MARKER_NAME: &str = "scheduled_call_marker";
+const SCHEDULED_BLOCK_TIME: u64 = DEFAULT_BLOCK_TIME + 100;
+const GAS_AMOUNT: u64 = 10_000_000;
+
+fn schedule_call(builder: &mut InMemoryWasmTestBuilder, gas_amount: U512) -> PurseId {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_SCHEDULE_CALL,
+        (SCHEDULED_BLOCK_TIME, gas_amount),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let gas_purse = account
+        .named_keys()
+        .get(GAS_PURSE_NAME)
+        .and_then(Key::as_uref)
+        .expect("should have gas purse");
+    PurseId::new(*gas_purse)
+}
+
+fn scheduled_call_results(builder: &InMemoryWasmTestBuilder, index: usize) -> usize {
+    builder
+        .get_exec_response(index)
+        .expect("should have response")
+        .get_success()
+        .get_deploy_results()
+        .len()
+}
+
+fn query_marker(builder: &InMemoryWasmTestBuilder) -> Option<Value> {
+    builder.query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[MARKER_NAME])
+}
+
+#[ignore]
+#[test]
+fn should_run_scheduled_call_once_due() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    let gas_purse = schedule_call(&mut builder, U512::from(GAS_AMOUNT));
+    assert_eq!(builder.get_purse_balance(gas_purse), U512::from(GAS_AMOUNT));
+
+    // Not due yet
+    builder
+        .run_scheduled_calls(SCHEDULED_BLOCK_TIME - 1)
+        .commit();
+    assert_eq!(scheduled_call_results(&builder, 1), 0);
+    assert_eq!(query_marker(&builder), None);
+
+    builder
+        .run_scheduled_calls(SCHEDULED_BLOCK_TIME)
+        .expect_success()
+        .commit();
+    assert_eq!(scheduled_call_results(&builder, 2), 1);
+    assert_eq!(
+        query_marker(&builder),
+        Some(Value::UInt64(SCHEDULED_BLOCK_TIME)),
+        "the call should run at the block time it was due"
+    );
+
+    // The gas spent by the call is paid from its gas purse
+    let cost = builder.exec_costs(2)[0].value() * U512::from(DEFAULT_MIN_GAS_PRICE);
+    assert!(!cost.is_zero());
+    assert_eq!(
+        builder.get_purse_balance(gas_purse),
+        U512::from(GAS_AMOUNT) - cost
+    );
+
+    // The call has been removed from the queue
+    builder
+        .run_scheduled_calls(SCHEDULED_BLOCK_TIME + 1)
+        .commit();
+    assert_eq!(scheduled_call_results(&builder, 3), 0);
+}
+
+#[ignore]
+#[test]
+fn should_drop_scheduled_call_running_out_of_gas() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    // A single mote doesn't buy enough gas to run the call
+    let gas_purse = schedule_call(&mut builder, U512::one());
+
+    builder.run_scheduled_calls(SCHEDULED_BLOCK_TIME).commit();
+    assert_eq!(scheduled_call_results(&builder, 1), 1);
+    assert!(builder.is_error());
+    assert_eq!(query_marker(&builder), None);
+    let cost = builder.exec_costs(1)[0].value() * U512::from(DEFAULT_MIN_GAS_PRICE);
+    assert_eq!(builder.get_purse_balance(gas_purse), U512::one() - cost);
+
+    builder
+        .run_scheduled_calls(SCHEDULED_BLOCK_TIME + 1)
+        .commit();
+    assert_eq!(scheduled_call_results(&builder, 2), 0);
+}
@@ -0,0 +1,100 @@
+use std::convert::TryInto;
+
+use contract_ffi::{
+    key::Key,
+    value::{account::PublicKey, Value, U512},
+};
+use engine_grpc_server::engine_server::mappings::TransformMap;
+
+use crate::{
+    support::test_support::{DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    test::{
+        CONTRACT_STANDARD_PAYMENT, DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
+    },
+};
+
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const ACCOUNT_1_ADDR: [u8; 32] = [1u8; 32];
+const ACCOUNT_2_ADDR: [u8; 32] = [2u8; 32];
+
+#[ignore]
+#[test]
+fn should_execute_deploys_against_state_of_preceding_deploys_and_commit() {
+    let account_1_funds = U512::from(100_000_000);
+    let account_2_funds = U512::from(1);
+
+    // account_1 only exists once the first deploy has been executed
+    let exec_request = {
+        let create_account_1 = DeployItemBuilder::new()
+            .with_address(DEFAULT_ACCOUNT_ADDR)
+            .with_deploy_hash([1; 32])
+            .with_session_code(
+                CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+                (PublicKey::new(ACCOUNT_1_ADDR), account_1_funds),
+            )
+            .with_payment_code(CONTRACT_STANDARD_PAYMENT, (*DEFAULT_PAYMENT,))
+            .with_authorization_keys(&[PublicKey::new(DEFAULT_ACCOUNT_ADDR)])
+            .build();
+
+        let create_account_2 = DeployItemBuilder::new()
+            .with_address(ACCOUNT_1_ADDR)
+            .with_deploy_hash([2; 32])
+            .with_session_code(
+                CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+                (PublicKey::new(ACCOUNT_2_ADDR), account_2_funds),
+            )
+            .with_payment_code(CONTRACT_STANDARD_PAYMENT, (*DEFAULT_PAYMENT,))
+            .with_authorization_keys(&[PublicKey::new(ACCOUNT_1_ADDR)])
+            .build();
+
+        ExecuteRequestBuilder::new()
+            .push_deploy(create_account_1)
+            .push_deploy(create_account_2)
+            .with_sequential(true)
+            .with_commit(true)
+            .build()
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request);
+
+    let exec_result = builder
+        .get_exec_response(0)
+        .expect("there should be a response")
+        .get_success();
+
+    let deploy_results = exec_result.get_deploy_results();
+    assert_eq!(deploy_results.len(), 2);
+    for deploy_result in deploy_results {
+        assert!(deploy_result.has_execution_result());
+        assert!(!deploy_result.get_execution_result().has_error());
+    }
+
+    let account_2_key = Key::Account(ACCOUNT_2_ADDR);
+    let combined_transforms: TransformMap = exec_result
+        .get_combined_effect()
+        .get_transform_map()
+        .to_vec()
+        .try_into()
+        .expect("should convert");
+    assert!(combined_transforms
+        .into_inner()
+        .get(&account_2_key)
+        .is_some());
+
+    let commit_response = exec_result.get_commit_response();
+    assert!(commit_response.has_success());
+    let post_state_hash = commit_response.get_success().get_poststate_hash().to_vec();
+
+    let account_2 = builder.query(Some(post_state_hash), account_2_key, &[]);
+    assert!(
+        match account_2 {
+            Some(Value::Account(account)) => account.pub_key() == ACCOUNT_2_ADDR,
+            _ => false,
+        },
+        "account_2 should exist in the committed post-state"
+    );
+}
@@ -0,0 +1,89 @@
+use std::convert::TryInto;
+
+use contract_ffi::{
+    key::Key,
+    value::{account::PublicKey, U512},
+};
+use engine_core::engine_state::CONV_RATE;
+use engine_grpc_server::engine_server::ipc::{DeployItem, EstimateResponse_Success};
+
+use crate::{
+    support::test_support::{DeployItemBuilder, InMemoryWasmTestBuilder},
+    test::{
+        CONTRACT_STANDARD_PAYMENT, DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
+    },
+};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT_01: &str = "transfer_to_account_01.wasm";
+const ACCOUNT_1_ADDR: [u8; 32] = [1u8; 32];
+const UNKNOWN_ADDR: [u8; 32] = [42u8; 32];
+
+fn transfer_deploy(authorization_key: [u8; 32]) -> DeployItem {
+    DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(CONTRACT_TRANSFER_TO_ACCOUNT_01, (ACCOUNT_1_ADDR,))
+        .with_payment_code(CONTRACT_STANDARD_PAYMENT, (*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[PublicKey::new(authorization_key)])
+        .with_deploy_hash([1u8; 32])
+        .build()
+}
+
+fn estimate(deploy_item: DeployItem, skip_authorization: bool) -> EstimateResponse_Success {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let mut estimate_response = builder.estimate(deploy_item, skip_authorization);
+    assert!(
+        estimate_response.has_success(),
+        "estimate failed: {:?}",
+        estimate_response
+    );
+
+    // Nothing is committed by an estimate
+    assert!(builder
+        .query(None, Key::Account(ACCOUNT_1_ADDR), &[])
+        .is_none());
+
+    estimate_response.take_success()
+}
+
+#[ignore]
+#[test]
+fn should_estimate_session_cost_and_payment() {
+    let mut success = estimate(transfer_deploy(DEFAULT_ACCOUNT_ADDR), false);
+
+    let mut execution_result = success.take_deploy_result().take_execution_result();
+    assert!(!execution_result.has_error());
+    assert!(!execution_result
+        .get_effects()
+        .get_transform_map()
+        .is_empty());
+
+    let cost: U512 = execution_result
+        .take_cost()
+        .try_into()
+        .expect("should map to U512");
+    assert!(cost > U512::zero());
+
+    let payment: U512 = success
+        .take_payment()
+        .try_into()
+        .expect("should map to U512");
+    assert_eq!(payment, cost * CONV_RATE);
+}
+
+#[ignore]
+#[test]
+fn should_check_authorization_keys_unless_skipped() {
+    let mut success = estimate(transfer_deploy(UNKNOWN_ADDR), false);
+    assert!(success.get_deploy_result().has_precondition_failure());
+    let payment: U512 = success
+        .take_payment()
+        .try_into()
+        .expect("should map to U512");
+    assert_eq!(payment, U512::zero());
+
+    let success = estimate(transfer_deploy(UNKNOWN_ADDR), true);
+    let execution_result = success.get_deploy_result().get_execution_result();
+    assert!(!execution_result.has_error());
+}
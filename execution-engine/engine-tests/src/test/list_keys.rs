@@ -0,0 +1,56 @@
+use contract_ffi::{key::Key, value::Value};
+use engine_core::engine_state::SYSTEM_ACCOUNT_ADDR;
+use engine_grpc_server::engine_server::ipc::ListKeysRequest_KeyKind;
+
+use crate::{
+    support::test_support::InMemoryWasmTestBuilder,
+    test::{DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG},
+};
+
+#[ignore]
+#[test]
+fn should_list_accounts_at_genesis_post_state() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let accounts = builder.list_keys(None, ListKeysRequest_KeyKind::ACCOUNT, None, 0);
+
+    let account_keys: Vec<Key> = accounts.iter().map(|(key, _)| *key).collect();
+    assert_eq!(
+        account_keys,
+        vec![
+            Key::Account(SYSTEM_ACCOUNT_ADDR),
+            Key::Account(DEFAULT_ACCOUNT_ADDR)
+        ]
+    );
+    for (key, value) in accounts {
+        match value {
+            Value::Account(account) => assert_eq!(key, Key::Account(account.pub_key())),
+            other => panic!("expected an account at {:?}, got {:?}", key, other),
+        }
+    }
+}
+
+#[ignore]
+#[test]
+fn should_resume_listing_from_cursor() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let expected = builder.list_keys(None, ListKeysRequest_KeyKind::ANY, None, 0);
+    assert!(!expected.is_empty());
+
+    let mut actual = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = builder.list_keys(None, ListKeysRequest_KeyKind::ANY, cursor, 2);
+        assert!(page.len() <= 2);
+        match page.last() {
+            Some((key, _)) => cursor = Some(*key),
+            None => break,
+        }
+        actual.extend(page);
+    }
+
+    assert_eq!(actual, expected);
+}
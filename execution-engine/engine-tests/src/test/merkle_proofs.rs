@@ -0,0 +1,79 @@
+use std::convert::TryFrom;
+
+use contract_ffi::{key::Key, value::Value};
+use engine_core::execution::MINT_NAME;
+use engine_shared::newtypes::Blake2bHash;
+use engine_storage::trie::merkle_proof::verify_proof;
+
+use crate::{
+    support::test_support::InMemoryWasmTestBuilder,
+    test::{DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG},
+};
+
+const MISSING_ACCOUNT_ADDR: [u8; 32] = [42u8; 32];
+
+#[ignore]
+#[test]
+fn should_return_verifiable_proof_of_account() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let post_state_hash = Blake2bHash::try_from(builder.get_post_state_hash().as_slice())
+        .expect("should convert post state hash");
+    let account_key = Key::Account(DEFAULT_ACCOUNT_ADDR);
+
+    let (maybe_value, proofs) = builder.query_with_proofs(None, account_key, &[]);
+
+    let account = match maybe_value {
+        Some(Value::Account(account)) => account,
+        other => panic!("expected an account, got {:?}", other),
+    };
+    assert_eq!(proofs.len(), 1);
+    assert_eq!(*proofs[0].key(), account_key);
+    let proven_value = verify_proof(&post_state_hash, &proofs[0]).expect("proof should be valid");
+    assert_eq!(proven_value, Some(&Value::Account(account)));
+}
+
+#[ignore]
+#[test]
+fn should_return_verifiable_proofs_along_query_path() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let post_state_hash = Blake2bHash::try_from(builder.get_post_state_hash().as_slice())
+        .expect("should convert post state hash");
+    let account_key = Key::Account(DEFAULT_ACCOUNT_ADDR);
+    let mint_key = Key::URef(builder.get_mint_contract_uref()).normalize();
+
+    let (maybe_value, proofs) = builder.query_with_proofs(None, account_key, &[MINT_NAME]);
+
+    match maybe_value {
+        Some(Value::Contract(_)) => (),
+        other => panic!("expected a contract, got {:?}", other),
+    }
+    let proven_keys: Vec<Key> = proofs.iter().map(|proof| *proof.key()).collect();
+    assert_eq!(proven_keys, vec![account_key, mint_key]);
+    for proof in &proofs {
+        let proven_value = verify_proof(&post_state_hash, proof).expect("proof should be valid");
+        assert!(proven_value.is_some());
+    }
+}
+
+#[ignore]
+#[test]
+fn should_return_verifiable_proof_of_absence() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let post_state_hash = Blake2bHash::try_from(builder.get_post_state_hash().as_slice())
+        .expect("should convert post state hash");
+    let missing_key = Key::Account(MISSING_ACCOUNT_ADDR);
+
+    let (maybe_value, proofs) = builder.query_with_proofs(None, missing_key, &[]);
+
+    assert!(maybe_value.is_none());
+    assert_eq!(proofs.len(), 1);
+    assert_eq!(*proofs[0].key(), missing_key);
+    let proven_value = verify_proof(&post_state_hash, &proofs[0]).expect("proof should be valid");
+    assert_eq!(proven_value, None);
+}
@@ -1,6 +1,18 @@
 #[cfg(test)]
+mod estimate;
+#[cfg(test)]
+mod list_keys;
+#[cfg(test)]
+mod merkle_proofs;
+#[cfg(test)]
 mod metrics;
 #[cfg(test)]
+mod prune;
+#[cfg(test)]
+mod state_diff;
+#[cfg(test)]
+mod trace;
+#[cfg(test)]
 mod upgrade;
 
 #[cfg(test)]
@@ -17,10 +29,13 @@ pub mod system_contracts;
 use lazy_static::lazy_static;
 use num_traits::identities::Zero;
 
-use contract_ffi::value::{account::PublicKey, ProtocolVersion, U512};
+use contract_ffi::{
+    system_contracts::pos,
+    value::{account::PublicKey, ProtocolVersion, U512},
+};
 use engine_core::engine_state::genesis::{GenesisAccount, GenesisConfig};
 use engine_shared::{motes::Motes, test_utils};
-use engine_wasm_prep::wasm_costs::WasmCosts;
+use engine_wasm_prep::{host_function_costs::HostFunctionCosts, wasm_costs::WasmCosts};
 
 use crate::support::test_support;
 
@@ -28,6 +43,8 @@ pub const DEFAULT_CHAIN_NAME: &str = "gerald";
 pub const DEFAULT_GENESIS_TIMESTAMP: u64 = 0;
 pub const DEFAULT_ACCOUNT_ADDR: [u8; 32] = [6u8; 32];
 pub const DEFAULT_ACCOUNT_INITIAL_BALANCE: u64 = 100_000_000_000;
+pub const DEFAULT_MIN_GAS_PRICE: u64 = 1;
+pub const DEFAULT_STORAGE_COST_PER_BYTE: u64 = 0;
 
 pub const CONTRACT_MINT_INSTALL: &str = "mint_install.wasm";
 pub const CONTRACT_POS_INSTALL: &str = "pos_install.wasm";
@@ -48,6 +65,14 @@ lazy_static! {
     pub static ref DEFAULT_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
     pub static ref DEFAULT_PAYMENT: U512 = 100_000_000.into();
     pub static ref DEFAULT_WASM_COSTS: WasmCosts = test_utils::wasm_costs_mock();
+    pub static ref DEFAULT_HOST_FUNCTION_COSTS: HostFunctionCosts =
+        test_utils::host_function_costs_mock();
+    // Without delays, bonding and unbonding requests take effect in the `step` of the next block.
+    pub static ref DEFAULT_PROOF_OF_STAKE_PARAMS: pos::Params = pos::Params {
+        bond_delay: 0,
+        unbond_delay: 0,
+        ..Default::default()
+    };
     pub static ref DEFAULT_GENESIS_CONFIG: GenesisConfig = {
         let mint_installer_bytes = test_support::read_wasm_file_bytes(CONTRACT_MINT_INSTALL);
         let pos_installer_bytes = test_support::read_wasm_file_bytes(CONTRACT_POS_INSTALL);
@@ -59,6 +84,10 @@ lazy_static! {
             pos_installer_bytes,
             DEFAULT_ACCOUNTS.clone(),
             *DEFAULT_WASM_COSTS,
+            *DEFAULT_HOST_FUNCTION_COSTS,
+            DEFAULT_MIN_GAS_PRICE,
+            DEFAULT_STORAGE_COST_PER_BYTE,
+            *DEFAULT_PROOF_OF_STAKE_PARAMS,
         )
     };
 }
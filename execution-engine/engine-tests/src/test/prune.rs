@@ -0,0 +1,102 @@
+use contract_ffi::key::Key;
+
+use crate::{
+    support::test_support::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    test::{DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG},
+};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT_01: &str = "transfer_to_account_01.wasm";
+const ACCOUNT_1_ADDR: [u8; 32] = [1u8; 32];
+const MISSING_STATE_HASH: [u8; 32] = [42u8; 32];
+
+fn setup() -> (InMemoryWasmTestBuilder, Vec<u8>) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT_01,
+        (ACCOUNT_1_ADDR,),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    let genesis_post_state = builder.get_post_state_hash();
+    builder.exec(exec_request).commit().expect_success();
+
+    (builder, genesis_post_state)
+}
+
+#[ignore]
+#[test]
+fn should_prune_unreachable_post_states() {
+    let (builder, genesis_post_state) = setup();
+    let post_state = builder.get_post_state_hash();
+
+    assert!(builder
+        .query(
+            Some(genesis_post_state.clone()),
+            Key::Account(DEFAULT_ACCOUNT_ADDR),
+            &[]
+        )
+        .is_some());
+
+    let prune_response = builder.prune(vec![post_state]);
+    assert!(
+        prune_response.has_success(),
+        "prune failed: {:?}",
+        prune_response
+    );
+    assert!(prune_response.get_success().get_deleted_count() > 0);
+
+    assert!(builder
+        .query(
+            Some(genesis_post_state),
+            Key::Account(DEFAULT_ACCOUNT_ADDR),
+            &[]
+        )
+        .is_none());
+    assert!(builder
+        .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[])
+        .is_some());
+    assert!(builder
+        .query(None, Key::Account(ACCOUNT_1_ADDR), &[])
+        .is_some());
+}
+
+#[ignore]
+#[test]
+fn should_not_prune_if_a_live_state_hash_is_missing() {
+    let (builder, genesis_post_state) = setup();
+    let post_state = builder.get_post_state_hash();
+
+    let prune_response = builder.prune(vec![post_state, MISSING_STATE_HASH.to_vec()]);
+    assert!(prune_response.has_missing_state());
+    assert_eq!(
+        prune_response.get_missing_state().get_hash(),
+        &MISSING_STATE_HASH[..]
+    );
+
+    assert!(builder
+        .query(
+            Some(genesis_post_state),
+            Key::Account(DEFAULT_ACCOUNT_ADDR),
+            &[]
+        )
+        .is_some());
+}
+
+#[ignore]
+#[test]
+fn should_not_prune_without_live_state_hashes() {
+    let (builder, genesis_post_state) = setup();
+
+    let prune_response = builder.prune(vec![]);
+    assert!(!prune_response.get_failure().is_empty());
+
+    assert!(builder
+        .query(
+            Some(genesis_post_state),
+            Key::Account(DEFAULT_ACCOUNT_ADDR),
+            &[]
+        )
+        .is_some());
+}
@@ -21,7 +21,7 @@ const ACCOUNT_1_ADDR: [u8; 32] = [7u8; 32];
 const GENESIS_VALIDATOR_STAKE: u64 = 50_000;
 lazy_static! {
     static ref ACCOUNT_1_FUND: U512 = *DEFAULT_PAYMENT;
-    static ref ACCOUNT_1_BALANCE: U512 = *ACCOUNT_1_FUND + 100_000;
+    static ref ACCOUNT_1_BALANCE: U512 = *ACCOUNT_1_FUND + *ACCOUNT_1_FUND + 100_000;
     static ref ACCOUNT_1_BOND: U512 = 25_000.into();
 }
 
@@ -51,7 +51,15 @@ fn should_fail_unboding_more_than_it_was_staked_ee_598_regression() {
         ),
     )
     .build();
-    let exec_request_2 = {
+    // The regression contract's own bond is only queued until the next block, so account 1 has to
+    // be bonded already for its unbond to exceed the stake.
+    let exec_request_2 = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from("bond-from-main-purse"), *ACCOUNT_1_BOND),
+    )
+    .build();
+    let exec_request_3 = {
         let deploy = DeployItemBuilder::new()
             .with_address(ACCOUNT_1_ADDR)
             .with_payment_code(STANDARD_PAYMENT_CONTRACT, (*ACCOUNT_1_FUND,))
@@ -68,12 +76,17 @@ fn should_fail_unboding_more_than_it_was_staked_ee_598_regression() {
         .expect_success()
         .commit()
         .exec(exec_request_2)
+        .expect_success()
+        .commit()
+        .step(0)
+        .commit()
+        .exec(exec_request_3)
         .commit()
         .finish();
 
     let response = result
         .builder()
-        .get_exec_response(1)
+        .get_exec_response(3)
         .expect("should have a response")
         .to_owned();
     let error_message = {
@@ -0,0 +1,122 @@
+use std::convert::TryInto;
+
+use contract_ffi::{key::Key, value::Value};
+
+use crate::{
+    support::test_support::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    test::{DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG},
+};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT_01: &str = "transfer_to_account_01.wasm";
+const ACCOUNT_1_ADDR: [u8; 32] = [1u8; 32];
+const MISSING_STATE_HASH: [u8; 32] = [42u8; 32];
+
+fn setup() -> (InMemoryWasmTestBuilder, Vec<u8>) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT_01,
+        (ACCOUNT_1_ADDR,),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    let genesis_post_state = builder.get_post_state_hash();
+    builder.exec(exec_request).commit().expect_success();
+
+    (builder, genesis_post_state)
+}
+
+/// Returns the diff entries as keys with optional pre and post values.
+#[allow(clippy::type_complexity)]
+fn state_diff(
+    builder: &InMemoryWasmTestBuilder,
+    pre_state_hash: Vec<u8>,
+    post_state_hash: Vec<u8>,
+) -> Vec<(Key, Option<Value>, Option<Value>)> {
+    let mut state_diff_response = builder.state_diff(pre_state_hash, post_state_hash);
+    assert!(
+        state_diff_response.has_success(),
+        "state diff failed: {:?}",
+        state_diff_response
+    );
+    state_diff_response
+        .take_success()
+        .take_entries()
+        .into_iter()
+        .map(|mut entry| {
+            let key = entry.take_key().try_into().expect("should parse key");
+            let pre_value = if entry.has_pre_value() {
+                Some(
+                    entry
+                        .take_pre_value()
+                        .try_into()
+                        .expect("should parse value"),
+                )
+            } else {
+                None
+            };
+            let post_value = if entry.has_post_value() {
+                Some(
+                    entry
+                        .take_post_value()
+                        .try_into()
+                        .expect("should parse value"),
+                )
+            } else {
+                None
+            };
+            (key, pre_value, post_value)
+        })
+        .collect()
+}
+
+#[ignore]
+#[test]
+fn should_diff_transfer_post_state_against_genesis() {
+    let (builder, genesis_post_state) = setup();
+    let post_state = builder.get_post_state_hash();
+
+    let entries = state_diff(&builder, genesis_post_state.clone(), post_state.clone());
+
+    let new_account = entries
+        .iter()
+        .find(|(key, _, _)| *key == Key::Account(ACCOUNT_1_ADDR))
+        .expect("new account should be in the diff");
+    assert!(new_account.1.is_none());
+    match &new_account.2 {
+        Some(Value::Account(account)) => assert_eq!(account.pub_key(), ACCOUNT_1_ADDR),
+        other => panic!("expected an account, got {:?}", other),
+    }
+
+    // The source purse's balance changed
+    assert!(entries
+        .iter()
+        .any(|(_, pre_value, post_value)| pre_value.is_some() && post_value.is_some()));
+
+    // Reversing the states reverses the diff
+    let reversed = state_diff(&builder, post_state.clone(), genesis_post_state);
+    assert_eq!(reversed.len(), entries.len());
+    for ((key, pre_value, post_value), (reversed_key, reversed_pre, reversed_post)) in
+        entries.iter().zip(reversed.iter())
+    {
+        assert_eq!(key, reversed_key);
+        assert_eq!(pre_value, reversed_post);
+        assert_eq!(post_value, reversed_pre);
+    }
+
+    assert!(state_diff(&builder, post_state.clone(), post_state).is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_report_missing_state_hash() {
+    let (builder, genesis_post_state) = setup();
+
+    let state_diff_response = builder.state_diff(genesis_post_state, MISSING_STATE_HASH.to_vec());
+    assert!(state_diff_response.has_missing_state());
+    assert_eq!(
+        state_diff_response.get_missing_state().get_hash(),
+        &MISSING_STATE_HASH[..]
+    );
+}
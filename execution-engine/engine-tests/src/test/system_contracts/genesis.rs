@@ -10,7 +10,10 @@ use engine_shared::motes::Motes;
 
 use crate::{
     support::test_support::{self, InMemoryWasmTestBuilder},
-    test::DEFAULT_WASM_COSTS,
+    test::{
+        DEFAULT_HOST_FUNCTION_COSTS, DEFAULT_MIN_GAS_PRICE, DEFAULT_PROOF_OF_STAKE_PARAMS,
+        DEFAULT_STORAGE_COST_PER_BYTE, DEFAULT_WASM_COSTS,
+    },
 };
 
 const MINT_INSTALL: &str = "mint_install.wasm";
@@ -57,6 +60,10 @@ fn should_run_genesis() {
     let accounts = vec![account_1, account_2];
     let protocol_version = ProtocolVersion::V1_0_0;
     let wasm_costs = *DEFAULT_WASM_COSTS;
+    let host_function_costs = *DEFAULT_HOST_FUNCTION_COSTS;
+    let min_gas_price = DEFAULT_MIN_GAS_PRICE;
+    let storage_cost_per_byte = DEFAULT_STORAGE_COST_PER_BYTE;
+    let proof_of_stake_params = *DEFAULT_PROOF_OF_STAKE_PARAMS;
 
     let genesis_config = GenesisConfig::new(
         name,
@@ -66,6 +73,10 @@ fn should_run_genesis() {
         pos_installer_bytes,
         accounts,
         wasm_costs,
+        host_function_costs,
+        min_gas_price,
+        storage_cost_per_byte,
+        proof_of_stake_params,
     );
 
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -139,6 +150,10 @@ fn should_fail_if_bad_mint_install_contract_is_provided() {
         let accounts = vec![account_1, account_2];
         let protocol_version = ProtocolVersion::V1_0_0;
         let wasm_costs = *DEFAULT_WASM_COSTS;
+        let host_function_costs = *DEFAULT_HOST_FUNCTION_COSTS;
+        let min_gas_price = DEFAULT_MIN_GAS_PRICE;
+        let storage_cost_per_byte = DEFAULT_STORAGE_COST_PER_BYTE;
+        let proof_of_stake_params = *DEFAULT_PROOF_OF_STAKE_PARAMS;
 
         GenesisConfig::new(
             name,
@@ -148,6 +163,10 @@ fn should_fail_if_bad_mint_install_contract_is_provided() {
             pos_installer_bytes,
             accounts,
             wasm_costs,
+            host_function_costs,
+            min_gas_price,
+            storage_cost_per_byte,
+            proof_of_stake_params,
         )
     };
 
@@ -187,6 +206,10 @@ fn should_fail_if_bad_pos_install_contract_is_provided() {
         let accounts = vec![account_1, account_2];
         let protocol_version = ProtocolVersion::V1_0_0;
         let wasm_costs = *DEFAULT_WASM_COSTS;
+        let host_function_costs = *DEFAULT_HOST_FUNCTION_COSTS;
+        let min_gas_price = DEFAULT_MIN_GAS_PRICE;
+        let storage_cost_per_byte = DEFAULT_STORAGE_COST_PER_BYTE;
+        let proof_of_stake_params = *DEFAULT_PROOF_OF_STAKE_PARAMS;
 
         GenesisConfig::new(
             name,
@@ -196,6 +219,10 @@ fn should_fail_if_bad_pos_install_contract_is_provided() {
             pos_installer_bytes,
             accounts,
             wasm_costs,
+            host_function_costs,
+            min_gas_price,
+            storage_cost_per_byte,
+            proof_of_stake_params,
         )
     };
 
@@ -6,6 +6,7 @@ use crate::support::{
 };
 use contract_ffi::{
     key::Key,
+    system_contracts::pos::Params,
     uref::{AccessRights, URef},
     value::{
         account::{PublicKey, PurseId},
@@ -21,8 +22,8 @@ const SYSTEM_ADDR: [u8; 32] = [0u8; 32];
 const DEPLOY_HASH_2: [u8; 32] = [2u8; 32];
 const N_VALIDATORS: u8 = 5;
 
-// one named_key for each validator and three for the purses
-const EXPECTED_KNOWN_KEYS_LEN: usize = (N_VALIDATORS as usize) + 3;
+// one named_key for each validator, three for the purses and one for each parameter
+const EXPECTED_KNOWN_KEYS_LEN: usize = (N_VALIDATORS as usize) + 3 + 11;
 
 const POS_BONDING_PURSE: &str = "pos_bonding_purse";
 const POS_PAYMENT_PURSE: &str = "pos_payment_purse";
@@ -58,7 +59,7 @@ fn should_run_pos_install_contract() {
         "pos_install.wasm",
         DEFAULT_BLOCK_TIME,
         DEPLOY_HASH_2,
-        (mint_uref, genesis_validators),
+        (mint_uref, genesis_validators, Params::default()),
         vec![mint_uref],
     )
     .expect("should run successfully");
@@ -103,6 +104,44 @@ fn should_run_pos_install_contract() {
     assert_eq!(rewards_purse_balance, U512::zero());
 }
 
+#[ignore]
+#[test]
+fn should_not_run_pos_install_contract_with_invalid_params() {
+    let mut builder = WasmTestBuilder::default();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT_01,
+        (SYSTEM_ADDR,),
+    )
+    .build();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+
+    let mint_uref = URef::new(builder.get_mint_contract_uref().addr(), AccessRights::READ);
+    let genesis_validators: BTreeMap<PublicKey, U512> = (1u8..=N_VALIDATORS)
+        .map(|i| (PublicKey::new([i; 32]), U512::from(i)))
+        .collect();
+    let params = Params {
+        proposer_reward_share: 1_000_001,
+        ..Default::default()
+    };
+
+    let result: Option<(URef, _, _)> = exec_with_return::exec(
+        &mut builder,
+        SYSTEM_ADDR,
+        "pos_install.wasm",
+        DEFAULT_BLOCK_TIME,
+        DEPLOY_HASH_2,
+        (mint_uref, genesis_validators, params),
+        vec![mint_uref],
+    );
+    assert!(result.is_none(), "should reject invalid params");
+}
+
 fn get_purse(named_keys: &BTreeMap<String, Key>, name: &str) -> Option<PurseId> {
     named_keys
         .get(name)
@@ -5,14 +5,14 @@ use contract_ffi::{
     key::Key,
     value::{
         account::{PublicKey, PurseId},
-        Value, U512,
+        U512,
     },
 };
 use engine_core::engine_state::{
     genesis::{GenesisAccount, POS_BONDING_PURSE},
     CONV_RATE,
 };
-use engine_shared::{motes::Motes, transform::Transform};
+use engine_shared::motes::Motes;
 
 use crate::{
     support::test_support::{self, ExecuteRequestBuilder, InMemoryWasmTestBuilder},
@@ -80,8 +80,6 @@ fn should_run_successful_bond_and_unbond() {
         .get_account(DEFAULT_ACCOUNT_ADDR)
         .expect("should get account 1");
 
-    let pos = result.builder().get_pos_contract_uref();
-
     let exec_request_1 = ExecuteRequestBuilder::standard(
         DEFAULT_ACCOUNT_ADDR,
         CONTRACT_POS_BONDING,
@@ -89,10 +87,13 @@ fn should_run_successful_bond_and_unbond() {
     )
     .build();
 
+    // The bond takes effect in the `step` of the next block.
     let result = InMemoryWasmTestBuilder::from_result(result)
         .exec(exec_request_1)
         .expect_success()
         .commit()
+        .step(0)
+        .commit()
         .finish();
 
     let exec_response = result
@@ -101,19 +102,8 @@ fn should_run_successful_bond_and_unbond() {
         .expect("should have exec response");
     let mut genesis_gas_cost = test_support::get_exec_costs(&exec_response)[0];
 
-    let transforms = &result.builder().get_transforms()[0];
-
-    let pos_transform = &transforms[&Key::from(pos).normalize()];
-
     // Verify that genesis account is in validator queue
-    let contract = if let Transform::Write(Value::Contract(contract)) = pos_transform {
-        contract
-    } else {
-        panic!(
-            "pos transform is expected to be of AddKeys variant but received {:?}",
-            pos_transform
-        );
-    };
+    let contract = result.builder().get_pos_contract();
 
     let lookup_key = format!(
         "v_{}_{}",
@@ -158,6 +148,8 @@ fn should_run_successful_bond_and_unbond() {
         .exec(exec_request_3)
         .expect_success()
         .commit()
+        .step(0)
+        .commit()
         .finish();
 
     let exec_response = result
@@ -171,21 +163,8 @@ fn should_run_successful_bond_and_unbond() {
         .get_account(ACCOUNT_1_ADDR)
         .expect("should get account 1");
 
-    let pos = result.builder().get_pos_contract_uref();
-
-    let transforms = &result.builder().get_transforms()[1];
-
-    let pos_transform = &transforms[&Key::from(pos).normalize()];
-
-    // Verify that genesis account is in validator queue
-    let contract = if let Transform::Write(Value::Contract(contract)) = pos_transform {
-        contract
-    } else {
-        panic!(
-            "pos transform is expected to be of AddKeys variant but received {:?}",
-            pos_transform
-        );
-    };
+    // Verify that account 1 is in validator queue
+    let contract = result.builder().get_pos_contract();
 
     let lookup_key = format!(
         "v_{}_{}",
@@ -204,7 +183,7 @@ fn should_run_successful_bond_and_unbond() {
 
     //
     // Stage 2a - Account 1 unbonds by decreasing less than 50% (and is still in the
-    // queue)
+    // queue). The unbonded motes are paid out in the `step` of the next block.
     //
     let exec_request_4 = ExecuteRequestBuilder::standard(
         ACCOUNT_1_ADDR,
@@ -220,6 +199,8 @@ fn should_run_successful_bond_and_unbond() {
         .exec(exec_request_4)
         .expect_success()
         .commit()
+        .step(0)
+        .commit()
         .finish();
 
     let account_1_bal_after = result.builder().get_purse_balance(account_1.purse_id());
@@ -277,6 +258,8 @@ fn should_run_successful_bond_and_unbond() {
         .exec(exec_request_5)
         .expect_success()
         .commit()
+        .step(0)
+        .commit()
         .finish();
 
     let exec_response = result
@@ -325,6 +308,8 @@ fn should_run_successful_bond_and_unbond() {
         .exec(exec_request_6)
         .expect_success()
         .commit()
+        .step(0)
+        .commit()
         .finish();
 
     let account_1_bal_after = result.builder().get_purse_balance(account_1.purse_id());
@@ -373,6 +358,8 @@ fn should_run_successful_bond_and_unbond() {
         .exec(exec_request_7)
         .expect_success()
         .commit()
+        .step(0)
+        .commit()
         .finish();
 
     let exec_response = result
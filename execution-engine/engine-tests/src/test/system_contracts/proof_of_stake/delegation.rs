@@ -0,0 +1,153 @@
+use base16;
+
+use contract_ffi::value::{account::PublicKey, U512};
+use engine_core::engine_state::genesis::GenesisAccount;
+use engine_shared::motes::Motes;
+
+use crate::{
+    support::test_support::{self, ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    test::{DEFAULT_ACCOUNTS, DEFAULT_ACCOUNT_ADDR},
+};
+
+const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
+const VALIDATOR_ADDR: [u8; 32] = [42u8; 32];
+const VALIDATOR_STAKE: u64 = 1_000_000;
+const DELEGATION_1: u64 = 300_000;
+const DELEGATION_2: u64 = 200_000;
+
+const TEST_DELEGATE: &str = "delegate";
+const TEST_UNDELEGATE: &str = "undelegate";
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        tmp.push(GenesisAccount::new(
+            PublicKey::new(VALIDATOR_ADDR),
+            Motes::new(VALIDATOR_STAKE.into()),
+            Motes::new(VALIDATOR_STAKE.into()),
+        ));
+        tmp
+    };
+    let genesis_config = test_support::create_genesis_config(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+    builder
+}
+
+fn get_validator_weight(builder: &InMemoryWasmTestBuilder) -> Option<U512> {
+    builder
+        .get_bonded_validators()
+        .last()
+        .and_then(|bonded_validators| bonded_validators.get(&PublicKey::new(VALIDATOR_ADDR)))
+        .cloned()
+}
+
+fn delegation_key_name(amount: u64) -> String {
+    format!(
+        "d_{}_{}_{}",
+        base16::encode_lower(&VALIDATOR_ADDR),
+        base16::encode_lower(&DEFAULT_ACCOUNT_ADDR),
+        amount
+    )
+}
+
+#[ignore]
+#[test]
+fn should_report_delegated_weight_of_validator() {
+    let mut builder = setup();
+
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (
+            String::from(TEST_DELEGATE),
+            U512::from(DELEGATION_1),
+            PublicKey::new(VALIDATOR_ADDR),
+        ),
+    )
+    .build();
+    // The delegation takes effect in the `step` of the next block.
+    builder
+        .exec(exec_request_1)
+        .expect_success()
+        .commit()
+        .step(0)
+        .commit();
+
+    assert_eq!(
+        get_validator_weight(&builder),
+        Some(U512::from(VALIDATOR_STAKE + DELEGATION_1))
+    );
+    assert!(builder
+        .get_pos_contract()
+        .named_keys()
+        .contains_key(&delegation_key_name(DELEGATION_1)));
+
+    let exec_request_2 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (
+            String::from(TEST_UNDELEGATE),
+            Some(U512::from(DELEGATION_2)),
+            PublicKey::new(VALIDATOR_ADDR),
+        ),
+    )
+    .build();
+    builder.exec(exec_request_2).expect_success().commit();
+
+    assert_eq!(
+        get_validator_weight(&builder),
+        Some(U512::from(VALIDATOR_STAKE + DELEGATION_1 - DELEGATION_2))
+    );
+    assert!(builder
+        .get_pos_contract()
+        .named_keys()
+        .contains_key(&delegation_key_name(DELEGATION_1 - DELEGATION_2)));
+
+    let exec_request_3 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (
+            String::from(TEST_UNDELEGATE),
+            None::<U512>,
+            PublicKey::new(VALIDATOR_ADDR),
+        ),
+    )
+    .build();
+    builder.exec(exec_request_3).expect_success().commit();
+
+    assert_eq!(
+        get_validator_weight(&builder),
+        Some(U512::from(VALIDATOR_STAKE))
+    );
+    assert!(!builder
+        .get_pos_contract()
+        .named_keys()
+        .keys()
+        .any(|name| name.starts_with("d_")));
+}
+
+#[ignore]
+#[test]
+fn should_not_delegate_to_unbonded_validator() {
+    let mut builder = setup();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (
+            String::from(TEST_DELEGATE),
+            U512::from(DELEGATION_1),
+            PublicKey::new([1u8; 32]),
+        ),
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    assert!(builder.is_error());
+    assert_eq!(
+        get_validator_weight(&builder),
+        Some(U512::from(VALIDATOR_STAKE))
+    );
+}
@@ -0,0 +1,141 @@
+use contract_ffi::{
+    key::Key,
+    value::{
+        account::{PublicKey, PurseId},
+        U512,
+    },
+};
+use engine_core::engine_state::genesis::{GenesisAccount, POS_BONDING_PURSE, POS_REWARDS_PURSE};
+use engine_shared::motes::Motes;
+
+use crate::{
+    support::test_support::{self, ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    test::{DEFAULT_ACCOUNTS, DEFAULT_ACCOUNT_ADDR},
+};
+
+const CONTRACT_LOCAL_STATE: &str = "local_state.wasm";
+const ACCOUNT_1_ADDR: [u8; 32] = [1u8; 32];
+const ACCOUNT_1_BOND: u64 = 1_000;
+const ACCOUNT_2_ADDR: [u8; 32] = [2u8; 32];
+const ACCOUNT_2_BOND: u64 = 3_000;
+const ACCOUNT_BALANCE: u64 = 10_000;
+/// The share of the rewards going to the proposer, as set in the PoS contract.
+const PROPOSER_REWARD_SHARE: u64 = 100_000;
+
+fn get_pos_purse_balance(builder: &InMemoryWasmTestBuilder, purse_name: &str) -> U512 {
+    let purse_id = builder
+        .get_pos_contract()
+        .named_keys()
+        .get(purse_name)
+        .and_then(Key::as_uref)
+        .map(|uref| PurseId::new(*uref))
+        .expect("should find PoS purse");
+    builder.get_purse_balance(purse_id)
+}
+
+/// Sets up two bonded validators and runs a deploy, so that its payment ends up in the rewards
+/// purse.  Returns the builder and the amount of rewards.
+fn setup() -> (InMemoryWasmTestBuilder, U512) {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        tmp.push(GenesisAccount::new(
+            PublicKey::new(ACCOUNT_1_ADDR),
+            Motes::new(ACCOUNT_BALANCE.into()),
+            Motes::new(ACCOUNT_1_BOND.into()),
+        ));
+        tmp.push(GenesisAccount::new(
+            PublicKey::new(ACCOUNT_2_ADDR),
+            Motes::new(ACCOUNT_BALANCE.into()),
+            Motes::new(ACCOUNT_2_BOND.into()),
+        ));
+        tmp
+    };
+    let genesis_config = test_support::create_genesis_config(accounts);
+
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_LOCAL_STATE, ()).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&genesis_config)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let rewards = get_pos_purse_balance(&builder, POS_REWARDS_PURSE);
+    assert!(!rewards.is_zero(), "payment should be in the rewards purse");
+    (builder, rewards)
+}
+
+fn get_stake(builder: &InMemoryWasmTestBuilder, validator: [u8; 32]) -> U512 {
+    builder
+        .get_bonded_validators()
+        .last()
+        .and_then(|bonded_validators| bonded_validators.get(&PublicKey::new(validator)))
+        .cloned()
+        .expect("should be bonded")
+}
+
+#[ignore]
+#[test]
+fn should_distribute_rewards_pro_rata_to_stakes() {
+    let (mut builder, rewards) = setup();
+    let bonding_purse_balance = get_pos_purse_balance(&builder, POS_BONDING_PURSE);
+
+    builder
+        .distribute_rewards(PublicKey::new(ACCOUNT_1_ADDR), Vec::new())
+        .expect_success()
+        .commit();
+
+    let proposer_reward = rewards * PROPOSER_REWARD_SHARE / 1_000_000;
+    let shared_rewards = rewards - proposer_reward;
+    let total_stake = ACCOUNT_1_BOND + ACCOUNT_2_BOND;
+    let account_1_reward = proposer_reward + shared_rewards * ACCOUNT_1_BOND / total_stake;
+    let account_2_reward = shared_rewards * ACCOUNT_2_BOND / total_stake;
+
+    assert_eq!(
+        get_stake(&builder, ACCOUNT_1_ADDR),
+        U512::from(ACCOUNT_1_BOND) + account_1_reward
+    );
+    assert_eq!(
+        get_stake(&builder, ACCOUNT_2_ADDR),
+        U512::from(ACCOUNT_2_BOND) + account_2_reward
+    );
+
+    // The distributed rewards are moved to the bonding purse, and only the remainder of the
+    // division is left behind
+    let distributed = account_1_reward + account_2_reward;
+    assert_eq!(
+        get_pos_purse_balance(&builder, POS_BONDING_PURSE),
+        bonding_purse_balance + distributed
+    );
+    assert_eq!(
+        get_pos_purse_balance(&builder, POS_REWARDS_PURSE),
+        rewards - distributed
+    );
+}
+
+#[ignore]
+#[test]
+fn should_distribute_rewards_only_to_finality_signers() {
+    let (mut builder, rewards) = setup();
+
+    builder
+        .distribute_rewards(
+            PublicKey::new(ACCOUNT_1_ADDR),
+            vec![PublicKey::new(ACCOUNT_2_ADDR)],
+        )
+        .expect_success()
+        .commit();
+
+    // The proposer only gets its share for proposing the block
+    let proposer_reward = rewards * PROPOSER_REWARD_SHARE / 1_000_000;
+    assert_eq!(
+        get_stake(&builder, ACCOUNT_1_ADDR),
+        U512::from(ACCOUNT_1_BOND) + proposer_reward
+    );
+    assert_eq!(
+        get_stake(&builder, ACCOUNT_2_ADDR),
+        U512::from(ACCOUNT_2_BOND) + (rewards - proposer_reward)
+    );
+}
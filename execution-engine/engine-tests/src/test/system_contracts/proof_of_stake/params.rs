@@ -0,0 +1,226 @@
+use contract_ffi::{
+    key::Key,
+    system_contracts::pos::Params,
+    value::{ProtocolVersion, Value, U512},
+};
+use engine_core::engine_state::genesis::GenesisConfig;
+use engine_shared::{additive_map::AdditiveMap, transform::Transform};
+
+use crate::{
+    support::test_support::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder,
+    },
+    test::{DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG, DEFAULT_PROOF_OF_STAKE_PARAMS},
+};
+
+const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
+const PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
+const DEFAULT_ACTIVATION_POINT: u64 = 1;
+const BOND_AMOUNT: u64 = 1_000;
+
+const TEST_BOND: &str = "bond";
+
+fn custom_params() -> Params {
+    Params {
+        bond_delay: 1,
+        unbond_delay: 2,
+        max_bond_len: 3,
+        max_unbond_len: 4,
+        max_spread: U512::from(5),
+        max_increase: U512::from(6),
+        max_decrease: U512::from(7),
+        max_rel_increase: 8,
+        max_rel_decrease: 9,
+        proposer_reward_share: 10,
+        commission_rate: 11,
+    }
+}
+
+fn genesis_config_with_params(params: Params) -> GenesisConfig {
+    let default_config = &*DEFAULT_GENESIS_CONFIG;
+    GenesisConfig::new(
+        default_config.name().to_string(),
+        default_config.timestamp(),
+        default_config.protocol_version(),
+        default_config.mint_installer_bytes().to_vec(),
+        default_config.proof_of_stake_installer_bytes().to_vec(),
+        default_config.accounts().to_vec(),
+        default_config.wasm_costs(),
+        default_config.host_function_costs(),
+        default_config.min_gas_price(),
+        default_config.storage_cost_per_byte(),
+        params,
+    )
+}
+
+fn assert_params(builder: &InMemoryWasmTestBuilder, params: Params) {
+    let pos_contract = Key::URef(builder.get_pos_contract_uref());
+    for (name, expected) in params.to_named_values() {
+        let value: Value = builder
+            .query(None, pos_contract, &[name])
+            .unwrap_or_else(|| panic!("should have PoS param {}", name));
+        assert_eq!(value, expected, "unexpected value of PoS param {}", name);
+    }
+}
+
+#[ignore]
+#[test]
+fn should_store_proof_of_stake_params_at_genesis() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config_with_params(custom_params()));
+
+    assert_params(&builder, custom_params());
+}
+
+#[ignore]
+#[test]
+fn should_upgrade_proof_of_stake_params() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_GENESIS_CONFIG);
+    assert_params(&builder, *DEFAULT_PROOF_OF_STAKE_PARAMS);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_new_proof_of_stake_params(custom_params())
+        .build();
+
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+    assert!(upgrade_response.has_success(), "expected success");
+
+    assert_params(&builder, custom_params());
+}
+
+/// Removes the parameters from the named keys of the PoS contract, like those of a contract
+/// installed before they were introduced.
+fn remove_params(builder: &mut InMemoryWasmTestBuilder) {
+    let mut pos_contract = builder.get_pos_contract();
+    for (name, _) in Params::default().to_named_values() {
+        pos_contract.named_keys_mut().remove(name);
+    }
+    let mut effects = AdditiveMap::new();
+    effects.insert(
+        Key::URef(builder.get_pos_contract_uref()).normalize(),
+        Transform::Write(Value::Contract(pos_contract)),
+    );
+    let prestate = builder.get_post_state_hash();
+    builder.commit_effects(prestate, effects);
+}
+
+fn upgrade_params(builder: &mut InMemoryWasmTestBuilder, maybe_params: Option<Params>) {
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let mut upgrade_request_builder = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT);
+    if let Some(params) = maybe_params {
+        upgrade_request_builder = upgrade_request_builder.with_new_proof_of_stake_params(params);
+    }
+    let mut upgrade_request = upgrade_request_builder.build();
+
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+    assert!(upgrade_response.has_success(), "expected success");
+}
+
+#[ignore]
+#[test]
+fn should_add_missing_proof_of_stake_params_on_upgrade() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_GENESIS_CONFIG);
+    remove_params(&mut builder);
+
+    upgrade_params(&mut builder, Some(custom_params()));
+
+    assert_params(&builder, custom_params());
+}
+
+#[ignore]
+#[test]
+fn should_add_default_proof_of_stake_params_on_upgrade_without_params() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_GENESIS_CONFIG);
+    remove_params(&mut builder);
+
+    upgrade_params(&mut builder, None);
+
+    assert_params(&builder, Params::default());
+}
+
+#[ignore]
+#[test]
+fn should_reject_bond_exceeding_max_bond_len() {
+    let params = Params {
+        max_bond_len: 0,
+        ..Default::default()
+    };
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config_with_params(params));
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_BOND), U512::from(BOND_AMOUNT)),
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    assert!(builder.is_error(), "bonding should fail with a full queue");
+}
+
+#[ignore]
+#[test]
+#[should_panic]
+fn should_not_run_genesis_with_invalid_proof_of_stake_params() {
+    let params = Params {
+        commission_rate: 1_000_001,
+        ..Default::default()
+    };
+    InMemoryWasmTestBuilder::default().run_genesis(&genesis_config_with_params(params));
+}
+
+#[ignore]
+#[test]
+fn should_not_upgrade_to_invalid_proof_of_stake_params() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_GENESIS_CONFIG);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_new_proof_of_stake_params(Params {
+            proposer_reward_share: 1_000_001,
+            ..Default::default()
+        })
+        .build();
+
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+    assert!(
+        upgrade_response.has_failed_deploy(),
+        "should have failed deploy"
+    );
+}
@@ -0,0 +1,114 @@
+use contract_ffi::{
+    key::Key,
+    value::{
+        account::{PublicKey, PurseId},
+        U512,
+    },
+};
+use engine_core::engine_state::genesis::{GenesisAccount, POS_BONDING_PURSE, POS_REWARDS_PURSE};
+use engine_shared::motes::Motes;
+
+use crate::{
+    support::test_support::{self, InMemoryWasmTestBuilder},
+    test::DEFAULT_ACCOUNTS,
+};
+
+const ACCOUNT_1_ADDR: [u8; 32] = [1u8; 32];
+const ACCOUNT_1_BOND: u64 = 1_000;
+const ACCOUNT_2_ADDR: [u8; 32] = [2u8; 32];
+const ACCOUNT_2_BOND: u64 = 3_000;
+const ACCOUNT_BALANCE: u64 = 10_000;
+
+fn get_pos_purse_balance(builder: &InMemoryWasmTestBuilder, purse_name: &str) -> U512 {
+    let purse_id = builder
+        .get_pos_contract()
+        .named_keys()
+        .get(purse_name)
+        .and_then(Key::as_uref)
+        .map(|uref| PurseId::new(*uref))
+        .expect("should find PoS purse");
+    builder.get_purse_balance(purse_id)
+}
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        tmp.push(GenesisAccount::new(
+            PublicKey::new(ACCOUNT_1_ADDR),
+            Motes::new(ACCOUNT_BALANCE.into()),
+            Motes::new(ACCOUNT_1_BOND.into()),
+        ));
+        tmp.push(GenesisAccount::new(
+            PublicKey::new(ACCOUNT_2_ADDR),
+            Motes::new(ACCOUNT_BALANCE.into()),
+            Motes::new(ACCOUNT_2_BOND.into()),
+        ));
+        tmp
+    };
+    let genesis_config = test_support::create_genesis_config(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_confiscate_stake_of_slashed_validator() {
+    let mut builder = setup();
+    let bonding_purse_balance = get_pos_purse_balance(&builder, POS_BONDING_PURSE);
+    let rewards_purse_balance = get_pos_purse_balance(&builder, POS_REWARDS_PURSE);
+
+    builder
+        .slash(vec![PublicKey::new(ACCOUNT_2_ADDR)])
+        .expect_success()
+        .commit();
+
+    let bonded_validators = builder
+        .get_bonded_validators()
+        .last()
+        .cloned()
+        .expect("should have bonded validators");
+    assert!(!bonded_validators.contains_key(&PublicKey::new(ACCOUNT_2_ADDR)));
+    assert_eq!(
+        bonded_validators.get(&PublicKey::new(ACCOUNT_1_ADDR)),
+        Some(&U512::from(ACCOUNT_1_BOND))
+    );
+
+    // The confiscated stake is moved to the rewards purse
+    let slashed = U512::from(ACCOUNT_2_BOND);
+    assert_eq!(
+        get_pos_purse_balance(&builder, POS_BONDING_PURSE),
+        bonding_purse_balance - slashed
+    );
+    assert_eq!(
+        get_pos_purse_balance(&builder, POS_REWARDS_PURSE),
+        rewards_purse_balance + slashed
+    );
+}
+
+#[ignore]
+#[test]
+fn should_ignore_validators_which_are_not_bonded() {
+    let mut builder = setup();
+    let bonding_purse_balance = get_pos_purse_balance(&builder, POS_BONDING_PURSE);
+
+    builder
+        .slash(vec![PublicKey::new([42u8; 32])])
+        .expect_success()
+        .commit();
+
+    let bonded_validators = builder
+        .get_bonded_validators()
+        .last()
+        .cloned()
+        .expect("should have bonded validators");
+    assert_eq!(
+        bonded_validators.get(&PublicKey::new(ACCOUNT_2_ADDR)),
+        Some(&U512::from(ACCOUNT_2_BOND))
+    );
+    assert_eq!(
+        get_pos_purse_balance(&builder, POS_BONDING_PURSE),
+        bonding_purse_balance
+    );
+}
@@ -0,0 +1,251 @@
+use std::convert::TryInto;
+
+use contract_ffi::{
+    bytesrepr::ToBytes,
+    key::Key,
+    system_contracts::pos::Params,
+    value::{
+        account::{PublicKey, PurseId},
+        U512,
+    },
+};
+use engine_core::engine_state::genesis::{GenesisAccount, GenesisConfig};
+use engine_grpc_server::engine_server::ipc::{CommitResult, UnbondPayout};
+use engine_shared::motes::Motes;
+
+use crate::{
+    support::test_support::{self, ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    test::{DEFAULT_ACCOUNTS, DEFAULT_ACCOUNT_ADDR},
+};
+
+const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+const ACCOUNT_1_ADDR: [u8; 32] = [1u8; 32];
+const ACCOUNT_2_ADDR: [u8; 32] = [2u8; 32];
+const ACCOUNT_BALANCE: u64 = 1_000_000_000;
+const ACCOUNT_BOND: u64 = 1_000_000;
+const UNBOND_AMOUNT: u64 = 400_000;
+const UNBOND_DELAY: u64 = 10;
+const UNBOND_TIME: u64 = 1;
+
+const TEST_UNBOND: &str = "unbond";
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        for addr in &[ACCOUNT_1_ADDR, ACCOUNT_2_ADDR] {
+            tmp.push(GenesisAccount::new(
+                PublicKey::new(*addr),
+                Motes::new(ACCOUNT_BALANCE.into()),
+                Motes::new(ACCOUNT_BOND.into()),
+            ));
+        }
+        tmp
+    };
+    let genesis_config = {
+        let default_config = test_support::create_genesis_config(accounts);
+        GenesisConfig::new(
+            default_config.name().to_string(),
+            default_config.timestamp(),
+            default_config.protocol_version(),
+            default_config.mint_installer_bytes().to_vec(),
+            default_config.proof_of_stake_installer_bytes().to_vec(),
+            default_config.accounts().to_vec(),
+            default_config.wasm_costs(),
+            default_config.host_function_costs(),
+            default_config.min_gas_price(),
+            default_config.storage_cost_per_byte(),
+            Params {
+                unbond_delay: UNBOND_DELAY,
+                ..Default::default()
+            },
+        )
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+    builder
+}
+
+/// Runs the PoS `step` at `block_time` and commits its effects on top of the builder's post state,
+/// without updating the builder's post state.
+fn step(builder: &mut InMemoryWasmTestBuilder, block_time: u64) -> CommitResult {
+    builder.step(block_time).expect_success();
+    let transforms = builder
+        .get_transforms()
+        .last()
+        .cloned()
+        .expect("should have step transforms");
+    let commit_response = builder.commit_transforms(builder.get_post_state_hash(), transforms);
+    assert!(commit_response.has_success(), "step should be committed");
+    commit_response.get_success().clone()
+}
+
+fn get_purse_balance_at(
+    builder: &InMemoryWasmTestBuilder,
+    post_state: Vec<u8>,
+    purse_id: PurseId,
+) -> U512 {
+    let mint = builder.get_mint_contract_uref();
+    let purse_bytes = purse_id
+        .value()
+        .addr()
+        .to_bytes()
+        .expect("should serialize purse address");
+    let balance_uref: Key = builder
+        .query(
+            Some(post_state.clone()),
+            Key::local(mint.addr(), &purse_bytes),
+            &[],
+        )
+        .and_then(|value| value.try_into().ok())
+        .expect("should find balance uref");
+    builder
+        .query(Some(post_state), balance_uref, &[])
+        .and_then(|value| value.try_into().ok())
+        .expect("should find balance")
+}
+
+fn unbond(builder: &mut InMemoryWasmTestBuilder) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_UNBOND), Some(U512::from(UNBOND_AMOUNT))),
+    )
+    .with_block_time(UNBOND_TIME)
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+}
+
+fn parse_payout_amount(unbond_payout: &UnbondPayout) -> U512 {
+    unbond_payout
+        .get_amount()
+        .clone()
+        .try_into()
+        .expect("should parse amount")
+}
+
+#[ignore]
+#[test]
+fn should_pay_out_matured_unbonds_in_step() {
+    let mut builder = setup();
+    unbond(&mut builder);
+
+    // Unbonding decreases the stake immediately, but the motes are only paid out after the delay.
+    let bonded_validators = builder
+        .get_bonded_validators()
+        .last()
+        .cloned()
+        .expect("should have bonded validators");
+    assert_eq!(
+        bonded_validators.get(&PublicKey::new(ACCOUNT_1_ADDR)),
+        Some(&U512::from(ACCOUNT_BOND - UNBOND_AMOUNT))
+    );
+    let purse_id = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account 1")
+        .purse_id();
+    let balance_before = builder.get_purse_balance(purse_id);
+
+    // A step before the unbond matured pays nothing out.
+    let commit_result = step(&mut builder, UNBOND_TIME + UNBOND_DELAY - 1);
+    assert!(commit_result.get_unbond_payouts().is_empty());
+    assert_eq!(
+        get_purse_balance_at(
+            &builder,
+            commit_result.get_poststate_hash().to_vec(),
+            purse_id
+        ),
+        balance_before
+    );
+
+    // The first step after the unbond matured pays it out to the validator's main purse, and the
+    // commit reports the payout.
+    let commit_result = step(&mut builder, UNBOND_TIME + UNBOND_DELAY);
+    let unbond_payouts = commit_result.get_unbond_payouts();
+    assert_eq!(unbond_payouts.len(), 1);
+    assert_eq!(unbond_payouts[0].get_public_key(), &ACCOUNT_1_ADDR[..]);
+    assert_eq!(
+        parse_payout_amount(&unbond_payouts[0]),
+        U512::from(UNBOND_AMOUNT)
+    );
+    assert_eq!(
+        get_purse_balance_at(
+            &builder,
+            commit_result.get_poststate_hash().to_vec(),
+            purse_id
+        ),
+        balance_before + U512::from(UNBOND_AMOUNT)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_pay_out_matured_unbonds_only_once() {
+    let mut builder = setup();
+    unbond(&mut builder);
+
+    builder
+        .step(UNBOND_TIME + UNBOND_DELAY)
+        .expect_success()
+        .commit();
+    let purse_id = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account 1")
+        .purse_id();
+    let balance_after_payout = builder.get_purse_balance(purse_id);
+
+    // The payout was removed from the queue, so stepping again pays nothing out.
+    let commit_result = step(&mut builder, UNBOND_TIME + UNBOND_DELAY);
+    assert!(commit_result.get_unbond_payouts().is_empty());
+    assert_eq!(
+        get_purse_balance_at(
+            &builder,
+            commit_result.get_poststate_hash().to_vec(),
+            purse_id
+        ),
+        balance_after_payout
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_run_step_when_executing_deploys() {
+    let mut builder = setup();
+    unbond(&mut builder);
+
+    let purse_id = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account 1")
+        .purse_id();
+    let balance_before = builder.get_purse_balance(purse_id);
+
+    for (sequential, parallel) in &[(false, false), (true, false), (false, true)] {
+        let exec_request =
+            ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_DO_NOTHING, ())
+                .with_block_time(UNBOND_TIME + UNBOND_DELAY)
+                .with_sequential(*sequential)
+                .with_parallel(*parallel)
+                .build();
+        builder.exec(exec_request).expect_success();
+
+        // Only the deploy's own effects are returned, so the matured unbond is not paid out.
+        let transforms = builder
+            .get_transforms()
+            .last()
+            .cloned()
+            .expect("should have deploy transforms");
+        let commit_response = builder.commit_transforms(builder.get_post_state_hash(), transforms);
+        assert!(commit_response.has_success(), "deploy should be committed");
+        let commit_result = commit_response.get_success();
+        assert!(commit_result.get_unbond_payouts().is_empty());
+        assert_eq!(
+            get_purse_balance_at(
+                &builder,
+                commit_result.get_poststate_hash().to_vec(),
+                purse_id
+            ),
+            balance_before
+        );
+    }
+}
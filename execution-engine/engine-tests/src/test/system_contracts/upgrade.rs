@@ -5,13 +5,18 @@ use contract_ffi::{
 use engine_core::engine_state::{upgrade::ActivationPoint, Error};
 use engine_grpc_server::engine_server::ipc::DeployCode;
 use engine_shared::transform::Transform;
-use engine_wasm_prep::wasm_costs::WasmCosts;
+use engine_wasm_prep::{
+    host_function_costs::{HostFunctionCost, HostFunctionCosts},
+    wasm_costs::WasmCosts,
+};
 
 use crate::{
     support::test_support::{
         self, ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder,
     },
-    test::{DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG, DEFAULT_WASM_COSTS},
+    test::{
+        DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG, DEFAULT_MIN_GAS_PRICE, DEFAULT_WASM_COSTS,
+    },
 };
 
 const PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
@@ -35,6 +40,15 @@ fn get_upgraded_wasm_costs() -> WasmCosts {
     }
 }
 
+fn get_upgraded_host_function_costs() -> HostFunctionCosts {
+    HostFunctionCosts {
+        read_value: HostFunctionCost::new(1, 1),
+        write: HostFunctionCost::new(1, 1),
+        call_contract: HostFunctionCost::new(1, 1),
+        ..Default::default()
+    }
+}
+
 #[ignore]
 #[test]
 fn should_upgrade_only_protocol_version() {
@@ -393,6 +407,107 @@ fn should_allow_only_wasm_costs_minor_version() {
     );
 }
 
+#[ignore]
+#[test]
+fn should_allow_only_host_function_costs_patch_version() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&*DEFAULT_GENESIS_CONFIG);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 2);
+
+    let new_host_function_costs = get_upgraded_host_function_costs();
+
+    let mut upgrade_request = {
+        UpgradeRequestBuilder::new()
+            .with_current_protocol_version(PROTOCOL_VERSION)
+            .with_new_protocol_version(new_protocol_version)
+            .with_activation_point(DEFAULT_ACTIVATION_POINT)
+            .with_new_host_function_costs(new_host_function_costs)
+            .build()
+    };
+
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+
+    assert!(upgrade_response.has_success(), "expected success");
+
+    let upgraded_host_function_costs = builder
+        .get_engine_state()
+        .host_function_costs(new_protocol_version)
+        .expect("should have result")
+        .expect("should have upgraded host function costs");
+
+    assert_eq!(
+        new_host_function_costs, upgraded_host_function_costs,
+        "upgraded host function costs should equal new host function costs"
+    );
+
+    let upgraded_wasm_costs = builder
+        .get_engine_state()
+        .wasm_costs(new_protocol_version)
+        .expect("should have result")
+        .expect("should have wasm costs");
+
+    assert_eq!(
+        *DEFAULT_WASM_COSTS, upgraded_wasm_costs,
+        "wasm costs should be carried over when not upgraded"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_allow_only_min_gas_price_patch_version() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&*DEFAULT_GENESIS_CONFIG);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 2);
+
+    let new_min_gas_price = DEFAULT_MIN_GAS_PRICE + 1;
+
+    let mut upgrade_request = {
+        UpgradeRequestBuilder::new()
+            .with_current_protocol_version(PROTOCOL_VERSION)
+            .with_new_protocol_version(new_protocol_version)
+            .with_activation_point(DEFAULT_ACTIVATION_POINT)
+            .with_new_min_gas_price(new_min_gas_price)
+            .build()
+    };
+
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+
+    assert!(upgrade_response.has_success(), "expected success");
+
+    let upgraded_protocol_data = builder
+        .get_engine_state()
+        .get_protocol_data(new_protocol_version)
+        .expect("should have result")
+        .expect("should have upgraded protocol data");
+
+    assert_eq!(
+        new_min_gas_price,
+        upgraded_protocol_data.min_gas_price(),
+        "upgraded min gas price should equal new min gas price"
+    );
+    assert_eq!(
+        *DEFAULT_WASM_COSTS,
+        *upgraded_protocol_data.wasm_costs(),
+        "wasm costs should be carried over when not upgraded"
+    );
+}
+
 #[ignore]
 #[test]
 fn should_upgrade_system_contract_and_wasm_costs_major() {
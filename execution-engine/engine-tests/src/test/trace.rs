@@ -0,0 +1,144 @@
+use std::convert::TryInto;
+
+use contract_ffi::{
+    key::Key,
+    value::{account::PublicKey, U512},
+};
+use engine_grpc_server::engine_server::{
+    ipc::{DeployResult_ExecutionResult, ExecutionTrace_Entry},
+    state,
+};
+
+use crate::{
+    support::test_support::{self, ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    test::{DEFAULT_ACCOUNT_ADDR, DEFAULT_GENESIS_CONFIG},
+};
+
+const CONTRACT_GET_CALLER_SUBCALL: &str = "get_caller_subcall.wasm";
+const CONTRACT_REVERT: &str = "revert.wasm";
+
+fn execute(session_file: &str, trace: bool) -> DeployResult_ExecutionResult {
+    let exec_request = match session_file {
+        CONTRACT_GET_CALLER_SUBCALL => ExecuteRequestBuilder::standard(
+            DEFAULT_ACCOUNT_ADDR,
+            session_file,
+            (PublicKey::new(DEFAULT_ACCOUNT_ADDR),),
+        ),
+        _ => ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, session_file, ()),
+    }
+    .with_trace(trace)
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    test_support::get_success_result(response)
+}
+
+fn key(pb_key: &state::Key) -> Key {
+    pb_key.clone().try_into().expect("should map to Key")
+}
+
+/// Returns the entries recorded for session code, which follow the payment code entries and
+/// start with the second call frame at depth 0.
+fn session_entries(entries: &[ExecutionTrace_Entry]) -> &[ExecutionTrace_Entry] {
+    let session_start = entries
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| entry.has_call() && entry.get_call().get_depth() == 0)
+        .map(|(index, _)| index)
+        .nth(1)
+        .expect("should have a session call frame");
+    &entries[session_start..]
+}
+
+#[ignore]
+#[test]
+fn should_not_return_trace_by_default() {
+    let execution_result = execute(CONTRACT_GET_CALLER_SUBCALL, false);
+    assert!(!execution_result.has_error());
+    assert!(!execution_result.has_trace());
+}
+
+#[ignore]
+#[test]
+fn should_trace_host_calls_and_call_frames() {
+    let execution_result = execute(CONTRACT_GET_CALLER_SUBCALL, true);
+    assert!(!execution_result.has_error());
+    assert!(execution_result.has_trace());
+
+    let entries = session_entries(execution_result.get_trace().get_entries());
+
+    let first_call = entries[0].get_call();
+    assert_eq!(
+        key(first_call.get_key()),
+        Key::Account(DEFAULT_ACCOUNT_ADDR)
+    );
+
+    let sub_call_index = entries
+        .iter()
+        .position(|entry| entry.has_call() && entry.get_call().get_depth() == 1)
+        .expect("should have entered the called contract");
+    assert!(key(entries[sub_call_index].get_call().get_key())
+        .as_hash()
+        .is_some());
+
+    let host_calls: Vec<(u32, &str)> = entries[sub_call_index..]
+        .iter()
+        .filter(|entry| entry.has_host_call())
+        .map(|entry| {
+            let host_call = entry.get_host_call();
+            (host_call.get_depth(), host_call.get_name())
+        })
+        .collect();
+    assert_eq!(host_calls[0], (1, "get_caller"));
+    assert!(host_calls.contains(&(0, "call_contract")));
+
+    for entry in entries.iter().filter(|entry| entry.has_host_call()) {
+        let host_call = entry.get_host_call();
+        let gas_before: U512 = host_call
+            .get_gas_before()
+            .clone()
+            .try_into()
+            .expect("should map to U512");
+        let gas_after: U512 = host_call
+            .get_gas_after()
+            .clone()
+            .try_into()
+            .expect("should map to U512");
+        assert!(gas_before <= gas_after);
+    }
+
+    assert!(entries.iter().all(|entry| !entry.has_trap()));
+}
+
+#[ignore]
+#[test]
+fn should_trace_trap_of_reverted_deploy() {
+    let execution_result = execute(CONTRACT_REVERT, true);
+    assert!(execution_result.has_error());
+
+    let entries = session_entries(execution_result.get_trace().get_entries());
+
+    let revert = entries
+        .iter()
+        .find(|entry| entry.has_host_call())
+        .expect("should have called revert")
+        .get_host_call();
+    assert_eq!(revert.get_name(), "revert");
+    assert_eq!(revert.get_depth(), 0);
+
+    let trap = entries
+        .iter()
+        .find(|entry| entry.has_trap())
+        .expect("should have a trap")
+        .get_trap();
+    assert_eq!(trap.get_depth(), 0);
+    assert_eq!(key(trap.get_key()), Key::Account(DEFAULT_ACCOUNT_ADDR));
+    assert!(trap.get_message().contains("Revert"));
+}
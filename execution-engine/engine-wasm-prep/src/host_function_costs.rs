@@ -0,0 +1,296 @@
+use contract_ffi::bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH};
+
+const HOST_FUNCTION_COST_NUM_FIELDS: usize = 2;
+pub const HOST_FUNCTION_COST_SERIALIZED_LENGTH: usize =
+    HOST_FUNCTION_COST_NUM_FIELDS * U32_SERIALIZED_LENGTH;
+
+const NUM_FIELDS: usize = 25;
+pub const HOST_FUNCTION_COSTS_SERIALIZED_LENGTH: usize =
+    NUM_FIELDS * HOST_FUNCTION_COST_SERIALIZED_LENGTH;
+
+/// Cost of a single host function call.
+///
+/// The total cost of a call is `base + per_byte * n`, where `n` is the number of bytes passed
+/// across the host boundary by that call.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct HostFunctionCost {
+    /// Flat cost charged on every call
+    pub base: u32,
+    /// Cost charged per byte of data passed to or returned from the host
+    pub per_byte: u32,
+}
+
+impl HostFunctionCost {
+    pub fn new(base: u32, per_byte: u32) -> Self {
+        HostFunctionCost { base, per_byte }
+    }
+}
+
+impl ToBytes for HostFunctionCost {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret: Vec<u8> = Vec::with_capacity(HOST_FUNCTION_COST_SERIALIZED_LENGTH);
+        ret.append(&mut self.base.to_bytes()?);
+        ret.append(&mut self.per_byte.to_bytes()?);
+        Ok(ret)
+    }
+}
+
+impl FromBytes for HostFunctionCost {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (base, rem): (u32, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (per_byte, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        Ok((HostFunctionCost { base, per_byte }, rem))
+    }
+}
+
+/// Costs of the host functions exposed to contracts by the runtime.
+///
+/// Host functions which are closely related share a single entry.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct HostFunctionCosts {
+    /// Cost of `read_value` and `read_value_local`
+    pub read_value: HostFunctionCost,
+    /// Cost of `write` and `write_local`
+    pub write: HostFunctionCost,
+    /// Cost of `add`
+    pub add: HostFunctionCost,
+    /// Cost of `new_uref`
+    pub new_uref: HostFunctionCost,
+    /// Cost of `load_arg`, `get_arg_size` and `get_arg`
+    pub get_arg: HostFunctionCost,
+    /// Cost of `ret`
+    pub ret: HostFunctionCost,
+    /// Cost of `call_contract`
+    pub call_contract: HostFunctionCost,
+    /// Cost of `get_key`
+    pub get_key: HostFunctionCost,
+    /// Cost of `has_key`
+    pub has_key: HostFunctionCost,
+    /// Cost of `put_key`
+    pub put_key: HostFunctionCost,
+    /// Cost of `remove_key`
+    pub remove_key: HostFunctionCost,
+    /// Cost of `serialize_named_keys` and `list_named_keys`
+    pub list_named_keys: HostFunctionCost,
+    /// Cost of `store_function`, `store_function_at_hash`, `store_contract` and `store_module`
+    pub store_function: HostFunctionCost,
+    /// Cost of `upgrade_contract_at_uref`
+    pub upgrade_contract_at_uref: HostFunctionCost,
+    /// Cost of the associated key and action threshold management functions
+    pub manage_keys: HostFunctionCost,
+    /// Cost of `create_purse`
+    pub create_purse: HostFunctionCost,
+    /// Cost of `transfer_to_account`, `transfer_from_purse_to_account` and
+    /// `transfer_from_purse_to_purse`
+    pub transfer: HostFunctionCost,
+    /// Cost of `get_balance`
+    pub get_balance: HostFunctionCost,
+    /// Cost of copying the host buffer into Wasm memory (`get_read`, `get_function` and
+    /// `get_call_result`)
+    pub host_buffer: HostFunctionCost,
+    /// Cost of the remaining functions which query the execution context (`get_caller`,
+    /// `get_blocktime`, `get_phase`, `get_main_purse`, `get_system_contract`, `is_valid`
+    /// and `revert`)
+    pub context: HostFunctionCost,
+    /// Cost of `emit_event`
+    pub emit_event: HostFunctionCost,
+    /// Cost of `get_call_stack`
+    pub get_call_stack: HostFunctionCost,
+    /// Cost of `delete`
+    pub delete: HostFunctionCost,
+    /// Cost of `schedule_call`
+    pub schedule_call: HostFunctionCost,
+    /// Cost of `call_entry_point`
+    pub call_entry_point: HostFunctionCost,
+}
+
+impl ToBytes for HostFunctionCosts {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret: Vec<u8> = Vec::with_capacity(HOST_FUNCTION_COSTS_SERIALIZED_LENGTH);
+        ret.append(&mut self.read_value.to_bytes()?);
+        ret.append(&mut self.write.to_bytes()?);
+        ret.append(&mut self.add.to_bytes()?);
+        ret.append(&mut self.new_uref.to_bytes()?);
+        ret.append(&mut self.get_arg.to_bytes()?);
+        ret.append(&mut self.ret.to_bytes()?);
+        ret.append(&mut self.call_contract.to_bytes()?);
+        ret.append(&mut self.get_key.to_bytes()?);
+        ret.append(&mut self.has_key.to_bytes()?);
+        ret.append(&mut self.put_key.to_bytes()?);
+        ret.append(&mut self.remove_key.to_bytes()?);
+        ret.append(&mut self.list_named_keys.to_bytes()?);
+        ret.append(&mut self.store_function.to_bytes()?);
+        ret.append(&mut self.upgrade_contract_at_uref.to_bytes()?);
+        ret.append(&mut self.manage_keys.to_bytes()?);
+        ret.append(&mut self.create_purse.to_bytes()?);
+        ret.append(&mut self.transfer.to_bytes()?);
+        ret.append(&mut self.get_balance.to_bytes()?);
+        ret.append(&mut self.host_buffer.to_bytes()?);
+        ret.append(&mut self.context.to_bytes()?);
+        ret.append(&mut self.emit_event.to_bytes()?);
+        ret.append(&mut self.get_call_stack.to_bytes()?);
+        ret.append(&mut self.delete.to_bytes()?);
+        ret.append(&mut self.schedule_call.to_bytes()?);
+        ret.append(&mut self.call_entry_point.to_bytes()?);
+        Ok(ret)
+    }
+}
+
+impl FromBytes for HostFunctionCosts {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (read_value, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (write, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (add, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (new_uref, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (get_arg, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (ret, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (call_contract, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (get_key, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (has_key, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (put_key, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (remove_key, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (list_named_keys, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (store_function, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (upgrade_contract_at_uref, rem): (HostFunctionCost, &[u8]) =
+            FromBytes::from_bytes(rem)?;
+        let (manage_keys, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (create_purse, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (transfer, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (get_balance, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (host_buffer, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (context, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (emit_event, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (get_call_stack, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (delete, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (schedule_call, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (call_entry_point, rem): (HostFunctionCost, &[u8]) = FromBytes::from_bytes(rem)?;
+        let host_function_costs = HostFunctionCosts {
+            read_value,
+            write,
+            add,
+            new_uref,
+            get_arg,
+            ret,
+            call_contract,
+            get_key,
+            has_key,
+            put_key,
+            remove_key,
+            list_named_keys,
+            store_function,
+            upgrade_contract_at_uref,
+            manage_keys,
+            create_purse,
+            transfer,
+            get_balance,
+            host_buffer,
+            context,
+            emit_event,
+            get_call_stack,
+            delete,
+            schedule_call,
+            call_entry_point,
+        };
+        Ok((host_function_costs, rem))
+    }
+}
+
+pub mod gens {
+    use proptest::{num, prop_compose};
+
+    use crate::host_function_costs::{HostFunctionCost, HostFunctionCosts};
+
+    prop_compose! {
+        pub fn host_function_cost_arb()(
+            base in num::u32::ANY,
+            per_byte in num::u32::ANY,
+        ) -> HostFunctionCost {
+            HostFunctionCost { base, per_byte }
+        }
+    }
+
+    prop_compose! {
+        pub fn host_function_costs_arb()(
+            read_value in host_function_cost_arb(),
+            write in host_function_cost_arb(),
+            add in host_function_cost_arb(),
+            new_uref in host_function_cost_arb(),
+            get_arg in host_function_cost_arb(),
+            ret in host_function_cost_arb(),
+            call_contract in host_function_cost_arb(),
+            get_key in host_function_cost_arb(),
+            has_key in host_function_cost_arb(),
+            put_key in host_function_cost_arb(),
+            remove_key in host_function_cost_arb(),
+            list_named_keys in host_function_cost_arb(),
+            store_function in host_function_cost_arb(),
+            upgrade_contract_at_uref in host_function_cost_arb(),
+            manage_keys in host_function_cost_arb(),
+            create_purse in host_function_cost_arb(),
+            transfer in host_function_cost_arb(),
+            get_balance in host_function_cost_arb(),
+            host_buffer in host_function_cost_arb(),
+            context in host_function_cost_arb(),
+            emit_event in host_function_cost_arb(),
+            get_call_stack in host_function_cost_arb(),
+            delete in host_function_cost_arb(),
+            schedule_call in host_function_cost_arb(),
+            call_entry_point in host_function_cost_arb(),
+        ) -> HostFunctionCosts {
+            HostFunctionCosts {
+                read_value,
+                write,
+                add,
+                new_uref,
+                get_arg,
+                ret,
+                call_contract,
+                get_key,
+                has_key,
+                put_key,
+                remove_key,
+                list_named_keys,
+                store_function,
+                upgrade_contract_at_uref,
+                manage_keys,
+                create_purse,
+                transfer,
+                get_balance,
+                host_buffer,
+                context,
+                emit_event,
+                get_call_stack,
+                delete,
+                schedule_call,
+                call_entry_point,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use contract_ffi::bytesrepr;
+    use engine_shared::test_utils;
+
+    use super::gens;
+
+    #[test]
+    fn should_serialize_and_deserialize() {
+        let mock = test_utils::host_function_costs_mock();
+        let free = test_utils::host_function_costs_free();
+        bytesrepr::test_serialization_roundtrip(&mock);
+        bytesrepr::test_serialization_roundtrip(&free);
+    }
+
+    proptest! {
+        #[test]
+        fn should_serialize_and_deserialize_with_arbitrary_values(
+            host_function_costs in gens::host_function_costs_arb()
+        ) {
+            bytesrepr::test_serialization_roundtrip(&host_function_costs);
+        }
+    }
+}